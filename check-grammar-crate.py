@@ -43,6 +43,9 @@ NEW_SUFFIX = "-new"
 EXTENSIONS = {
     "tree-sitter-tsx": ["*.tsx"],
     "tree-sitter-typescript": ["*.ts", "*.jsw", "*.jsmw"],
+    "tree-sitter-hcl": ["*.hcl", "*.tf", "*.tfvars"],
+    "tree-sitter-c-sharp": ["*.cs"],
+    "tree-sitter-go": ["*.go"],
     "tree-sitter-java": ["*.java"],
     "tree-sitter-kotlin": ["*.kt", "*.kts"],
     "tree-sitter-rust": ["*.rs"],
@@ -23,6 +23,10 @@ pub fn sanitize_identifier(name: &str) -> String {
     if name == "Self" {
         return "SELF".to_string();
     }
+    // The names are C strings, so the `\0` token is empty
+    if name.is_empty() {
+        return "NULL".to_string();
+    }
 
     let mut result = String::with_capacity(name.len());
     for c in name.chars() {
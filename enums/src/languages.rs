@@ -4,6 +4,9 @@ mk_langs!(
     // 1) Name for enum
     // 2) tree-sitter function to call to get a Language
     (Kotlin, tree_sitter_kotlin_ng),
+    (Hcl, tree_sitter_hcl),
+    (Csharp, tree_sitter_c_sharp),
+    (Go, tree_sitter_go),
     (Java, tree_sitter_java),
     (Rust, tree_sitter_rust),
     (Cpp, tree_sitter_cpp),
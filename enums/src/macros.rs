@@ -20,6 +20,9 @@ macro_rules! mk_get_language {
         pub fn get_language(lang: &Lang) -> Language {
             match lang {
                 Lang::Kotlin => tree_sitter_kotlin_ng::LANGUAGE.into(),
+                Lang::Hcl => tree_sitter_hcl::LANGUAGE.into(),
+                Lang::Csharp => tree_sitter_c_sharp::LANGUAGE.into(),
+                Lang::Go => tree_sitter_go::LANGUAGE.into(),
                 Lang::Java => tree_sitter_java::LANGUAGE.into(),
                 Lang::Typescript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
                 Lang::Tsx => tree_sitter_typescript::LANGUAGE_TSX.into(),
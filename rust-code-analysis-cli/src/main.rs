@@ -2,6 +2,7 @@ mod formats;
 
 use std::cmp::Ordering;
 use std::collections::{HashMap, hash_map};
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 use std::process;
 use std::sync::{Arc, Mutex};
@@ -10,6 +11,7 @@ use std::thread::available_parallelism;
 use clap::Parser;
 use clap::builder::{PossibleValuesParser, TypedValueParser};
 use globset::{Glob, GlobSet, GlobSetBuilder};
+use regex::Regex;
 
 use formats::Format;
 
@@ -17,16 +19,18 @@ use formats::Format;
 use rust_code_analysis::LANG;
 
 // Structs
+use rust_code_analysis::nom::NomExclusions;
 use rust_code_analysis::{
     CommentRm, CommentRmCfg, ConcurrentRunner, Count, CountCfg, Dump, DumpCfg, FilesData, Find,
-    FindCfg, Function, FunctionCfg, Metrics, MetricsCfg, OpsCfg, OpsCode, PreprocParser,
-    PreprocResults,
+    FindCfg, Function, FunctionCfg, Metrics, MetricsCfg, MetricsOptions, OpsCfg, OpsCode,
+    PreprocParser, PreprocResults,
 };
 
 // Functions
 use rust_code_analysis::{
-    action, fix_includes, get_from_ext, get_function_spaces, get_ops, guess_language, preprocess,
-    read_file, read_file_with_eol, write_file,
+    action, extract_vue_script, fix_includes, folded_stacks, get_from_ext,
+    get_function_spaces_with_options, get_ops, guess_language, is_generated, preprocess, read_file,
+    read_file_with_eol, write_file,
 };
 
 // Traits
@@ -42,6 +46,8 @@ struct Config {
     language: Option<LANG>,
     function: bool,
     metrics: bool,
+    metrics_options: MetricsOptions,
+    folded: bool,
     ops: bool,
     output_format: Option<Format>,
     output: Option<PathBuf>,
@@ -51,6 +57,7 @@ struct Config {
     preproc_lock: Option<Arc<Mutex<PreprocResults>>>,
     preproc: Option<Arc<PreprocResults>>,
     count_lock: Option<Arc<Mutex<Count>>>,
+    skip_generated: bool,
 }
 
 fn mk_globset(elems: Vec<String>) -> GlobSet {
@@ -67,14 +74,41 @@ fn mk_globset(elems: Vec<String>) -> GlobSet {
     globset.build().map_or(GlobSet::empty(), |globset| globset)
 }
 
+// Parses an inclusive range of lines, such as `10-12`, or a single line
+fn parse_line_range(range: &str) -> Option<Range<usize>> {
+    let (start, end) = range.split_once('-').unwrap_or((range, range));
+    let start = start.trim().parse().ok()?;
+    let end: usize = end.trim().parse().ok()?;
+
+    (start <= end).then_some(start..end + 1)
+}
+
 fn act_on_file(path: PathBuf, cfg: &Config) -> std::io::Result<()> {
     let source = if let Some(source) = read_file_with_eol(&path)? {
         source
     } else {
         return Ok(());
     };
+    if cfg.skip_generated && is_generated(&source) {
+        return Ok(());
+    }
 
-    let language = if let Some(language) = cfg.language {
+    // Only the scripts of a Vue single-file component are analyzed,
+    // while its comments are never removed to avoid rewriting it
+    let (language, source) = if cfg.language.is_none()
+        && !cfg.comments
+        && path.extension().is_some_and(|ext| ext == "vue")
+    {
+        if let Some((language, script)) = extract_vue_script(&source) {
+            (Some(language), script)
+        } else {
+            return Ok(());
+        }
+    } else {
+        (cfg.language, source)
+    };
+
+    let language = if let Some(language) = language {
         language
     } else if let Some(language) = guess_language(&source, &path).0 {
         language
@@ -90,13 +124,25 @@ fn act_on_file(path: PathBuf, cfg: &Config) -> std::io::Result<()> {
         };
         action::<Dump>(&language, source, &path, pr, cfg)
     } else if cfg.metrics {
-        if let Some(output_format) = &cfg.output_format {
-            if let Some(space) = get_function_spaces(&language, source, &path, pr) {
+        if cfg.folded {
+            if let Some(space) =
+                get_function_spaces_with_options(&language, source, &path, pr, &cfg.metrics_options)
+            {
+                print!("{}", folded_stacks(&space));
+            }
+            Ok(())
+        } else if let Some(output_format) = &cfg.output_format {
+            if let Some(space) =
+                get_function_spaces_with_options(&language, source, &path, pr, &cfg.metrics_options)
+            {
                 output_format.dump_formats(space, path, cfg.output.as_ref(), cfg.pretty);
             }
             Ok(())
         } else {
-            let cfg = MetricsCfg { path };
+            let cfg = MetricsCfg {
+                path,
+                options: cfg.metrics_options.clone(),
+            };
             let path = cfg.path.clone();
             action::<Metrics>(&language, source, &path, pr, cfg)
         }
@@ -198,6 +244,96 @@ struct Opts {
     /// Compute different metrics.
     #[clap(long, short)]
     metrics: bool,
+    /// Only compute the metrics of the functions matching this regex.
+    #[clap(long, requires = "metrics")]
+    function_filter: Option<String>,
+    /// Count the suspension points of generators in the cyclomatic complexity.
+    #[clap(long, requires = "metrics")]
+    count_yields: bool,
+    /// Consider the closures passed to chains of method calls as functions.
+    #[clap(long, requires = "metrics")]
+    chained_closure_spaces: bool,
+    /// Consider the Python and C# lambdas as functions.
+    #[clap(long, requires = "metrics")]
+    lambda_spaces: bool,
+    /// Count the end of a function without a final return as an exit.
+    #[clap(long, requires = "metrics")]
+    implicit_exits: bool,
+    /// Kinds of methods not counted by the NOM metric: comma separated list.
+    #[clap(long, requires = "metrics", value_delimiter = ',',
+        value_parser = PossibleValuesParser::new(["constructors", "accessors", "static-methods"]))]
+    nom_exclude: Vec<String>,
+    /// Compute the metrics on the code without its comments, except for LOC.
+    #[clap(long, requires = "metrics")]
+    strip_comments: bool,
+    /// Only compute the metrics of the functions of the public API.
+    #[clap(long, requires = "metrics")]
+    public_only: bool,
+    /// Output the cyclomatic complexity of each space contained in a space.
+    #[clap(long, requires = "metrics")]
+    cyclomatic_values: bool,
+    /// Count the conditional compilation attributes, such as `#[cfg(test)]`.
+    #[clap(long, requires = "metrics")]
+    cfg_attributes: bool,
+    /// Count the members of the union types and the cases of the enums.
+    #[clap(long, requires = "metrics")]
+    type_complexity: bool,
+    /// Count the compile-time conditions of the C++ function templates.
+    #[clap(long, requires = "metrics")]
+    template_complexity: bool,
+    /// Compute the nesting depth of the literal data structures, such as objects and arrays.
+    #[clap(long, requires = "metrics")]
+    literal_nesting: bool,
+    /// Count the variants of the enums and the ones carrying data.
+    #[clap(long, requires = "metrics")]
+    enum_variants: bool,
+    /// Count the goroutines launched by the Go functions.
+    #[clap(long, requires = "metrics")]
+    goroutines: bool,
+    /// Compute the nesting depth of the expressions.
+    #[clap(long, requires = "metrics")]
+    expr_nesting: bool,
+    /// Count the returns placed before the last statement of the functions.
+    #[clap(long, requires = "metrics")]
+    early_returns: bool,
+    /// Count the variables captured by the closures.
+    #[clap(long, requires = "metrics")]
+    captures: bool,
+    /// Count the local variables declared by the functions.
+    #[clap(long, requires = "metrics")]
+    nlocals: bool,
+    /// Count the if statements without an else branch.
+    #[clap(long, requires = "metrics")]
+    bare_ifs: bool,
+    /// Count the distinct exception types handled by the functions.
+    #[clap(long, requires = "metrics")]
+    exception_types: bool,
+    /// Compute the length of the chains of method calls.
+    #[clap(long, requires = "metrics")]
+    chain_length: bool,
+    /// Count the statements of the functions.
+    #[clap(long, requires = "metrics")]
+    statement_count: bool,
+    /// Count the distinct types referenced by the functions.
+    #[clap(long, requires = "metrics")]
+    referenced_types: bool,
+    /// Count the negation operators and the double negations.
+    #[clap(long, requires = "metrics")]
+    negations: bool,
+    /// Count the conditional and the dynamic imports.
+    #[clap(long, requires = "metrics")]
+    conditional_imports: bool,
+    /// Count the unsafe blocks and functions.
+    #[clap(long, requires = "metrics")]
+    unsafe_code: bool,
+    /// Only compute the metrics of the functions overlapping these ranges
+    /// of lines, such as `10-12` or `7`: comma separated list.
+    #[clap(long, requires = "metrics", value_delimiter = ',')]
+    lines: Vec<String>,
+    /// Print the metrics as folded stacks for flamegraph tools,
+    /// weighted by the cyclomatic complexity of the functions.
+    #[clap(long, requires = "metrics", conflicts_with = "output_format")]
+    folded: bool,
     /// Retrieve all operands and operators in a code.
     #[clap(long, conflicts_with = "metrics")]
     ops: bool,
@@ -210,6 +346,9 @@ struct Opts {
     /// Glob to exclude files.
     #[clap(long, short = 'X', num_args(0..))]
     exclude: Vec<String>,
+    /// Skip the files marked as generated, such as `// Code generated ... DO NOT EDIT`.
+    #[clap(long)]
+    skip_generated: bool,
     /// Number of jobs.
     #[clap(long, short = 'j')]
     num_jobs: Option<usize>,
@@ -297,6 +436,25 @@ fn main() {
             ) - 1
         });
 
+    let function_filter = opts.function_filter.map(|filter| {
+        Regex::new(&filter).unwrap_or_else(|e| {
+            eprintln!("Error: Invalid function filter: {e}");
+            process::exit(1);
+        })
+    });
+
+    let line_ranges = (!opts.lines.is_empty()).then(|| {
+        opts.lines
+            .iter()
+            .map(|range| {
+                parse_line_range(range).unwrap_or_else(|| {
+                    eprintln!("Error: Invalid range of lines: {range}");
+                    process::exit(1);
+                })
+            })
+            .collect()
+    });
+
     let include = mk_globset(opts.include);
     let exclude = mk_globset(opts.exclude);
 
@@ -309,6 +467,42 @@ fn main() {
         language,
         function: opts.function,
         metrics: opts.metrics,
+        metrics_options: MetricsOptions {
+            function_filter,
+            count_yields: opts.count_yields,
+            chained_closure_spaces: opts.chained_closure_spaces,
+            lambda_spaces: opts.lambda_spaces,
+            implicit_exits: opts.implicit_exits,
+            nom_exclusions: NomExclusions {
+                constructors: opts.nom_exclude.iter().any(|kind| kind == "constructors"),
+                accessors: opts.nom_exclude.iter().any(|kind| kind == "accessors"),
+                static_methods: opts.nom_exclude.iter().any(|kind| kind == "static-methods"),
+            },
+            strip_comments: opts.strip_comments,
+            public_only: opts.public_only,
+            cyclomatic_values: opts.cyclomatic_values,
+            line_ranges,
+            cfg_attributes: opts.cfg_attributes,
+            type_complexity: opts.type_complexity,
+            template_complexity: opts.template_complexity,
+            literal_nesting: opts.literal_nesting,
+            enum_variants: opts.enum_variants,
+            goroutines: opts.goroutines,
+            expr_nesting: opts.expr_nesting,
+            early_returns: opts.early_returns,
+            captures: opts.captures,
+            nlocals: opts.nlocals,
+            bare_ifs: opts.bare_ifs,
+            exception_types: opts.exception_types,
+            chain_length: opts.chain_length,
+            statement_count: opts.statement_count,
+            referenced_types: opts.referenced_types,
+            negations: opts.negations,
+            conditional_imports: opts.conditional_imports,
+            unsafe_code: opts.unsafe_code,
+            ..Default::default()
+        },
+        folded: opts.folded,
         ops: opts.ops,
         output_format: opts.output_format,
         pretty: opts.pretty,
@@ -318,6 +512,7 @@ fn main() {
         preproc_lock: preproc_lock.clone(),
         preproc,
         count_lock: count_lock.clone(),
+        skip_generated: opts.skip_generated,
     };
 
     let files_data = FilesData {
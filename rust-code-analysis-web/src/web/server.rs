@@ -39,6 +39,7 @@ async fn ast_parser(item: web::Json<AstPayload>) -> HttpResponse {
             id: payload.id,
             comment: payload.comment,
             span: payload.span,
+            utf16: payload.utf16,
         };
 
         // TODO: the 4th arg should be preproc data
@@ -332,6 +333,7 @@ mod tests {
                 code: "int x = 1;".to_string(),
                 comment: false,
                 span: true,
+                utf16: false,
             })
             .to_request();
 
@@ -407,6 +409,7 @@ mod tests {
                 code: "var x = \"hello world\";".to_string(),
                 comment: false,
                 span: true,
+                utf16: false,
             })
             .to_request();
 
@@ -640,11 +643,12 @@ mod tests {
             "spaces": {"kind": "unit",
                        "start_line": 1,
                        "end_line": 4,
-                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min":1.0, "max":1.0},
+                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min":1.0, "max":1.0, "std_dev": 0.0, "median": 1.0},
                                    "cognitive": {"sum": 0.0, "average": 0.0, "min":0.0, "max":0.0},
                                    "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0},
                                    "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0},
                                    "halstead": {"bugs": 0.000_942_552_557_372_941_4,
+                                                "delivered_bugs": 0.001584962500721156,
                                                 "difficulty": 1.0,
                                                 "effort": 4.754_887_502_163_468,
                                                 "length": 3.0,
@@ -663,16 +667,20 @@ mod tests {
                                    "mi": {"mi_original": 139.974_331_558_152_1,
                                           "mi_sei": 161.414_455_240_662_22,
                                           "mi_visual_studio": 81.856_334_244_533_39},
-                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                   "npm": {"classes": 1.0, "interfaces": 0.0, "class_methods": 1.0, "interface_methods": 0.0, "classes_average": 1.0, "interfaces_average": null, "total": 1.0, "total_methods": 1.0, "average": 1.0},
+                                   "npa": {"classes": 0.0, "interfaces": 0.0, "class_attributes": 0.0, "interface_attributes": 0.0, "classes_average": null, "interfaces_average": null, "total": 0.0, "total_attributes": 0.0, "average": null},
+                                   "decisions": {"branches": 0.0, "loops": 0.0, "switches": 0.0, "catches": 0.0, "ratio": 0.0, "distinct": 0.0}},
                        "name": "test.py",
                        "spaces": [{"kind": "function",
                                    "start_line": 3,
                                    "end_line": 4,
-                                   "metrics": {"cyclomatic": {"sum": 1.0, "average": 1.0, "min":1.0, "max":1.0},
+                                   "metrics": {"cyclomatic": {"sum": 1.0, "average": 1.0, "min":1.0, "max":1.0, "std_dev": 0.0, "median": 1.0},
                                                "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0},
                                                "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0},
                                                "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0},
                                                "halstead": {"bugs": 0.000_942_552_557_372_941_4,
+                                                            "delivered_bugs": 0.001584962500721156,
                                                             "difficulty": 1.0,
                                                             "effort": 4.754_887_502_163_468,
                                                             "length": 3.0,
@@ -691,7 +699,8 @@ mod tests {
                                                "mi": {"mi_original": 151.433_315_883_223_23,
                                                       "mi_sei": 142.873_061_717_489_78,
                                                       "mi_visual_studio": 88.557_494_668_551_6},
-                                               "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                                               "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                               "decisions": {"branches": 0.0, "loops": 0.0, "switches": 0.0, "catches": 0.0, "ratio": 0.0, "distinct": 0.0}},
                                    "name": "foo",
                                    "spaces": []}]}
         });
@@ -722,11 +731,12 @@ mod tests {
             "spaces": {"kind": "unit",
                        "start_line": 1,
                        "end_line": 2,
-                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min":1.0, "max":1.0},
+                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min":1.0, "max":1.0, "std_dev": 0.0, "median": 1.0},
                                    "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0},
                                    "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0},
                                    "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0},
                                    "halstead": {"bugs": 0.000_942_552_557_372_941_4,
+                                                "delivered_bugs": 0.001584962500721156,
                                                 "difficulty": 1.0,
                                                 "effort": 4.754_887_502_163_468,
                                                 "length": 3.0,
@@ -745,7 +755,10 @@ mod tests {
                                    "mi": {"mi_original": 151.203_315_883_223_2,
                                           "mi_sei": 142.643_061_717_489_76,
                                           "mi_visual_studio": 88.422_991_744_574_97},
-                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                   "npm": {"classes": 1.0, "interfaces": 0.0, "class_methods": 1.0, "interface_methods": 0.0, "classes_average": 1.0, "interfaces_average": null, "total": 1.0, "total_methods": 1.0, "average": 1.0},
+                                   "npa": {"classes": 0.0, "interfaces": 0.0, "class_attributes": 0.0, "interface_attributes": 0.0, "classes_average": null, "interfaces_average": null, "total": 0.0, "total_attributes": 0.0, "average": null},
+                                   "decisions": {"branches": 0.0, "loops": 0.0, "switches": 0.0, "catches": 0.0, "ratio": 0.0, "distinct": 0.0}},
                        "name": "test.py",
                        "spaces": []}
         });
@@ -772,11 +785,12 @@ mod tests {
             "spaces": {"kind": "unit",
                        "start_line": 1,
                        "end_line": 2,
-                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min": 1.0,"max": 1.0},
+                       "metrics": {"cyclomatic": {"sum": 2.0, "average": 1.0, "min": 1.0,"max": 1.0, "std_dev": 0.0, "median": 1.0},
                                    "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0},
                                    "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0},
                                    "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0},
                                    "halstead": {"bugs": 0.000_942_552_557_372_941_4,
+                                                "delivered_bugs": 0.001584962500721156,
                                                 "difficulty": 1.0,
                                                 "effort": 4.754_887_502_163_468,
                                                 "length": 3.0,
@@ -795,16 +809,20 @@ mod tests {
                                    "mi": {"mi_original": 151.203_315_883_223_2,
                                           "mi_sei": 142.643_061_717_489_76,
                                           "mi_visual_studio": 88.422_991_744_574_97},
-                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                                   "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                   "npm": {"classes": 1.0, "interfaces": 0.0, "class_methods": 1.0, "interface_methods": 0.0, "classes_average": 1.0, "interfaces_average": null, "total": 1.0, "total_methods": 1.0, "average": 1.0},
+                                   "npa": {"classes": 0.0, "interfaces": 0.0, "class_attributes": 0.0, "interface_attributes": 0.0, "classes_average": null, "interfaces_average": null, "total": 0.0, "total_attributes": 0.0, "average": null},
+                                   "decisions": {"branches": 0.0, "loops": 0.0, "switches": 0.0, "catches": 0.0, "ratio": 0.0, "distinct": 0.0}},
                        "name": "test.py",
                        "spaces": [{"kind": "function",
                                    "start_line": 1,
                                    "end_line": 2,
-                                   "metrics": {"cyclomatic": {"sum": 1.0, "average": 1.0, "min": 1.0,"max": 1.0},
+                                   "metrics": {"cyclomatic": {"sum": 1.0, "average": 1.0, "min": 1.0,"max": 1.0, "std_dev": 0.0, "median": 1.0},
                                                "cognitive": {"sum": 0.0, "average": 0.0 , "min":0.0, "max":0.0},
                                                "nargs": {"total_functions": 0.0, "average_functions": 0.0, "total_closures": 0.0, "average_closures": 0.0, "total": 0.0, "average": 0.0, "closures_max": 0.0, "closures_min": 0.0, "functions_max": 0.0, "functions_min": 0.0},
                                                "nexits": {"sum": 0.0, "average": 0.0,"min":0.0,"max":0.0},
                                                "halstead": {"bugs": 0.000_942_552_557_372_941_4,
+                                                            "delivered_bugs": 0.001584962500721156,
                                                             "difficulty": 1.0,
                                                             "effort": 4.754_887_502_163_468,
                                                             "length": 3.0,
@@ -823,7 +841,8 @@ mod tests {
                                                "mi": {"mi_original": 151.433_315_883_223_23,
                                                       "mi_sei": 142.873_061_717_489_78,
                                                       "mi_visual_studio": 88.557_494_668_551_6},
-                                               "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0}},
+                                               "abc": {"assignments": 0.0, "branches": 0.0, "conditions": 0.0, "magnitude": 0.0, "assignments_average": 0.0, "branches_average": 0.0, "conditions_average": 0.0, "assignments_min": 0.0, "assignments_max": 0.0, "branches_min": 0.0, "branches_max": 0.0, "conditions_min": 0.0, "conditions_max": 0.0},
+                                               "decisions": {"branches": 0.0, "loops": 0.0, "switches": 0.0, "catches": 0.0, "ratio": 0.0, "distinct": 0.0}},
                                    "name": "foo",
                                    "spaces": []}]}
         });
@@ -0,0 +1,627 @@
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::langs::LANG;
+use crate::spaces::{CodeMetrics, FuncSpace, SpaceKind, metrics};
+use crate::traits::*;
+
+use crate::languages::*;
+
+/// The metrics of a single function, retained by a [`MetricsAggregate`].
+///
+/// [`MetricsAggregate`]: struct.MetricsAggregate.html
+#[derive(Debug, Clone, Serialize)]
+pub struct FunctionRecord {
+    /// The path of the file containing the function
+    pub path: PathBuf,
+    /// The name of the function
+    pub name: Option<String>,
+    /// The first line of the function
+    pub start_line: usize,
+    /// The last line of the function
+    pub end_line: usize,
+    /// All metrics of the function
+    pub metrics: CodeMetrics,
+}
+
+/// The metrics of many files merged together.
+///
+/// By default only the merged summary is kept, while an aggregate built
+/// with [`with_functions`] also retains a [`FunctionRecord`] for each
+/// function, so that it is still possible to drill down into the single
+/// functions of all files.
+///
+/// [`with_functions`]: #method.with_functions
+/// [`FunctionRecord`]: struct.FunctionRecord.html
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MetricsAggregate {
+    /// The metrics of all files merged together
+    ///
+    /// If `None`, no file has been added yet
+    pub summary: Option<CodeMetrics>,
+    /// The metrics of each function of all files
+    pub functions: Vec<FunctionRecord>,
+    #[serde(skip)]
+    keep_functions: bool,
+}
+
+impl MetricsAggregate {
+    /// Creates an aggregate which keeps only the merged summary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an aggregate which also retains the metrics
+    /// of each function.
+    pub fn with_functions() -> Self {
+        Self {
+            keep_functions: true,
+            ..Self::default()
+        }
+    }
+
+    /// Adds the metrics of a file to the aggregate.
+    ///
+    /// `space` must be the unit space of the file at `path`.
+    pub fn add(&mut self, path: &Path, space: &FuncSpace) {
+        match self.summary.as_mut() {
+            Some(summary) => summary.merge(&space.metrics),
+            None => self.summary = Some(space.metrics.clone()),
+        }
+
+        if self.keep_functions {
+            self.add_functions(path, space);
+        }
+    }
+
+    /// Returns the retained function with the greatest value
+    /// for the metric selected by `key`.
+    pub fn worst_function<F>(&self, key: F) -> Option<&FunctionRecord>
+    where
+        F: Fn(&CodeMetrics) -> f64,
+    {
+        self.functions
+            .iter()
+            .max_by(|a, b| key(&a.metrics).total_cmp(&key(&b.metrics)))
+    }
+
+    // Adds the metrics of a space which is not the unit one,
+    // retaining the space itself when it is a function
+    fn add_subspace(&mut self, path: &Path, space: &FuncSpace) {
+        match self.summary.as_mut() {
+            Some(summary) => summary.merge(&space.metrics),
+            None => self.summary = Some(space.metrics.clone()),
+        }
+
+        if self.keep_functions {
+            self.add_function(path, space);
+            self.add_functions(path, space);
+        }
+    }
+
+    fn add_function(&mut self, path: &Path, space: &FuncSpace) {
+        if matches!(space.kind, SpaceKind::Function | SpaceKind::TraitDefault) {
+            self.functions.push(FunctionRecord {
+                path: path.to_path_buf(),
+                name: space.name.clone(),
+                start_line: space.start_line,
+                end_line: space.end_line,
+                metrics: space.metrics.clone(),
+            });
+        }
+    }
+
+    fn add_functions(&mut self, path: &Path, space: &FuncSpace) {
+        for subspace in &space.spaces {
+            self.add_function(path, subspace);
+            self.add_functions(path, subspace);
+        }
+    }
+}
+
+/// The metrics of many files split into production and test code,
+/// so that they can be tracked separately.
+///
+/// A file is considered test code when its path follows one of the
+/// usual conventions, as checked by [`is_test_path`].
+/// When the code of a file is added with [`add_code`], the test
+/// functions and modules it contains are split from its production
+/// code too.
+///
+/// [`is_test_path`]: fn.is_test_path.html
+/// [`add_code`]: #method.add_code
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SplitAggregate {
+    /// The metrics of the production code
+    pub production: MetricsAggregate,
+    /// The metrics of the test code
+    pub tests: MetricsAggregate,
+}
+
+impl SplitAggregate {
+    /// Creates a split aggregate which keeps only the merged summaries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a split aggregate which also retains the metrics
+    /// of each function.
+    pub fn with_functions() -> Self {
+        Self {
+            production: MetricsAggregate::with_functions(),
+            tests: MetricsAggregate::with_functions(),
+        }
+    }
+
+    /// Adds the metrics of a file to the production or to the test aggregate,
+    /// according to its path.
+    ///
+    /// `space` must be the unit space of the file at `path`.
+    pub fn add(&mut self, path: &Path, space: &FuncSpace) {
+        if is_test_path(path) {
+            self.tests.add(path, space);
+        } else {
+            self.production.add(path, space);
+        }
+    }
+
+    /// Adds the metrics of the code of a file to the production and to
+    /// the test aggregates, according to its path and to its attributes.
+    ///
+    /// A file whose path is a test one is entirely test code, otherwise
+    /// the items marked as test code by an attribute, as returned by
+    /// [`test_items`], are split from the rest of the file:
+    /// the metrics of their spaces are added to the test aggregate,
+    /// while the ones of the file without them are added to the
+    /// production aggregate.
+    ///
+    /// Returns `false` when the metrics of the file cannot be computed.
+    ///
+    /// [`test_items`]: fn.test_items.html
+    pub fn add_code<T: ParserTrait>(&mut self, parser: &T, path: &Path) -> bool {
+        let Some(space) = metrics(parser, path) else {
+            return false;
+        };
+        if is_test_path(path) {
+            self.tests.add(path, &space);
+            return true;
+        }
+
+        let items = test_items(parser);
+        if items.is_empty() {
+            self.production.add(path, &space);
+            return true;
+        }
+
+        // Blank out the test items, keeping the newlines so that
+        // the lines of the production code are unchanged
+        let mut code = parser.get_code().to_vec();
+        for &(start, end) in &items {
+            for byte in &mut code[start..end] {
+                if *byte != b'\n' {
+                    *byte = b' ';
+                }
+            }
+        }
+        let production = T::new(code, path, None);
+        let Some(production) = metrics(&production, path) else {
+            return false;
+        };
+        self.production.add(path, &production);
+
+        let lines: Vec<_> = items
+            .iter()
+            .map(|&(start, end)| {
+                (
+                    line_of(parser.get_code(), start),
+                    line_of(parser.get_code(), end),
+                )
+            })
+            .collect();
+        self.add_test_spaces(path, &space, &lines);
+
+        true
+    }
+
+    fn add_test_spaces(&mut self, path: &Path, space: &FuncSpace, lines: &[(usize, usize)]) {
+        for subspace in &space.spaces {
+            if lines
+                .iter()
+                .any(|&(start, end)| (start..=end).contains(&subspace.start_line))
+            {
+                self.tests.add_subspace(path, subspace);
+            } else {
+                self.add_test_spaces(path, subspace, lines);
+            }
+        }
+    }
+
+    /// Returns the metrics of production and test code merged together.
+    ///
+    /// If `None`, no file has been added yet.
+    pub fn total(&self) -> Option<CodeMetrics> {
+        match (&self.production.summary, &self.tests.summary) {
+            (Some(production), Some(tests)) => {
+                let mut total = production.clone();
+                total.merge(tests);
+                Some(total)
+            }
+            (summary, None) | (None, summary) => summary.clone(),
+        }
+    }
+}
+
+/// Returns whether a path is conventionally the one of test code.
+///
+/// A path is a test one when it is contained in a `test`, `tests`,
+/// `__tests__` or `spec` directory, or when its file name follows the
+/// test naming of a language, such as `test_*.py`, `*_test.rs`,
+/// `*.test.js`, `*.spec.ts` or `*Test.java`.
+pub fn is_test_path(path: &Path) -> bool {
+    let in_test_dir = path.parent().is_some_and(|parent| {
+        parent.components().any(|component| {
+            matches!(
+                component.as_os_str().to_str(),
+                Some("test" | "tests" | "__tests__" | "spec")
+            )
+        })
+    });
+    if in_test_dir {
+        return true;
+    }
+
+    let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+        return false;
+    };
+    stem.starts_with("test_")
+        || stem.ends_with("_test")
+        || stem.ends_with("_tests")
+        || stem.ends_with("_unittest")
+        || stem.ends_with(".test")
+        || stem.ends_with(".spec")
+        || stem.ends_with("Test")
+        || stem.ends_with("Tests")
+}
+
+/// Returns the byte ranges of the items of a code which are marked
+/// as test code by an attribute.
+///
+/// Only Rust attributes are detected: an item is test code when it is
+/// annotated with `#[test]`, `#[cfg(test)]` or a test attribute of a
+/// crate, such as `#[tokio::test]`, while a `#![cfg(test)]` attribute
+/// at the top of a file makes the whole file test code.
+/// The range of an item also contains its attributes.
+///
+/// For any other language, the test code is only detected by path
+/// with [`is_test_path`], so the returned vector is always empty.
+///
+/// [`is_test_path`]: fn.is_test_path.html
+pub fn test_items<T: ParserTrait>(parser: &T) -> Vec<(usize, usize)> {
+    if parser.get_language() != LANG::Rust {
+        return Vec::new();
+    }
+
+    let root = parser.get_root();
+    let code = parser.get_code();
+    let mut items: Vec<(usize, usize)> = Vec::new();
+
+    root.act_on_node(&mut |n| {
+        match n.kind_id().into() {
+            Rust::AttributeItem => {
+                if !n.utf8_text(code).is_some_and(is_test_attribute) {
+                    return;
+                }
+                let mut item = n.next_sibling();
+                while let Some(sibling) = item {
+                    if !matches!(
+                        sibling.kind_id().into(),
+                        Rust::AttributeItem | Rust::LineComment | Rust::BlockComment
+                    ) {
+                        break;
+                    }
+                    item = sibling.next_sibling();
+                }
+                // The attributes preceding the test one belong to the item too
+                let mut start = *n;
+                while let Some(sibling) = start.previous_sibling() {
+                    if sibling.kind_id() != Rust::AttributeItem {
+                        break;
+                    }
+                    start = sibling;
+                }
+                if let Some(item) = item {
+                    items.push((start.start_byte(), item.end_byte()));
+                }
+            }
+            Rust::InnerAttributeItem
+                if n.parent().is_some_and(|parent| parent.id() == root.id())
+                    && n.utf8_text(code).is_some_and(is_test_attribute) =>
+            {
+                items.push((0, code.len()));
+            }
+            _ => {}
+        }
+    });
+
+    // Drop the items contained in another one
+    items.sort_by_key(|&(start, end)| (start, std::cmp::Reverse(end)));
+    let mut outer: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in items {
+        if outer.last().is_none_or(|&(_, last_end)| start >= last_end) {
+            outer.push((start, end));
+        }
+    }
+    outer
+}
+
+fn is_test_attribute(text: &str) -> bool {
+    let text: String = text.split_whitespace().collect();
+    let attribute = text
+        .trim_start_matches('#')
+        .trim_start_matches('!')
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    let path = attribute.split('(').next().unwrap_or(attribute);
+    attribute == "cfg(test)" || path == "test" || path.ends_with("::test")
+}
+
+fn line_of(code: &[u8], byte: usize) -> usize {
+    code[..byte].iter().filter(|&&b| b == b'\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{PythonParser, RustParser, get_function_spaces};
+
+    use super::*;
+
+    fn aggregate(keep_functions: bool) -> MetricsAggregate {
+        let files = [
+            ("a.rs", "fn a() { if true {} }"),
+            (
+                "b.rs",
+                "fn b1() {}
+                 fn b2(x: i32) {
+                     match x {
+                         0 => {}
+                         1 => {}
+                         2 => {}
+                         _ => {}
+                     }
+                 }",
+            ),
+            ("c.rs", "impl S { fn c(&self) { while true {} } }"),
+        ];
+
+        let mut aggregate = if keep_functions {
+            MetricsAggregate::with_functions()
+        } else {
+            MetricsAggregate::new()
+        };
+        for (path, source) in files {
+            let path = PathBuf::from(path);
+            let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+            aggregate.add(&path, &metrics(&parser, &path).unwrap());
+        }
+        aggregate
+    }
+
+    #[test]
+    fn keep_functions() {
+        let aggregate = aggregate(true);
+        let summary = aggregate.summary.as_ref().unwrap();
+
+        let names: Vec<_> = aggregate
+            .functions
+            .iter()
+            .map(|record| record.name.as_deref().unwrap())
+            .collect();
+        assert_eq!(names, ["a", "b1", "b2", "c"]);
+        assert_eq!(summary.nom.functions_sum(), 4.);
+
+        let worst = aggregate
+            .worst_function(|metrics| metrics.cyclomatic.cyclomatic())
+            .unwrap();
+        assert_eq!(worst.name.as_deref(), Some("b2"));
+        assert_eq!(worst.path, PathBuf::from("b.rs"));
+        assert_eq!(
+            worst.metrics.cyclomatic.cyclomatic(),
+            summary.cyclomatic.cyclomatic_max()
+        );
+    }
+
+    #[test]
+    fn summary_only() {
+        let aggregate = aggregate(false);
+
+        assert!(aggregate.functions.is_empty());
+        assert_eq!(aggregate.summary.unwrap().nom.functions_sum(), 4.);
+    }
+
+    #[test]
+    fn test_paths() {
+        for path in [
+            "tests/foo.rs",
+            "src/test/java/FooTest.java",
+            "lib/__tests__/foo.js",
+            "test_foo.py",
+            "foo_test.rs",
+            "foo_unittest.cc",
+            "foo.test.js",
+            "foo.spec.ts",
+            "FooTests.java",
+        ] {
+            assert!(is_test_path(Path::new(path)), "{path}");
+        }
+        for path in ["src/foo.rs", "src/testing.rs", "contest.py", "tests"] {
+            assert!(!is_test_path(Path::new(path)), "{path}");
+        }
+    }
+
+    #[test]
+    fn split_tests() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = [
+            ("src/lib.rs", "fn a(x: bool) { if x {} }"),
+            ("src/b.rs", "fn b1() {}\nfn b2(x: bool) { while x {} }"),
+            ("tests/c.rs", "#[test]\nfn c() { assert!(true); }"),
+            ("tests/common/mod.rs", "fn d(x: bool) { if x {} else {} }"),
+        ];
+        for (path, source) in files {
+            let path = dir.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, source).unwrap();
+        }
+
+        let mut split = SplitAggregate::new();
+        let mut total = MetricsAggregate::new();
+        let mut dirs = vec![dir.path().to_path_buf()];
+        while let Some(current) = dirs.pop() {
+            for entry in fs::read_dir(current).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                    continue;
+                }
+                let relative = path.strip_prefix(dir.path()).unwrap();
+                let source = fs::read(&path).unwrap();
+                let space = get_function_spaces(&LANG::Rust, source, relative, None).unwrap();
+                split.add(relative, &space);
+                total.add(relative, &space);
+            }
+        }
+
+        let production = split.production.summary.as_ref().unwrap();
+        let tests = split.tests.summary.as_ref().unwrap();
+        let total = total.summary.unwrap();
+        let merged = split.total().unwrap();
+
+        assert_eq!(production.nom.functions_sum(), 3.);
+        assert_eq!(tests.nom.functions_sum(), 2.);
+        assert_eq!(production.cyclomatic.cyclomatic_sum(), 7.);
+        assert_eq!(tests.cyclomatic.cyclomatic_sum(), 5.);
+        for metrics in [&total, &merged] {
+            assert_eq!(metrics.nom.functions_sum(), 5.);
+            assert_eq!(metrics.cyclomatic.cyclomatic_sum(), 12.);
+            assert_eq!(
+                metrics.cognitive.cognitive_sum(),
+                production.cognitive.cognitive_sum() + tests.cognitive.cognitive_sum()
+            );
+        }
+    }
+
+    #[test]
+    fn test_attributes() {
+        for attribute in [
+            "#[test]",
+            "#[ test ]",
+            "#[cfg(test)]",
+            "#![cfg(test)]",
+            "#[tokio::test]",
+            "#[tokio::test(flavor = \"multi_thread\")]",
+        ] {
+            assert!(is_test_attribute(attribute), "{attribute}");
+        }
+        for attribute in [
+            "#[cfg(not(test))]",
+            "#[inline]",
+            "#[testing]",
+            "#[derive(Test)]",
+        ] {
+            assert!(!is_test_attribute(attribute), "{attribute}");
+        }
+    }
+
+    #[test]
+    fn split_test_attributes() {
+        let path = PathBuf::from("src/lib.rs");
+        let source = "fn a(x: bool) {
+                          if x {}
+                      }
+
+                      #[cfg(test)]
+                      mod tests {
+                          use super::*;
+
+                          // A helper of the tests
+                          fn helper(x: bool) -> bool {
+                              x && true
+                          }
+
+                          #[test]
+                          fn test_a() {
+                              a(helper(true));
+                          }
+                      }
+
+                      #[inline]
+                      #[test]
+                      fn test_b() {
+                          for _ in 0..2 {}
+                      }";
+        let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+        let code = parser.get_code();
+        let items: Vec<_> = test_items(&parser)
+            .into_iter()
+            .map(|(start, end)| std::str::from_utf8(&code[start..end]).unwrap())
+            .collect();
+        assert_eq!(items.len(), 2);
+        assert!(items[0].starts_with("#[cfg(test)]") && items[0].ends_with('}'));
+        assert!(items[1].starts_with("#[inline]") && items[1].ends_with('}'));
+
+        let mut split = SplitAggregate::with_functions();
+        assert!(split.add_code(&parser, &path));
+        let total = metrics(&parser, &path).unwrap().metrics;
+
+        let names = |aggregate: &MetricsAggregate| -> Vec<_> {
+            aggregate
+                .functions
+                .iter()
+                .map(|record| record.name.clone().unwrap())
+                .collect()
+        };
+        assert_eq!(names(&split.production), ["a"]);
+        assert_eq!(names(&split.tests), ["helper", "test_a", "test_b"]);
+        assert_eq!(split.tests.functions[1].start_line, 15);
+
+        let production = split.production.summary.as_ref().unwrap();
+        let tests = split.tests.summary.as_ref().unwrap();
+        let merged = split.total().unwrap();
+        assert_eq!(production.nom.functions_sum(), 1.);
+        assert_eq!(tests.nom.functions_sum(), 3.);
+        assert_eq!(production.cyclomatic.cyclomatic_sum(), 3.);
+        assert_eq!(tests.cyclomatic.cyclomatic_sum(), 5.);
+        assert_eq!(merged.nom.functions_sum(), total.nom.functions_sum());
+        assert_eq!(
+            merged.cyclomatic.cyclomatic_sum(),
+            total.cyclomatic.cyclomatic_sum()
+        );
+    }
+
+    #[test]
+    fn split_test_file() {
+        // An inner attribute makes the whole file test code
+        let path = PathBuf::from("src/helpers.rs");
+        let source = "#![cfg(test)]\nfn a() {}\nfn b() {}";
+        let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+
+        let mut split = SplitAggregate::new();
+        assert!(split.add_code(&parser, &path));
+        assert_eq!(split.production.summary.unwrap().nom.functions_sum(), 0.);
+        assert_eq!(split.tests.summary.unwrap().nom.functions_sum(), 2.);
+
+        // The attributes of the other languages are not detected
+        let path = PathBuf::from("src/foo.py");
+        let source = "import pytest\n\n@pytest.mark.skip\ndef test_a():\n    pass";
+        let parser = PythonParser::new(source.as_bytes().to_vec(), &path, None);
+        assert!(test_items(&parser).is_empty());
+
+        let mut split = SplitAggregate::new();
+        assert!(split.add_code(&parser, &path));
+        assert_eq!(split.production.summary.unwrap().nom.functions_sum(), 1.);
+        assert!(split.tests.summary.is_none());
+    }
+}
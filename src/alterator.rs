@@ -84,6 +84,12 @@ impl Alterator for CppCode {
 
 impl Alterator for PythonCode {}
 
+impl Alterator for HclCode {}
+
+impl Alterator for CsharpCode {}
+
+impl Alterator for GoCode {}
+
 impl Alterator for JavaCode {}
 impl Alterator for KotlinCode {}
 
@@ -0,0 +1,448 @@
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use globset::GlobSet;
+use regex::Regex;
+use walkdir::WalkDir;
+
+use crate::gate::{OverDecomposition, ThresholdGate, Violation};
+use crate::langs::*;
+use crate::output::folded_stacks;
+use crate::spaces::{FuncSpace, MetricsOptions};
+use crate::tools::{guess_language, is_generated, read_file_with_eol};
+
+/// The result of the analysis of a file.
+#[derive(Debug, Clone)]
+pub struct FileAnalysis {
+    /// The path of the file
+    pub path: PathBuf,
+    /// The language of the file
+    pub language: LANG,
+    /// The function spaces of the file
+    pub space: FuncSpace,
+    /// The violations of the limits of the gate by the functions of the file
+    pub violations: Vec<Violation>,
+    /// The trivial functions of the file, when they exceed
+    /// the percentage allowed by the gate
+    pub over_decomposition: Option<OverDecomposition>,
+}
+
+/// A file which could not be analyzed.
+#[derive(Debug)]
+pub struct FileError {
+    /// The path of the file
+    pub path: PathBuf,
+    /// The error raised while reading the file
+    pub error: std::io::Error,
+}
+
+/// The results of an analysis run.
+#[derive(Debug, Default)]
+pub struct AnalysisResults {
+    /// The analyzed files, sorted by path
+    pub files: Vec<FileAnalysis>,
+    /// The files which could not be analyzed, sorted by path
+    pub errors: Vec<FileError>,
+}
+
+/// The format of the files written by [`AnalysisBuilder::run_to_dir`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// The function spaces serialized as compact `JSON`
+    Json,
+    /// The function spaces serialized as indented `JSON`
+    PrettyJson,
+    /// The `Cyclomatic` complexity of the functions as folded stacks,
+    /// one line per function
+    Folded,
+}
+
+impl OutputFormat {
+    /// Returns the extension appended to the name of the analyzed
+    /// file to name its output file.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json | Self::PrettyJson => "json",
+            Self::Folded => "folded",
+        }
+    }
+
+    fn write(&self, space: &FuncSpace, path: &Path) -> std::io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        match self {
+            Self::Json => serde_json::to_writer(&mut writer, space)?,
+            Self::PrettyJson => serde_json::to_writer_pretty(&mut writer, space)?,
+            Self::Folded => writer.write_all(folded_stacks(space).as_bytes())?,
+        }
+        writer.flush()
+    }
+}
+
+/// A builder collecting the options of an analysis run,
+/// which is then started on a file or a directory by [`run`].
+///
+/// [`run`]: AnalysisBuilder::run
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{AnalysisBuilder, LANG, ThresholdGate};
+///
+/// let results = AnalysisBuilder::new()
+///     .set_languages([LANG::Rust])
+///     .set_gate(ThresholdGate {
+///         max_cyclomatic: Some(10.),
+///         ..Default::default()
+///     })
+///     .run(Path::new("src"));
+///
+/// for error in &results.errors {
+///     eprintln!("{}: {}", error.path.display(), error.error);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct AnalysisBuilder {
+    options: MetricsOptions,
+    languages: Option<Vec<LANG>>,
+    include: Option<GlobSet>,
+    exclude: Option<GlobSet>,
+    gate: ThresholdGate,
+    skip_generated: bool,
+}
+
+impl AnalysisBuilder {
+    /// Creates a new `AnalysisBuilder` with the default options,
+    /// which analyzes all the files written in a supported language.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the options used to compute the metrics.
+    pub fn set_options(mut self, options: MetricsOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Keeps only the functions whose name matches a regular expression.
+    ///
+    /// See [`MetricsOptions::function_filter`].
+    pub fn set_function_filter(mut self, filter: Regex) -> Self {
+        self.options.function_filter = Some(filter);
+        self
+    }
+
+    /// Sets the languages of the files to analyze, while the
+    /// files written in the other languages are skipped.
+    pub fn set_languages(mut self, languages: impl IntoIterator<Item = LANG>) -> Self {
+        self.languages = Some(languages.into_iter().collect());
+        self
+    }
+
+    /// Sets the files of a directory to analyze.
+    pub fn set_include(mut self, include: GlobSet) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sets the files of a directory to skip.
+    pub fn set_exclude(mut self, exclude: GlobSet) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+
+    /// Sets the limits checked on the functions of each file.
+    pub fn set_gate(mut self, gate: ThresholdGate) -> Self {
+        self.gate = gate;
+        self
+    }
+
+    /// Skips the files marked as generated by a tool.
+    ///
+    /// See [`is_generated`].
+    pub fn set_skip_generated(mut self, skip_generated: bool) -> Self {
+        self.skip_generated = skip_generated;
+        self
+    }
+
+    fn is_selected(&self, path: &Path) -> bool {
+        self.include.as_ref().is_none_or(|g| g.is_match(path))
+            && self.exclude.as_ref().is_none_or(|g| !g.is_match(path))
+    }
+
+    fn analyze(&self, path: PathBuf) -> std::io::Result<Option<FileAnalysis>> {
+        let Some(source) = read_file_with_eol(&path)? else {
+            return Ok(None);
+        };
+        if self.skip_generated && is_generated(&source) {
+            return Ok(None);
+        }
+        let Some(language) = guess_language(&source, &path).0 else {
+            return Ok(None);
+        };
+        if self
+            .languages
+            .as_ref()
+            .is_some_and(|languages| !languages.contains(&language))
+        {
+            return Ok(None);
+        }
+
+        let space = get_function_spaces_with_options(&language, source, &path, None, &self.options);
+        Ok(space.map(|space| FileAnalysis {
+            violations: self.gate.check(&space),
+            over_decomposition: self.gate.check_decomposition(&space),
+            path,
+            language,
+            space,
+        }))
+    }
+
+    /// Analyzes a file, or the files contained in a directory
+    /// and in its own subdirectories, sorted by path.
+    ///
+    /// The files which are not written in a selected language,
+    /// or which cannot be parsed, are skipped, together with the
+    /// generated ones when [`set_skip_generated`] is enabled.
+    ///
+    /// A file which cannot be read does not stop the analysis of
+    /// the others: its error is collected next to the results.
+    ///
+    /// [`set_skip_generated`]: AnalysisBuilder::set_skip_generated
+    pub fn run(&self, path: &Path) -> AnalysisResults {
+        let mut results = AnalysisResults::default();
+        let mut paths = Vec::new();
+        if path.is_dir() {
+            for entry in WalkDir::new(path) {
+                match entry {
+                    Ok(entry) => {
+                        if entry.file_type().is_file() && self.is_selected(entry.path()) {
+                            paths.push(entry.into_path());
+                        }
+                    }
+                    Err(error) => results.errors.push(FileError {
+                        path: error.path().unwrap_or(path).to_path_buf(),
+                        error: error.into(),
+                    }),
+                }
+            }
+            paths.sort();
+        } else {
+            paths.push(path.to_path_buf());
+        }
+
+        for path in paths {
+            match self.analyze(path.clone()) {
+                Ok(file) => results.files.extend(file),
+                Err(error) => results.errors.push(FileError { path, error }),
+            }
+        }
+        results.errors.sort_by(|a, b| a.path.cmp(&b.path));
+
+        results
+    }
+
+    /// Analyzes a file or a directory as [`run`] does, and writes the
+    /// metrics of each analyzed file into `output` in the given `format`.
+    ///
+    /// The output directory mirrors the structure of the analyzed one:
+    /// the metrics of `src/a/b.rs` are written into `output/a/b.rs.json`
+    /// when `path` is `src`, creating the directories as needed. The
+    /// extension is appended, so `b.rs` and `b.py` do not collide.
+    ///
+    /// Returns the paths of the written files, sorted by the paths
+    /// of the analyzed files, together with the files which could
+    /// not be analyzed. An error writing the output stops the run.
+    ///
+    /// [`run`]: AnalysisBuilder::run
+    pub fn run_to_dir(
+        &self,
+        path: &Path,
+        output: &Path,
+        format: OutputFormat,
+    ) -> std::io::Result<(Vec<PathBuf>, Vec<FileError>)> {
+        let results = self.run(path);
+        let mut written = Vec::new();
+        for result in results.files {
+            let relative = if path.is_dir() {
+                result.path.strip_prefix(path).unwrap_or(&result.path)
+            } else {
+                Path::new(result.path.file_name().unwrap_or_default())
+            };
+            let mut file_name = relative.as_os_str().to_owned();
+            file_name.push(".");
+            file_name.push(format.extension());
+
+            let output_path = output.join(file_name);
+            if let Some(parent) = output_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            format.write(&result.space, &output_path)?;
+            written.push(output_path);
+        }
+
+        Ok((written, results.errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use globset::{Glob, GlobSetBuilder};
+
+    use crate::gate::Limit;
+
+    use super::*;
+
+    #[test]
+    fn builder_options() {
+        let dir = std::env::temp_dir().join(format!("rca-analysis-{}", std::process::id()));
+        fs::create_dir_all(dir.join("vendor")).unwrap();
+        fs::write(
+            dir.join("a.rs"),
+            "fn handle_a(x: bool) {\n    if x {}\n}\nfn other() {}\n",
+        )
+        .unwrap();
+        fs::write(dir.join("b.py"), "def handle_b():\n    pass\n").unwrap();
+        fs::write(dir.join("vendor/c.rs"), "fn handle_c() {}\n").unwrap();
+
+        let exclude = GlobSetBuilder::new()
+            .add(Glob::new("**/vendor/**").unwrap())
+            .build()
+            .unwrap();
+        let results = AnalysisBuilder::new()
+            .set_function_filter(Regex::new("^handle_").unwrap())
+            .set_languages([LANG::Rust])
+            .set_exclude(exclude)
+            .set_gate(ThresholdGate {
+                max_cyclomatic: Some(1.),
+                ..Default::default()
+            })
+            .run(&dir)
+            .files;
+        fs::remove_dir_all(&dir).unwrap();
+
+        // The Python file is not selected and the vendored one is excluded
+        assert_eq!(results.len(), 1);
+        let result = &results[0];
+        assert_eq!(result.path, dir.join("a.rs"));
+        assert_eq!(result.language, LANG::Rust);
+
+        // `other` is filtered out
+        let names: Vec<_> = result
+            .space
+            .spaces
+            .iter()
+            .map(|space| space.name.as_deref())
+            .collect();
+        assert_eq!(names, [Some("handle_a")]);
+
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].limit, Limit::Cyclomatic);
+        assert_eq!(result.violations[0].function.as_deref(), Some("handle_a"));
+    }
+
+    #[test]
+    fn single_file() {
+        let dir = std::env::temp_dir().join(format!("rca-analysis-file-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("a.py");
+        fs::write(&path, "def f():\n    pass\n").unwrap();
+
+        let results = AnalysisBuilder::new().run(&path).files;
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].language, LANG::Python);
+        assert!(results[0].violations.is_empty());
+        assert!(results[0].over_decomposition.is_none());
+    }
+
+    #[test]
+    fn run_to_dir() {
+        let root = std::env::temp_dir().join(format!("rca-analysis-tree-{}", std::process::id()));
+        let dir = root.join("src");
+        let output = root.join("out");
+        fs::create_dir_all(dir.join("a/b")).unwrap();
+        fs::write(dir.join("main.rs"), "fn main() {}\n").unwrap();
+        fs::write(dir.join("a/c.py"), "def f():\n    pass\n").unwrap();
+        fs::write(dir.join("a/b/d.rs"), "fn d(x: bool) {\n    if x {}\n}\n").unwrap();
+        fs::write(dir.join("a/b/notes.txt"), "not analyzed\n").unwrap();
+
+        let (written, errors) = AnalysisBuilder::new()
+            .run_to_dir(&dir, &output, OutputFormat::Json)
+            .unwrap();
+        assert!(errors.is_empty());
+        let relative: Vec<_> = written
+            .iter()
+            .map(|path| path.strip_prefix(&output).unwrap().to_path_buf())
+            .collect();
+        let d: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(output.join("a/b/d.rs.json")).unwrap())
+                .unwrap();
+        let files = WalkDir::new(&output)
+            .into_iter()
+            .filter(|entry| entry.as_ref().unwrap().file_type().is_file())
+            .count();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            relative,
+            [
+                PathBuf::from("a/b/d.rs.json"),
+                PathBuf::from("a/c.py.json"),
+                PathBuf::from("main.rs.json"),
+            ]
+        );
+        // One result file for each analyzed file, the text file is skipped
+        assert_eq!(files, 3);
+        assert_eq!(d["metrics"]["cyclomatic"]["sum"], 3.0);
+    }
+
+    #[test]
+    fn skip_generated() {
+        let dir = std::env::temp_dir().join(format!("rca-analysis-gen-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.rs"), "fn a() {}\n").unwrap();
+        fs::write(
+            dir.join("b.rs"),
+            "// Code generated by a tool. DO NOT EDIT.\nfn b() {}\n",
+        )
+        .unwrap();
+
+        let all = AnalysisBuilder::new().run(&dir).files;
+        let handwritten = AnalysisBuilder::new()
+            .set_skip_generated(true)
+            .run(&dir)
+            .files;
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(all.len(), 2);
+        assert_eq!(handwritten.len(), 1);
+        assert_eq!(handwritten[0].path, dir.join("a.rs"));
+    }
+
+    #[test]
+    fn collect_errors() {
+        let dir = std::env::temp_dir().join(format!("rca-analysis-err-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.rs"), "fn a() {}\n").unwrap();
+
+        let missing = dir.join("missing.rs");
+        let results = AnalysisBuilder::new().run(&missing);
+        let all = AnalysisBuilder::new().run(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        // A file which cannot be read is reported instead of aborting the run
+        assert!(results.files.is_empty());
+        assert_eq!(results.errors.len(), 1);
+        assert_eq!(results.errors[0].path, missing);
+        assert_eq!(results.errors[0].error.kind(), std::io::ErrorKind::NotFound);
+
+        assert_eq!(all.files.len(), 1);
+        assert!(all.errors.is_empty());
+    }
+}
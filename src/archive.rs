@@ -0,0 +1,125 @@
+use std::io::{Read, Seek};
+use std::path::PathBuf;
+
+use zip::ZipArchive;
+use zip::result::ZipResult;
+
+use crate::langs::*;
+use crate::spaces::FuncSpace;
+use crate::tools::{guess_language, remove_blank_lines};
+
+/// Computes the function spaces data of the files contained
+/// in a zip archive, without extracting them to disk.
+///
+/// The returned paths are the ones of the files inside the archive.
+/// Only the files written in a supported language are analyzed, while
+/// the ones which cannot be parsed or whose path escapes the archive,
+/// such as `../foo.rs`, are skipped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+///
+/// use rust_code_analysis::zip_metrics;
+///
+/// let archive = File::open("release.zip").unwrap();
+/// zip_metrics(archive).unwrap();
+/// ```
+pub fn zip_metrics<R: Read + Seek>(reader: R) -> ZipResult<Vec<(PathBuf, FuncSpace)>> {
+    let mut archive = ZipArchive::new(reader)?;
+    let mut spaces = Vec::new();
+
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index)?;
+        if !file.is_file() {
+            continue;
+        }
+        let Some(path) = file.enclosed_name() else {
+            continue;
+        };
+        let supported = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| get_from_ext(&ext.to_lowercase()))
+            .is_some();
+        if !supported {
+            continue;
+        }
+
+        let mut source = Vec::with_capacity(file.size() as usize + 1);
+        file.read_to_end(&mut source)?;
+        remove_blank_lines(&mut source);
+
+        let Some(language) = guess_language(&source, &path).0 else {
+            continue;
+        };
+        if let Some(space) = get_function_spaces(&language, source, &path, None) {
+            spaces.push((path, space));
+        }
+    }
+
+    Ok(spaces)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+    use std::path::Path;
+
+    use zip::ZipWriter;
+    use zip::write::SimpleFileOptions;
+
+    use super::*;
+
+    fn zip(files: &[(&str, &str)]) -> Cursor<Vec<u8>> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (path, source) in files {
+            writer
+                .start_file(*path, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(source.as_bytes()).unwrap();
+        }
+        let mut archive = writer.finish().unwrap();
+        archive.set_position(0);
+        archive
+    }
+
+    #[test]
+    fn source_files() {
+        let archive = zip(&[
+            ("project/src/a.rs", "fn a(x: bool) { if x {} }"),
+            ("project/README.md", "# Not a source file"),
+            ("project/lib/b.py", "def b():\n    pass\n"),
+        ]);
+        let spaces = zip_metrics(archive).unwrap();
+
+        let paths: Vec<_> = spaces.iter().map(|(path, _)| path.as_path()).collect();
+        assert_eq!(
+            paths,
+            [Path::new("project/src/a.rs"), Path::new("project/lib/b.py")]
+        );
+
+        let (_, a) = &spaces[0];
+        assert_eq!(a.name.as_deref(), Some("project/src/a.rs"));
+        assert_eq!(a.spaces[0].name.as_deref(), Some("a"));
+        assert_eq!(a.spaces[0].metrics.cyclomatic.cyclomatic(), 2.);
+
+        let (_, b) = &spaces[1];
+        assert_eq!(b.spaces[0].name.as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn escaping_paths() {
+        let archive = zip(&[("../a.rs", "fn a() {}"), ("b.rs", "fn b() {}")]);
+        let spaces = zip_metrics(archive).unwrap();
+
+        assert_eq!(spaces.len(), 1);
+        assert_eq!(spaces[0].0, Path::new("b.rs"));
+    }
+
+    #[test]
+    fn invalid_archive() {
+        assert!(zip_metrics(Cursor::new(b"not a zip".to_vec())).is_err());
+    }
+}
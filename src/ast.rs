@@ -26,6 +26,11 @@ pub struct AstPayload {
     /// If `true`, the start and end positions of a node in a code
     /// are considered
     pub span: bool,
+    /// If `true`, the columns of the positions are counted in `UTF-16`
+    /// code units, as the ones of the Language Server Protocol,
+    /// rather than in bytes
+    #[serde(default)]
+    pub utf16: bool,
 }
 
 /// The response of an `AST` request.
@@ -123,6 +128,32 @@ fn build<T: ParserTrait>(parser: &T, span: bool, comment: bool) -> Option<AstNod
     }
 }
 
+// Converts the columns of the spans of a node and of all its descendants
+// from bytes to UTF-16 code units.
+fn to_utf16_columns(root: &mut AstNode, code: &[u8]) {
+    let lines: Vec<_> = code.split(|&c| c == b'\n').collect();
+    // Spans are 1-based
+    let convert = |row: usize, column: usize| {
+        lines.get(row - 1).map_or(column, |line| {
+            let prefix = &line[..(column - 1).min(line.len())];
+            String::from_utf8_lossy(prefix).encode_utf16().count() + 1
+        })
+    };
+
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if let Some((start_row, start_column, end_row, end_column)) = node.span {
+            node.span = Some((
+                start_row,
+                convert(start_row, start_column),
+                end_row,
+                convert(end_row, end_column),
+            ));
+        }
+        stack.extend(node.children.iter_mut());
+    }
+}
+
 pub struct AstCallback {
     _guard: (),
 }
@@ -137,6 +168,9 @@ pub struct AstCfg {
     /// If `true`, the start and end positions of a node in a code
     /// are considered
     pub span: bool,
+    /// If `true`, the columns of the positions are counted in `UTF-16`
+    /// code units rather than in bytes
+    pub utf16: bool,
 }
 
 impl Callback for AstCallback {
@@ -144,9 +178,70 @@ impl Callback for AstCallback {
     type Cfg = AstCfg;
 
     fn call<T: ParserTrait>(cfg: Self::Cfg, parser: &T) -> Self::Res {
-        AstResponse {
-            id: cfg.id,
-            root: build(parser, cfg.span, cfg.comment),
+        let mut root = build(parser, cfg.span, cfg.comment);
+        if cfg.span
+            && cfg.utf16
+            && let Some(root) = root.as_mut()
+        {
+            to_utf16_columns(root, parser.get_code());
         }
+
+        AstResponse { id: cfg.id, root }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    fn leaves(source: &str, utf16: bool) -> Vec<(String, Span)> {
+        let cfg = AstCfg {
+            id: "0".to_string(),
+            comment: false,
+            span: true,
+            utf16,
+        };
+        let response = action::<AstCallback>(
+            &LANG::Rust,
+            source.as_bytes().to_vec(),
+            Path::new("foo.rs"),
+            None,
+            cfg,
+        );
+
+        let mut leaves = Vec::new();
+        let mut stack = vec![response.root.unwrap()];
+        while let Some(node) = stack.pop() {
+            if node.children.is_empty() {
+                leaves.push((node.value, node.span));
+            }
+            stack.extend(node.children.into_iter().rev());
+        }
+        leaves
+    }
+
+    #[test]
+    fn utf16_columns() {
+        let source = "let s = \"🦀é\"; let x = 1;";
+
+        let span = |leaves: &[(String, Span)], value: &str| {
+            leaves
+                .iter()
+                .find(|(text, _)| text == value)
+                .and_then(|(_, span)| *span)
+                .unwrap()
+        };
+
+        // The crab takes 4 bytes and 2 UTF-16 code units,
+        // while the accented letter takes 2 bytes and 1 code unit
+        let bytes = leaves(source, false);
+        let utf16 = leaves(source, true);
+        assert_eq!(span(&bytes, "x"), (1, 23, 1, 24));
+        assert_eq!(span(&utf16, "x"), (1, 20, 1, 21));
+
+        // The columns before the multibyte characters are the same
+        assert_eq!(span(&bytes, "s"), span(&utf16, "s"));
     }
 }
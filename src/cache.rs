@@ -0,0 +1,404 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::halstead::HalsteadOverrides;
+use crate::*;
+
+/// A cache of the metrics of files, stored in a directory.
+///
+/// The metrics of a file are stored serialized as `JSON` and retrieved
+/// through a hash of its path, its language, its content and the options
+/// the metrics are computed with, so unchanged files are not parsed again.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{MetricsCache, LANG};
+///
+/// let mut cache = MetricsCache::new("/tmp/rca-cache").unwrap();
+/// let source = b"fn f() {}".to_vec();
+///
+/// cache.metrics(&LANG::Rust, source, Path::new("foo.rs")).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MetricsCache {
+    dir: PathBuf,
+    hits: usize,
+    misses: usize,
+}
+
+impl MetricsCache {
+    /// Creates a cache stored in `dir`, creating the directory if needed.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> std::io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            hits: 0,
+            misses: 0,
+        })
+    }
+
+    /// Returns the function spaces data of a code.
+    ///
+    /// The data is read from the cache when present, otherwise
+    /// it is computed and stored into the cache.
+    ///
+    /// Returns `None` if the code cannot be parsed.
+    pub fn metrics(
+        &mut self,
+        lang: &LANG,
+        source: Vec<u8>,
+        path: &Path,
+    ) -> std::io::Result<Option<FuncSpace>> {
+        self.metrics_with_options(lang, source, path, &MetricsOptions::default())
+    }
+
+    /// Returns the function spaces data of a code, computed according
+    /// to the given options.
+    ///
+    /// The data is read from the cache when present, otherwise
+    /// it is computed and stored into the cache.
+    ///
+    /// Returns `None` if the code cannot be parsed.
+    pub fn metrics_with_options(
+        &mut self,
+        lang: &LANG,
+        source: Vec<u8>,
+        path: &Path,
+        options: &MetricsOptions,
+    ) -> std::io::Result<Option<FuncSpace>> {
+        let entry = self
+            .dir
+            .join(format!("{:016x}.json", hash(lang, &source, path, options)));
+
+        // The length of the code is checked too, such as to not
+        // return the data of another code having the same hash
+        if let Ok(data) = fs::read(&entry)
+            && let Ok(cached) = serde_json::from_slice::<Entry>(&data)
+            && cached.len == source.len()
+        {
+            self.hits += 1;
+            return Ok(Some(cached.space));
+        }
+
+        self.misses += 1;
+        let len = source.len();
+        let Some(space) = get_function_spaces_with_options(lang, source, path, None, options)
+        else {
+            return Ok(None);
+        };
+        let cached = Entry { len, space };
+        fs::write(&entry, serde_json::to_vec(&cached)?)?;
+
+        Ok(Some(cached.space))
+    }
+
+    /// Returns the number of times the data of a code has been
+    /// read from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Returns the number of times the data of a code has been computed,
+    /// parsing the code.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+// The data of a code stored in the cache
+#[derive(Deserialize, Serialize)]
+struct Entry {
+    len: usize,
+    #[serde(with = "Space")]
+    space: FuncSpace,
+}
+
+// A function space stored with the whole state of its metrics, unlike
+// its usual serialization, such as to be deserialized back
+#[derive(Deserialize, Serialize)]
+#[serde(remote = "FuncSpace")]
+struct Space {
+    name: Option<String>,
+    start_line: usize,
+    end_line: usize,
+    start_byte: usize,
+    end_byte: usize,
+    kind: SpaceKind,
+    #[serde(
+        serialize_with = "serialize_spaces",
+        deserialize_with = "deserialize_spaces"
+    )]
+    spaces: Vec<FuncSpace>,
+    #[serde(with = "Metrics")]
+    metrics: CodeMetrics,
+}
+
+#[derive(Deserialize)]
+struct OwnedSpace(#[serde(with = "Space")] FuncSpace);
+
+struct SpaceRef<'a>(&'a FuncSpace);
+
+impl Serialize for SpaceRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Space::serialize(self.0, serializer)
+    }
+}
+
+fn serialize_spaces<S: Serializer>(spaces: &[FuncSpace], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(spaces.iter().map(SpaceRef))
+}
+
+fn deserialize_spaces<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<FuncSpace>, D::Error> {
+    let spaces = Vec::<OwnedSpace>::deserialize(deserializer)?;
+    Ok(spaces.into_iter().map(|OwnedSpace(space)| space).collect())
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(remote = "CodeMetrics")]
+struct Metrics {
+    #[serde(with = "nargs::Stats")]
+    nargs: nargs::Stats,
+    #[serde(with = "exit::Stats")]
+    nexits: exit::Stats,
+    #[serde(with = "cognitive::Stats")]
+    cognitive: cognitive::Stats,
+    #[serde(with = "cyclomatic::Stats")]
+    cyclomatic: cyclomatic::Stats,
+    #[serde(with = "halstead::Stats")]
+    halstead: halstead::Stats,
+    #[serde(with = "loc::Stats")]
+    loc: loc::Stats,
+    #[serde(with = "nom::Stats")]
+    nom: nom::Stats,
+    #[serde(with = "mi::Stats")]
+    mi: mi::Stats,
+    #[serde(with = "abc::Stats")]
+    abc: abc::Stats,
+    #[serde(with = "wmc::Stats")]
+    wmc: wmc::Stats,
+    #[serde(with = "npm::Stats")]
+    npm: npm::Stats,
+    #[serde(with = "npa::Stats")]
+    npa: npa::Stats,
+    #[serde(with = "expr_nesting::Stats")]
+    expr_nesting: expr_nesting::Stats,
+    #[serde(with = "cfg::Stats")]
+    cfg: cfg::Stats,
+    #[serde(with = "early_returns::Stats")]
+    early_returns: early_returns::Stats,
+    #[serde(with = "type_complexity::Stats")]
+    type_complexity: type_complexity::Stats,
+    #[serde(with = "captures::Stats")]
+    captures: captures::Stats,
+    #[serde(with = "nlocals::Stats")]
+    nlocals: nlocals::Stats,
+    #[serde(with = "bare_ifs::Stats")]
+    bare_ifs: bare_ifs::Stats,
+    #[serde(with = "exception_types::Stats")]
+    exception_types: exception_types::Stats,
+    #[serde(with = "chain_length::Stats")]
+    chain_length: chain_length::Stats,
+    #[serde(with = "statement_count::Stats")]
+    statement_count: statement_count::Stats,
+    #[serde(with = "referenced_types::Stats")]
+    referenced_types: referenced_types::Stats,
+    #[serde(with = "template_complexity::Stats")]
+    template_complexity: template_complexity::Stats,
+    #[serde(with = "literal_nesting::Stats")]
+    literal_nesting: literal_nesting::Stats,
+    #[serde(with = "negations::Stats")]
+    negations: negations::Stats,
+    #[serde(with = "decisions::Stats")]
+    decisions: decisions::Stats,
+    #[serde(with = "enum_variants::Stats")]
+    enum_variants: enum_variants::Stats,
+    #[serde(with = "conditional_imports::Stats")]
+    conditional_imports: conditional_imports::Stats,
+    #[serde(with = "unsafe_code::Stats")]
+    unsafe_code: unsafe_code::Stats,
+    #[serde(with = "goroutines::Stats")]
+    goroutines: goroutines::Stats,
+}
+
+// Stores a float as its bits, since `JSON` has no representation
+// for the non-finite values, such as the `NaN` of an empty average
+pub(crate) mod float_bits {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(value.to_bits())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        u64::deserialize(deserializer).map(f64::from_bits)
+    }
+}
+
+// The 64-bit FNV-1a hash, which is stable across releases unlike
+// the standard hasher. The version of the crate is hashed too,
+// since the metrics may change from a release to another.
+fn hash(lang: &LANG, source: &[u8], path: &Path, options: &MetricsOptions) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let path = path.to_string_lossy();
+    let options = options_key(lang, options);
+    let parts: [&[u8]; 5] = [
+        env!("CARGO_PKG_VERSION").as_bytes(),
+        lang.get_name().as_bytes(),
+        options.as_bytes(),
+        path.as_bytes(),
+        source,
+    ];
+    parts.iter().fold(OFFSET_BASIS, |hash, part| {
+        // A separator avoids collisions between contiguous parts
+        part.iter()
+            .chain(&[0xff])
+            .fold(hash, |hash, byte| (hash ^ *byte as u64).wrapping_mul(PRIME))
+    })
+}
+
+// The options as text. The `Halstead` overrides of the language are
+// written sorted apart, since the order of a map changes between runs.
+fn options_key(lang: &LANG, options: &MetricsOptions) -> String {
+    let mut overrides = options
+        .halstead_overrides
+        .get(*lang)
+        .map(|types| {
+            types
+                .iter()
+                .map(|(kind, typ)| format!("{kind}={typ:?}"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    overrides.sort_unstable();
+
+    let options = MetricsOptions {
+        halstead_overrides: HalsteadOverrides::default(),
+        ..options.clone()
+    };
+    format!("{options:?}{overrides:?}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_hit() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = MetricsCache::new(dir.path()).unwrap();
+        let path = Path::new("foo.rs");
+        let source = b"fn f(x: bool) { if x {} }".to_vec();
+
+        let first = cache.metrics(&LANG::Rust, source.clone(), path).unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (0, 1));
+
+        let second = cache.metrics(&LANG::Rust, source, path).unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+        // The cached space is the computed one, metrics state included
+        let (first, second) = (first.unwrap(), second.unwrap());
+        assert_eq!(
+            serde_json::to_value(&first).unwrap(),
+            serde_json::to_value(&second).unwrap()
+        );
+        assert_eq!(second.spaces[0].metrics.cyclomatic.cyclomatic_sum(), 2.);
+        assert_eq!(second.spaces[0].start_byte, first.spaces[0].start_byte);
+        // The lines of code are merged again without being counted twice
+        let mut merged = second.metrics.clone();
+        merged.merge(&second.metrics);
+        assert_eq!(merged.loc.ploc(), 1.);
+
+        // A new cache on the same directory reads the stored data
+        let mut cache = MetricsCache::new(dir.path()).unwrap();
+        cache
+            .metrics(&LANG::Rust, b"fn f(x: bool) { if x {} }".to_vec(), path)
+            .unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (1, 0));
+    }
+
+    #[test]
+    fn changed_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = MetricsCache::new(dir.path()).unwrap();
+        let path = Path::new("foo.rs");
+
+        cache
+            .metrics(&LANG::Rust, b"fn f() {}".to_vec(), path)
+            .unwrap();
+        cache
+            .metrics(&LANG::Rust, b"fn g() {}".to_vec(), path)
+            .unwrap();
+        cache
+            .metrics(&LANG::Rust, b"fn f() {}".to_vec(), Path::new("bar.rs"))
+            .unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (0, 3));
+    }
+
+    #[test]
+    fn changed_options() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = MetricsCache::new(dir.path()).unwrap();
+        let path = Path::new("foo.rs");
+        let source = b"fn f() {}".to_vec();
+        let options = MetricsOptions {
+            cyclomatic_values: true,
+            ..Default::default()
+        };
+
+        cache.metrics(&LANG::Rust, source.clone(), path).unwrap();
+        let space = cache
+            .metrics_with_options(&LANG::Rust, source.clone(), path, &options)
+            .unwrap()
+            .unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (0, 2));
+        assert_eq!(space.metrics.cyclomatic.cyclomatic_values(), [1., 1.]);
+
+        let space = cache
+            .metrics_with_options(&LANG::Rust, source.clone(), path, &options)
+            .unwrap()
+            .unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (1, 2));
+        assert_eq!(space.metrics.cyclomatic.cyclomatic_values(), [1., 1.]);
+
+        // The same code in another language is another entry
+        cache.metrics(&LANG::Cpp, source, path).unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (1, 3));
+    }
+
+    #[test]
+    fn changed_length() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = MetricsCache::new(dir.path()).unwrap();
+        let path = Path::new("foo.rs");
+        let source = b"fn f() {}".to_vec();
+
+        cache.metrics(&LANG::Rust, source.clone(), path).unwrap();
+
+        // An entry stored for a code of another length, as with a
+        // collision of the hashes, is not returned
+        let entry = fs::read_dir(dir.path())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        let mut data: serde_json::Value =
+            serde_json::from_slice(&fs::read(&entry).unwrap()).unwrap();
+        data["len"] = 0.into();
+        fs::write(&entry, serde_json::to_vec(&data).unwrap()).unwrap();
+
+        cache.metrics(&LANG::Rust, source, path).unwrap();
+        assert_eq!((cache.hits(), cache.misses()), (0, 2));
+    }
+}
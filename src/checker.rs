@@ -86,6 +86,51 @@ macro_rules! is_js_func_and_closure_checker {
     };
 }
 
+// A function is public when it is exported, such as `export function f() {}`
+// or `export const f = () => {}`, or when it is a method of an exported class
+// not named as a private one, such as `#f() {}`.
+macro_rules! is_js_public_func {
+    ($language: ident, $node: ident) => {{
+        use $language::*;
+
+        let is_exported = |node: &Node| {
+            node.parent()
+                .is_some_and(|parent| match parent.kind_id().into() {
+                    ExportStatement => true,
+                    VariableDeclarator => parent
+                        .parent()
+                        .and_then(|declaration| declaration.parent())
+                        .is_some_and(|parent| parent.kind_id() == ExportStatement),
+                    _ => false,
+                })
+        };
+        if $node.kind_id() == MethodDefinition {
+            $node
+                .first_child(|id| id == PrivatePropertyIdentifier)
+                .is_none()
+                && $node
+                    .parent()
+                    .and_then(|body| body.parent())
+                    .is_some_and(|class| is_exported(&class))
+        } else {
+            is_exported(&$node)
+        }
+    }};
+}
+
+// The `TypeScript` methods with a `private` or `protected`
+// modifier are not public.
+macro_rules! is_ts_public_func {
+    ($language: ident, $node: ident) => {{
+        use $language::*;
+
+        $node
+            .first_child(|id| id == AccessibilityModifier)
+            .is_none_or(|modifier| modifier.first_child(|id| id == Public).is_some())
+            && is_js_public_func!($language, $node)
+    }};
+}
+
 #[inline(always)]
 fn get_aho_corasick_match(code: &[u8]) -> bool {
     AHO_CORASICK
@@ -108,6 +153,36 @@ pub trait Checker {
     fn is_error(node: &Node) -> bool {
         node.has_error()
     }
+
+    /// Checks whether an argument has a default value, so it can
+    /// be omitted by the callers of its function.
+    fn is_defaulted_arg(_: &Node) -> bool {
+        false
+    }
+
+    /// Checks whether an argument collects a variable number of values,
+    /// such as the `Python` `*args` and `**kwargs`.
+    fn is_variadic_arg(_: &Node) -> bool {
+        false
+    }
+
+    /// Checks whether a node is a closure passed to a call
+    /// in a chain of method calls, such as a stream pipeline.
+    fn is_chained_closure(_: &Node) -> bool {
+        false
+    }
+
+    /// Checks whether a node is an anonymous function which can be
+    /// considered as a function space, such as a `Python` lambda.
+    fn is_lambda_space(_: &Node) -> bool {
+        false
+    }
+
+    /// Checks whether a function is part of the public API of a code,
+    /// such as a `Rust` `pub` function or an exported `JavaScript` one.
+    fn is_public_func(_: &Node, _: &[u8]) -> bool {
+        true
+    }
 }
 
 impl Checker for PreprocCode {
@@ -226,6 +301,36 @@ impl Checker for CppCode {
         )
     }
 
+    fn is_public_func(node: &Node, _: &[u8]) -> bool {
+        // The declaration of a function template is its template
+        let declaration = node
+            .parent()
+            .filter(|parent| parent.kind_id() == Cpp::TemplateDeclaration)
+            .unwrap_or(*node);
+        let Some(list) = declaration
+            .parent()
+            .filter(|parent| parent.kind_id() == Cpp::FieldDeclarationList)
+        else {
+            // A free function is public unless it has internal linkage
+            return !node.children().any(|child| {
+                child.kind_id() == Cpp::StorageClassSpecifier
+                    && child.first_child(|id| id == Cpp::Static).is_some()
+            });
+        };
+
+        // The access of a member is set by the last access specifier
+        // preceding it, otherwise the members of a class are private
+        let mut sibling = declaration.previous_sibling();
+        while let Some(node) = sibling {
+            if node.kind_id() == Cpp::AccessSpecifier {
+                return node.first_child(|id| id == Cpp::Public).is_some();
+            }
+            sibling = node.previous_sibling();
+        }
+        list.parent()
+            .is_none_or(|class| class.kind_id() != Cpp::ClassSpecifier)
+    }
+
     fn is_closure(node: &Node) -> bool {
         node.kind_id() == Cpp::LambdaExpression
     }
@@ -241,6 +346,17 @@ impl Checker for CppCode {
         )
     }
 
+    fn is_defaulted_arg(node: &Node) -> bool {
+        node.kind_id() == Cpp::OptionalParameterDeclaration
+    }
+
+    fn is_variadic_arg(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Cpp::VariadicParameterDeclaration | Cpp::DOTDOTDOT
+        )
+    }
+
     fn is_string(node: &Node) -> bool {
         matches!(
             node.kind_id().into(),
@@ -290,10 +406,24 @@ impl Checker for PythonCode {
         node.kind_id() == Python::FunctionDefinition
     }
 
+    fn is_public_func(node: &Node, code: &[u8]) -> bool {
+        // By convention, a name starting with an underscore is private,
+        // except for the ones of special methods, such as `__init__`
+        node.child_by_field_name("name")
+            .and_then(|name| name.utf8_text(code))
+            .is_some_and(|name| {
+                !name.starts_with('_') || (name.starts_with("__") && name.ends_with("__"))
+            })
+    }
+
     fn is_closure(node: &Node) -> bool {
         node.kind_id() == Python::Lambda
     }
 
+    fn is_lambda_space(node: &Node) -> bool {
+        Self::is_closure(node)
+    }
+
     fn is_call(node: &Node) -> bool {
         node.kind_id() == Python::Call
     }
@@ -301,10 +431,32 @@ impl Checker for PythonCode {
     fn is_non_arg(node: &Node) -> bool {
         matches!(
             node.kind_id().into(),
-            Python::LPAREN | Python::COMMA | Python::RPAREN
+            Python::LPAREN
+                | Python::COMMA
+                | Python::RPAREN
+                | Python::PositionalSeparator
+                | Python::KeywordSeparator
+        )
+    }
+
+    fn is_defaulted_arg(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Python::DefaultParameter | Python::TypedDefaultParameter
         )
     }
 
+    fn is_variadic_arg(node: &Node) -> bool {
+        match node.kind_id().into() {
+            Python::ListSplatPattern | Python::DictionarySplatPattern => true,
+            // An annotated `*args: int`
+            Python::TypedParameter => node
+                .child(0)
+                .is_some_and(|child| Self::is_variadic_arg(&child)),
+            _ => false,
+        }
+    }
+
     fn is_string(node: &Node) -> bool {
         node.kind_id() == Python::String || node.kind_id() == Python::ConcatenatedString
     }
@@ -338,10 +490,52 @@ impl Checker for JavaCode {
         node.kind_id() == Java::MethodDeclaration || node.kind_id() == Java::ConstructorDeclaration
     }
 
+    fn is_public_func(node: &Node, _: &[u8]) -> bool {
+        let modifiers = node.first_child(|id| id == Java::Modifiers);
+        let has_modifier = |modifier: fn(u16) -> bool| {
+            modifiers.is_some_and(|modifiers| modifiers.first_child(modifier).is_some())
+        };
+
+        // The methods of an interface are implicitly public
+        has_modifier(|id| id == Java::Public)
+            || (node
+                .parent()
+                .is_some_and(|body| body.kind_id() == Java::InterfaceBody)
+                && !has_modifier(|id| id == Java::Private))
+    }
+
     fn is_closure(node: &Node) -> bool {
         node.kind_id() == Java::LambdaExpression
     }
 
+    fn is_chained_closure(node: &Node) -> bool {
+        if !Self::is_closure(node) {
+            return false;
+        }
+
+        let is_call = |node: &Node| node.kind_id() == Java::MethodInvocation;
+
+        // lambda -> argument_list -> method_invocation
+        let Some(call) = node
+            .parent()
+            .filter(|args| args.kind_id() == Java::ArgumentList)
+            .and_then(|args| args.parent())
+            .filter(is_call)
+        else {
+            return false;
+        };
+
+        // The call either follows or precedes another call in the chain
+        call.child_by_field_name("object")
+            .is_some_and(|object| is_call(&object))
+            || call.parent().is_some_and(|parent| {
+                is_call(&parent)
+                    && parent
+                        .child_by_field_name("object")
+                        .is_some_and(|object| object.id() == call.id())
+            })
+    }
+
     fn is_call(node: &Node) -> bool {
         node.kind_id() == Java::MethodInvocation
     }
@@ -354,8 +548,12 @@ impl Checker for JavaCode {
         node.kind_id() == Java::StringLiteral
     }
 
-    fn is_else_if(_: &Node) -> bool {
-        false
+    fn is_else_if(node: &Node) -> bool {
+        // The `else` branch of a Java `if` statement is not wrapped in a clause
+        node.kind_id() == Java::IfStatement
+            && node
+                .previous_sibling()
+                .is_some_and(|prev| prev.kind_id() == Java::Else)
     }
 
     fn is_primitive(_id: u16) -> bool {
@@ -389,6 +587,10 @@ impl Checker for MozjsCode {
 
     is_js_func_and_closure_checker!(MozjsParser, Mozjs);
 
+    fn is_public_func(node: &Node, _: &[u8]) -> bool {
+        is_js_public_func!(Mozjs, node)
+    }
+
     fn is_call(node: &Node) -> bool {
         node.kind_id() == Mozjs::CallExpression
     }
@@ -400,6 +602,14 @@ impl Checker for MozjsCode {
         )
     }
 
+    fn is_defaulted_arg(node: &Node) -> bool {
+        node.kind_id() == Mozjs::AssignmentPattern
+    }
+
+    fn is_variadic_arg(node: &Node) -> bool {
+        node.kind_id() == Mozjs::RestPattern
+    }
+
     fn is_string(node: &Node) -> bool {
         node.kind_id() == Mozjs::String || node.kind_id() == Mozjs::TemplateString
     }
@@ -446,6 +656,10 @@ impl Checker for JavascriptCode {
 
     is_js_func_and_closure_checker!(JavascriptParser, Javascript);
 
+    fn is_public_func(node: &Node, _: &[u8]) -> bool {
+        is_js_public_func!(Javascript, node)
+    }
+
     fn is_call(node: &Node) -> bool {
         node.kind_id() == Javascript::CallExpression
     }
@@ -457,6 +671,14 @@ impl Checker for JavascriptCode {
         )
     }
 
+    fn is_defaulted_arg(node: &Node) -> bool {
+        node.kind_id() == Javascript::AssignmentPattern
+    }
+
+    fn is_variadic_arg(node: &Node) -> bool {
+        node.kind_id() == Javascript::RestPattern
+    }
+
     fn is_string(node: &Node) -> bool {
         node.kind_id() == Javascript::String || node.kind_id() == Javascript::TemplateString
     }
@@ -467,8 +689,7 @@ impl Checker for JavascriptCode {
             return false;
         }
         if let Some(parent) = node.parent() {
-            return node.kind_id() == Javascript::IfStatement
-                && parent.kind_id() == Javascript::IfStatement;
+            return parent.kind_id() == Javascript::ElseClause;
         }
         false
     }
@@ -505,6 +726,10 @@ impl Checker for TypescriptCode {
 
     is_js_func_and_closure_checker!(TypescriptParser, Typescript);
 
+    fn is_public_func(node: &Node, _: &[u8]) -> bool {
+        is_ts_public_func!(Typescript, node)
+    }
+
     fn is_call(node: &Node) -> bool {
         node.kind_id() == Typescript::CallExpression
     }
@@ -516,6 +741,21 @@ impl Checker for TypescriptCode {
         )
     }
 
+    fn is_defaulted_arg(node: &Node) -> bool {
+        match node.kind_id().into() {
+            Typescript::OptionalParameter => true,
+            Typescript::RequiredParameter => node.child_by_field_name("value").is_some(),
+            _ => false,
+        }
+    }
+
+    fn is_variadic_arg(node: &Node) -> bool {
+        node.kind_id() == Typescript::RequiredParameter
+            && node
+                .child_by_field_name("pattern")
+                .is_some_and(|pattern| pattern.kind_id() == Typescript::RestPattern)
+    }
+
     fn is_string(node: &Node) -> bool {
         node.kind_id() == Typescript::String || node.kind_id() == Typescript::TemplateString
     }
@@ -564,6 +804,10 @@ impl Checker for TsxCode {
 
     is_js_func_and_closure_checker!(TsxParser, Tsx);
 
+    fn is_public_func(node: &Node, _: &[u8]) -> bool {
+        is_ts_public_func!(Tsx, node)
+    }
+
     fn is_call(node: &Node) -> bool {
         node.kind_id() == Tsx::CallExpression
     }
@@ -575,16 +819,32 @@ impl Checker for TsxCode {
         )
     }
 
+    fn is_defaulted_arg(node: &Node) -> bool {
+        match node.kind_id().into() {
+            Tsx::OptionalParameter => true,
+            Tsx::RequiredParameter => node.child_by_field_name("value").is_some(),
+            _ => false,
+        }
+    }
+
+    fn is_variadic_arg(node: &Node) -> bool {
+        node.kind_id() == Tsx::RequiredParameter
+            && node
+                .child_by_field_name("pattern")
+                .is_some_and(|pattern| pattern.kind_id() == Tsx::RestPattern)
+    }
+
     fn is_string(node: &Node) -> bool {
         node.kind_id() == Tsx::String || node.kind_id() == Tsx::TemplateString
     }
 
+    #[inline(always)]
     fn is_else_if(node: &Node) -> bool {
         if node.kind_id() != Tsx::IfStatement {
             return false;
         }
         if let Some(parent) = node.parent() {
-            return node.kind_id() == Tsx::IfStatement && parent.kind_id() == Tsx::IfStatement;
+            return parent.kind_id() == Tsx::ElseClause;
         }
         false
     }
@@ -626,6 +886,21 @@ impl Checker for RustCode {
         node.kind_id() == Rust::FunctionItem
     }
 
+    fn is_public_func(node: &Node, code: &[u8]) -> bool {
+        // A restricted visibility, such as `pub(crate)`, is not public,
+        // while the methods of traits are as visible as their traits
+        node.first_child(|id| id == Rust::VisibilityModifier)
+            .is_some_and(|visibility| visibility.utf8_text(code) == Some("pub"))
+            || node
+                .parent()
+                .and_then(|list| list.parent())
+                .is_some_and(|item| {
+                    item.kind_id() == Rust::TraitItem
+                        || (item.kind_id() == Rust::ImplItem
+                            && item.child_by_field_name("trait").is_some())
+                })
+    }
+
     fn is_closure(node: &Node) -> bool {
         node.kind_id() == Rust::ClosureExpression
     }
@@ -641,6 +916,10 @@ impl Checker for RustCode {
         )
     }
 
+    fn is_variadic_arg(node: &Node) -> bool {
+        node.kind_id() == Rust::VariadicParameter
+    }
+
     fn is_string(node: &Node) -> bool {
         node.kind_id() == Rust::StringLiteral || node.kind_id() == Rust::RawStringLiteral
     }
@@ -662,6 +941,51 @@ impl Checker for RustCode {
     }
 }
 
+impl Checker for HclCode {
+    fn is_comment(node: &Node) -> bool {
+        node.kind_id() == Hcl::Comment
+    }
+
+    fn is_useful_comment(_: &Node, _: &[u8]) -> bool {
+        false
+    }
+
+    fn is_func_space(node: &Node) -> bool {
+        node.kind_id() == Hcl::ConfigFile
+    }
+
+    fn is_func(_: &Node) -> bool {
+        false
+    }
+
+    fn is_closure(_: &Node) -> bool {
+        false
+    }
+
+    fn is_call(node: &Node) -> bool {
+        node.kind_id() == Hcl::FunctionCall
+    }
+
+    fn is_non_arg(_: &Node) -> bool {
+        false
+    }
+
+    fn is_string(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Hcl::StringLit | Hcl::QuotedTemplate | Hcl::HeredocTemplate
+        )
+    }
+
+    fn is_else_if(_: &Node) -> bool {
+        false
+    }
+
+    fn is_primitive(_id: u16) -> bool {
+        false
+    }
+}
+
 impl Checker for KotlinCode {
     fn is_comment(_: &Node) -> bool {
         false
@@ -671,8 +995,8 @@ impl Checker for KotlinCode {
         false
     }
 
-    fn is_func_space(_: &Node) -> bool {
-        false
+    fn is_func_space(node: &Node) -> bool {
+        node.kind_id() == Kotlin::SourceFile
     }
 
     fn is_func(_: &Node) -> bool {
@@ -703,3 +1027,211 @@ impl Checker for KotlinCode {
         false
     }
 }
+
+impl Checker for GoCode {
+    fn is_comment(node: &Node) -> bool {
+        node.kind_id() == Go::Comment
+    }
+
+    fn is_useful_comment(_: &Node, _: &[u8]) -> bool {
+        false
+    }
+
+    fn is_func_space(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Go::SourceFile | Go::FunctionDeclaration | Go::MethodDeclaration | Go::FuncLiteral
+        )
+    }
+
+    fn is_func(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Go::FunctionDeclaration | Go::MethodDeclaration
+        )
+    }
+
+    fn is_closure(node: &Node) -> bool {
+        node.kind_id() == Go::FuncLiteral
+    }
+
+    fn is_call(node: &Node) -> bool {
+        node.kind_id() == Go::CallExpression
+    }
+
+    fn is_non_arg(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Go::LPAREN | Go::COMMA | Go::RPAREN | Go::Comment
+        )
+    }
+
+    fn is_string(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Go::RawStringLiteral | Go::InterpretedStringLiteral
+        )
+    }
+
+    fn is_else_if(node: &Node) -> bool {
+        // The `else if` of a Go `if` statement is its alternative
+        node.kind_id() == Go::IfStatement
+            && node
+                .parent()
+                .is_some_and(|parent| parent.kind_id() == Go::IfStatement)
+    }
+
+    fn is_primitive(_id: u16) -> bool {
+        false
+    }
+
+    fn is_variadic_arg(node: &Node) -> bool {
+        node.kind_id() == Go::VariadicParameterDeclaration
+    }
+
+    fn is_public_func(node: &Node, code: &[u8]) -> bool {
+        // The exported names start with an upper case letter
+        node.child_by_field_name("name")
+            .and_then(|name| name.utf8_text(code))
+            .and_then(|name| name.chars().next())
+            .is_some_and(char::is_uppercase)
+    }
+}
+
+impl Checker for CsharpCode {
+    fn is_comment(node: &Node) -> bool {
+        node.kind_id() == Csharp::Comment
+    }
+
+    fn is_useful_comment(_: &Node, _: &[u8]) -> bool {
+        false
+    }
+
+    fn is_func_space(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Csharp::CompilationUnit
+                | Csharp::NamespaceDeclaration
+                | Csharp::ClassDeclaration
+                | Csharp::StructDeclaration
+                | Csharp::RecordDeclaration
+                | Csharp::InterfaceDeclaration
+        )
+    }
+
+    fn is_func(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Csharp::MethodDeclaration
+                | Csharp::ConstructorDeclaration
+                | Csharp::DestructorDeclaration
+                | Csharp::OperatorDeclaration
+                | Csharp::ConversionOperatorDeclaration
+                | Csharp::LocalFunctionStatement
+        )
+    }
+
+    fn is_public_func(node: &Node, _: &[u8]) -> bool {
+        let has_modifier = |modifier: fn(u16) -> bool| {
+            node.children().any(|child| {
+                child.kind_id() == Csharp::Modifier && child.first_child(modifier).is_some()
+            })
+        };
+
+        // The members of an interface are implicitly public
+        has_modifier(|id| id == Csharp::Public)
+            || (node
+                .parent()
+                .and_then(|body| body.parent())
+                .is_some_and(|parent| parent.kind_id() == Csharp::InterfaceDeclaration)
+                && !has_modifier(|id| id == Csharp::Private))
+    }
+
+    fn is_closure(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Csharp::LambdaExpression | Csharp::AnonymousMethodExpression
+        )
+    }
+
+    fn is_lambda_space(node: &Node) -> bool {
+        Self::is_closure(node)
+    }
+
+    fn is_chained_closure(node: &Node) -> bool {
+        if !Self::is_closure(node) {
+            return false;
+        }
+
+        let is_call = |node: &Node| node.kind_id() == Csharp::InvocationExpression;
+        // The object whose method is invoked, such as `a.Where(..)` in
+        // `a.Where(..).Select(..)`
+        fn object<'a>(call: &Node<'a>) -> Option<Node<'a>> {
+            call.child_by_field_name("function")
+                .filter(|function| function.kind_id() == Csharp::MemberAccessExpression)
+                .and_then(|function| function.child_by_field_name("expression"))
+        }
+
+        // lambda -> argument -> argument_list -> invocation_expression
+        let Some(call) = node
+            .parent()
+            .filter(|arg| arg.kind_id() == Csharp::Argument)
+            .and_then(|arg| arg.parent())
+            .filter(|args| args.kind_id() == Csharp::ArgumentList)
+            .and_then(|args| args.parent())
+            .filter(is_call)
+        else {
+            return false;
+        };
+
+        // The call either follows or precedes another call in the chain
+        object(&call).is_some_and(|object| is_call(&object))
+            || call
+                .parent()
+                .filter(|access| access.kind_id() == Csharp::MemberAccessExpression)
+                .and_then(|access| access.parent())
+                .is_some_and(|parent| {
+                    is_call(&parent)
+                        && object(&parent).is_some_and(|object| object.id() == call.id())
+                })
+    }
+
+    fn is_call(node: &Node) -> bool {
+        node.kind_id() == Csharp::InvocationExpression
+    }
+
+    fn is_non_arg(node: &Node) -> bool {
+        // The `params` argument is not wrapped in a parameter
+        !matches!(node.kind_id().into(), Csharp::Parameter | Csharp::Params)
+    }
+
+    fn is_string(node: &Node) -> bool {
+        matches!(
+            node.kind_id().into(),
+            Csharp::StringLiteral
+                | Csharp::VerbatimStringLiteral
+                | Csharp::RawStringLiteral
+                | Csharp::InterpolatedStringExpression
+        )
+    }
+
+    fn is_else_if(node: &Node) -> bool {
+        // The `else` branch of a C# `if` statement is not wrapped in a clause
+        node.kind_id() == Csharp::IfStatement
+            && node
+                .previous_sibling()
+                .is_some_and(|prev| prev.kind_id() == Csharp::Else)
+    }
+
+    fn is_primitive(_id: u16) -> bool {
+        false
+    }
+
+    fn is_defaulted_arg(node: &Node) -> bool {
+        node.kind_id() == Csharp::Parameter && node.first_child(|id| id == Csharp::EQ).is_some()
+    }
+
+    fn is_variadic_arg(node: &Node) -> bool {
+        node.kind_id() == Csharp::Params
+    }
+}
@@ -0,0 +1,133 @@
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+
+use crate::langs::*;
+use crate::mi::MiVariant;
+use crate::spaces::CodeMetrics;
+use crate::tools::{guess_language, read_file_with_eol};
+
+/// The names accepted by [`compute_metric`].
+pub const METRIC_NAMES: &[&str] = &[
+    "abc",
+    "blank",
+    "cloc",
+    "cognitive",
+    "cyclomatic",
+    "halstead",
+    "lloc",
+    "mi",
+    "mi_sei",
+    "mi_visual_studio",
+    "nargs",
+    "nexits",
+    "nom",
+    "npa",
+    "npm",
+    "ploc",
+    "sloc",
+    "wmc",
+];
+
+fn metric_value(metrics: &CodeMetrics, name: &str) -> Option<f64> {
+    let value = match name {
+        "abc" => metrics.abc.magnitude_sum(),
+        "blank" => metrics.loc.blank(),
+        "cloc" => metrics.loc.cloc(),
+        "cognitive" => metrics.cognitive.cognitive_sum(),
+        "cyclomatic" => metrics.cyclomatic.cyclomatic_sum(),
+        "halstead" => metrics.halstead.volume(),
+        "lloc" => metrics.loc.lloc(),
+        "mi" => metrics.mi.mi(MiVariant::Original),
+        "mi_sei" => metrics.mi.mi(MiVariant::Sei),
+        "mi_visual_studio" => metrics.mi.mi(MiVariant::VisualStudio),
+        "nargs" => metrics.nargs.nargs_total(),
+        "nexits" => metrics.nexits.exit_sum(),
+        "nom" => metrics.nom.total(),
+        "npa" => metrics.npa.total_npa(),
+        "npm" => metrics.npm.total_npm(),
+        "ploc" => metrics.loc.ploc(),
+        "sloc" => metrics.loc.sloc(),
+        "wmc" => metrics.wmc.total_wmc(),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Computes the metrics of a file and returns the primary value of the
+/// one named `name`, such as `"cyclomatic"`, for the whole file.
+///
+/// The primary value is the sum over the spaces of the file, such as the
+/// `Cyclomatic` sum, the `Halstead` volume for `"halstead"` and the
+/// original `MI` for `"mi"`. See [`METRIC_NAMES`] for the accepted names.
+///
+/// All the metrics are computed during the same traversal of the code,
+/// so requesting a single one does not save any time.
+///
+/// Returns an error of kind [`ErrorKind::InvalidInput`] when the name is
+/// unknown, and of kind [`ErrorKind::InvalidData`] when the language of
+/// the file is not supported or the file cannot be parsed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// use rust_code_analysis::compute_metric;
+///
+/// let cyclomatic = compute_metric("cyclomatic", Path::new("src/lib.rs")).unwrap();
+/// ```
+pub fn compute_metric(name: &str, path: &Path) -> Result<f64> {
+    if !METRIC_NAMES.contains(&name) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("unknown metric: {name}"),
+        ));
+    }
+
+    let invalid = |message: &str| Error::new(ErrorKind::InvalidData, message.to_string());
+    let source = read_file_with_eol(path)?.ok_or_else(|| invalid("the file is empty"))?;
+    let language = guess_language(&source, path)
+        .0
+        .ok_or_else(|| invalid("the language of the file is not supported"))?;
+    let space = get_function_spaces(&language, source, path, None)
+        .ok_or_else(|| invalid("the file cannot be parsed"))?;
+
+    // Safe to unwrap: the name has just been checked
+    Ok(metric_value(&space.metrics, name).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn cyclomatic_sum() {
+        let dir = std::env::temp_dir().join(format!("rca-compute-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("a.py");
+        fs::write(
+            &path,
+            "def f(a):\n    if a:\n        pass\n\ndef g(a, b):\n    return a and b\n",
+        )
+        .unwrap();
+
+        let cyclomatic = compute_metric("cyclomatic", &path);
+        let unknown = compute_metric("foo", &path);
+        fs::remove_dir_all(&dir).unwrap();
+
+        // 1 (unit) + 2 (f) + 2 (g)
+        assert_eq!(cyclomatic.unwrap(), 5.);
+        assert_eq!(unknown.unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn every_name() {
+        let path = Path::new("foo.rs");
+        let space = get_function_spaces(&LANG::Rust, b"fn f() {}".to_vec(), path, None).unwrap();
+        for name in METRIC_NAMES {
+            assert!(metric_value(&space.metrics, name).is_some(), "{name}");
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::traits::*;
+
+use crate::checker::Checker;
+use crate::getter::Getter;
+use crate::node::Node;
+
+/// A condition expression repeated within a function.
+#[derive(Debug, Serialize)]
+pub struct DuplicatedCondition {
+    /// The name of the function containing the condition
+    pub function: String,
+    /// The normalized text of the condition
+    pub condition: String,
+    /// The line of each occurrence of the condition
+    pub lines: Vec<usize>,
+}
+
+/// Detects the condition expressions which are repeated within
+/// the same function, as in `if (x > 0) {} if (x > 0) {}`.
+///
+/// Two conditions are the same when they are made of the same tokens,
+/// ignoring whitespaces, comments and any parentheses wrapping a whole
+/// condition, so `x>0` and `(x > 0)` are the same condition.
+///
+/// Returns a vector containing a [`DuplicatedCondition`] for each
+/// repeated condition, sorted by the line of its first occurrence.
+///
+/// [`DuplicatedCondition`]: struct.DuplicatedCondition.html
+pub fn duplicated_conditions<T: ParserTrait>(parser: &T) -> Vec<DuplicatedCondition> {
+    let root = parser.get_root();
+    let code = parser.get_code();
+    let mut conditions: HashMap<(usize, String), (String, Vec<usize>)> = HashMap::new();
+
+    root.act_on_node(&mut |n| {
+        let Some(condition) = n.child_by_field_name("condition") else {
+            return;
+        };
+
+        let mut function = n.parent();
+        while let Some(parent) = function {
+            if T::Checker::is_func(&parent) {
+                break;
+            }
+            function = parent.parent();
+        }
+        let Some(function) = function else {
+            return;
+        };
+
+        conditions
+            .entry((function.id(), normalize::<T>(&condition, code)))
+            .or_insert_with(|| {
+                let name = T::Getter::get_func_name(&function, code).unwrap_or("");
+                (name.to_string(), Vec::new())
+            })
+            .1
+            .push(condition.start_row() + 1);
+    });
+
+    let mut duplicates: Vec<_> = conditions
+        .into_iter()
+        .filter(|(_, (_, lines))| lines.len() > 1)
+        .map(|((_, condition), (function, lines))| DuplicatedCondition {
+            function,
+            condition,
+            lines,
+        })
+        .collect();
+    duplicates.sort_by_key(|duplicate| duplicate.lines[0]);
+
+    duplicates
+}
+
+fn normalize<T: ParserTrait>(condition: &Node, code: &[u8]) -> String {
+    let mut tokens = Vec::new();
+    push_tokens::<T>(condition, code, &mut tokens);
+
+    let mut tokens = tokens.as_slice();
+    while is_wrapped(tokens) {
+        tokens = &tokens[1..tokens.len() - 1];
+    }
+
+    let mut text = String::new();
+    for (i, token) in tokens.iter().enumerate() {
+        let glued = i == 0
+            || matches!(tokens[i - 1], "(" | "[" | "." | "->" | "::")
+            || matches!(*token, ")" | "[" | "]" | "," | "." | "->" | "::");
+        if !glued {
+            text.push(' ');
+        }
+        text.push_str(token);
+    }
+    text
+}
+
+// Pushes the texts of the leaves of a node, skipping the comments.
+// The text between the children of a node, such as the content of
+// a string literal in some grammars, is pushed verbatim as a token too.
+fn push_tokens<'a, T: ParserTrait>(node: &Node, code: &'a [u8], tokens: &mut Vec<&'a str>) {
+    let push_text = |start: usize, end: usize, tokens: &mut Vec<&'a str>| {
+        if let Ok(text) = std::str::from_utf8(&code[start..end])
+            && !text.trim().is_empty()
+        {
+            tokens.push(text);
+        }
+    };
+
+    if node.child_count() == 0 {
+        push_text(node.start_byte(), node.end_byte(), tokens);
+        return;
+    }
+
+    let mut position = node.start_byte();
+    for child in node.children() {
+        push_text(position, child.start_byte(), tokens);
+        if !T::Checker::is_comment(&child) {
+            push_tokens::<T>(&child, code, tokens);
+        }
+        position = child.end_byte();
+    }
+    push_text(position, node.end_byte(), tokens);
+}
+
+// Checks whether the opening parenthesis at the start of some tokens
+// is closed at their end, so `(a) && (b)` is not wrapped.
+fn is_wrapped(tokens: &[&str]) -> bool {
+    if tokens.first() != Some(&"(") || tokens.last() != Some(&")") {
+        return false;
+    }
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        match *token {
+            "(" => depth += 1,
+            ")" => {
+                depth -= 1;
+                if depth == 0 {
+                    return i == tokens.len() - 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{CppParser, PythonParser};
+
+    use super::*;
+
+    #[test]
+    fn cpp_repeated_condition() {
+        let path = PathBuf::from("foo.cpp");
+        let source = "int f(int x) {
+                if (x > 0) {
+                    x--;
+                }
+                if ( x >  0 ) {
+                    return 1;
+                }
+                while ((x > 0) && (x < 10)) {}
+                return 0;
+            }
+            int g(int x) {
+                return x > 0 ? 1 : 0;
+            }";
+        let parser = CppParser::new(source.as_bytes().to_vec(), &path, None);
+        let duplicates = duplicated_conditions(&parser);
+
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].function, "f");
+        assert_eq!(duplicates[0].condition, "x > 0");
+        assert_eq!(duplicates[0].lines, [2, 5]);
+    }
+
+    #[test]
+    fn python_repeated_condition() {
+        let path = PathBuf::from("foo.py");
+        let source = "def f(a):
+    if a:
+        pass
+    elif not a:
+        pass
+    while not a:
+        pass
+    if a:
+        pass";
+        let parser = PythonParser::new(source.as_bytes().to_vec(), &path, None);
+        let duplicates = duplicated_conditions(&parser);
+
+        assert_eq!(duplicates.len(), 2);
+        assert_eq!(duplicates[0].condition, "a");
+        assert_eq!(duplicates[0].lines, [2, 8]);
+        assert_eq!(duplicates[1].condition, "not a");
+        assert_eq!(duplicates[1].lines, [4, 6]);
+    }
+
+    #[test]
+    fn cpp_tokens() {
+        let path = PathBuf::from("foo.cpp");
+        let source = "int f(int x, const char *s) {
+                if (x>0) {}
+                if (x > 0 /* positive */) {}
+                if (s == \"a b\") {}
+                if (s == \"a  b\") {}
+                while (p->next != 0 && v[ x ] > 0) {}
+                while (p -> next != 0 && v[x]>0) {}
+                return 0;
+            }";
+        let parser = CppParser::new(source.as_bytes().to_vec(), &path, None);
+        let duplicates = duplicated_conditions(&parser);
+
+        assert_eq!(duplicates.len(), 2);
+        assert_eq!(duplicates[0].condition, "x > 0");
+        assert_eq!(duplicates[0].lines, [2, 3]);
+        assert_eq!(duplicates[1].condition, "p->next != 0 && v[x] > 0");
+        assert_eq!(duplicates[1].lines, [6, 7]);
+    }
+}
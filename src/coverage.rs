@@ -0,0 +1,222 @@
+use crate::abc::Abc;
+use crate::bare_ifs::BareIfs;
+use crate::captures::Captures;
+use crate::cfg::Cfg;
+use crate::chain_length::ChainLength;
+use crate::cognitive::Cognitive;
+use crate::conditional_imports::ConditionalImports;
+use crate::cyclomatic::Cyclomatic;
+use crate::decisions::Decisions;
+use crate::early_returns::EarlyReturns;
+use crate::enum_variants::EnumVariants;
+use crate::exception_types::ExceptionTypes;
+use crate::exit::Exit;
+use crate::expr_nesting::ExprNesting;
+use crate::goroutines::Goroutines;
+use crate::halstead::Halstead;
+use crate::langs::{LANG, get_metrics_coverage};
+use crate::literal_nesting::LiteralNesting;
+use crate::loc::Loc;
+use crate::mi::Mi;
+use crate::nargs::NArgs;
+use crate::negations::Negations;
+use crate::nlocals::NLocals;
+use crate::nom::Nom;
+use crate::npa::Npa;
+use crate::npm::Npm;
+use crate::referenced_types::ReferencedTypes;
+use crate::statement_count::StatementCount;
+use crate::template_complexity::TemplateComplexity;
+use crate::traits::ParserTrait;
+use crate::type_complexity::TypeComplexity;
+use crate::unsafe_code::UnsafeCode;
+use crate::wmc::Wmc;
+
+/// Whether a metric is computed for a language.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Coverage {
+    /// The metric is computed.
+    Implemented,
+    /// The metric is a stub, so its value is always the default one.
+    Stubbed,
+}
+
+impl Coverage {
+    fn new(implemented: bool) -> Self {
+        if implemented {
+            Self::Implemented
+        } else {
+            Self::Stubbed
+        }
+    }
+}
+
+// The metrics derived from other metrics, with their inputs
+const DERIVED_METRICS: &[(&str, &[&str])] = &[
+    ("mi", &["loc", "cyclomatic", "halstead"]),
+    ("wmc", &["cyclomatic"]),
+];
+
+// Returns the coverage of each metric for the language of a parser,
+// naming the metrics as their fields in the serialized spaces
+pub(crate) fn metrics_coverage_of<T: ParserTrait>() -> Vec<(&'static str, Coverage)> {
+    let implemented = [
+        ("nargs", <T::NArgs as NArgs>::IMPLEMENTED),
+        ("nexits", <T::Exit as Exit>::IMPLEMENTED),
+        ("cognitive", <T::Cognitive as Cognitive>::IMPLEMENTED),
+        ("cyclomatic", <T::Cyclomatic as Cyclomatic>::IMPLEMENTED),
+        ("halstead", <T::Halstead as Halstead>::IMPLEMENTED),
+        ("loc", <T::Loc as Loc>::IMPLEMENTED),
+        ("nom", <T::Nom as Nom>::IMPLEMENTED),
+        ("mi", <T::Mi as Mi>::IMPLEMENTED),
+        ("abc", <T::Abc as Abc>::IMPLEMENTED),
+        ("wmc", <T::Wmc as Wmc>::IMPLEMENTED),
+        ("npm", <T::Npm as Npm>::IMPLEMENTED),
+        ("npa", <T::Npa as Npa>::IMPLEMENTED),
+        ("expr_nesting", <T::ExprNesting as ExprNesting>::IMPLEMENTED),
+        ("cfg", <T::Cfg as Cfg>::IMPLEMENTED),
+        (
+            "early_returns",
+            <T::EarlyReturns as EarlyReturns>::IMPLEMENTED,
+        ),
+        (
+            "type_complexity",
+            <T::TypeComplexity as TypeComplexity>::IMPLEMENTED,
+        ),
+        ("captures", <T::Captures as Captures>::IMPLEMENTED),
+        ("nlocals", <T::NLocals as NLocals>::IMPLEMENTED),
+        ("bare_ifs", <T::BareIfs as BareIfs>::IMPLEMENTED),
+        (
+            "exception_types",
+            <T::ExceptionTypes as ExceptionTypes>::IMPLEMENTED,
+        ),
+        ("chain_length", <T::ChainLength as ChainLength>::IMPLEMENTED),
+        (
+            "statement_count",
+            <T::StatementCount as StatementCount>::IMPLEMENTED,
+        ),
+        (
+            "referenced_types",
+            <T::ReferencedTypes as ReferencedTypes>::IMPLEMENTED,
+        ),
+        (
+            "template_complexity",
+            <T::TemplateComplexity as TemplateComplexity>::IMPLEMENTED,
+        ),
+        (
+            "literal_nesting",
+            <T::LiteralNesting as LiteralNesting>::IMPLEMENTED,
+        ),
+        ("negations", <T::Negations as Negations>::IMPLEMENTED),
+        ("decisions", <T::Decisions as Decisions>::IMPLEMENTED),
+        (
+            "enum_variants",
+            <T::EnumVariants as EnumVariants>::IMPLEMENTED,
+        ),
+        (
+            "conditional_imports",
+            <T::ConditionalImports as ConditionalImports>::IMPLEMENTED,
+        ),
+        ("unsafe_code", <T::UnsafeCode as UnsafeCode>::IMPLEMENTED),
+        ("goroutines", <T::Goroutines as Goroutines>::IMPLEMENTED),
+    ];
+
+    // A derived metric is stubbed when any of its inputs is stubbed
+    let is_implemented = |metric: &str| {
+        implemented
+            .iter()
+            .any(|&(m, implemented)| m == metric && implemented)
+    };
+    implemented
+        .iter()
+        .map(|&(metric, implemented)| {
+            let inputs_implemented = DERIVED_METRICS
+                .iter()
+                .filter(|(derived, _)| *derived == metric)
+                .all(|(_, inputs)| inputs.iter().all(|input| is_implemented(input)));
+            (metric, Coverage::new(implemented && inputs_implemented))
+        })
+        .collect()
+}
+
+/// Returns which metrics are computed for each supported language,
+/// as a list of `(language, metric, coverage)` entries.
+///
+/// The coverage is derived from the implementations of the metrics,
+/// so a metric is stubbed for a language until it is implemented.
+/// A metric derived from other metrics, such as the `Mi` one, is
+/// stubbed too when any of the metrics it is derived from is stubbed.
+///
+/// # Examples
+///
+/// ```
+/// use rust_code_analysis::{Coverage, LANG, metrics_coverage};
+///
+/// for (lang, metric, coverage) in metrics_coverage() {
+///     if coverage == Coverage::Stubbed {
+///         println!("{} is not computed for {}", metric, lang.get_name());
+///     }
+/// }
+/// ```
+pub fn metrics_coverage() -> Vec<(LANG, &'static str, Coverage)> {
+    LANG::into_enum_iter()
+        .flat_map(|lang| {
+            get_metrics_coverage(&lang)
+                .into_iter()
+                .map(move |(metric, coverage)| (lang, metric, coverage))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coverage(lang: LANG, metric: &str) -> Coverage {
+        metrics_coverage()
+            .into_iter()
+            .find(|&(l, m, _)| l == lang && m == metric)
+            .map(|(_, _, coverage)| coverage)
+            .unwrap()
+    }
+
+    #[test]
+    fn stubbed_metrics() {
+        assert_eq!(coverage(LANG::Kotlin, "npm"), Coverage::Stubbed);
+        assert_eq!(coverage(LANG::Kotlin, "halstead"), Coverage::Stubbed);
+        assert_eq!(coverage(LANG::Preproc, "cyclomatic"), Coverage::Stubbed);
+        // The derived metrics inherit the stubs of their inputs
+        assert_eq!(coverage(LANG::Kotlin, "mi"), Coverage::Stubbed);
+        assert_eq!(coverage(LANG::Preproc, "wmc"), Coverage::Stubbed);
+    }
+
+    #[test]
+    fn implemented_metrics() {
+        assert_eq!(coverage(LANG::Kotlin, "cyclomatic"), Coverage::Implemented);
+        assert_eq!(coverage(LANG::Rust, "npm"), Coverage::Implemented);
+        assert_eq!(coverage(LANG::Go, "goroutines"), Coverage::Implemented);
+        // The default implementation is not a stub
+        assert_eq!(coverage(LANG::Rust, "mi"), Coverage::Implemented);
+        assert_eq!(coverage(LANG::Java, "wmc"), Coverage::Implemented);
+    }
+
+    #[test]
+    fn all_languages_and_metrics() {
+        let languages = LANG::into_enum_iter().count();
+        let metrics = metrics_coverage_of::<crate::RustParser>().len();
+        assert_eq!(metrics_coverage().len(), languages * metrics);
+
+        // Every language lists the same metrics
+        for lang in LANG::into_enum_iter() {
+            let names: Vec<_> = get_metrics_coverage(&lang)
+                .into_iter()
+                .map(|(metric, _)| metric)
+                .collect();
+            let expected: Vec<_> = metrics_coverage_of::<crate::RustParser>()
+                .into_iter()
+                .map(|(metric, _)| metric)
+                .collect();
+            assert_eq!(names, expected);
+        }
+    }
+}
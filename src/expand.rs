@@ -0,0 +1,181 @@
+use std::env;
+use std::io::{Error, Result};
+use std::path::Path;
+use std::process::Command;
+
+use crate::langs::*;
+use crate::spaces::{FuncSpace, SpaceKind};
+use crate::tools::read_file_with_eol;
+
+/// Expands the macros of the `Rust` crate whose root file is `path`,
+/// returning the expanded code as printed by `rustc -Zunpretty=expanded`.
+///
+/// The compiler is the one set in the `RUSTC` environment variable,
+/// `rustc` otherwise. As `cargo expand` does, the unstable printing of
+/// the expanded code is enabled on a stable compiler through the
+/// `RUSTC_BOOTSTRAP` environment variable.
+///
+/// The crate is expanded as a library of the 2021 edition and
+/// without its dependencies, so the macros of other crates, apart
+/// from the standard ones, cannot be expanded.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// use rust_code_analysis::expand_rust_macros;
+///
+/// let expanded = expand_rust_macros(Path::new("src/lib.rs")).unwrap();
+/// ```
+pub fn expand_rust_macros(path: &Path) -> Result<Vec<u8>> {
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let output = Command::new(rustc)
+        .env("RUSTC_BOOTSTRAP", "1")
+        .args(["-Zunpretty=expanded", "--crate-type=lib", "--edition=2021"])
+        .arg(path)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(Error::other(
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        ));
+    }
+
+    Ok(output.stdout)
+}
+
+// Returns whether an expanded space is the same as an original one.
+// Many implementations of the same type share the same name, so the
+// ones containing functions must also share at least one of them.
+fn same_space(expanded: &FuncSpace, original: &FuncSpace) -> bool {
+    expanded.kind == original.kind
+        && expanded.name == original.name
+        && (original.spaces.is_empty()
+            || original
+                .spaces
+                .iter()
+                .any(|space| expanded.spaces.iter().any(|s| s.name == space.name)))
+}
+
+// Maps the lines of the expanded spaces back to the original ones.
+// A generated space takes the lines of its closest original space,
+// such as the unit for the implementations of a `derive` attribute.
+fn map_lines(expanded: &mut FuncSpace, original: &FuncSpace) {
+    expanded.start_line = original.start_line;
+    expanded.end_line = original.end_line;
+
+    let mut originals: Vec<_> = original.spaces.iter().map(Some).collect();
+    for space in expanded.spaces.iter_mut() {
+        let matched = originals
+            .iter_mut()
+            .find(|o| o.is_some_and(|o| same_space(space, o)))
+            .and_then(|o| o.take());
+        match matched {
+            Some(matched) => map_lines(space, matched),
+            None => map_lines(space, &generated(original)),
+        }
+    }
+}
+
+fn generated(original: &FuncSpace) -> FuncSpace {
+    FuncSpace {
+        name: None,
+        spaces: Vec::new(),
+        kind: SpaceKind::Unknown,
+        ..original.clone()
+    }
+}
+
+/// Computes the function spaces data of the `Rust` crate whose root
+/// file is `path` after the expansion of its macros, so that the
+/// functions generated by the macros, such as the implementations of
+/// a `derive` attribute, are taken into account.
+///
+/// The lines of the spaces are mapped back to the ones of the root file,
+/// while a generated space takes the lines of the closest space of the
+/// source code containing it.
+///
+/// Returns `None` if the expanded code cannot be parsed.
+///
+/// See [`expand_rust_macros`] for how the macros are expanded.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// use rust_code_analysis::expanded_rust_metrics;
+///
+/// let space = expanded_rust_metrics(Path::new("src/lib.rs")).unwrap();
+/// ```
+pub fn expanded_rust_metrics(path: &Path) -> Result<Option<FuncSpace>> {
+    let expanded = expand_rust_macros(path)?;
+    let Some(mut space) = get_function_spaces(&LANG::Rust, expanded, path, None) else {
+        return Ok(None);
+    };
+
+    let original = read_file_with_eol(path)?
+        .and_then(|source| get_function_spaces(&LANG::Rust, source, path, None));
+    if let Some(original) = original {
+        map_lines(&mut space, &original);
+    }
+
+    Ok(Some(space))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn functions(space: &FuncSpace, names: &mut Vec<(String, usize)>) {
+        for space in &space.spaces {
+            if space.kind == SpaceKind::Function {
+                names.push((space.name.clone().unwrap(), space.start_line));
+            }
+            functions(space, names);
+        }
+    }
+
+    #[test]
+    fn derive_functions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lib.rs");
+        fs::write(
+            &path,
+            "#[derive(Debug, Clone)]
+struct A;
+
+impl A {
+    fn new() -> Self {
+        A
+    }
+}
+",
+        )
+        .unwrap();
+
+        let raw = get_function_spaces(&LANG::Rust, fs::read(&path).unwrap(), &path, None).unwrap();
+        let expanded = expanded_rust_metrics(&path).unwrap().unwrap();
+
+        let mut raw_names = Vec::new();
+        functions(&raw, &mut raw_names);
+        assert_eq!(raw_names, [("new".to_string(), 5)]);
+
+        // The functions generated by `derive` take the lines of the unit
+        let mut expanded_names = Vec::new();
+        functions(&expanded, &mut expanded_names);
+        expanded_names.sort();
+        assert_eq!(
+            expanded_names,
+            [
+                ("clone".to_string(), 1),
+                ("fmt".to_string(), 1),
+                ("new".to_string(), 5)
+            ]
+        );
+        assert_eq!(expanded.metrics.nom.functions_sum(), 3.);
+    }
+}
@@ -0,0 +1,273 @@
+use serde::Serialize;
+
+use crate::spaces::{FuncSpace, SpaceKind};
+
+/// The limit exceeded by a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Limit {
+    /// The maximum `Cyclomatic` complexity
+    Cyclomatic,
+    /// The maximum number of source lines
+    Length,
+    /// The maximum number of arguments
+    Params,
+}
+
+/// A function exceeding a limit of a [`ThresholdGate`].
+///
+/// [`ThresholdGate`]: struct.ThresholdGate.html
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Violation {
+    /// The name of the function
+    pub function: Option<String>,
+    /// The first line of the function
+    pub line: usize,
+    /// The exceeded limit
+    pub limit: Limit,
+    /// The value of the metric for the function
+    pub value: f64,
+    /// The maximum allowed value of the metric
+    pub max: f64,
+}
+
+/// The limits a function must not exceed, such as the ones
+/// checked to make a continuous integration job fail.
+///
+/// A `None` limit is not checked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct ThresholdGate {
+    /// The maximum `Cyclomatic` complexity of a function
+    pub max_cyclomatic: Option<f64>,
+    /// The maximum number of source lines of a function
+    pub max_length: Option<f64>,
+    /// The maximum number of arguments of a function
+    pub max_params: Option<f64>,
+    /// The `Cyclomatic` complexity under which a function is trivial,
+    /// such as a getter
+    pub min_cyclomatic: Option<f64>,
+    /// The maximum percentage of trivial functions of a file,
+    /// above which the file is over-decomposed
+    ///
+    /// It is checked only when `min_cyclomatic` is set.
+    pub max_trivial_percentage: Option<f64>,
+}
+
+/// A file whose functions are mostly trivial, such as one-line getters,
+/// which is a sign of over-decomposition.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OverDecomposition {
+    /// The number of trivial functions
+    pub trivial: usize,
+    /// The number of functions
+    pub functions: usize,
+    /// The percentage of trivial functions
+    pub percentage: f64,
+    /// The maximum allowed percentage of trivial functions
+    pub max: f64,
+}
+
+impl ThresholdGate {
+    /// Returns all the violations of the limits by the functions
+    /// contained in a space, nested ones included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_code_analysis::{metrics, ParserTrait, RustParser, ThresholdGate};
+    ///
+    /// let source_code = "fn f(a: i32, b: i32, c: i32) {}";
+    /// let path = PathBuf::from("foo.rs");
+    /// let parser = RustParser::new(source_code.as_bytes().to_vec(), &path, None);
+    /// let space = metrics(&parser, &path).unwrap();
+    ///
+    /// let gate = ThresholdGate {
+    ///     max_params: Some(2.),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(gate.check(&space).len(), 1);
+    /// ```
+    pub fn check(&self, space: &FuncSpace) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        self.check_space(space, &mut violations);
+        violations
+    }
+
+    /// Returns whether no function contained in a space
+    /// exceeds the limits, and whether the space is not over-decomposed.
+    pub fn passes(&self, space: &FuncSpace) -> bool {
+        self.check(space).is_empty() && self.check_decomposition(space).is_none()
+    }
+
+    /// Returns the number and the percentage of the trivial functions
+    /// contained in a space, nested ones included, when the percentage
+    /// exceeds the `max_trivial_percentage` limit.
+    pub fn check_decomposition(&self, space: &FuncSpace) -> Option<OverDecomposition> {
+        let min = self.min_cyclomatic?;
+        let max = self.max_trivial_percentage?;
+
+        let (mut trivial, mut functions) = (0, 0);
+        count_trivial(space, min, &mut trivial, &mut functions);
+        if functions == 0 {
+            return None;
+        }
+
+        let percentage = trivial as f64 * 100. / functions as f64;
+        (percentage > max).then_some(OverDecomposition {
+            trivial,
+            functions,
+            percentage,
+            max,
+        })
+    }
+
+    fn check_space(&self, space: &FuncSpace, violations: &mut Vec<Violation>) {
+        if matches!(space.kind, SpaceKind::Function | SpaceKind::TraitDefault) {
+            let metrics = &space.metrics;
+            let checks = [
+                (
+                    Limit::Cyclomatic,
+                    self.max_cyclomatic,
+                    metrics.cyclomatic.cyclomatic(),
+                ),
+                (Limit::Length, self.max_length, metrics.loc.sloc()),
+                (Limit::Params, self.max_params, metrics.nargs.fn_args()),
+            ];
+
+            for (limit, max, value) in checks {
+                if let Some(max) = max.filter(|&max| value > max) {
+                    violations.push(Violation {
+                        function: space.name.clone(),
+                        line: space.start_line,
+                        limit,
+                        value,
+                        max,
+                    });
+                }
+            }
+        }
+
+        for subspace in &space.spaces {
+            self.check_space(subspace, violations);
+        }
+    }
+}
+
+fn count_trivial(space: &FuncSpace, min: f64, trivial: &mut usize, functions: &mut usize) {
+    if matches!(space.kind, SpaceKind::Function | SpaceKind::TraitDefault) {
+        *functions += 1;
+        if space.metrics.cyclomatic.cyclomatic() < min {
+            *trivial += 1;
+        }
+    }
+
+    for subspace in &space.spaces {
+        count_trivial(subspace, min, trivial, functions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{ParserTrait, RustParser, metrics};
+
+    use super::*;
+
+    fn space() -> FuncSpace {
+        let path = PathBuf::from("foo.rs");
+        let source = "fn simple(x: i32) -> i32 {
+                x + 1
+            }
+            fn complex(x: i32) -> i32 {
+                if x > 0 {
+                    if x > 10 {
+                        return 2;
+                    }
+                    return 1;
+                }
+                match x {
+                    -1 => 3,
+                    -2 => 4,
+                    _ => 0,
+                }
+            }";
+        let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+        metrics(&parser, &path).unwrap()
+    }
+
+    #[test]
+    fn one_violation() {
+        let gate = ThresholdGate {
+            max_cyclomatic: Some(3.),
+            max_length: Some(20.),
+            max_params: Some(4.),
+            ..Default::default()
+        };
+        let violations = gate.check(&space());
+
+        assert_eq!(
+            violations,
+            [Violation {
+                function: Some("complex".to_string()),
+                line: 4,
+                limit: Limit::Cyclomatic,
+                value: 6.,
+                max: 3.,
+            }]
+        );
+        assert!(!gate.passes(&space()));
+    }
+
+    #[test]
+    fn no_limits() {
+        assert!(ThresholdGate::default().passes(&space()));
+
+        // Values equal to a limit are allowed
+        let gate = ThresholdGate {
+            max_cyclomatic: Some(6.),
+            max_params: Some(1.),
+            ..Default::default()
+        };
+        assert!(gate.passes(&space()));
+    }
+    #[test]
+    fn over_decomposition() {
+        let path = PathBuf::from("foo.rs");
+        let source = "impl Point {
+                fn x(&self) -> i32 { self.x }
+                fn y(&self) -> i32 { self.y }
+                fn z(&self) -> i32 { self.z }
+                fn norm(&self) -> i32 {
+                    if self.x > 0 { self.x } else { -self.x }
+                }
+            }";
+        let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+        let space = metrics(&parser, &path).unwrap();
+
+        let gate = ThresholdGate {
+            min_cyclomatic: Some(2.),
+            max_trivial_percentage: Some(50.),
+            ..Default::default()
+        };
+        assert_eq!(
+            gate.check_decomposition(&space),
+            Some(OverDecomposition {
+                trivial: 3,
+                functions: 4,
+                percentage: 75.,
+                max: 50.,
+            })
+        );
+        assert!(!gate.passes(&space));
+
+        let gate = ThresholdGate {
+            max_trivial_percentage: Some(75.),
+            ..gate
+        };
+        assert_eq!(gate.check_decomposition(&space), None);
+        assert!(gate.passes(&space));
+    }
+}
@@ -5,6 +5,9 @@ use crate::traits::Search;
 
 use crate::*;
 
+/// The name of the spaces without a name, such as closures.
+pub(crate) const ANONYMOUS: &str = "<anonymous>";
+
 macro_rules! get_operator {
     ($language:ident) => {
         #[inline(always)]
@@ -31,7 +34,7 @@ pub trait Getter {
             let code = &code[name.start_byte()..name.end_byte()];
             std::str::from_utf8(code).ok()
         } else {
-            Some("<anonymous>")
+            Some(ANONYMOUS)
         }
     }
 
@@ -51,7 +54,7 @@ pub trait Getter {
 impl Getter for PythonCode {
     fn get_space_kind(node: &Node) -> SpaceKind {
         match node.kind_id().into() {
-            Python::FunctionDefinition => SpaceKind::Function,
+            Python::FunctionDefinition | Python::Lambda => SpaceKind::Function,
             Python::ClassDefinition => SpaceKind::Class,
             Python::Module => SpaceKind::Unit,
             _ => SpaceKind::Unknown,
@@ -132,7 +135,7 @@ impl Getter for MozjsCode {
                     _ => {}
                 }
             }
-            Some("<anonymous>")
+            Some(ANONYMOUS)
         }
     }
 
@@ -200,7 +203,7 @@ impl Getter for JavascriptCode {
                     _ => {}
                 }
             }
-            Some("<anonymous>")
+            Some(ANONYMOUS)
         }
     }
 
@@ -269,7 +272,7 @@ impl Getter for TypescriptCode {
                     _ => {}
                 }
             }
-            Some("<anonymous>")
+            Some(ANONYMOUS)
         }
     }
 
@@ -338,7 +341,7 @@ impl Getter for TsxCode {
                     _ => {}
                 }
             }
-            Some("<anonymous>")
+            Some(ANONYMOUS)
         }
     }
 
@@ -376,7 +379,7 @@ impl Getter for RustCode {
             let code = &code[name.start_byte()..name.end_byte()];
             std::str::from_utf8(code).ok()
         } else {
-            Some("<anonymous>")
+            Some(ANONYMOUS)
         }
     }
 
@@ -384,6 +387,14 @@ impl Getter for RustCode {
         use Rust::*;
 
         match node.kind_id().into() {
+            FunctionItem
+                if node
+                    .parent()
+                    .and_then(|list| list.parent())
+                    .is_some_and(|parent| parent.kind_id() == TraitItem) =>
+            {
+                SpaceKind::TraitDefault
+            }
             FunctionItem | ClosureExpression => SpaceKind::Function,
             TraitItem => SpaceKind::Trait,
             ImplItem => SpaceKind::Impl,
@@ -414,14 +425,28 @@ impl Getter for RustCode {
                 }
                 _ => HalsteadType::Unknown,
             },
+            // A lifetime, such as `'a`, is a single operand spelled with its quote,
+            // so its identifier is skipped and does not clash with a variable `a`
+            Identifier => match node.parent() {
+                Some(parent) if matches!(parent.kind_id().into(), Lifetime) => {
+                    HalsteadType::Unknown
+                }
+                _ => HalsteadType::Operand,
+            },
+            // The generics are counted as the C++ templates: the type names,
+            // type parameters included, are operands, while the `::` of the
+            // paths and of the turbofish, and the `<` and `>` delimiting the
+            // generic arguments, are operators
             LPAREN | LBRACE | LBRACK | EQGT | PLUS | STAR | Async | Await | Continue | For | If
             | Let | Loop | Match | Return | Unsafe | While | EQ | COMMA | DASHGT | QMARK | LT
             | GT | AMP | MutableSpecifier | DOTDOT | DOTDOTEQ | DASH | AMPAMP | PIPE | CARET
             | EQEQ | BANGEQ | LTEQ | GTEQ | LTLT | GTGT | PERCENT | PLUSEQ | DASHEQ | STAREQ
             | SLASHEQ | PERCENTEQ | AMPEQ | PIPEEQ | CARETEQ | LTLTEQ | GTGTEQ | Move | DOT
-            | PrimitiveType | Fn | SEMI => HalsteadType::Operator,
-            Identifier | StringLiteral | RawStringLiteral | IntegerLiteral | FloatLiteral
-            | BooleanLiteral | Zelf | CharLiteral | UNDERSCORE => HalsteadType::Operand,
+            | PrimitiveType | Fn | SEMI | COLONCOLON => HalsteadType::Operator,
+            Lifetime | TypeIdentifier | StringLiteral | RawStringLiteral | IntegerLiteral
+            | FloatLiteral | BooleanLiteral | Zelf | CharLiteral | UNDERSCORE => {
+                HalsteadType::Operand
+            }
             _ => HalsteadType::Unknown,
         }
     }
@@ -576,4 +601,127 @@ impl Getter for JavaCode {
     }
 }
 
-impl Getter for KotlinCode {}
+impl Getter for HclCode {
+    fn get_space_kind(node: &Node) -> SpaceKind {
+        match node.kind_id().into() {
+            Hcl::ConfigFile => SpaceKind::Unit,
+            _ => SpaceKind::Unknown,
+        }
+    }
+}
+
+impl Getter for CsharpCode {
+    fn get_space_kind(node: &Node) -> SpaceKind {
+        use Csharp::*;
+
+        match node.kind_id().into() {
+            ClassDeclaration | RecordDeclaration => SpaceKind::Class,
+            StructDeclaration => SpaceKind::Struct,
+            InterfaceDeclaration => SpaceKind::Interface,
+            NamespaceDeclaration => SpaceKind::Namespace,
+            MethodDeclaration
+            | ConstructorDeclaration
+            | DestructorDeclaration
+            | OperatorDeclaration
+            | ConversionOperatorDeclaration
+            | LocalFunctionStatement
+            | LambdaExpression
+            | AnonymousMethodExpression => SpaceKind::Function,
+            CompilationUnit => SpaceKind::Unit,
+            _ => SpaceKind::Unknown,
+        }
+    }
+
+    fn get_op_type(node: &Node) -> HalsteadType {
+        use Csharp::*;
+
+        match node.kind_id().into() {
+            // Operator: control flow
+            | If | Else | Switch | Case | Default | Try | Catch | When | Throw | Finally | For
+            | Foreach | While | Do | Continue | Break | Goto | Return | Yield | Await | Lock
+            // Operator: keywords
+            | New | This | Base | Is | As | Typeof | Sizeof | Checked | Unchecked
+            | Using | Var | Stackalloc | With
+            // Operator: LINQ clauses
+            | From | Where | Select | Orderby | Group | By | Join | Let | Into
+            // Operator: brackets and comma and terminators (separators)
+            | SEMI | COMMA | COLONCOLON | LBRACE | LBRACK | LPAREN
+            // Operator: operators
+            | EQ | LT | GT | BANG | TILDE | QMARK | COLON | EQGT | DOT | DASHGT | DOTDOT
+            | EQEQ | LTEQ | GTEQ | BANGEQ | AMPAMP | PIPEPIPE | QMARKQMARK | PLUSPLUS | DASHDASH
+            | PLUS | DASH | STAR | SLASH | AMP | PIPE | CARET | PERCENT | LTLT | GTGT | GTGTGT
+            | PLUSEQ | DASHEQ | STAREQ | SLASHEQ | AMPEQ | PIPEEQ | CARETEQ | PERCENTEQ | LTLTEQ
+            | GTGTEQ | GTGTGTEQ | QMARKQMARKEQ
+            // primitive types
+            | PredefinedType
+            => {
+                HalsteadType::Operator
+            },
+            // Operands: variables, constants, literals
+            Identifier | NullLiteral | True | False | IntegerLiteral | RealLiteral | CharacterLiteral
+            | StringLiteral | VerbatimStringLiteral | RawStringLiteral => {
+                HalsteadType::Operand
+            },
+            _ => {
+                HalsteadType::Unknown
+            },
+        }
+    }
+
+    get_operator!(Csharp);
+}
+
+impl Getter for GoCode {
+    fn get_space_kind(node: &Node) -> SpaceKind {
+        use crate::languages::Go::*;
+
+        match node.kind_id().into() {
+            FunctionDeclaration | MethodDeclaration | FuncLiteral => SpaceKind::Function,
+            SourceFile => SpaceKind::Unit,
+            _ => SpaceKind::Unknown,
+        }
+    }
+
+    fn get_op_type(node: &Node) -> HalsteadType {
+        use crate::languages::Go::*;
+
+        match node.kind_id().into() {
+            // Operator: control flow
+            | If | Else | For | Range | Switch | Case | Default | Select | Break | Continue | Goto
+            | Fallthrough | Return | Go | Defer
+            // Operator: keywords
+            | Func | Var | Const | Type | Struct | Interface | Map | Chan
+            // Operator: brackets and comma and terminators (separators)
+            | SEMI | COMMA | COLON | LBRACE | LBRACK | LPAREN
+            // Operator: operators
+            | EQ | COLONEQ | PLUSPLUS | DASHDASH | LTDASH | DOTDOTDOT
+            | EQEQ | BANGEQ | LT | LTEQ | GT | GTEQ | AMPAMP | PIPEPIPE | BANG
+            | PLUS | DASH | STAR | SLASH | PERCENT | AMP | PIPE | CARET | AMPCARET | LTLT | GTGT | TILDE
+            | PLUSEQ | DASHEQ | STAREQ | SLASHEQ | PERCENTEQ | AMPEQ | PIPEEQ | CARETEQ | AMPCARETEQ
+            | LTLTEQ | GTGTEQ
+            => {
+                HalsteadType::Operator
+            },
+            // Operands: variables, constants, literals
+            Identifier | FieldIdentifier | PackageIdentifier | IntLiteral | FloatLiteral
+            | ImaginaryLiteral | RuneLiteral | RawStringLiteral | InterpretedStringLiteral | Nil
+            | True | False | Iota => {
+                HalsteadType::Operand
+            },
+            _ => {
+                HalsteadType::Unknown
+            },
+        }
+    }
+
+    get_operator!(Go);
+}
+
+impl Getter for KotlinCode {
+    fn get_space_kind(node: &Node) -> SpaceKind {
+        match node.kind_id().into() {
+            Kotlin::SourceFile => SpaceKind::Unit,
+            _ => SpaceKind::Unknown,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::path::{Path, PathBuf};
+
+use git2::{Delta, DiffOptions, Error, Repository};
+
+use crate::langs::*;
+use crate::spaces::FuncSpace;
+use crate::tools::{guess_language, read_file_with_eol};
+
+/// Returns the files changed since the `base` revision, such as a branch
+/// or a commit, in the working tree of the git repository containing
+/// `repo_path`.
+///
+/// Both committed and uncommitted changes are considered, untracked
+/// files included, while deleted files are skipped.
+/// Only the files written in a supported language are returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+///
+/// use rust_code_analysis::changed_files;
+///
+/// // Gets the files changed in the current branch
+/// changed_files(Path::new("."), "main").unwrap();
+/// ```
+pub fn changed_files(repo_path: &Path, base: &str) -> Result<Vec<PathBuf>, Error> {
+    let repo = Repository::discover(repo_path)?;
+    let workdir = repo
+        .workdir()
+        .ok_or_else(|| Error::from_str("bare repositories have no working tree"))?;
+    let base_tree = repo.revparse_single(base)?.peel_to_tree()?;
+
+    let mut options = DiffOptions::new();
+    options.include_untracked(true).recurse_untracked_dirs(true);
+    let diff = repo.diff_tree_to_workdir_with_index(Some(&base_tree), Some(&mut options))?;
+
+    let mut files: Vec<_> = diff
+        .deltas()
+        .filter(|delta| delta.status() != Delta::Deleted)
+        .filter_map(|delta| delta.new_file().path().map(|path| workdir.join(path)))
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .and_then(|ext| get_from_ext(&ext.to_lowercase()))
+                .is_some()
+        })
+        .collect();
+    files.sort();
+
+    Ok(files)
+}
+
+/// Computes the function spaces data of the files changed since
+/// the `base` revision in the git repository containing `repo_path`.
+///
+/// The files which cannot be read or parsed are skipped.
+///
+/// See [`changed_files`] for the files taken into account.
+///
+/// [`changed_files`]: fn.changed_files.html
+pub fn changed_files_metrics(
+    repo_path: &Path,
+    base: &str,
+) -> Result<Vec<(PathBuf, FuncSpace)>, Error> {
+    Ok(changed_files(repo_path, base)?
+        .into_iter()
+        .filter_map(|path| {
+            let source = read_file_with_eol(&path).ok()??;
+            let language = guess_language(&source, &path).0?;
+            let space = get_function_spaces(&language, source, &path, None)?;
+            Some((path, space))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use git2::{Commit, Signature};
+
+    use super::*;
+
+    fn commit_all(repo: &Repository, parent: Option<&Commit>) -> git2::Oid {
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        index.update_all(["*"], None).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::now("foo", "foo@example.com").unwrap();
+        let parents: Vec<_> = parent.into_iter().collect();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            "commit",
+            &tree,
+            &parents,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn only_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+
+        fs::write(dir.path().join("a.rs"), "fn a() {}\n").unwrap();
+        fs::write(dir.path().join("b.rs"), "fn b() {}\n").unwrap();
+        let base = commit_all(&repo, None);
+
+        fs::write(dir.path().join("b.rs"), "fn b() { if true {} }\n").unwrap();
+        fs::write(dir.path().join("notes.txt"), "not a source file\n").unwrap();
+
+        let changed = changed_files_metrics(dir.path(), &base.to_string()).unwrap();
+        assert_eq!(changed.len(), 1);
+
+        let (path, space) = &changed[0];
+        assert_eq!(path.file_name().unwrap(), "b.rs");
+        assert_eq!(space.spaces[0].name.as_deref(), Some("b"));
+        assert_eq!(space.spaces[0].metrics.cyclomatic.cyclomatic(), 2.);
+    }
+
+    #[test]
+    fn committed_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+
+        fs::write(dir.path().join("a.py"), "def a():\n    pass\n").unwrap();
+        fs::write(dir.path().join("b.py"), "def b():\n    pass\n").unwrap();
+        let base = commit_all(&repo, None);
+
+        fs::remove_file(dir.path().join("b.py")).unwrap();
+        fs::write(dir.path().join("c.py"), "def c():\n    pass\n").unwrap();
+        let base_commit = repo.find_commit(base).unwrap();
+        commit_all(&repo, Some(&base_commit));
+
+        let changed = changed_files(dir.path(), &base.to_string()).unwrap();
+        let names: Vec<_> = changed
+            .iter()
+            .map(|path| path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(names, ["c.py"]);
+    }
+}
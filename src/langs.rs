@@ -47,6 +47,26 @@ mk_langs!(
         [java],
         ["java"]
     ),
+    (
+        Csharp,
+        "The `C#` language",
+        "csharp",
+        CsharpCode,
+        CsharpParser,
+        tree_sitter_c_sharp,
+        [cs],
+        ["csharp"]
+    ),
+    (
+        Go,
+        "The `Go` language",
+        "go",
+        GoCode,
+        GoParser,
+        tree_sitter_go,
+        [go],
+        ["go"]
+    ),
     (
         Kotlin,
         "The `Kotlin` language",
@@ -57,6 +77,16 @@ mk_langs!(
         [kt, kts],
         ["kotlin"]
     ),
+    (
+        Hcl,
+        "The `HCL` language, such as the `Terraform` configurations",
+        "hcl",
+        HclCode,
+        HclParser,
+        tree_sitter_hcl,
+        [hcl, tf, tfvars],
+        ["hcl", "terraform"]
+    ),
     (
         Rust,
         "The `Rust` language",
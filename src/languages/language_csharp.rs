@@ -0,0 +1,1052 @@
+// Code generated; DO NOT EDIT.
+
+use num_derive::FromPrimitive;
+
+#[derive(Clone, Debug, PartialEq, Eq, FromPrimitive)]
+pub enum Csharp {
+    End = 0,
+    IdentifierToken = 1,
+    Extern = 2,
+    Alias = 3,
+    SEMI = 4,
+    Global = 5,
+    Using = 6,
+    Unsafe = 7,
+    EQ = 8,
+    Static = 9,
+    LBRACK = 10,
+    Assembly = 11,
+    Module = 12,
+    COLON = 13,
+    COMMA = 14,
+    RBRACK = 15,
+    LPAREN = 16,
+    RPAREN = 17,
+    Field = 18,
+    Event = 19,
+    Method = 20,
+    Param = 21,
+    Property = 22,
+    Return = 23,
+    Type = 24,
+    Namespace = 25,
+    Class = 26,
+    Ref = 27,
+    Struct = 28,
+    Enum = 29,
+    LBRACE = 30,
+    RBRACE = 31,
+    Interface = 32,
+    Delegate = 33,
+    Record = 34,
+    Abstract = 35,
+    Async = 36,
+    Const = 37,
+    File = 38,
+    Fixed = 39,
+    Internal = 40,
+    New = 41,
+    Override = 42,
+    Partial = 43,
+    Private = 44,
+    Protected = 45,
+    Public = 46,
+    Readonly = 47,
+    Required = 48,
+    Sealed = 49,
+    Virtual = 50,
+    Volatile = 51,
+    LT = 52,
+    GT = 53,
+    In = 54,
+    Out = 55,
+    Where = 56,
+    QMARK = 57,
+    Notnull = 58,
+    Unmanaged = 59,
+    Operator = 60,
+    Checked = 61,
+    BANG = 62,
+    TILDE = 63,
+    PLUSPLUS = 64,
+    DASHDASH = 65,
+    True = 66,
+    False = 67,
+    PLUS = 68,
+    DASH = 69,
+    STAR = 70,
+    SLASH = 71,
+    PERCENT = 72,
+    CARET = 73,
+    PIPE = 74,
+    AMP = 75,
+    LTLT = 76,
+    GTGT = 77,
+    GTGTGT = 78,
+    EQEQ = 79,
+    BANGEQ = 80,
+    GTEQ = 81,
+    LTEQ = 82,
+    Implicit = 83,
+    Explicit = 84,
+    Get = 85,
+    Set = 86,
+    Add = 87,
+    Remove = 88,
+    Init = 89,
+    This = 90,
+    DOT = 91,
+    Scoped = 92,
+    Params = 93,
+    Base = 94,
+    EQGT = 95,
+    COLONCOLON = 96,
+    Var = 97,
+    Managed = 98,
+    Cdecl = 99,
+    Stdcall = 100,
+    Thiscall = 101,
+    Fastcall = 102,
+    PredefinedType = 103,
+    Break = 104,
+    Unchecked = 105,
+    Continue = 106,
+    Do = 107,
+    While = 108,
+    For = 109,
+    Lock = 110,
+    Yield = 111,
+    Switch = 112,
+    Case = 113,
+    Default = 114,
+    Throw = 115,
+    Try = 116,
+    Catch = 117,
+    When = 118,
+    Finally = 119,
+    Await = 120,
+    Foreach = 121,
+    Goto = 122,
+    If = 123,
+    Else = 124,
+    Discard = 125,
+    DOTDOT = 126,
+    Not = 127,
+    And = 128,
+    Or = 129,
+    PLUSEQ = 130,
+    DASHEQ = 131,
+    STAREQ = 132,
+    SLASHEQ = 133,
+    PERCENTEQ = 134,
+    AMPEQ = 135,
+    CARETEQ = 136,
+    PIPEEQ = 137,
+    LTLTEQ = 138,
+    GTGTEQ = 139,
+    GTGTGTEQ = 140,
+    QMARKQMARKEQ = 141,
+    AMPAMP = 142,
+    PIPEPIPE = 143,
+    QMARKQMARK = 144,
+    From = 145,
+    Into = 146,
+    Join = 147,
+    On = 148,
+    Equals = 149,
+    Let = 150,
+    Orderby = 151,
+    Ascending = 152,
+    Descending = 153,
+    Group = 154,
+    By = 155,
+    Select = 156,
+    As = 157,
+    Is = 158,
+    InterpolationFormatClauseToken1 = 159,
+    DASHGT = 160,
+    Stackalloc = 161,
+    With = 162,
+    Sizeof = 163,
+    Typeof = 164,
+    Makeref = 165,
+    Reftype = 166,
+    Refvalue = 167,
+    NullLiteral = 168,
+    SQUOTE = 169,
+    CharacterLiteralContent = 170,
+    IntegerLiteral = 171,
+    RealLiteral = 172,
+    DQUOTE = 173,
+    StringLiteralContentToken1 = 174,
+    StringLiteralContentToken2 = 175,
+    EscapeSequence = 176,
+    StringLiteralEncoding = 177,
+    VerbatimStringLiteral = 178,
+    RawStringLiteralToken1 = 179,
+    HASHif = 180,
+    PreprocIfToken2 = 181,
+    HASHendif = 182,
+    HASHelse = 183,
+    HASHelif = 184,
+    PreprocArg = 185,
+    HASHregion = 186,
+    HASHendregion = 187,
+    HASHline = 188,
+    Hidden = 189,
+    HASHpragma = 190,
+    Warning = 191,
+    Disable = 192,
+    Restore = 193,
+    Checksum = 194,
+    HASHnullable = 195,
+    Enable = 196,
+    Annotations = 197,
+    Warnings = 198,
+    HASHerror = 199,
+    HASHwarning = 200,
+    HASHdefine = 201,
+    HASHundef = 202,
+    ShebangDirective = 203,
+    Comment = 204,
+    OptionalSemi = 205,
+    InterpolationStart = 206,
+    InterpolationStart2 = 207,
+    InterpolationStart3 = 208,
+    DQUOTE2 = 209,
+    DQUOTE3 = 210,
+    InterpolationBrace = 211,
+    InterpolationBrace2 = 212,
+    StringContent = 213,
+    RawStringStart = 214,
+    RawStringEnd = 215,
+    RawStringContent = 216,
+    CompilationUnit = 217,
+    TopLevelItem = 218,
+    GlobalStatement = 219,
+    ExternAliasDirective = 220,
+    UsingDirective = 221,
+    GlobalAttribute = 222,
+    Attribute = 223,
+    AttributeArgumentList = 224,
+    AttributeArgument = 225,
+    AttributeList = 226,
+    AttributeTargetSpecifier = 227,
+    NamespaceDeclaration = 228,
+    FileScopedNamespaceDeclaration = 229,
+    TypeDeclaration = 230,
+    ClassDeclaration = 231,
+    StructDeclaration = 232,
+    EnumDeclaration = 233,
+    EnumMemberDeclarationList = 234,
+    EnumMemberDeclaration = 235,
+    InterfaceDeclaration = 236,
+    DelegateDeclaration = 237,
+    RecordDeclaration = 238,
+    BaseList = 239,
+    PrimaryConstructorBaseType = 240,
+    Modifier = 241,
+    TypeParameterList = 242,
+    TypeParameter = 243,
+    BaseList2 = 244,
+    TypeParameterConstraintsClause = 245,
+    TypeParameterConstraint = 246,
+    ConstructorConstraint = 247,
+    OperatorDeclaration = 248,
+    ConversionOperatorDeclaration = 249,
+    DeclarationList = 250,
+    Declaration = 251,
+    FieldDeclaration = 252,
+    ConstructorDeclaration = 253,
+    DestructorDeclaration = 254,
+    MethodDeclaration = 255,
+    EventDeclaration = 256,
+    EventFieldDeclaration = 257,
+    AccessorList = 258,
+    AccessorDeclaration = 259,
+    IndexerDeclaration = 260,
+    BracketedParameterList = 261,
+    PropertyDeclaration = 262,
+    ExplicitInterfaceSpecifier = 263,
+    ParameterList = 264,
+    Parameter = 265,
+    ParameterArray = 266,
+    ConstructorInitializer = 267,
+    ArgumentList = 268,
+    TuplePattern = 269,
+    Argument = 270,
+    Block = 271,
+    ArrowExpressionClause = 272,
+    FunctionBody = 273,
+    VariableDeclaration = 274,
+    VariableDeclaration2 = 275,
+    VariableDeclarator = 276,
+    VariableDeclarator2 = 277,
+    BracketedArgumentList = 278,
+    Name = 279,
+    AliasQualifiedName = 280,
+    SimpleName = 281,
+    QualifiedName = 282,
+    GenericName = 283,
+    TypeArgumentList = 284,
+    Type2 = 285,
+    ImplicitType = 286,
+    ArrayType = 287,
+    ArrayBaseType = 288,
+    ArrayRankSpecifier = 289,
+    NullableType = 290,
+    PointerType = 291,
+    PointerBaseType = 292,
+    FunctionPointerType = 293,
+    CallingConvention = 294,
+    FunctionPointerParameter = 295,
+    RefType = 296,
+    RefBaseType = 297,
+    ScopedType = 298,
+    ScopedBaseType = 299,
+    TupleType = 300,
+    TupleElement = 301,
+    Statement = 302,
+    BreakStatement = 303,
+    CheckedStatement = 304,
+    ContinueStatement = 305,
+    DoStatement = 306,
+    EmptyStatement = 307,
+    ExpressionStatement = 308,
+    FixedStatement = 309,
+    ForStatement = 310,
+    ReturnStatement = 311,
+    LockStatement = 312,
+    YieldStatement = 313,
+    SwitchStatement = 314,
+    SwitchBody = 315,
+    SwitchSection = 316,
+    ThrowStatement = 317,
+    TryStatement = 318,
+    CatchClause = 319,
+    CatchDeclaration = 320,
+    CatchFilterClause = 321,
+    FinallyClause = 322,
+    UnsafeStatement = 323,
+    UsingStatement = 324,
+    ForeachStatement = 325,
+    GotoStatement = 326,
+    LabeledStatement = 327,
+    IfStatement = 328,
+    WhileStatement = 329,
+    LocalDeclarationStatement = 330,
+    LocalFunctionStatement = 331,
+    Pattern = 332,
+    ConstantPattern = 333,
+    ParenthesizedPattern = 334,
+    VarPattern = 335,
+    TypePattern = 336,
+    ListPattern = 337,
+    RecursivePattern = 338,
+    PositionalPatternClause = 339,
+    PropertyPatternClause = 340,
+    Subpattern = 341,
+    RelationalPattern = 342,
+    NegatedPattern = 343,
+    AndPattern = 344,
+    OrPattern = 345,
+    DeclarationPattern = 346,
+    VariableDesignation = 347,
+    ParenthesizedVariableDesignation = 348,
+    Expression = 349,
+    NonLvalueExpression = 350,
+    LvalueExpression = 351,
+    ExpressionStatementExpression = 352,
+    AssignmentExpression = 353,
+    BinaryExpression = 354,
+    PostfixUnaryExpression = 355,
+    PrefixUnaryExpression = 356,
+    PrefixUnaryExpression2 = 357,
+    QueryExpression = 358,
+    FromClause = 359,
+    QueryBody = 360,
+    QueryClause = 361,
+    JoinClause = 362,
+    JoinHeader = 363,
+    JoinBody = 364,
+    JoinIntoClause = 365,
+    LetClause = 366,
+    OrderByClause = 367,
+    Ordering = 368,
+    WhereClause = 369,
+    SelectOrGroupClause = 370,
+    GroupClause = 371,
+    SelectClause = 372,
+    ConditionalExpression = 373,
+    ConditionalAccessExpression = 374,
+    AsExpression = 375,
+    IsExpression = 376,
+    IsPatternExpression = 377,
+    CastExpression = 378,
+    CheckedExpression = 379,
+    InvocationExpression = 380,
+    SwitchExpression = 381,
+    SwitchExpressionArm = 382,
+    WhenClause = 383,
+    AwaitExpression = 384,
+    ThrowExpression = 385,
+    ElementAccessExpression = 386,
+    InterpolatedStringExpression = 387,
+    InterpolatedStringContent = 388,
+    InterpolatedVerbatimStringContent = 389,
+    InterpolatedRawStringContent = 390,
+    Interpolation = 391,
+    InterpolationAlignmentClause = 392,
+    InterpolationFormatClause = 393,
+    MemberAccessExpression = 394,
+    MemberBindingExpression = 395,
+    ObjectCreationExpression = 396,
+    ParenthesizedExpression = 397,
+    ParenthesizedExpression2 = 398,
+    LambdaExpression = 399,
+    LambdaParameters = 400,
+    ArrayCreationExpression = 401,
+    AnonymousMethodExpression = 402,
+    AnonymousObjectCreationExpression = 403,
+    AnonymousObjectMemberDeclarator = 404,
+    ImplicitArrayCreationExpression = 405,
+    ImplicitObjectCreationExpression = 406,
+    ImplicitStackallocExpression = 407,
+    InitializerExpression = 408,
+    DeclarationExpression = 409,
+    DefaultExpression = 410,
+    WithExpression = 411,
+    WithInitializer = 412,
+    SizeofExpression = 413,
+    TypeofExpression = 414,
+    MakerefExpression = 415,
+    RefExpression = 416,
+    ReftypeExpression = 417,
+    RefvalueExpression = 418,
+    StackallocExpression = 419,
+    RangeExpression = 420,
+    TupleExpression = 421,
+    Literal = 422,
+    CharacterLiteral = 423,
+    StringLiteral = 424,
+    StringLiteralContent = 425,
+    RawStringLiteral = 426,
+    BooleanLiteral = 427,
+    Identifier = 428,
+    ReservedIdentifier = 429,
+    PreprocIf = 430,
+    PreprocElse = 431,
+    PreprocElif = 432,
+    PreprocIf2 = 433,
+    PreprocElse2 = 434,
+    PreprocElif2 = 435,
+    PreprocIf3 = 436,
+    PreprocElse3 = 437,
+    PreprocElif3 = 438,
+    PreprocIf4 = 439,
+    PreprocElse4 = 440,
+    PreprocElif4 = 441,
+    PreprocExpression = 442,
+    ParenthesizedExpression3 = 443,
+    UnaryExpression = 444,
+    BinaryExpression2 = 445,
+    PreprocRegion = 446,
+    PreprocEndregion = 447,
+    PreprocLine = 448,
+    PreprocPragma = 449,
+    PreprocNullable = 450,
+    PreprocError = 451,
+    PreprocWarning = 452,
+    PreprocDefine = 453,
+    PreprocUndef = 454,
+    CompilationUnitRepeat1 = 455,
+    GlobalAttributeRepeat1 = 456,
+    AttributeArgumentListRepeat1 = 457,
+    ClassDeclarationRepeat1 = 458,
+    ClassDeclarationRepeat2 = 459,
+    ClassDeclarationRepeat3 = 460,
+    EnumMemberDeclarationListRepeat1 = 461,
+    RecordBaseRepeat1 = 462,
+    TypeParameterListRepeat1 = 463,
+    BaseListRepeat1 = 464,
+    TypeParameterConstraintsClauseRepeat1 = 465,
+    DeclarationListRepeat1 = 466,
+    AccessorListRepeat1 = 467,
+    BracketedParameterListRepeat1 = 468,
+    ParameterTypeWithModifiersRepeat1 = 469,
+    ArgumentListRepeat1 = 470,
+    TuplePatternRepeat1 = 471,
+    BlockRepeat1 = 472,
+    VariableDeclarationRepeat1 = 473,
+    UsingVariableDeclarationRepeat1 = 474,
+    TypeArgumentListRepeat1 = 475,
+    TypeArgumentListRepeat2 = 476,
+    ArrayRankSpecifierRepeat1 = 477,
+    FunctionPointerTypeRepeat1 = 478,
+    CallingConventionRepeat1 = 479,
+    TupleTypeRepeat1 = 480,
+    ForStatementRepeat1 = 481,
+    SwitchBodyRepeat1 = 482,
+    TryStatementRepeat1 = 483,
+    ListPatternRepeat1 = 484,
+    PositionalPatternClauseRepeat1 = 485,
+    ParenthesizedVariableDesignationRepeat1 = 486,
+    QueryBodyRepeat1 = 487,
+    QueryBodyRepeat2 = 488,
+    OrderByClauseRepeat1 = 489,
+    SwitchExpressionRepeat1 = 490,
+    InterpolatedStringExpressionRepeat1 = 491,
+    InterpolatedStringExpressionRepeat2 = 492,
+    InterpolatedStringExpressionRepeat3 = 493,
+    LambdaExpressionRepeat1 = 494,
+    AnonymousObjectCreationExpressionRepeat1 = 495,
+    WithExpressionRepeat1 = 496,
+    StringLiteralRepeat1 = 497,
+    PreprocIfInTopLevelRepeat1 = 498,
+    PreprocPragmaRepeat1 = 499,
+    ElementBindingExpression = 500,
+    ImplicitParameter = 501,
+    InterpolationQuote = 502,
+    Error = 503,
+}
+
+impl From<Csharp> for &'static str {
+    #[inline(always)]
+    fn from(tok: Csharp) -> Self {
+        match tok {
+            Csharp::End => "end",
+            Csharp::IdentifierToken => "_identifier_token",
+            Csharp::Extern => "extern",
+            Csharp::Alias => "alias",
+            Csharp::SEMI => ";",
+            Csharp::Global => "global",
+            Csharp::Using => "using",
+            Csharp::Unsafe => "unsafe",
+            Csharp::EQ => "=",
+            Csharp::Static => "static",
+            Csharp::LBRACK => "[",
+            Csharp::Assembly => "assembly",
+            Csharp::Module => "module",
+            Csharp::COLON => ":",
+            Csharp::COMMA => ",",
+            Csharp::RBRACK => "]",
+            Csharp::LPAREN => "(",
+            Csharp::RPAREN => ")",
+            Csharp::Field => "field",
+            Csharp::Event => "event",
+            Csharp::Method => "method",
+            Csharp::Param => "param",
+            Csharp::Property => "property",
+            Csharp::Return => "return",
+            Csharp::Type => "type",
+            Csharp::Namespace => "namespace",
+            Csharp::Class => "class",
+            Csharp::Ref => "ref",
+            Csharp::Struct => "struct",
+            Csharp::Enum => "enum",
+            Csharp::LBRACE => "{",
+            Csharp::RBRACE => "}",
+            Csharp::Interface => "interface",
+            Csharp::Delegate => "delegate",
+            Csharp::Record => "record",
+            Csharp::Abstract => "abstract",
+            Csharp::Async => "async",
+            Csharp::Const => "const",
+            Csharp::File => "file",
+            Csharp::Fixed => "fixed",
+            Csharp::Internal => "internal",
+            Csharp::New => "new",
+            Csharp::Override => "override",
+            Csharp::Partial => "partial",
+            Csharp::Private => "private",
+            Csharp::Protected => "protected",
+            Csharp::Public => "public",
+            Csharp::Readonly => "readonly",
+            Csharp::Required => "required",
+            Csharp::Sealed => "sealed",
+            Csharp::Virtual => "virtual",
+            Csharp::Volatile => "volatile",
+            Csharp::LT => "<",
+            Csharp::GT => ">",
+            Csharp::In => "in",
+            Csharp::Out => "out",
+            Csharp::Where => "where",
+            Csharp::QMARK => "?",
+            Csharp::Notnull => "notnull",
+            Csharp::Unmanaged => "unmanaged",
+            Csharp::Operator => "operator",
+            Csharp::Checked => "checked",
+            Csharp::BANG => "!",
+            Csharp::TILDE => "~",
+            Csharp::PLUSPLUS => "++",
+            Csharp::DASHDASH => "--",
+            Csharp::True => "true",
+            Csharp::False => "false",
+            Csharp::PLUS => "+",
+            Csharp::DASH => "-",
+            Csharp::STAR => "*",
+            Csharp::SLASH => "/",
+            Csharp::PERCENT => "%",
+            Csharp::CARET => "^",
+            Csharp::PIPE => "|",
+            Csharp::AMP => "&",
+            Csharp::LTLT => "<<",
+            Csharp::GTGT => ">>",
+            Csharp::GTGTGT => ">>>",
+            Csharp::EQEQ => "==",
+            Csharp::BANGEQ => "!=",
+            Csharp::GTEQ => ">=",
+            Csharp::LTEQ => "<=",
+            Csharp::Implicit => "implicit",
+            Csharp::Explicit => "explicit",
+            Csharp::Get => "get",
+            Csharp::Set => "set",
+            Csharp::Add => "add",
+            Csharp::Remove => "remove",
+            Csharp::Init => "init",
+            Csharp::This => "this",
+            Csharp::DOT => ".",
+            Csharp::Scoped => "scoped",
+            Csharp::Params => "params",
+            Csharp::Base => "base",
+            Csharp::EQGT => "=>",
+            Csharp::COLONCOLON => "::",
+            Csharp::Var => "var",
+            Csharp::Managed => "managed",
+            Csharp::Cdecl => "Cdecl",
+            Csharp::Stdcall => "Stdcall",
+            Csharp::Thiscall => "Thiscall",
+            Csharp::Fastcall => "Fastcall",
+            Csharp::PredefinedType => "predefined_type",
+            Csharp::Break => "break",
+            Csharp::Unchecked => "unchecked",
+            Csharp::Continue => "continue",
+            Csharp::Do => "do",
+            Csharp::While => "while",
+            Csharp::For => "for",
+            Csharp::Lock => "lock",
+            Csharp::Yield => "yield",
+            Csharp::Switch => "switch",
+            Csharp::Case => "case",
+            Csharp::Default => "default",
+            Csharp::Throw => "throw",
+            Csharp::Try => "try",
+            Csharp::Catch => "catch",
+            Csharp::When => "when",
+            Csharp::Finally => "finally",
+            Csharp::Await => "await",
+            Csharp::Foreach => "foreach",
+            Csharp::Goto => "goto",
+            Csharp::If => "if",
+            Csharp::Else => "else",
+            Csharp::Discard => "discard",
+            Csharp::DOTDOT => "..",
+            Csharp::Not => "not",
+            Csharp::And => "and",
+            Csharp::Or => "or",
+            Csharp::PLUSEQ => "+=",
+            Csharp::DASHEQ => "-=",
+            Csharp::STAREQ => "*=",
+            Csharp::SLASHEQ => "/=",
+            Csharp::PERCENTEQ => "%=",
+            Csharp::AMPEQ => "&=",
+            Csharp::CARETEQ => "^=",
+            Csharp::PIPEEQ => "|=",
+            Csharp::LTLTEQ => "<<=",
+            Csharp::GTGTEQ => ">>=",
+            Csharp::GTGTGTEQ => ">>>=",
+            Csharp::QMARKQMARKEQ => "??=",
+            Csharp::AMPAMP => "&&",
+            Csharp::PIPEPIPE => "||",
+            Csharp::QMARKQMARK => "??",
+            Csharp::From => "from",
+            Csharp::Into => "into",
+            Csharp::Join => "join",
+            Csharp::On => "on",
+            Csharp::Equals => "equals",
+            Csharp::Let => "let",
+            Csharp::Orderby => "orderby",
+            Csharp::Ascending => "ascending",
+            Csharp::Descending => "descending",
+            Csharp::Group => "group",
+            Csharp::By => "by",
+            Csharp::Select => "select",
+            Csharp::As => "as",
+            Csharp::Is => "is",
+            Csharp::InterpolationFormatClauseToken1 => "interpolation_format_clause_token1",
+            Csharp::DASHGT => "->",
+            Csharp::Stackalloc => "stackalloc",
+            Csharp::With => "with",
+            Csharp::Sizeof => "sizeof",
+            Csharp::Typeof => "typeof",
+            Csharp::Makeref => "__makeref",
+            Csharp::Reftype => "__reftype",
+            Csharp::Refvalue => "__refvalue",
+            Csharp::NullLiteral => "null_literal",
+            Csharp::SQUOTE => "'",
+            Csharp::CharacterLiteralContent => "character_literal_content",
+            Csharp::IntegerLiteral => "integer_literal",
+            Csharp::RealLiteral => "real_literal",
+            Csharp::DQUOTE => "\"",
+            Csharp::StringLiteralContentToken1 => "string_literal_content_token1",
+            Csharp::StringLiteralContentToken2 => "string_literal_content_token2",
+            Csharp::EscapeSequence => "escape_sequence",
+            Csharp::StringLiteralEncoding => "string_literal_encoding",
+            Csharp::VerbatimStringLiteral => "verbatim_string_literal",
+            Csharp::RawStringLiteralToken1 => "raw_string_literal_token1",
+            Csharp::HASHif => "#if",
+            Csharp::PreprocIfToken2 => "preproc_if_token2",
+            Csharp::HASHendif => "#endif",
+            Csharp::HASHelse => "#else",
+            Csharp::HASHelif => "#elif",
+            Csharp::PreprocArg => "preproc_arg",
+            Csharp::HASHregion => "#region",
+            Csharp::HASHendregion => "#endregion",
+            Csharp::HASHline => "#line",
+            Csharp::Hidden => "hidden",
+            Csharp::HASHpragma => "#pragma",
+            Csharp::Warning => "warning",
+            Csharp::Disable => "disable",
+            Csharp::Restore => "restore",
+            Csharp::Checksum => "checksum",
+            Csharp::HASHnullable => "#nullable",
+            Csharp::Enable => "enable",
+            Csharp::Annotations => "annotations",
+            Csharp::Warnings => "warnings",
+            Csharp::HASHerror => "#error",
+            Csharp::HASHwarning => "#warning",
+            Csharp::HASHdefine => "#define",
+            Csharp::HASHundef => "#undef",
+            Csharp::ShebangDirective => "shebang_directive",
+            Csharp::Comment => "comment",
+            Csharp::OptionalSemi => "_optional_semi",
+            Csharp::InterpolationStart => "interpolation_start",
+            Csharp::InterpolationStart2 => "interpolation_start",
+            Csharp::InterpolationStart3 => "interpolation_start",
+            Csharp::DQUOTE2 => "\"",
+            Csharp::DQUOTE3 => "\"",
+            Csharp::InterpolationBrace => "interpolation_brace",
+            Csharp::InterpolationBrace2 => "interpolation_brace",
+            Csharp::StringContent => "string_content",
+            Csharp::RawStringStart => "raw_string_start",
+            Csharp::RawStringEnd => "raw_string_end",
+            Csharp::RawStringContent => "raw_string_content",
+            Csharp::CompilationUnit => "compilation_unit",
+            Csharp::TopLevelItem => "_top_level_item",
+            Csharp::GlobalStatement => "global_statement",
+            Csharp::ExternAliasDirective => "extern_alias_directive",
+            Csharp::UsingDirective => "using_directive",
+            Csharp::GlobalAttribute => "global_attribute",
+            Csharp::Attribute => "attribute",
+            Csharp::AttributeArgumentList => "attribute_argument_list",
+            Csharp::AttributeArgument => "attribute_argument",
+            Csharp::AttributeList => "attribute_list",
+            Csharp::AttributeTargetSpecifier => "attribute_target_specifier",
+            Csharp::NamespaceDeclaration => "namespace_declaration",
+            Csharp::FileScopedNamespaceDeclaration => "file_scoped_namespace_declaration",
+            Csharp::TypeDeclaration => "type_declaration",
+            Csharp::ClassDeclaration => "class_declaration",
+            Csharp::StructDeclaration => "struct_declaration",
+            Csharp::EnumDeclaration => "enum_declaration",
+            Csharp::EnumMemberDeclarationList => "enum_member_declaration_list",
+            Csharp::EnumMemberDeclaration => "enum_member_declaration",
+            Csharp::InterfaceDeclaration => "interface_declaration",
+            Csharp::DelegateDeclaration => "delegate_declaration",
+            Csharp::RecordDeclaration => "record_declaration",
+            Csharp::BaseList => "base_list",
+            Csharp::PrimaryConstructorBaseType => "primary_constructor_base_type",
+            Csharp::Modifier => "modifier",
+            Csharp::TypeParameterList => "type_parameter_list",
+            Csharp::TypeParameter => "type_parameter",
+            Csharp::BaseList2 => "base_list",
+            Csharp::TypeParameterConstraintsClause => "type_parameter_constraints_clause",
+            Csharp::TypeParameterConstraint => "type_parameter_constraint",
+            Csharp::ConstructorConstraint => "constructor_constraint",
+            Csharp::OperatorDeclaration => "operator_declaration",
+            Csharp::ConversionOperatorDeclaration => "conversion_operator_declaration",
+            Csharp::DeclarationList => "declaration_list",
+            Csharp::Declaration => "declaration",
+            Csharp::FieldDeclaration => "field_declaration",
+            Csharp::ConstructorDeclaration => "constructor_declaration",
+            Csharp::DestructorDeclaration => "destructor_declaration",
+            Csharp::MethodDeclaration => "method_declaration",
+            Csharp::EventDeclaration => "event_declaration",
+            Csharp::EventFieldDeclaration => "event_field_declaration",
+            Csharp::AccessorList => "accessor_list",
+            Csharp::AccessorDeclaration => "accessor_declaration",
+            Csharp::IndexerDeclaration => "indexer_declaration",
+            Csharp::BracketedParameterList => "bracketed_parameter_list",
+            Csharp::PropertyDeclaration => "property_declaration",
+            Csharp::ExplicitInterfaceSpecifier => "explicit_interface_specifier",
+            Csharp::ParameterList => "parameter_list",
+            Csharp::Parameter => "parameter",
+            Csharp::ParameterArray => "_parameter_array",
+            Csharp::ConstructorInitializer => "constructor_initializer",
+            Csharp::ArgumentList => "argument_list",
+            Csharp::TuplePattern => "tuple_pattern",
+            Csharp::Argument => "argument",
+            Csharp::Block => "block",
+            Csharp::ArrowExpressionClause => "arrow_expression_clause",
+            Csharp::FunctionBody => "_function_body",
+            Csharp::VariableDeclaration => "variable_declaration",
+            Csharp::VariableDeclaration2 => "variable_declaration",
+            Csharp::VariableDeclarator => "variable_declarator",
+            Csharp::VariableDeclarator2 => "variable_declarator",
+            Csharp::BracketedArgumentList => "bracketed_argument_list",
+            Csharp::Name => "_name",
+            Csharp::AliasQualifiedName => "alias_qualified_name",
+            Csharp::SimpleName => "_simple_name",
+            Csharp::QualifiedName => "qualified_name",
+            Csharp::GenericName => "generic_name",
+            Csharp::TypeArgumentList => "type_argument_list",
+            Csharp::Type2 => "type",
+            Csharp::ImplicitType => "implicit_type",
+            Csharp::ArrayType => "array_type",
+            Csharp::ArrayBaseType => "_array_base_type",
+            Csharp::ArrayRankSpecifier => "array_rank_specifier",
+            Csharp::NullableType => "nullable_type",
+            Csharp::PointerType => "pointer_type",
+            Csharp::PointerBaseType => "_pointer_base_type",
+            Csharp::FunctionPointerType => "function_pointer_type",
+            Csharp::CallingConvention => "calling_convention",
+            Csharp::FunctionPointerParameter => "function_pointer_parameter",
+            Csharp::RefType => "ref_type",
+            Csharp::RefBaseType => "_ref_base_type",
+            Csharp::ScopedType => "scoped_type",
+            Csharp::ScopedBaseType => "_scoped_base_type",
+            Csharp::TupleType => "tuple_type",
+            Csharp::TupleElement => "tuple_element",
+            Csharp::Statement => "statement",
+            Csharp::BreakStatement => "break_statement",
+            Csharp::CheckedStatement => "checked_statement",
+            Csharp::ContinueStatement => "continue_statement",
+            Csharp::DoStatement => "do_statement",
+            Csharp::EmptyStatement => "empty_statement",
+            Csharp::ExpressionStatement => "expression_statement",
+            Csharp::FixedStatement => "fixed_statement",
+            Csharp::ForStatement => "for_statement",
+            Csharp::ReturnStatement => "return_statement",
+            Csharp::LockStatement => "lock_statement",
+            Csharp::YieldStatement => "yield_statement",
+            Csharp::SwitchStatement => "switch_statement",
+            Csharp::SwitchBody => "switch_body",
+            Csharp::SwitchSection => "switch_section",
+            Csharp::ThrowStatement => "throw_statement",
+            Csharp::TryStatement => "try_statement",
+            Csharp::CatchClause => "catch_clause",
+            Csharp::CatchDeclaration => "catch_declaration",
+            Csharp::CatchFilterClause => "catch_filter_clause",
+            Csharp::FinallyClause => "finally_clause",
+            Csharp::UnsafeStatement => "unsafe_statement",
+            Csharp::UsingStatement => "using_statement",
+            Csharp::ForeachStatement => "foreach_statement",
+            Csharp::GotoStatement => "goto_statement",
+            Csharp::LabeledStatement => "labeled_statement",
+            Csharp::IfStatement => "if_statement",
+            Csharp::WhileStatement => "while_statement",
+            Csharp::LocalDeclarationStatement => "local_declaration_statement",
+            Csharp::LocalFunctionStatement => "local_function_statement",
+            Csharp::Pattern => "pattern",
+            Csharp::ConstantPattern => "constant_pattern",
+            Csharp::ParenthesizedPattern => "parenthesized_pattern",
+            Csharp::VarPattern => "var_pattern",
+            Csharp::TypePattern => "type_pattern",
+            Csharp::ListPattern => "list_pattern",
+            Csharp::RecursivePattern => "recursive_pattern",
+            Csharp::PositionalPatternClause => "positional_pattern_clause",
+            Csharp::PropertyPatternClause => "property_pattern_clause",
+            Csharp::Subpattern => "subpattern",
+            Csharp::RelationalPattern => "relational_pattern",
+            Csharp::NegatedPattern => "negated_pattern",
+            Csharp::AndPattern => "and_pattern",
+            Csharp::OrPattern => "or_pattern",
+            Csharp::DeclarationPattern => "declaration_pattern",
+            Csharp::VariableDesignation => "_variable_designation",
+            Csharp::ParenthesizedVariableDesignation => "parenthesized_variable_designation",
+            Csharp::Expression => "expression",
+            Csharp::NonLvalueExpression => "non_lvalue_expression",
+            Csharp::LvalueExpression => "lvalue_expression",
+            Csharp::ExpressionStatementExpression => "_expression_statement_expression",
+            Csharp::AssignmentExpression => "assignment_expression",
+            Csharp::BinaryExpression => "binary_expression",
+            Csharp::PostfixUnaryExpression => "postfix_unary_expression",
+            Csharp::PrefixUnaryExpression => "prefix_unary_expression",
+            Csharp::PrefixUnaryExpression2 => "prefix_unary_expression",
+            Csharp::QueryExpression => "query_expression",
+            Csharp::FromClause => "from_clause",
+            Csharp::QueryBody => "_query_body",
+            Csharp::QueryClause => "_query_clause",
+            Csharp::JoinClause => "join_clause",
+            Csharp::JoinHeader => "_join_header",
+            Csharp::JoinBody => "_join_body",
+            Csharp::JoinIntoClause => "join_into_clause",
+            Csharp::LetClause => "let_clause",
+            Csharp::OrderByClause => "order_by_clause",
+            Csharp::Ordering => "_ordering",
+            Csharp::WhereClause => "where_clause",
+            Csharp::SelectOrGroupClause => "_select_or_group_clause",
+            Csharp::GroupClause => "group_clause",
+            Csharp::SelectClause => "select_clause",
+            Csharp::ConditionalExpression => "conditional_expression",
+            Csharp::ConditionalAccessExpression => "conditional_access_expression",
+            Csharp::AsExpression => "as_expression",
+            Csharp::IsExpression => "is_expression",
+            Csharp::IsPatternExpression => "is_pattern_expression",
+            Csharp::CastExpression => "cast_expression",
+            Csharp::CheckedExpression => "checked_expression",
+            Csharp::InvocationExpression => "invocation_expression",
+            Csharp::SwitchExpression => "switch_expression",
+            Csharp::SwitchExpressionArm => "switch_expression_arm",
+            Csharp::WhenClause => "when_clause",
+            Csharp::AwaitExpression => "await_expression",
+            Csharp::ThrowExpression => "throw_expression",
+            Csharp::ElementAccessExpression => "element_access_expression",
+            Csharp::InterpolatedStringExpression => "interpolated_string_expression",
+            Csharp::InterpolatedStringContent => "_interpolated_string_content",
+            Csharp::InterpolatedVerbatimStringContent => "_interpolated_verbatim_string_content",
+            Csharp::InterpolatedRawStringContent => "_interpolated_raw_string_content",
+            Csharp::Interpolation => "interpolation",
+            Csharp::InterpolationAlignmentClause => "interpolation_alignment_clause",
+            Csharp::InterpolationFormatClause => "interpolation_format_clause",
+            Csharp::MemberAccessExpression => "member_access_expression",
+            Csharp::MemberBindingExpression => "member_binding_expression",
+            Csharp::ObjectCreationExpression => "object_creation_expression",
+            Csharp::ParenthesizedExpression => "parenthesized_expression",
+            Csharp::ParenthesizedExpression2 => "parenthesized_expression",
+            Csharp::LambdaExpression => "lambda_expression",
+            Csharp::LambdaParameters => "_lambda_parameters",
+            Csharp::ArrayCreationExpression => "array_creation_expression",
+            Csharp::AnonymousMethodExpression => "anonymous_method_expression",
+            Csharp::AnonymousObjectCreationExpression => "anonymous_object_creation_expression",
+            Csharp::AnonymousObjectMemberDeclarator => "_anonymous_object_member_declarator",
+            Csharp::ImplicitArrayCreationExpression => "implicit_array_creation_expression",
+            Csharp::ImplicitObjectCreationExpression => "implicit_object_creation_expression",
+            Csharp::ImplicitStackallocExpression => "implicit_stackalloc_expression",
+            Csharp::InitializerExpression => "initializer_expression",
+            Csharp::DeclarationExpression => "declaration_expression",
+            Csharp::DefaultExpression => "default_expression",
+            Csharp::WithExpression => "with_expression",
+            Csharp::WithInitializer => "with_initializer",
+            Csharp::SizeofExpression => "sizeof_expression",
+            Csharp::TypeofExpression => "typeof_expression",
+            Csharp::MakerefExpression => "makeref_expression",
+            Csharp::RefExpression => "ref_expression",
+            Csharp::ReftypeExpression => "reftype_expression",
+            Csharp::RefvalueExpression => "refvalue_expression",
+            Csharp::StackallocExpression => "stackalloc_expression",
+            Csharp::RangeExpression => "range_expression",
+            Csharp::TupleExpression => "tuple_expression",
+            Csharp::Literal => "literal",
+            Csharp::CharacterLiteral => "character_literal",
+            Csharp::StringLiteral => "string_literal",
+            Csharp::StringLiteralContent => "string_literal_content",
+            Csharp::RawStringLiteral => "raw_string_literal",
+            Csharp::BooleanLiteral => "boolean_literal",
+            Csharp::Identifier => "identifier",
+            Csharp::ReservedIdentifier => "_reserved_identifier",
+            Csharp::PreprocIf => "preproc_if",
+            Csharp::PreprocElse => "preproc_else",
+            Csharp::PreprocElif => "preproc_elif",
+            Csharp::PreprocIf2 => "preproc_if",
+            Csharp::PreprocElse2 => "preproc_else",
+            Csharp::PreprocElif2 => "preproc_elif",
+            Csharp::PreprocIf3 => "preproc_if",
+            Csharp::PreprocElse3 => "preproc_else",
+            Csharp::PreprocElif3 => "preproc_elif",
+            Csharp::PreprocIf4 => "preproc_if",
+            Csharp::PreprocElse4 => "preproc_else",
+            Csharp::PreprocElif4 => "preproc_elif",
+            Csharp::PreprocExpression => "_preproc_expression",
+            Csharp::ParenthesizedExpression3 => "parenthesized_expression",
+            Csharp::UnaryExpression => "unary_expression",
+            Csharp::BinaryExpression2 => "binary_expression",
+            Csharp::PreprocRegion => "preproc_region",
+            Csharp::PreprocEndregion => "preproc_endregion",
+            Csharp::PreprocLine => "preproc_line",
+            Csharp::PreprocPragma => "preproc_pragma",
+            Csharp::PreprocNullable => "preproc_nullable",
+            Csharp::PreprocError => "preproc_error",
+            Csharp::PreprocWarning => "preproc_warning",
+            Csharp::PreprocDefine => "preproc_define",
+            Csharp::PreprocUndef => "preproc_undef",
+            Csharp::CompilationUnitRepeat1 => "compilation_unit_repeat1",
+            Csharp::GlobalAttributeRepeat1 => "global_attribute_repeat1",
+            Csharp::AttributeArgumentListRepeat1 => "attribute_argument_list_repeat1",
+            Csharp::ClassDeclarationRepeat1 => "class_declaration_repeat1",
+            Csharp::ClassDeclarationRepeat2 => "class_declaration_repeat2",
+            Csharp::ClassDeclarationRepeat3 => "class_declaration_repeat3",
+            Csharp::EnumMemberDeclarationListRepeat1 => "enum_member_declaration_list_repeat1",
+            Csharp::RecordBaseRepeat1 => "record_base_repeat1",
+            Csharp::TypeParameterListRepeat1 => "type_parameter_list_repeat1",
+            Csharp::BaseListRepeat1 => "base_list_repeat1",
+            Csharp::TypeParameterConstraintsClauseRepeat1 => {
+                "type_parameter_constraints_clause_repeat1"
+            }
+            Csharp::DeclarationListRepeat1 => "declaration_list_repeat1",
+            Csharp::AccessorListRepeat1 => "accessor_list_repeat1",
+            Csharp::BracketedParameterListRepeat1 => "bracketed_parameter_list_repeat1",
+            Csharp::ParameterTypeWithModifiersRepeat1 => "_parameter_type_with_modifiers_repeat1",
+            Csharp::ArgumentListRepeat1 => "argument_list_repeat1",
+            Csharp::TuplePatternRepeat1 => "tuple_pattern_repeat1",
+            Csharp::BlockRepeat1 => "block_repeat1",
+            Csharp::VariableDeclarationRepeat1 => "variable_declaration_repeat1",
+            Csharp::UsingVariableDeclarationRepeat1 => "using_variable_declaration_repeat1",
+            Csharp::TypeArgumentListRepeat1 => "type_argument_list_repeat1",
+            Csharp::TypeArgumentListRepeat2 => "type_argument_list_repeat2",
+            Csharp::ArrayRankSpecifierRepeat1 => "array_rank_specifier_repeat1",
+            Csharp::FunctionPointerTypeRepeat1 => "function_pointer_type_repeat1",
+            Csharp::CallingConventionRepeat1 => "calling_convention_repeat1",
+            Csharp::TupleTypeRepeat1 => "tuple_type_repeat1",
+            Csharp::ForStatementRepeat1 => "for_statement_repeat1",
+            Csharp::SwitchBodyRepeat1 => "switch_body_repeat1",
+            Csharp::TryStatementRepeat1 => "try_statement_repeat1",
+            Csharp::ListPatternRepeat1 => "list_pattern_repeat1",
+            Csharp::PositionalPatternClauseRepeat1 => "positional_pattern_clause_repeat1",
+            Csharp::ParenthesizedVariableDesignationRepeat1 => {
+                "parenthesized_variable_designation_repeat1"
+            }
+            Csharp::QueryBodyRepeat1 => "_query_body_repeat1",
+            Csharp::QueryBodyRepeat2 => "_query_body_repeat2",
+            Csharp::OrderByClauseRepeat1 => "order_by_clause_repeat1",
+            Csharp::SwitchExpressionRepeat1 => "switch_expression_repeat1",
+            Csharp::InterpolatedStringExpressionRepeat1 => "interpolated_string_expression_repeat1",
+            Csharp::InterpolatedStringExpressionRepeat2 => "interpolated_string_expression_repeat2",
+            Csharp::InterpolatedStringExpressionRepeat3 => "interpolated_string_expression_repeat3",
+            Csharp::LambdaExpressionRepeat1 => "lambda_expression_repeat1",
+            Csharp::AnonymousObjectCreationExpressionRepeat1 => {
+                "anonymous_object_creation_expression_repeat1"
+            }
+            Csharp::WithExpressionRepeat1 => "with_expression_repeat1",
+            Csharp::StringLiteralRepeat1 => "string_literal_repeat1",
+            Csharp::PreprocIfInTopLevelRepeat1 => "preproc_if_in_top_level_repeat1",
+            Csharp::PreprocPragmaRepeat1 => "preproc_pragma_repeat1",
+            Csharp::ElementBindingExpression => "element_binding_expression",
+            Csharp::ImplicitParameter => "implicit_parameter",
+            Csharp::InterpolationQuote => "interpolation_quote",
+            Csharp::Error => "ERROR",
+        }
+    }
+}
+
+impl From<u16> for Csharp {
+    #[inline(always)]
+    fn from(x: u16) -> Self {
+        num::FromPrimitive::from_u16(x).unwrap_or(Self::Error)
+    }
+}
+
+// Csharp == u16
+impl PartialEq<u16> for Csharp {
+    #[inline(always)]
+    fn eq(&self, x: &u16) -> bool {
+        *self == Into::<Self>::into(*x)
+    }
+}
+
+// u16 == Csharp
+impl PartialEq<Csharp> for u16 {
+    #[inline(always)]
+    fn eq(&self, x: &Csharp) -> bool {
+        *x == *self
+    }
+}
@@ -0,0 +1,282 @@
+// Code generated; DO NOT EDIT.
+
+use num_derive::FromPrimitive;
+
+#[derive(Clone, Debug, PartialEq, Eq, FromPrimitive)]
+pub enum Hcl {
+    End = 0,
+    EQ = 1,
+    LBRACE = 2,
+    RBRACE = 3,
+    Identifier = 4,
+    LPAREN = 5,
+    RPAREN = 6,
+    NumericLitToken1 = 7,
+    NumericLitToken2 = 8,
+    True = 9,
+    False = 10,
+    NullLit = 11,
+    COMMA = 12,
+    LBRACK = 13,
+    RBRACK = 14,
+    COLON = 15,
+    DOT = 16,
+    LegacyIndexToken1 = 17,
+    DOTSTAR = 18,
+    LBRACKSTARRBRACK = 19,
+    EQGT = 20,
+    For = 21,
+    In = 22,
+    If = 23,
+    Ellipsis = 24,
+    QMARK = 25,
+    DASH = 26,
+    BANG = 27,
+    STAR = 28,
+    SLASH = 29,
+    PERCENT = 30,
+    PLUS = 31,
+    GT = 32,
+    GTEQ = 33,
+    LT = 34,
+    LTEQ = 35,
+    EQEQ = 36,
+    BANGEQ = 37,
+    AMPAMP = 38,
+    PIPEPIPE = 39,
+    LTLT = 40,
+    LTLTDASH = 41,
+    StripMarker = 42,
+    Endfor = 43,
+    Else = 44,
+    Endif = 45,
+    Comment = 46,
+    Whitespace = 47,
+    QuotedTemplateStart = 48,
+    QuotedTemplateEnd = 49,
+    TemplateLiteralChunk = 50,
+    TemplateInterpolationStart = 51,
+    TemplateInterpolationEnd = 52,
+    TemplateDirectiveStart = 53,
+    TemplateDirectiveEnd = 54,
+    HeredocIdentifier = 55,
+    ConfigFile = 56,
+    Body = 57,
+    Attribute = 58,
+    Block = 59,
+    BlockStart = 60,
+    BlockEnd = 61,
+    Expression = 62,
+    ExprTerm = 63,
+    LiteralValue = 64,
+    NumericLit = 65,
+    BoolLit = 66,
+    StringLit = 67,
+    CollectionValue = 68,
+    Comma = 69,
+    Tuple = 70,
+    TupleStart = 71,
+    TupleEnd = 72,
+    TupleElems = 73,
+    Object = 74,
+    ObjectStart = 75,
+    ObjectEnd = 76,
+    ObjectElems = 77,
+    ObjectElem = 78,
+    Index = 79,
+    NewIndex = 80,
+    LegacyIndex = 81,
+    GetAttr = 82,
+    Splat = 83,
+    AttrSplat = 84,
+    FullSplat = 85,
+    ForExpr = 86,
+    ForTupleExpr = 87,
+    ForObjectExpr = 88,
+    ForIntro = 89,
+    ForCond = 90,
+    VariableExpr = 91,
+    FunctionCall = 92,
+    FunctionCallStart = 93,
+    FunctionCallEnd = 94,
+    FunctionArguments = 95,
+    Conditional = 96,
+    Operation = 97,
+    UnaryOperation = 98,
+    BinaryOperation = 99,
+    TemplateExpr = 100,
+    QuotedTemplate = 101,
+    HeredocTemplate = 102,
+    HeredocStart = 103,
+    Template = 104,
+    TemplateLiteral = 105,
+    TemplateInterpolation = 106,
+    TemplateDirective = 107,
+    TemplateFor = 108,
+    TemplateForStart = 109,
+    TemplateForEnd = 110,
+    TemplateIf = 111,
+    TemplateIfIntro = 112,
+    TemplateElseIntro = 113,
+    TemplateIfEnd = 114,
+    BodyRepeat1 = 115,
+    BlockRepeat1 = 116,
+    TupleElemsRepeat1 = 117,
+    ObjectElemsRepeat1 = 118,
+    AttrSplatRepeat1 = 119,
+    TemplateLiteralRepeat1 = 120,
+    Error = 121,
+}
+
+impl From<Hcl> for &'static str {
+    #[inline(always)]
+    fn from(tok: Hcl) -> Self {
+        match tok {
+            Hcl::End => "end",
+            Hcl::EQ => "=",
+            Hcl::LBRACE => "{",
+            Hcl::RBRACE => "}",
+            Hcl::Identifier => "identifier",
+            Hcl::LPAREN => "(",
+            Hcl::RPAREN => ")",
+            Hcl::NumericLitToken1 => "numeric_lit_token1",
+            Hcl::NumericLitToken2 => "numeric_lit_token2",
+            Hcl::True => "true",
+            Hcl::False => "false",
+            Hcl::NullLit => "null_lit",
+            Hcl::COMMA => ",",
+            Hcl::LBRACK => "[",
+            Hcl::RBRACK => "]",
+            Hcl::COLON => ":",
+            Hcl::DOT => ".",
+            Hcl::LegacyIndexToken1 => "legacy_index_token1",
+            Hcl::DOTSTAR => ".*",
+            Hcl::LBRACKSTARRBRACK => "[*]",
+            Hcl::EQGT => "=>",
+            Hcl::For => "for",
+            Hcl::In => "in",
+            Hcl::If => "if",
+            Hcl::Ellipsis => "ellipsis",
+            Hcl::QMARK => "?",
+            Hcl::DASH => "-",
+            Hcl::BANG => "!",
+            Hcl::STAR => "*",
+            Hcl::SLASH => "/",
+            Hcl::PERCENT => "%",
+            Hcl::PLUS => "+",
+            Hcl::GT => ">",
+            Hcl::GTEQ => ">=",
+            Hcl::LT => "<",
+            Hcl::LTEQ => "<=",
+            Hcl::EQEQ => "==",
+            Hcl::BANGEQ => "!=",
+            Hcl::AMPAMP => "&&",
+            Hcl::PIPEPIPE => "||",
+            Hcl::LTLT => "<<",
+            Hcl::LTLTDASH => "<<-",
+            Hcl::StripMarker => "strip_marker",
+            Hcl::Endfor => "endfor",
+            Hcl::Else => "else",
+            Hcl::Endif => "endif",
+            Hcl::Comment => "comment",
+            Hcl::Whitespace => "_whitespace",
+            Hcl::QuotedTemplateStart => "quoted_template_start",
+            Hcl::QuotedTemplateEnd => "quoted_template_end",
+            Hcl::TemplateLiteralChunk => "_template_literal_chunk",
+            Hcl::TemplateInterpolationStart => "template_interpolation_start",
+            Hcl::TemplateInterpolationEnd => "template_interpolation_end",
+            Hcl::TemplateDirectiveStart => "template_directive_start",
+            Hcl::TemplateDirectiveEnd => "template_directive_end",
+            Hcl::HeredocIdentifier => "heredoc_identifier",
+            Hcl::ConfigFile => "config_file",
+            Hcl::Body => "body",
+            Hcl::Attribute => "attribute",
+            Hcl::Block => "block",
+            Hcl::BlockStart => "block_start",
+            Hcl::BlockEnd => "block_end",
+            Hcl::Expression => "expression",
+            Hcl::ExprTerm => "_expr_term",
+            Hcl::LiteralValue => "literal_value",
+            Hcl::NumericLit => "numeric_lit",
+            Hcl::BoolLit => "bool_lit",
+            Hcl::StringLit => "string_lit",
+            Hcl::CollectionValue => "collection_value",
+            Hcl::Comma => "_comma",
+            Hcl::Tuple => "tuple",
+            Hcl::TupleStart => "tuple_start",
+            Hcl::TupleEnd => "tuple_end",
+            Hcl::TupleElems => "_tuple_elems",
+            Hcl::Object => "object",
+            Hcl::ObjectStart => "object_start",
+            Hcl::ObjectEnd => "object_end",
+            Hcl::ObjectElems => "_object_elems",
+            Hcl::ObjectElem => "object_elem",
+            Hcl::Index => "index",
+            Hcl::NewIndex => "new_index",
+            Hcl::LegacyIndex => "legacy_index",
+            Hcl::GetAttr => "get_attr",
+            Hcl::Splat => "splat",
+            Hcl::AttrSplat => "attr_splat",
+            Hcl::FullSplat => "full_splat",
+            Hcl::ForExpr => "for_expr",
+            Hcl::ForTupleExpr => "for_tuple_expr",
+            Hcl::ForObjectExpr => "for_object_expr",
+            Hcl::ForIntro => "for_intro",
+            Hcl::ForCond => "for_cond",
+            Hcl::VariableExpr => "variable_expr",
+            Hcl::FunctionCall => "function_call",
+            Hcl::FunctionCallStart => "_function_call_start",
+            Hcl::FunctionCallEnd => "_function_call_end",
+            Hcl::FunctionArguments => "function_arguments",
+            Hcl::Conditional => "conditional",
+            Hcl::Operation => "operation",
+            Hcl::UnaryOperation => "unary_operation",
+            Hcl::BinaryOperation => "binary_operation",
+            Hcl::TemplateExpr => "template_expr",
+            Hcl::QuotedTemplate => "quoted_template",
+            Hcl::HeredocTemplate => "heredoc_template",
+            Hcl::HeredocStart => "heredoc_start",
+            Hcl::Template => "_template",
+            Hcl::TemplateLiteral => "template_literal",
+            Hcl::TemplateInterpolation => "template_interpolation",
+            Hcl::TemplateDirective => "template_directive",
+            Hcl::TemplateFor => "template_for",
+            Hcl::TemplateForStart => "template_for_start",
+            Hcl::TemplateForEnd => "template_for_end",
+            Hcl::TemplateIf => "template_if",
+            Hcl::TemplateIfIntro => "template_if_intro",
+            Hcl::TemplateElseIntro => "template_else_intro",
+            Hcl::TemplateIfEnd => "template_if_end",
+            Hcl::BodyRepeat1 => "body_repeat1",
+            Hcl::BlockRepeat1 => "block_repeat1",
+            Hcl::TupleElemsRepeat1 => "_tuple_elems_repeat1",
+            Hcl::ObjectElemsRepeat1 => "_object_elems_repeat1",
+            Hcl::AttrSplatRepeat1 => "attr_splat_repeat1",
+            Hcl::TemplateLiteralRepeat1 => "template_literal_repeat1",
+            Hcl::Error => "ERROR",
+        }
+    }
+}
+
+impl From<u16> for Hcl {
+    #[inline(always)]
+    fn from(x: u16) -> Self {
+        num::FromPrimitive::from_u16(x).unwrap_or(Self::Error)
+    }
+}
+
+// Hcl == u16
+impl PartialEq<u16> for Hcl {
+    #[inline(always)]
+    fn eq(&self, x: &u16) -> bool {
+        *self == Into::<Self>::into(*x)
+    }
+}
+
+// u16 == Hcl
+impl PartialEq<Hcl> for u16 {
+    #[inline(always)]
+    fn eq(&self, x: &Hcl) -> bool {
+        *x == *self
+    }
+}
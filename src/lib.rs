@@ -12,6 +12,7 @@
 //! - C#
 //! - CSS
 //! - Go
+//! - HCL
 //! - HTML
 //! - Java
 //! - JavaScript
@@ -43,6 +44,42 @@
 //! - NEXITS: it counts the number of possible exit points
 //!   from a method/function.
 //! - NARGS: it counts the number of arguments of a function/method.
+//! - NEGATIONS: it counts the number of boolean negations of a function/method,
+//!   and the double negations among them.
+//! - NLOCALS: it counts the number of local variables of a function/method.
+//! - EXPR_NESTING: it computes the maximum nesting depth of the expressions
+//!   contained in a function/method.
+//! - CHAIN_LENGTH: it computes the maximum length of the method chains
+//!   contained in a function/method.
+//! - EARLY_RETURNS: it counts the number of return statements placed
+//!   before the last statement of a function/method.
+//! - TYPE_COMPLEXITY: it counts the number of union type members
+//!   and enum cases.
+//! - CAPTURES: it counts the number of variables captured by a closure.
+//! - CFG: it counts the number of conditional compilation attributes
+//!   contained in a space.
+//! - EXCEPTION_TYPES: it counts the number of distinct exception types
+//!   handled by a function/method.
+//! - BARE_IFS: it counts the number of `if` statements lacking an `else`
+//!   branch in a function/method.
+//! - REFERENCED_TYPES: it counts the number of distinct types referenced
+//!   by a function/method.
+//! - STATEMENT_COUNT: it counts the number of statements of each
+//!   function/method, averaging them over the functions of a file.
+//! - TEMPLATE_COMPLEXITY: it counts the number of `requires` clauses and
+//!   `enable_if` conditions of each `C++` function template.
+//! - LITERAL_NESTING: it computes the maximum nesting depth of the literal
+//!   data structures contained in a function/method.
+//! - DECISIONS: it counts the decision points of a function/method by category,
+//!   the ratio of its branches to its loops and the number of distinct
+//!   categories it uses.
+//! - ENUM_VARIANTS: it counts the variants of the enums defined in a space,
+//!   and the ones among them carrying data.
+//! - CONDITIONAL_IMPORTS: it counts the imports of a file depending on the
+//!   control flow, that is the conditional and the dynamic ones.
+//! - UNSAFE_CODE: it counts the `unsafe` blocks and functions of a space.
+//! - GOROUTINES: it counts the goroutines launched by the `go` statements
+//!   of a space.
 
 #![allow(clippy::upper_case_acronyms)]
 
@@ -72,6 +109,27 @@ pub use output::*;
 mod spaces;
 pub use crate::spaces::*;
 
+mod vue;
+pub use crate::vue::*;
+
+mod score;
+pub use crate::score::*;
+
+mod gate;
+pub use crate::gate::*;
+
+mod aggregate;
+pub use crate::aggregate::*;
+
+mod multi_language;
+pub use crate::multi_language::*;
+
+mod report;
+pub use crate::report::*;
+
+mod analysis;
+pub use crate::analysis::*;
+
 mod ops;
 pub use crate::ops::*;
 
@@ -81,6 +139,15 @@ pub use crate::find::*;
 mod function;
 pub use crate::function::*;
 
+mod conditions;
+pub use crate::conditions::*;
+
+mod switches;
+pub use crate::switches::*;
+
+mod recursion;
+pub use crate::recursion::*;
+
 mod ast;
 pub use crate::ast::*;
 
@@ -96,6 +163,21 @@ pub use crate::langs::*;
 mod tools;
 pub use crate::tools::*;
 
+mod reader;
+pub use crate::reader::*;
+
+mod compute;
+pub use crate::compute::*;
+
+mod cache;
+pub use crate::cache::*;
+
+mod session;
+pub use crate::session::*;
+
+mod coverage;
+pub use crate::coverage::*;
+
 mod concurrent_files;
 pub use crate::concurrent_files::*;
 
@@ -107,3 +189,18 @@ pub use crate::parser::*;
 
 mod comment_rm;
 pub use crate::comment_rm::*;
+
+#[cfg(feature = "git")]
+mod git;
+#[cfg(feature = "git")]
+pub use crate::git::*;
+
+#[cfg(feature = "archive")]
+mod archive;
+#[cfg(feature = "archive")]
+pub use crate::archive::*;
+
+#[cfg(feature = "expand")]
+mod expand;
+#[cfg(feature = "expand")]
+pub use crate::expand::*;
@@ -13,10 +13,14 @@ macro_rules! get_language {
     };
 }
 
+// Implements a metric trait for some languages with stubs computing
+// nothing, except for the bracketed trait which uses its default methods
 macro_rules! implement_metric_trait {
     (Abc, $($code:ident),+) => (
         $(
            impl Abc for $code {
+               const IMPLEMENTED: bool = false;
+
                fn compute(_node: &Node, _stats: &mut Stats) {}
            }
         )+
@@ -24,13 +28,95 @@ macro_rules! implement_metric_trait {
     (Cognitive, $($code:ident),+) => (
         $(
            impl Cognitive for $code {
-               fn compute(_node: &Node, _stats: &mut Stats, _nesting_map: &mut HashMap<usize, (usize, usize, usize)>,) {}
+               const IMPLEMENTED: bool = false;
+
+               fn compute(
+                   _node: &Node,
+                   _code: &[u8],
+                   _stats: &mut Stats,
+                   _levels: &mut (usize, usize, usize),
+                   _loops: &mut Loops,
+               ) {}
+           }
+        )+
+    );
+    (Captures, $($code:ident),+) => (
+        $(
+           impl Captures for $code {
+               const IMPLEMENTED: bool = false;
+
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
+    (ConditionalImports, $($code:ident),+) => (
+        $(
+           impl ConditionalImports for $code {
+               const IMPLEMENTED: bool = false;
+
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
+    (Cfg, $($code:ident),+) => (
+        $(
+           impl Cfg for $code {
+               const IMPLEMENTED: bool = false;
+
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
+    (ExprNesting, $($code:ident),+) => (
+        $(
+           impl ExprNesting for $code {
+               const IMPLEMENTED: bool = false;
+
+               fn compute(_node: &Node, _stats: &mut Stats, _depth: &mut Depth) {}
+           }
+        )+
+    );
+    (EarlyReturns, $($code:ident),+) => (
+        $(
+           impl EarlyReturns for $code {
+               const IMPLEMENTED: bool = false;
+
+               fn compute(_node: &Node, _stats: &mut Stats, _body: &mut Body) {}
+           }
+        )+
+    );
+    (ExceptionTypes, $($code:ident),+) => (
+        $(
+           impl ExceptionTypes for $code {
+               const IMPLEMENTED: bool = false;
+
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
+    (ReferencedTypes, $($code:ident),+) => (
+        $(
+           impl ReferencedTypes for $code {
+               const IMPLEMENTED: bool = false;
+
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
+    (TemplateComplexity, $($code:ident),+) => (
+        $(
+           impl TemplateComplexity for $code {
+               const IMPLEMENTED: bool = false;
+
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
            }
         )+
     );
     (Halstead, $($code:ident),+) => (
         $(
            impl Halstead for $code {
+               const IMPLEMENTED: bool = false;
+
                fn compute<'a>(_node: &Node<'a>, _code: &'a [u8], _halstead_maps: &mut HalsteadMaps<'a>) {}
            }
         )+
@@ -38,13 +124,35 @@ macro_rules! implement_metric_trait {
     (Loc, $($code:ident),+) => (
         $(
            impl Loc for $code {
+               const IMPLEMENTED: bool = false;
+
                fn compute(_node: &Node, _stats: &mut Stats, _is_func_space: bool, _is_unit: bool) {}
            }
         )+
     );
+    (Npm, $($code:ident),+) => (
+        $(
+           impl Npm for $code {
+               const IMPLEMENTED: bool = false;
+
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
+    (Npa, $($code:ident),+) => (
+        $(
+           impl Npa for $code {
+               const IMPLEMENTED: bool = false;
+
+               fn compute(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
+           }
+        )+
+    );
     (Wmc, $($code:ident),+) => (
         $(
            impl Wmc for $code {
+               const IMPLEMENTED: bool = false;
+
                fn compute(_space_kind: SpaceKind, _cyclomatic: &cyclomatic::Stats, _stats: &mut Stats) {}
            }
         )+
@@ -57,6 +165,8 @@ macro_rules! implement_metric_trait {
     ($trait:ident, $($code:ident),+) => (
         $(
            impl $trait for $code {
+               const IMPLEMENTED: bool = false;
+
                fn compute(_node: &Node, _stats: &mut Stats) {}
            }
         )+
@@ -66,7 +176,7 @@ macro_rules! implement_metric_trait {
 macro_rules! mk_lang {
     ( $( ($camel:ident, $name:ident, $display: expr, $description:expr) ),* ) => {
         /// The list of supported languages.
-        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
         pub enum LANG {
             $(
                 #[doc = $description]
@@ -133,7 +243,7 @@ macro_rules! mk_action {
         /// ```
         /// use std::path::PathBuf;
         ///
-        /// use rust_code_analysis::{action, Callback, LANG, Metrics, MetricsCfg};
+        /// use rust_code_analysis::{action, Callback, LANG, Metrics, MetricsCfg, MetricsOptions};
         ///
         /// let source_code = "int a = 42;";
         /// let language = LANG::Cpp;
@@ -145,6 +255,7 @@ macro_rules! mk_action {
         /// // Configuration options used by the function which computes the metrics
         /// let cfg = MetricsCfg {
         ///     path,
+        ///     options: MetricsOptions::default(),
         /// };
         ///
         /// action::<Metrics>(&language, source_as_vec, &cfg.path.clone(), None, cfg);
@@ -193,6 +304,70 @@ macro_rules! mk_action {
             }
         }
 
+        /// Returns all function spaces data of a code, reusing the parser
+        /// contained in the given handle.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::path::PathBuf;
+        ///
+        /// use rust_code_analysis::{get_function_spaces_with_handle, ParserHandle, LANG};
+        ///
+        /// let source_code = "int a = 42;";
+        /// let mut handle = ParserHandle::new(LANG::Cpp);
+        ///
+        /// // The path to a dummy file used to contain the source code
+        /// let path = PathBuf::from("foo.c");
+        /// let source_as_vec = source_code.as_bytes().to_vec();
+        ///
+        /// get_function_spaces_with_handle(&mut handle, source_as_vec, &path, None).unwrap();
+        /// ```
+        #[inline(always)]
+        pub fn get_function_spaces_with_handle(handle: &mut ParserHandle, source: Vec<u8>, path: &Path, pr: Option<Arc<PreprocResults>>) -> Option<FuncSpace> {
+            match handle.language() {
+                $(
+                    LANG::$camel => {
+                        let parser = $parser::with_handle(source, &path, pr, handle);
+                        metrics(&parser, &path)
+                    },
+                )*
+            }
+        }
+
+        /// Returns all function spaces data of a code, computed according
+        /// to the given options.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::path::PathBuf;
+        ///
+        /// use rust_code_analysis::{get_function_spaces_with_options, LANG, MetricsOptions};
+        ///
+        /// let source_code = "int a = 42;";
+        /// let language = LANG::Cpp;
+        ///
+        /// // The path to a dummy file used to contain the source code
+        /// let path = PathBuf::from("foo.c");
+        /// let source_as_vec = source_code.as_bytes().to_vec();
+        ///
+        /// let options = MetricsOptions::default();
+        ///
+        /// get_function_spaces_with_options(&language, source_as_vec, &path, None, &options).unwrap();
+        /// ```
+        #[inline(always)]
+        pub fn get_function_spaces_with_options(lang: &LANG, source: Vec<u8>, path: &Path, pr: Option<Arc<PreprocResults>>, options: &MetricsOptions) -> Option<FuncSpace> {
+            match lang {
+                $(
+                    LANG::$camel => {
+                        let parser = $parser::new(source, &path, pr);
+                        metrics_with_options(&parser, &path, options)
+                    },
+                )*
+            }
+        }
+
         /// Returns all operators and operands of each space in a code.
         ///
         /// # Examples
@@ -224,6 +399,25 @@ macro_rules! mk_action {
                 )*
             }
         }
+
+        /// Returns which metrics are computed for a language.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rust_code_analysis::{get_metrics_coverage, LANG};
+        ///
+        /// for (metric, coverage) in get_metrics_coverage(&LANG::Rust) {
+        ///     println!("{}: {:?}", metric, coverage);
+        /// }
+        /// ```
+        pub fn get_metrics_coverage(lang: &LANG) -> Vec<(&'static str, Coverage)> {
+            match lang {
+                $(
+                    LANG::$camel => metrics_coverage_of::<$parser>(),
+                )*
+            }
+        }
     };
 }
 
@@ -1,5 +1,5 @@
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::checker::Checker;
@@ -20,25 +20,42 @@ use crate::*;
 /// Fitzpatrick, Jerry (1997). "Applying the ABC metric to C, C++ and Java". C++ Report.
 ///
 /// <https://www.softwarerenovation.com/Articles.aspx>
-#[derive(Debug, Clone)]
+///
+/// Except for `Java`, the conditions are the comparison and the boolean
+/// operators and the alternative paths, such as an `else` or a `case`,
+/// while the unary conditions, such as `if (x)`, are not counted.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
+    #[serde(with = "crate::cache::float_bits")]
     assignments: f64,
+    #[serde(with = "crate::cache::float_bits")]
     assignments_sum: f64,
+    #[serde(with = "crate::cache::float_bits")]
     assignments_min: f64,
+    #[serde(with = "crate::cache::float_bits")]
     assignments_max: f64,
+    #[serde(with = "crate::cache::float_bits")]
     branches: f64,
+    #[serde(with = "crate::cache::float_bits")]
     branches_sum: f64,
+    #[serde(with = "crate::cache::float_bits")]
     branches_min: f64,
+    #[serde(with = "crate::cache::float_bits")]
     branches_max: f64,
+    #[serde(with = "crate::cache::float_bits")]
     conditions: f64,
+    #[serde(with = "crate::cache::float_bits")]
     conditions_sum: f64,
+    #[serde(with = "crate::cache::float_bits")]
     conditions_min: f64,
+    #[serde(with = "crate::cache::float_bits")]
     conditions_max: f64,
     space_count: usize,
     declaration: Vec<DeclKind>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 enum DeclKind {
     Var,
     Const,
@@ -250,6 +267,8 @@ pub trait Abc
 where
     Self: Checker,
 {
+    const IMPLEMENTED: bool = true;
+
     fn compute(node: &Node, stats: &mut Stats);
 }
 
@@ -342,19 +361,235 @@ fn java_count_unary_conditions(list_node: &Node, conditions: &mut f64) {
     }
 }
 
-implement_metric_trait!(
-    Abc,
-    PythonCode,
-    MozjsCode,
-    JavascriptCode,
-    TypescriptCode,
-    TsxCode,
-    RustCode,
-    CppCode,
-    PreprocCode,
-    CcommentCode,
-    KotlinCode
-);
+// Returns true when a binary expression has one of the `operators`
+fn has_operator(node: &Node, operators: &[u16]) -> bool {
+    node.child_by_field_name("operator")
+        .is_some_and(|operator| operators.contains(&operator.kind_id()))
+}
+
+implement_metric_trait!(Abc, PreprocCode, CcommentCode, HclCode, KotlinCode, GoCode);
+
+impl Abc for PythonCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Python::*;
+
+        match node.kind_id().into() {
+            // An annotation without a value, such as `x: int`, is not an assignment
+            Assignment if node.child_by_field_name("right").is_some() => {
+                stats.assignments += 1.;
+            }
+            AugmentedAssignment => {
+                stats.assignments += 1.;
+            }
+            Call => {
+                stats.branches += 1.;
+            }
+            // A chained comparison, such as `a < b < c`, has many operators
+            ComparisonOperator => {
+                stats.conditions +=
+                    node.children().filter(|child| !child.is_named()).count() as f64;
+            }
+            BooleanOperator
+            | NotOperator
+            | ElifClause
+            | ElseClause
+            | CaseClause
+            | ConditionalExpression
+            | TryStatement
+            | ExceptClause => {
+                stats.conditions += 1.;
+            }
+            _ => {}
+        }
+    }
+}
+
+macro_rules! js_abc {
+    ($lang:ident) => {
+        fn compute(node: &Node, stats: &mut Stats) {
+            use $lang::*;
+
+            match node.kind_id().into() {
+                AssignmentExpression | AugmentedAssignmentExpression | UpdateExpression => {
+                    stats.assignments += 1.;
+                }
+                VariableDeclarator if node.child_by_field_name("value").is_some() => {
+                    stats.assignments += 1.;
+                }
+                CallExpression | CallExpression2 | NewExpression => {
+                    stats.branches += 1.;
+                }
+                BinaryExpression
+                    if has_operator(
+                        node,
+                        &[
+                            EQEQ as u16,
+                            EQEQEQ as u16,
+                            BANGEQ as u16,
+                            BANGEQEQ as u16,
+                            LT as u16,
+                            GT as u16,
+                            LTEQ as u16,
+                            GTEQ as u16,
+                            AMPAMP as u16,
+                            PIPEPIPE as u16,
+                            QMARKQMARK as u16,
+                        ],
+                    ) =>
+                {
+                    stats.conditions += 1.;
+                }
+                ElseClause | SwitchCase | SwitchDefault | TernaryExpression | TryStatement
+                | CatchClause => {
+                    stats.conditions += 1.;
+                }
+                _ => {}
+            }
+        }
+    };
+}
+
+impl Abc for MozjsCode {
+    js_abc!(Mozjs);
+}
+
+impl Abc for JavascriptCode {
+    js_abc!(Javascript);
+}
+
+impl Abc for TypescriptCode {
+    js_abc!(Typescript);
+}
+
+impl Abc for TsxCode {
+    js_abc!(Tsx);
+}
+
+impl Abc for RustCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Rust::*;
+
+        match node.kind_id().into() {
+            LetDeclaration if node.child_by_field_name("value").is_some() => {
+                stats.assignments += 1.;
+            }
+            AssignmentExpression | CompoundAssignmentExpr => {
+                stats.assignments += 1.;
+            }
+            CallExpression | MacroInvocation => {
+                stats.branches += 1.;
+            }
+            BinaryExpression
+                if has_operator(
+                    node,
+                    &[
+                        EQEQ as u16,
+                        BANGEQ as u16,
+                        LT as u16,
+                        GT as u16,
+                        LTEQ as u16,
+                        GTEQ as u16,
+                        AMPAMP as u16,
+                        PIPEPIPE as u16,
+                    ],
+                ) =>
+            {
+                stats.conditions += 1.;
+            }
+            // The `?` operator returns early on errors
+            ElseClause | MatchArm | MatchArm2 | TryExpression => {
+                stats.conditions += 1.;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Abc for CppCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Cpp::*;
+
+        match node.kind_id().into() {
+            AssignmentExpression | AssignmentExpression2 | UpdateExpression | InitDeclarator => {
+                stats.assignments += 1.;
+            }
+            CallExpression | CallExpression2 | NewExpression | DeleteExpression => {
+                stats.branches += 1.;
+            }
+            BinaryExpression | BinaryExpression2
+                if has_operator(
+                    node,
+                    &[
+                        EQEQ as u16,
+                        BANGEQ as u16,
+                        LT as u16,
+                        GT as u16,
+                        LTEQ as u16,
+                        GTEQ as u16,
+                        AMPAMP as u16,
+                        PIPEPIPE as u16,
+                        And as u16,
+                        Or as u16,
+                    ],
+                ) =>
+            {
+                stats.conditions += 1.;
+            }
+            // Both the `case` and the `default` labels are case statements
+            ElseClause | CaseStatement | ConditionalExpression | TryStatement | CatchClause => {
+                stats.conditions += 1.;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Abc for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Csharp::*;
+
+        match node.kind_id().into() {
+            // Both the simple and the compound assignments
+            AssignmentExpression | PLUSPLUS | DASHDASH => {
+                stats.assignments += 1.;
+            }
+            VariableDeclarator if node.first_child(|id| id == EQ).is_some() => {
+                stats.assignments += 1.;
+            }
+            InvocationExpression | ObjectCreationExpression | ImplicitObjectCreationExpression => {
+                stats.branches += 1.;
+            }
+            BinaryExpression
+                if has_operator(
+                    node,
+                    &[
+                        EQEQ as u16,
+                        BANGEQ as u16,
+                        LT as u16,
+                        GT as u16,
+                        LTEQ as u16,
+                        GTEQ as u16,
+                        AMPAMP as u16,
+                        PIPEPIPE as u16,
+                    ],
+                ) =>
+            {
+                stats.conditions += 1.;
+            }
+            SwitchSection => {
+                // Both the `case` and the `default` labels are case statements
+                stats.conditions += node
+                    .children()
+                    .filter(|child| matches!(child.kind_id().into(), Case | Default))
+                    .count() as f64;
+            }
+            Else | SwitchExpressionArm | ConditionalExpression | TryStatement | CatchClause => {
+                stats.conditions += 1.;
+            }
+            _ => {}
+        }
+    }
+}
 
 // Fitzpatrick, Jerry (1997). "Applying the ABC metric to C, C++ and Java". C++ Report.
 // Source: https://www.softwarerenovation.com/Articles.aspx
@@ -548,7 +783,7 @@ impl Abc for JavaCode {
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::check_metrics;
+    use crate::tools::{check_func_space, check_metrics};
 
     use super::*;
 
@@ -1147,4 +1382,121 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn python_abc() {
+        check_func_space::<PythonParser, _>(
+            "def f(items, limit=10):
+                 total = 0  # +1a
+                 for item in items:
+                     if item > 0 and item < limit:  # +3c
+                         total += item  # +1a
+                     elif item == 0:  # +2c
+                         print(item)  # +1b
+                     else:  # +1c
+                         continue
+                 try:  # +1c
+                     return int(total)  # +1b
+                 except ValueError:  # +1c
+                     return 0",
+            "foo.py",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.abc;
+                assert_eq!(f.assignments(), 2.);
+                assert_eq!(f.branches(), 2.);
+                assert_eq!(f.conditions(), 8.);
+                // sqrt(4 + 4 + 64)
+                assert_eq!(f.magnitude(), 72f64.sqrt());
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_abc() {
+        check_func_space::<JavascriptParser, _>(
+            "function f(items) {
+                 let total = 0, last; // +1a
+                 for (let i = 0; i < items.length; i++) { // +2a +1c
+                     if (items[i] === null || items[i] === undefined) { // +3c
+                         continue;
+                     } else { // +1c
+                         total += items[i]; // +1a
+                     }
+                 }
+                 try { // +1c
+                     last = new Date(total); // +1a +1b
+                 } catch (e) { // +1c
+                     console.log(e); // +1b
+                 }
+                 return total ?? 0; // +1c
+             }",
+            "foo.js",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.abc;
+                assert_eq!(f.assignments(), 5.);
+                assert_eq!(f.branches(), 2.);
+                assert_eq!(f.conditions(), 8.);
+                // sqrt(25 + 4 + 64)
+                assert_eq!(f.magnitude(), 93f64.sqrt());
+            },
+        );
+    }
+
+    #[test]
+    fn rust_abc() {
+        check_func_space::<RustParser, _>(
+            "fn f(v: &[i32]) -> Option<i32> {
+                 let mut total = 0; // +1a
+                 for x in v {
+                     if *x > 0 && total < 100 { // +3c
+                         total += x; // +1a
+                     } else { // +1c
+                         println!(\"{}\", x); // +1b
+                     }
+                 }
+                 match v.first()? { // +1b +1c
+                     0 => None, // +1c
+                     _ => Some(total), // +1b +1c
+                 }
+             }",
+            "foo.rs",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.abc;
+                assert_eq!(f.assignments(), 2.);
+                assert_eq!(f.branches(), 3.);
+                assert_eq!(f.conditions(), 7.);
+                // sqrt(4 + 9 + 49)
+                assert_eq!(f.magnitude(), 62f64.sqrt());
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_abc() {
+        check_func_space::<CppParser, _>(
+            "int f(int *v, int n) {
+                 int total = 0; // +1a
+                 for (int i = 0; i < n; i++) { // +2a +1c
+                     switch (v[i]) {
+                         case 0: // +1c
+                             break;
+                         default: // +1c
+                             total += abs(v[i]); // +1a +1b
+                     }
+                 }
+                 int *copy = new int[n]; // +1a +1b
+                 delete[] copy; // +1b
+                 return total > 0 ? total : 0; // +2c
+             }",
+            "foo.cpp",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.abc;
+                assert_eq!(f.assignments(), 5.);
+                assert_eq!(f.branches(), 3.);
+                assert_eq!(f.conditions(), 5.);
+                // sqrt(25 + 9 + 25)
+                assert_eq!(f.magnitude(), 59f64.sqrt());
+            },
+        );
+    }
 }
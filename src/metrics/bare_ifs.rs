@@ -0,0 +1,365 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `BareIfs` metric.
+///
+/// This metric counts the number of `if` statements of a function/method
+/// lacking an `else` branch, which leave an implicit path where none
+/// of their branches is taken.
+///
+/// A chain of `else if` branches is counted once when it does not
+/// end with an `else` branch.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    bare_ifs: usize,
+    bare_ifs_sum: usize,
+    bare_ifs_min: usize,
+    bare_ifs_max: usize,
+    space_count: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            bare_ifs: 0,
+            bare_ifs_sum: 0,
+            bare_ifs_min: usize::MAX,
+            bare_ifs_max: 0,
+            space_count: 1,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("bare_ifs", 4)?;
+        st.serialize_field("sum", &self.bare_ifs_sum())?;
+        st.serialize_field("average", &self.bare_ifs_average())?;
+        st.serialize_field("min", &self.bare_ifs_min())?;
+        st.serialize_field("max", &self.bare_ifs_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}",
+            self.bare_ifs_sum(),
+            self.bare_ifs_average(),
+            self.bare_ifs_min(),
+            self.bare_ifs_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `BareIfs` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.bare_ifs_max = self.bare_ifs_max.max(other.bare_ifs_max);
+        self.bare_ifs_min = self.bare_ifs_min.min(other.bare_ifs_min);
+        self.bare_ifs_sum += other.bare_ifs_sum;
+        self.space_count += other.space_count;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `BareIfs` metric value of a space
+    pub fn bare_ifs(&self) -> f64 {
+        self.bare_ifs as f64
+    }
+
+    /// Returns the `BareIfs` metric sum value
+    pub fn bare_ifs_sum(&self) -> f64 {
+        self.bare_ifs_sum as f64
+    }
+
+    /// Returns the `BareIfs` metric average value
+    ///
+    /// This value is computed dividing the sum of the `BareIfs`
+    /// values for the number of spaces.
+    pub fn bare_ifs_average(&self) -> f64 {
+        self.bare_ifs_sum() / self.space_count as f64
+    }
+
+    /// Returns the `BareIfs` metric minimum value
+    pub fn bare_ifs_min(&self) -> f64 {
+        self.bare_ifs_min as f64
+    }
+
+    /// Returns the `BareIfs` metric maximum value
+    pub fn bare_ifs_max(&self) -> f64 {
+        self.bare_ifs_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.bare_ifs_sum += self.bare_ifs;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.bare_ifs_max = self.bare_ifs_max.max(self.bare_ifs);
+        self.bare_ifs_min = self.bare_ifs_min.min(self.bare_ifs);
+        self.compute_sum();
+    }
+
+    // Enables the `BareIfs` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `BareIfs` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait BareIfs
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+impl BareIfs for PythonCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        // The elif and else clauses are children of the if statement
+        if node.kind_id() == Python::IfStatement
+            && !node
+                .children()
+                .any(|child| child.kind_id() == Python::ElseClause)
+        {
+            stats.bare_ifs += 1;
+        }
+    }
+}
+
+macro_rules! bare_if {
+    ($lang:ident, $if:ident) => {
+        fn compute(node: &Node, stats: &mut Stats) {
+            // An else if is an if statement contained in an else clause,
+            // so only the last if of a chain may lack its alternative
+            if node.kind_id() == $lang::$if && node.child_by_field_name("alternative").is_none() {
+                stats.bare_ifs += 1;
+            }
+        }
+    };
+}
+
+impl BareIfs for MozjsCode {
+    bare_if!(Mozjs, IfStatement);
+}
+
+impl BareIfs for JavascriptCode {
+    bare_if!(Javascript, IfStatement);
+}
+
+impl BareIfs for TypescriptCode {
+    bare_if!(Typescript, IfStatement);
+}
+
+impl BareIfs for TsxCode {
+    bare_if!(Tsx, IfStatement);
+}
+
+impl BareIfs for RustCode {
+    bare_if!(Rust, IfExpression);
+}
+
+impl BareIfs for CppCode {
+    bare_if!(Cpp, IfStatement);
+}
+
+impl BareIfs for JavaCode {
+    bare_if!(Java, IfStatement);
+}
+
+impl BareIfs for CsharpCode {
+    bare_if!(Csharp, IfStatement);
+}
+
+implement_metric_trait!(
+    BareIfs,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::{check_func_space_with_options, check_metrics_with_options};
+
+    use super::*;
+
+    fn bare_ifs_options() -> MetricsOptions {
+        MetricsOptions {
+            bare_ifs: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn c_bare_ifs() {
+        check_func_space_with_options::<CppParser, _>(
+            "int f(int a, int b) {
+                 if (a) { // +1
+                     a++;
+                 }
+                 if (b) { // +1
+                     b++;
+                 }
+                 if (a > b) {
+                     return a;
+                 } else {
+                     return b;
+                 }
+             }",
+            "foo.c",
+            &bare_ifs_options(),
+            |func_space| {
+                assert_eq!(func_space.spaces[0].metrics.bare_ifs.bare_ifs(), 2.);
+            },
+        );
+    }
+
+    #[test]
+    fn python_bare_ifs() {
+        check_metrics_with_options::<PythonParser>(
+            "def f(a):
+                 if a == 0: # +1
+                     a = 1
+                 if a == 1: # +1
+                     return 1
+                 elif a == 2:
+                     return 2
+                 if a == 3:
+                     return 3
+                 elif a == 4:
+                     return 4
+                 else:
+                     return 5",
+            "foo.py",
+            &bare_ifs_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.bare_ifs,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_else_if_chains() {
+        check_metrics_with_options::<JavascriptParser>(
+            "function f(a) {
+                 if (a == 0) {
+                     return 0;
+                 } else if (a == 1) { // +1
+                     return 1;
+                 }
+                 if (a == 2) {
+                     return 2;
+                 } else if (a == 3) {
+                     return 3;
+                 } else {
+                     return 4;
+                 }
+             }",
+            "foo.js",
+            &bare_ifs_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.bare_ifs,
+                    @r###"
+                    {
+                      "sum": 1.0,
+                      "average": 0.5,
+                      "min": 0.0,
+                      "max": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_bare_ifs() {
+        check_metrics_with_options::<RustParser>(
+            "fn f(a: Option<i32>) -> i32 {
+                 if let Some(a) = a { // +1
+                     println!(\"{a}\");
+                 }
+                 let b = if a.is_some() { 1 } else { 0 };
+                 b
+             }",
+            "foo.rs",
+            &bare_ifs_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.bare_ifs,
+                    @r###"
+                    {
+                      "sum": 1.0,
+                      "average": 0.5,
+                      "min": 0.0,
+                      "max": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_bare_ifs() {
+        check_func_space_with_options::<JavaParser, _>(
+            "class A {
+                 void f(int a) {
+                     if (a > 0) { // +1
+                         a--;
+                     }
+                     if (a < 0) {
+                         a++;
+                     } else {
+                         a = 1;
+                     }
+                 }
+             }",
+            "foo.java",
+            &bare_ifs_options(),
+            |func_space| {
+                assert_eq!(
+                    func_space.spaces[0].spaces[0].metrics.bare_ifs.bare_ifs(),
+                    1.
+                );
+            },
+        );
+    }
+}
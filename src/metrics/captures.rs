@@ -0,0 +1,452 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `Captures` metric.
+///
+/// This metric counts the number of variables captured by a closure,
+/// approximated as the distinct identifiers used in a closure but not
+/// declared within it, as its parameters or its local variables.
+///
+/// The minimum, maximum and average values are computed over closures only.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    captures: usize,
+    captures_sum: usize,
+    captures_min: usize,
+    captures_max: usize,
+    is_closure: bool,
+    closures: usize,
+    enabled: bool,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("captures", 4)?;
+        st.serialize_field("sum", &self.captures_sum())?;
+        st.serialize_field("average", &self.captures_average())?;
+        st.serialize_field("min", &self.captures_min())?;
+        st.serialize_field("max", &self.captures_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}",
+            self.captures_sum(),
+            self.captures_average(),
+            self.captures_min(),
+            self.captures_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Captures` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        if other.closures == 0 {
+            return;
+        }
+        self.captures_min = if self.closures == 0 {
+            other.captures_min
+        } else {
+            self.captures_min.min(other.captures_min)
+        };
+        self.captures_max = self.captures_max.max(other.captures_max);
+        self.captures_sum += other.captures_sum;
+        self.closures += other.closures;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `Captures` metric value of a closure
+    pub fn captures(&self) -> f64 {
+        self.captures as f64
+    }
+
+    /// Returns the `Captures` metric sum value
+    pub fn captures_sum(&self) -> f64 {
+        self.captures_sum as f64
+    }
+
+    /// Returns the `Captures` metric average value
+    ///
+    /// This value is computed dividing the sum of the `Captures`
+    /// values for the number of closures.
+    ///
+    /// If there are no closures in a code, its value is `NAN`.
+    pub fn captures_average(&self) -> f64 {
+        self.captures_sum() / self.closures as f64
+    }
+
+    /// Returns the `Captures` metric minimum value
+    pub fn captures_min(&self) -> f64 {
+        self.captures_min as f64
+    }
+
+    /// Returns the `Captures` metric maximum value
+    pub fn captures_max(&self) -> f64 {
+        self.captures_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        if !self.is_closure {
+            return;
+        }
+        self.captures_min = if self.closures == 0 {
+            self.captures
+        } else {
+            self.captures_min.min(self.captures)
+        };
+        self.captures_max = self.captures_max.max(self.captures);
+        self.captures_sum += self.captures;
+        self.closures += 1;
+    }
+
+    // Enables the `Captures` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `Captures` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait Captures
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+// Counts the identifiers used in a closure but not declared within it.
+//
+// `is_identifier` matches the identifiers which refer to variables, while
+// `is_declaration` checks whether a child of a node is the place where
+// new variables are declared, such as the parameters of a closure.
+#[inline(always)]
+fn compute_captures<T: Checker>(
+    node: &Node,
+    code: &[u8],
+    stats: &mut Stats,
+    is_identifier: fn(&Node) -> bool,
+    is_declaration: fn(&Node, &Node) -> bool,
+) {
+    if !T::is_closure(node) {
+        return;
+    }
+
+    let mut declared = HashSet::new();
+    let mut used = HashSet::new();
+    node.act_on_node(&mut |n| {
+        if !is_identifier(n) {
+            return;
+        }
+        let Some(name) = n.utf8_text(code) else {
+            return;
+        };
+
+        let mut child = *n;
+        while let Some(parent) = child.parent() {
+            if is_declaration(&parent, &child) {
+                declared.insert(name);
+                return;
+            }
+            if parent.id() == node.id() {
+                break;
+            }
+            child = parent;
+        }
+        used.insert(name);
+    });
+
+    stats.is_closure = true;
+    stats.captures = used.difference(&declared).count();
+}
+
+#[inline(always)]
+fn is_field(parent: &Node, child: &Node, field: &str) -> bool {
+    parent
+        .child_by_field_name(field)
+        .is_some_and(|node| node.id() == child.id())
+}
+
+macro_rules! js_captures {
+    ($lang:ident) => {
+        fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+            compute_captures::<Self>(
+                node,
+                code,
+                stats,
+                |node| node.kind_id() == $lang::Identifier,
+                |parent, child| match parent.kind_id().into() {
+                    $lang::FormalParameters => true,
+                    $lang::ArrowFunction => is_field(parent, child, "parameter"),
+                    $lang::VariableDeclarator
+                    | $lang::FunctionDeclaration
+                    | $lang::FunctionExpression
+                    | $lang::ClassDeclaration => is_field(parent, child, "name"),
+                    $lang::CatchClause => is_field(parent, child, "parameter"),
+                    _ => false,
+                },
+            );
+        }
+    };
+}
+
+impl Captures for MozjsCode {
+    js_captures!(Mozjs);
+}
+
+impl Captures for JavascriptCode {
+    js_captures!(Javascript);
+}
+
+impl Captures for TypescriptCode {
+    js_captures!(Typescript);
+}
+
+impl Captures for TsxCode {
+    js_captures!(Tsx);
+}
+
+impl Captures for RustCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Rust::*;
+
+        compute_captures::<Self>(
+            node,
+            code,
+            stats,
+            // The identifiers in a path, such as `std::mem::swap`,
+            // and the names of macros are not variables
+            |node| {
+                node.kind_id() == Identifier
+                    && node.parent().is_none_or(|parent| {
+                        parent.kind_id() != ScopedIdentifier
+                            && !(parent.kind_id() == MacroInvocation
+                                && is_field(&parent, node, "macro"))
+                    })
+            },
+            |parent, child| match parent.kind_id().into() {
+                ClosureParameters | MatchPattern => true,
+                Parameter | LetDeclaration | LetCondition | ForExpression => {
+                    is_field(parent, child, "pattern")
+                }
+                _ => false,
+            },
+        );
+    }
+}
+
+// Returns whether a child of a node is its first identifier, such as
+// the range variable of a `let` or a `join` clause of a `LINQ` query
+#[inline(always)]
+fn is_first_identifier(parent: &Node, child: &Node) -> bool {
+    parent
+        .first_child(|id| id == Csharp::Identifier)
+        .is_some_and(|node| node.id() == child.id())
+}
+
+impl Captures for CsharpCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Csharp::*;
+
+        compute_captures::<Self>(
+            node,
+            code,
+            stats,
+            // The types, the members accessed through an expression
+            // and the names of generic types are not variables
+            |node| match node.kind_id().into() {
+                ImplicitParameter => true,
+                Identifier => node
+                    .parent()
+                    .is_none_or(|parent| match parent.kind_id().into() {
+                        GenericName | QualifiedName => false,
+                        MemberAccessExpression => !is_field(&parent, node, "name"),
+                        _ => !is_field(&parent, node, "type"),
+                    }),
+                _ => false,
+            },
+            |parent, child| match parent.kind_id().into() {
+                LambdaExpression => child.kind_id() == ImplicitParameter,
+                Parameter
+                | VariableDeclarator
+                | CatchDeclaration
+                | DeclarationExpression
+                | DeclarationPattern
+                | VarPattern
+                | RecursivePattern
+                | TuplePattern
+                | ParenthesizedVariableDesignation
+                | FromClause
+                | LocalFunctionStatement => is_field(parent, child, "name"),
+                ForeachStatement => is_field(parent, child, "left"),
+                LetClause | JoinClause => is_first_identifier(parent, child),
+                _ => false,
+            },
+        );
+    }
+}
+
+implement_metric_trait!(
+    Captures,
+    PythonCode,
+    CppCode,
+    JavaCode,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics_with_options;
+
+    use super::*;
+
+    fn captures_options() -> MetricsOptions {
+        MetricsOptions {
+            captures: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rust_closure_captures() {
+        check_metrics_with_options::<RustParser>(
+            "fn f(a: i32, b: i32, v: Vec<i32>) -> Vec<i32> {
+                 let g = |x: i32| { // +2 (a and b)
+                     let y = x * 2;
+                     println!(\"{}\", y);
+                     a + b + y
+                 };
+                 v.into_iter().map(g).collect()
+             }",
+            "foo.rs",
+            &captures_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.captures,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 2.0,
+                      "min": 2.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_no_closures() {
+        check_metrics_with_options::<RustParser>(
+            "fn f(a: i32) -> i32 {
+                 a + 1
+             }",
+            "foo.rs",
+            &captures_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.captures,
+                    @r###"
+                    {
+                      "sum": 0.0,
+                      "average": null,
+                      "min": 0.0,
+                      "max": 0.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_closure_captures() {
+        check_metrics_with_options::<JavascriptParser>(
+            "function f(a, b, c, arr) {
+                 arr.forEach((x) => { // +2 (a and b)
+                     const y = x + a;
+                     return y + b;
+                 });
+                 return arr.map(function (x) { return x; }); // +0
+             }",
+            "foo.js",
+            &captures_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.captures,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn csharp_closure_captures() {
+        // The lambdas are not spaces by default
+        let options = MetricsOptions {
+            lambda_spaces: true,
+            ..captures_options()
+        };
+        check_metrics_with_options::<CsharpParser>(
+            "class C {
+                 int F(int a, int b, List<int> list) {
+                     Func<int, int> f = x => x + a; // +1 (a)
+                     Func<int, IEnumerable<int>> g = n => from c in list // +2 (list and b)
+                                                          let d = c * n
+                                                          where d > b
+                                                          select d;
+                     Action h = delegate(int y) { // +3 (Console, f and a)
+                         var z = y;
+                         Console.WriteLine(f(z + a));
+                     };
+                     return g(a).Count();
+                 }
+             }",
+            "foo.cs",
+            &options,
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.captures,
+                    @r###"
+                    {
+                      "sum": 6.0,
+                      "average": 2.0,
+                      "min": 1.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `Cfg` metric.
+///
+/// This metric counts the number of conditional compilation attributes,
+/// such as `#[cfg(test)]` in Rust, or directives, such as `#if DEBUG`
+/// and `#elif` in C#, contained in a space.
+///
+/// Each of these attributes is a branch taken at build time, so they
+/// contribute to the complexity of a code as much as runtime branches.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    cfg: usize,
+    cfg_sum: usize,
+    cfg_min: usize,
+    cfg_max: usize,
+    space_count: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            cfg: 0,
+            cfg_sum: 0,
+            cfg_min: usize::MAX,
+            cfg_max: 0,
+            space_count: 1,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("cfg", 4)?;
+        st.serialize_field("sum", &self.cfg_sum())?;
+        st.serialize_field("average", &self.cfg_average())?;
+        st.serialize_field("min", &self.cfg_min())?;
+        st.serialize_field("max", &self.cfg_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}",
+            self.cfg_sum(),
+            self.cfg_average(),
+            self.cfg_min(),
+            self.cfg_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Cfg` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.cfg_max = self.cfg_max.max(other.cfg_max);
+        self.cfg_min = self.cfg_min.min(other.cfg_min);
+        self.cfg_sum += other.cfg_sum;
+        self.space_count += other.space_count;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `Cfg` metric value of a space
+    pub fn cfg(&self) -> f64 {
+        self.cfg as f64
+    }
+
+    /// Returns the `Cfg` metric sum value
+    pub fn cfg_sum(&self) -> f64 {
+        self.cfg_sum as f64
+    }
+
+    /// Returns the `Cfg` metric average value
+    ///
+    /// This value is computed dividing the sum of the `Cfg`
+    /// values for the number of spaces.
+    pub fn cfg_average(&self) -> f64 {
+        self.cfg_sum() / self.space_count as f64
+    }
+
+    /// Returns the `Cfg` metric minimum value
+    pub fn cfg_min(&self) -> f64 {
+        self.cfg_min as f64
+    }
+
+    /// Returns the `Cfg` metric maximum value
+    pub fn cfg_max(&self) -> f64 {
+        self.cfg_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.cfg_sum += self.cfg;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.cfg_max = self.cfg_max.max(self.cfg);
+        self.cfg_min = self.cfg_min.min(self.cfg);
+        self.compute_sum();
+    }
+
+    // Enables the `Cfg` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `Cfg` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait Cfg
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+impl Cfg for RustCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        // Both `#[cfg(...)]` and `#![cfg(...)]` contain an attribute
+        // whose path is the `cfg` identifier
+        if matches!(node.kind_id().into(), Rust::Attribute)
+            && node.child(0).is_some_and(|path| {
+                matches!(path.kind_id().into(), Rust::Identifier)
+                    && path.utf8_text(code) == Some("cfg")
+            })
+        {
+            stats.cfg += 1;
+        }
+    }
+}
+
+impl Cfg for CsharpCode {
+    fn compute(node: &Node, _code: &[u8], stats: &mut Stats) {
+        // An `#else` is the fallback of the conditions before it
+        if matches!(
+            node.kind_id().into(),
+            Csharp::PreprocIf | Csharp::PreprocElif
+        ) {
+            stats.cfg += 1;
+        }
+    }
+}
+
+implement_metric_trait!(
+    Cfg,
+    PythonCode,
+    MozjsCode,
+    JavascriptCode,
+    TypescriptCode,
+    TsxCode,
+    CppCode,
+    JavaCode,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics_with_options;
+
+    use super::*;
+
+    fn cfg_options() -> MetricsOptions {
+        MetricsOptions {
+            cfg_attributes: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rust_cfg_functions() {
+        check_metrics_with_options::<RustParser>(
+            "#[cfg(unix)]
+             fn f() {}
+
+             #[cfg(not(unix))]
+             fn f() {}
+
+             #[inline]
+             fn g() {}",
+            "foo.rs",
+            &cfg_options(),
+            |metric| {
+                // nspace = 4 (unit and 3 functions)
+                insta::assert_json_snapshot!(
+                    metric.cfg,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 0.5,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_cfg_attr_not_counted() {
+        check_metrics_with_options::<RustParser>(
+            "#![cfg(test)]
+
+             #[cfg_attr(test, derive(Debug))]
+             struct S;",
+            "foo.rs",
+            &cfg_options(),
+            |metric| {
+                // nspace = 1 (unit)
+                insta::assert_json_snapshot!(
+                    metric.cfg,
+                    @r###"
+                    {
+                      "sum": 1.0,
+                      "average": 1.0,
+                      "min": 1.0,
+                      "max": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn csharp_cfg_directives() {
+        check_metrics_with_options::<CsharpParser>(
+            "#if DEBUG // +1
+             using System.Diagnostics;
+             #endif
+             class C {
+                 void F() {
+             #if TRACE // +1
+                     Trace.WriteLine(1);
+             #elif LOG // +1
+                     Log.Write(1);
+             #else
+                     Console.WriteLine(1);
+             #endif
+                 }
+             }",
+            "foo.cs",
+            &cfg_options(),
+            |metric| {
+                // nspace = 3 (unit, class and method)
+                insta::assert_json_snapshot!(
+                    metric.cfg,
+                    @r###"
+                    {
+                      "sum": 3.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,513 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `ChainLength` metric.
+///
+/// This metric computes the maximum length of the method chains
+/// contained in a function/method, that is the number of calls where
+/// each call is invoked on the result of the previous one, so
+/// `a.b().c().d()` has a length of 3.
+///
+/// In Rust, the `?` operator and `.await` do not break a chain.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    chain_length: usize,
+    chain_length_sum: usize,
+    chain_length_min: usize,
+    chain_length_max: usize,
+    space_count: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            chain_length: 0,
+            chain_length_sum: 0,
+            chain_length_min: usize::MAX,
+            chain_length_max: 0,
+            space_count: 1,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("chain_length", 3)?;
+        st.serialize_field("average", &self.chain_length_average())?;
+        st.serialize_field("min", &self.chain_length_min())?;
+        st.serialize_field("max", &self.chain_length_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "average: {}, min: {}, max: {}",
+            self.chain_length_average(),
+            self.chain_length_min(),
+            self.chain_length_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `ChainLength` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.chain_length_max = self.chain_length_max.max(other.chain_length_max);
+        self.chain_length_min = self.chain_length_min.min(other.chain_length_min);
+        self.chain_length_sum += other.chain_length_sum;
+        self.space_count += other.space_count;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `ChainLength` metric value of a space
+    pub fn chain_length(&self) -> f64 {
+        self.chain_length as f64
+    }
+
+    /// Returns the `ChainLength` metric average value
+    ///
+    /// This value is computed dividing the sum of the `ChainLength`
+    /// values for the number of spaces.
+    pub fn chain_length_average(&self) -> f64 {
+        self.chain_length_sum as f64 / self.space_count as f64
+    }
+
+    /// Returns the `ChainLength` metric minimum value
+    pub fn chain_length_min(&self) -> f64 {
+        self.chain_length_min as f64
+    }
+
+    /// Returns the `ChainLength` metric maximum value
+    pub fn chain_length_max(&self) -> f64 {
+        self.chain_length_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.chain_length_sum += self.chain_length;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.chain_length_max = self.chain_length_max.max(self.chain_length);
+        self.chain_length_min = self.chain_length_min.min(self.chain_length);
+        self.compute_sum();
+    }
+
+    // Enables the `ChainLength` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `ChainLength` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait ChainLength
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+// Computes the length of the chain ending with a call walking down
+// the objects the calls are invoked on.
+//
+// `receiver` returns the object a call is invoked on, if any.
+#[inline(always)]
+fn compute_length<'a>(
+    node: &Node<'a>,
+    stats: &mut Stats,
+    is_call: fn(u16) -> bool,
+    receiver: fn(&Node<'a>) -> Option<Node<'a>>,
+) {
+    if !is_call(node.kind_id()) {
+        return;
+    }
+
+    let mut length = 1;
+    let mut call = *node;
+    while let Some(object) = receiver(&call) {
+        if !is_call(object.kind_id()) {
+            break;
+        }
+        length += 1;
+        call = object;
+    }
+
+    stats.chain_length = stats.chain_length.max(length);
+}
+
+// Returns the object of a call whose function is a member access.
+#[inline(always)]
+fn member_object<'a>(
+    node: &Node<'a>,
+    is_member: fn(u16) -> bool,
+    object_field: &str,
+) -> Option<Node<'a>> {
+    node.child_by_field_name("function")
+        .filter(|function| is_member(function.kind_id()))
+        .and_then(|function| function.child_by_field_name(object_field))
+}
+
+impl ChainLength for PythonCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_length(
+            node,
+            stats,
+            |id| id == Python::Call,
+            |node| member_object(node, |id| id == Python::Attribute, "object"),
+        );
+    }
+}
+
+impl ChainLength for MozjsCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_length(
+            node,
+            stats,
+            |id| matches!(id.into(), Mozjs::CallExpression | Mozjs::CallExpression2),
+            |node| {
+                member_object(
+                    node,
+                    |id| {
+                        matches!(
+                            id.into(),
+                            Mozjs::MemberExpression
+                                | Mozjs::MemberExpression2
+                                | Mozjs::MemberExpression3
+                        )
+                    },
+                    "object",
+                )
+            },
+        );
+    }
+}
+
+impl ChainLength for JavascriptCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_length(
+            node,
+            stats,
+            |id| {
+                matches!(
+                    id.into(),
+                    Javascript::CallExpression | Javascript::CallExpression2
+                )
+            },
+            |node| {
+                member_object(
+                    node,
+                    |id| {
+                        matches!(
+                            id.into(),
+                            Javascript::MemberExpression
+                                | Javascript::MemberExpression2
+                                | Javascript::MemberExpression3
+                        )
+                    },
+                    "object",
+                )
+            },
+        );
+    }
+}
+
+impl ChainLength for TypescriptCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_length(
+            node,
+            stats,
+            |id| {
+                matches!(
+                    id.into(),
+                    Typescript::CallExpression
+                        | Typescript::CallExpression2
+                        | Typescript::CallExpression3
+                        | Typescript::CallExpression4
+                )
+            },
+            |node| {
+                member_object(
+                    node,
+                    |id| {
+                        matches!(
+                            id.into(),
+                            Typescript::MemberExpression
+                                | Typescript::MemberExpression2
+                                | Typescript::MemberExpression3
+                                | Typescript::MemberExpression4
+                        )
+                    },
+                    "object",
+                )
+            },
+        );
+    }
+}
+
+impl ChainLength for TsxCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_length(
+            node,
+            stats,
+            |id| {
+                matches!(
+                    id.into(),
+                    Tsx::CallExpression
+                        | Tsx::CallExpression2
+                        | Tsx::CallExpression3
+                        | Tsx::CallExpression4
+                )
+            },
+            |node| {
+                member_object(
+                    node,
+                    |id| {
+                        matches!(
+                            id.into(),
+                            Tsx::MemberExpression
+                                | Tsx::MemberExpression2
+                                | Tsx::MemberExpression3
+                                | Tsx::MemberExpression4
+                        )
+                    },
+                    "object",
+                )
+            },
+        );
+    }
+}
+
+impl ChainLength for RustCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_length(
+            node,
+            stats,
+            |id| id == Rust::CallExpression,
+            |node| {
+                // The function of a call with a turbofish is a generic function
+                let mut function = node.child_by_field_name("function")?;
+                if function.kind_id() == Rust::GenericFunction {
+                    function = function.child_by_field_name("function")?;
+                }
+                if function.kind_id() != Rust::FieldExpression {
+                    return None;
+                }
+
+                let mut value = function.child_by_field_name("value")?;
+                while matches!(
+                    value.kind_id().into(),
+                    Rust::TryExpression | Rust::AwaitExpression
+                ) {
+                    value = value.child(0)?;
+                }
+                Some(value)
+            },
+        );
+    }
+}
+
+impl ChainLength for CppCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_length(
+            node,
+            stats,
+            |id| matches!(id.into(), Cpp::CallExpression | Cpp::CallExpression2),
+            |node| member_object(node, |id| id == Cpp::FieldExpression, "argument"),
+        );
+    }
+}
+
+impl ChainLength for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_length(
+            node,
+            stats,
+            |id| id == Java::MethodInvocation,
+            |node| node.child_by_field_name("object"),
+        );
+    }
+}
+
+impl ChainLength for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_length(
+            node,
+            stats,
+            |id| id == Csharp::InvocationExpression,
+            |node| {
+                member_object(
+                    node,
+                    |id| id == Csharp::MemberAccessExpression,
+                    "expression",
+                )
+            },
+        );
+    }
+}
+
+implement_metric_trait!(
+    ChainLength,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics_with_options;
+
+    use super::*;
+
+    fn chain_length_options() -> MetricsOptions {
+        MetricsOptions {
+            chain_length: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn javascript_chain() {
+        check_metrics_with_options::<MozjsParser>(
+            "function f(items) {
+                 return items.filter(g).map(h).slice(1).reverse().join(','); // 5
+             }",
+            "foo.js",
+            &chain_length_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.chain_length,
+                    @r###"
+                    {
+                      "average": 2.5,
+                      "min": 0.0,
+                      "max": 5.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_chain() {
+        check_metrics_with_options::<RustParser>(
+            "async fn f(x: Vec<i32>) -> Option<i32> {
+                 let a = x.iter().map(g).sum::<i32>(); // 3
+                 let b = h()?.get(a)?.into_inner().await; // 3
+                 k(x.len().pow(2)) // 2
+             }",
+            "foo.rs",
+            &chain_length_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.chain_length,
+                    @r###"
+                    {
+                      "average": 1.5,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_chain() {
+        check_metrics_with_options::<JavaParser>(
+            "class A {
+                 String f(StringBuilder s) {
+                     return s.append(1).append(2).toString(); // 3
+                 }
+                 int g(Map m) {
+                     return m.values.size(); // 1
+                 }
+             }",
+            "foo.java",
+            &chain_length_options(),
+            |metric| {
+                // nspace = 4 (2 methods, class and unit)
+                insta::assert_json_snapshot!(
+                    metric.chain_length,
+                    @r###"
+                    {
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_chain() {
+        check_metrics_with_options::<PythonParser>(
+            "def f(s):
+                 return s.strip().lower().split(',') # 3",
+            "foo.py",
+            &chain_length_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.chain_length,
+                    @r###"
+                    {
+                      "average": 1.5,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_chain() {
+        check_metrics_with_options::<CppParser>(
+            "int f(A a) {
+                 return a.b().c(); // 2
+             }",
+            "foo.cpp",
+            &chain_length_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.chain_length,
+                    @r###"
+                    {
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -1,8 +1,7 @@
-use std::collections::HashMap;
-
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::rc::Rc;
 
 use crate::checker::Checker;
 use crate::macros::implement_metric_trait;
@@ -18,13 +17,15 @@ use crate::*;
 // function is recursive or not.
 
 /// The `Cognitive Complexity` metric.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
     structural: usize,
     structural_sum: usize,
     structural_min: usize,
     structural_max: usize,
     nesting: usize,
+    nesting_max: usize,
     total_space_functions: usize,
     boolean_seq: BoolSequence,
 }
@@ -37,6 +38,7 @@ impl Default for Stats {
             structural_min: usize::MAX,
             structural_max: 0,
             nesting: 0,
+            nesting_max: 0,
             total_space_functions: 1,
             boolean_seq: BoolSequence::default(),
         }
@@ -96,6 +98,15 @@ impl Stats {
         self.structural_max as f64
     }
 
+    /// Returns the maximum nesting level of the structures of a space,
+    /// such as conditions and loops, which increase the nesting penalty
+    /// of the `Cognitive Complexity`
+    ///
+    /// A space without any of them has a level of `0`.
+    pub fn nesting_max(&self) -> f64 {
+        self.nesting_max as f64
+    }
+
     /// Returns the `Cognitive Complexity` metric average value
     ///
     /// This value is computed dividing the `Cognitive Complexity` value
@@ -125,13 +136,101 @@ pub trait Cognitive
 where
     Self: Checker,
 {
+    const IMPLEMENTED: bool = true;
+
+    /// Computes the metric on a node.
+    ///
+    /// `levels` contains the conditional, function and lambda nesting
+    /// levels of the parent of the node, and `loops` the loops enclosing
+    /// it. Both are updated to the ones of the node itself, which its
+    /// children receive.
     fn compute(
         node: &Node,
+        code: &[u8],
         stats: &mut Stats,
-        nesting_map: &mut HashMap<usize, (usize, usize, usize)>,
+        levels: &mut (usize, usize, usize),
+        loops: &mut Loops,
     );
 }
 
+/// The loops enclosing a node within its function/closure,
+/// which its children receive.
+#[derive(Debug, Clone, Default)]
+pub struct Loops {
+    // The number of loops enclosing a node
+    depth: usize,
+    // Whether a `break` leaves a switch instead of a loop
+    switch: bool,
+    // The label of a labeled statement, which is given to its loop
+    label: Option<(usize, usize)>,
+    // The innermost labeled loop enclosing a node
+    labeled: Option<Rc<LabeledLoop>>,
+}
+
+#[derive(Debug)]
+struct LabeledLoop {
+    label: (usize, usize),
+    depth: usize,
+    outer: Option<Rc<LabeledLoop>>,
+}
+
+impl Loops {
+    // Updates the loops enclosing a node from the ones of its parent.
+    //
+    // `label` is the label of a labeled loop, or the one of a labeled
+    // statement, which is given to the loop it contains.
+    fn enter<T: Checker>(
+        &mut self,
+        node: &Node,
+        is_loop: bool,
+        is_switch: bool,
+        label: Option<Node>,
+    ) {
+        let label = label
+            .map(|label| (label.start_byte(), label.end_byte()))
+            .or(self.label.take());
+        if T::is_func(node) || T::is_closure(node) {
+            *self = Self::default();
+        } else if is_loop {
+            self.depth += 1;
+            self.switch = false;
+            if let Some(label) = label {
+                self.labeled = Some(Rc::new(LabeledLoop {
+                    label,
+                    depth: self.depth,
+                    outer: self.labeled.take(),
+                }));
+            }
+        } else if is_switch {
+            self.switch = true;
+        } else {
+            self.label = label;
+        }
+    }
+
+    // Returns the depth of the loop a `break` or a `continue` jumps out of,
+    // which is 0 when it leaves a switch or a labeled block.
+    fn target(&self, code: &[u8], is_break: bool, label: Option<Node>) -> usize {
+        let Some(label) = label else {
+            return if is_break && self.switch {
+                0
+            } else {
+                self.depth
+            };
+        };
+        let name = &code[label.start_byte()..label.end_byte()];
+        let mut labeled = self.labeled.as_deref();
+        while let Some(labeled_loop) = labeled {
+            let (start, end) = labeled_loop.label;
+            if &code[start..end] == name {
+                return labeled_loop.depth;
+            }
+            labeled = labeled_loop.outer.as_deref();
+        }
+        0
+    }
+}
+
 fn compute_booleans<T: std::cmp::PartialEq + std::convert::From<u16>>(
     node: &Node,
     stats: &mut Stats,
@@ -147,7 +246,7 @@ fn compute_booleans<T: std::cmp::PartialEq + std::convert::From<u16>>(
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
 struct BoolSequence {
     boolean_op: Option<u16>,
 }
@@ -191,21 +290,6 @@ fn increment_by_one(stats: &mut Stats) {
     stats.structural += 1;
 }
 
-fn get_nesting_from_map(
-    node: &Node,
-    nesting_map: &mut HashMap<usize, (usize, usize, usize)>,
-) -> (usize, usize, usize) {
-    if let Some(parent) = node.parent() {
-        if let Some(n) = nesting_map.get(&parent.id()) {
-            *n
-        } else {
-            (0, 0, 0)
-        }
-    } else {
-        (0, 0, 0)
-    }
-}
-
 fn increment_function_depth<T: std::cmp::PartialEq + std::convert::From<u16>>(
     depth: &mut usize,
     node: &Node,
@@ -222,27 +306,52 @@ fn increment_function_depth<T: std::cmp::PartialEq + std::convert::From<u16>>(
     }
 }
 
+// Increments the structural complexity of a `break` or a `continue`
+// by the number of loops enclosing the loop it jumps out of, so that
+// jumping out of deeper loops costs more.
+fn increment_loop_jump(
+    stats: &mut Stats,
+    loops: &Loops,
+    code: &[u8],
+    is_break: bool,
+    label: Option<Node>,
+) {
+    stats.structural += loops.target(code, is_break, label).saturating_sub(1);
+}
+
 #[inline(always)]
 fn increase_nesting(stats: &mut Stats, nesting: &mut usize, depth: usize, lambda: usize) {
     stats.nesting = *nesting + depth + lambda;
     increment(stats);
     *nesting += 1;
+    stats.nesting_max = stats.nesting_max.max(*nesting);
     stats.boolean_seq.reset();
 }
 
 impl Cognitive for PythonCode {
     fn compute(
         node: &Node,
+        code: &[u8],
         stats: &mut Stats,
-        nesting_map: &mut HashMap<usize, (usize, usize, usize)>,
+        levels: &mut (usize, usize, usize),
+        loops: &mut Loops,
     ) {
         use Python::*;
 
-        // Get nesting of the parent
-        let (mut nesting, mut depth, mut lambda) = get_nesting_from_map(node, nesting_map);
+        let (mut nesting, mut depth, mut lambda) = *levels;
+        loops.enter::<Self>(
+            node,
+            matches!(node.kind_id().into(), ForStatement | WhileStatement),
+            false,
+            Option::None,
+        );
 
         match node.kind_id().into() {
-            IfStatement | ForStatement | WhileStatement | ConditionalExpression => {
+            IfStatement
+            | ForStatement
+            | WhileStatement
+            | ConditionalExpression
+            | MatchStatement => {
                 increase_nesting(stats, &mut nesting, depth, lambda);
             }
             ElifClause => {
@@ -258,8 +367,13 @@ impl Cognitive for PythonCode {
                 increment_by_one(stats);
             }
             ExceptClause => {
-                nesting += 1;
-                increment(stats);
+                // As a catch clause, it costs its own nesting level,
+                // such as the one of a loop retrying an async operation
+                increase_nesting(stats, &mut nesting, depth, lambda);
+            }
+            BreakStatement | ContinueStatement => {
+                let is_break = node.kind_id() == BreakStatement;
+                increment_loop_jump(stats, loops, code, is_break, Option::None);
             }
             ExpressionList | ExpressionStatement | Tuple => {
                 stats.boolean_seq.reset();
@@ -299,20 +413,29 @@ impl Cognitive for PythonCode {
             }
             _ => {}
         }
-        // Add node to nesting map
-        nesting_map.insert(node.id(), (nesting, depth, lambda));
+        *levels = (nesting, depth, lambda);
     }
 }
 
 impl Cognitive for RustCode {
     fn compute(
         node: &Node,
+        code: &[u8],
         stats: &mut Stats,
-        nesting_map: &mut HashMap<usize, (usize, usize, usize)>,
+        levels: &mut (usize, usize, usize),
+        loops: &mut Loops,
     ) {
         use Rust::*;
         //TODO: Implement macros
-        let (mut nesting, mut depth, mut lambda) = get_nesting_from_map(node, nesting_map);
+        let (mut nesting, mut depth, mut lambda) = *levels;
+        let is_loop = matches!(
+            node.kind_id().into(),
+            ForExpression | WhileExpression | LoopExpression
+        );
+        let label = is_loop
+            .then(|| node.first_child(|id| id == Label))
+            .flatten();
+        loops.enter::<Self>(node, is_loop, false, label);
 
         match node.kind_id().into() {
             IfExpression => {
@@ -327,12 +450,17 @@ impl Cognitive for RustCode {
             Else /*else-if also */ => {
                 increment_by_one(stats);
             }
+            // A `?` returns early on an error, so it weighs as a jump,
+            // without any nesting penalty
+            TryExpression => {
+                increment_by_one(stats);
+            }
             BreakExpression | ContinueExpression => {
-                if let Some(label_child) = node.child(1) {
-                    if let Label = label_child.kind_id().into() {
-                        increment_by_one(stats);
-                    }
+                let label = node.first_child(|id| id == Label);
+                if label.is_some() {
+                    increment_by_one(stats);
                 }
+                increment_loop_jump(stats, loops, code, node.kind_id() == BreakExpression, label);
             }
             UnaryExpression => {
                 stats.boolean_seq.not_operator(node.kind_id());
@@ -350,20 +478,35 @@ impl Cognitive for RustCode {
             }
             _ => {}
         }
-        nesting_map.insert(node.id(), (nesting, depth, lambda));
+        *levels = (nesting, depth, lambda);
     }
 }
 
+fn is_cpp_loop(node: &Node) -> bool {
+    matches!(
+        node.kind_id().into(),
+        Cpp::ForStatement | Cpp::ForRangeLoop | Cpp::WhileStatement | Cpp::DoStatement
+    )
+}
+
 impl Cognitive for CppCode {
     fn compute(
         node: &Node,
+        code: &[u8],
         stats: &mut Stats,
-        nesting_map: &mut HashMap<usize, (usize, usize, usize)>,
+        levels: &mut (usize, usize, usize),
+        loops: &mut Loops,
     ) {
         use Cpp::*;
 
         //TODO: Implement macros
-        let (mut nesting, depth, mut lambda) = get_nesting_from_map(node, nesting_map);
+        let (mut nesting, depth, mut lambda) = *levels;
+        loops.enter::<Self>(
+            node,
+            is_cpp_loop(node),
+            node.kind_id() == SwitchStatement,
+            None,
+        );
 
         match node.kind_id().into() {
             IfStatement => {
@@ -371,12 +514,20 @@ impl Cognitive for CppCode {
                     increase_nesting(stats,&mut nesting, depth, lambda);
                 }
             }
-            ForStatement | WhileStatement | DoStatement | SwitchStatement | CatchClause => {
+            ForStatement | WhileStatement | DoStatement | SwitchStatement | CatchClause
+            | ConditionalExpression => {
                 increase_nesting(stats,&mut nesting, depth, lambda);
             }
             GotoStatement | Else /* else-if also */ => {
                 increment_by_one(stats);
             }
+            BreakStatement => {
+                // A break inside a switch leaves the switch
+                increment_loop_jump(stats, loops, code, true, None);
+            }
+            ContinueStatement => {
+                increment_loop_jump(stats, loops, code, false, None);
+            }
             UnaryExpression2 => {
                 stats.boolean_seq.not_operator(node.kind_id());
             }
@@ -388,15 +539,30 @@ impl Cognitive for CppCode {
             }
             _ => {}
         }
-        nesting_map.insert(node.id(), (nesting, depth, lambda));
+        *levels = (nesting, depth, lambda);
     }
 }
 
 macro_rules! js_cognitive {
-    ($lang:ident) => {
-        fn compute(node: &Node, stats: &mut Stats, nesting_map: &mut HashMap<usize, (usize, usize, usize)>) {
+    ($lang:ident, $parser:ident) => {
+        fn compute(
+            node: &Node,
+            code: &[u8],
+            stats: &mut Stats,
+            levels: &mut (usize, usize, usize),
+            loops: &mut Loops,
+        ) {
             use $lang::*;
-            let (mut nesting, mut depth, mut lambda) = get_nesting_from_map(node, nesting_map);
+            let (mut nesting, mut depth, mut lambda) = *levels;
+            let label = (node.kind_id() == LabeledStatement)
+                .then(|| node.child_by_field_name("label"))
+                .flatten();
+            loops.enter::<Self>(
+                node,
+                matches!(node.kind_id().into(), ForStatement | ForInStatement | WhileStatement | DoStatement),
+                node.kind_id() == SwitchStatement,
+                label,
+            );
 
             match node.kind_id().into() {
                 IfStatement => {
@@ -410,6 +576,15 @@ macro_rules! js_cognitive {
                 Else /* else-if also */ => {
                     increment_by_one(stats);
                 }
+                BreakStatement => {
+                    // A break inside a switch leaves the switch
+                    let label = node.child_by_field_name("label");
+                    increment_loop_jump(stats, loops, code, true, label);
+                }
+                ContinueStatement => {
+                    let label = node.child_by_field_name("label");
+                    increment_loop_jump(stats, loops, code, false, label);
+                }
                 ExpressionStatement => {
                     // Reset the boolean sequence
                     stats.boolean_seq.reset();
@@ -432,36 +607,54 @@ macro_rules! js_cognitive {
                 }
                 _ => {}
             }
-            nesting_map.insert(node.id(), (nesting, depth, lambda));
+            *levels = (nesting, depth, lambda);
         }
     };
 }
 
 impl Cognitive for MozjsCode {
-    js_cognitive!(Mozjs);
+    js_cognitive!(Mozjs, MozjsParser);
 }
 
 impl Cognitive for JavascriptCode {
-    js_cognitive!(Javascript);
+    js_cognitive!(Javascript, JavascriptParser);
 }
 
 impl Cognitive for TypescriptCode {
-    js_cognitive!(Typescript);
+    js_cognitive!(Typescript, TypescriptParser);
 }
 
 impl Cognitive for TsxCode {
-    js_cognitive!(Tsx);
+    js_cognitive!(Tsx, TsxParser);
+}
+
+fn is_java_loop(node: &Node) -> bool {
+    matches!(
+        node.kind_id().into(),
+        Java::ForStatement | Java::EnhancedForStatement | Java::WhileStatement | Java::DoStatement
+    )
 }
 
 impl Cognitive for JavaCode {
     fn compute(
         node: &Node,
+        code: &[u8],
         stats: &mut Stats,
-        nesting_map: &mut HashMap<usize, (usize, usize, usize)>,
+        levels: &mut (usize, usize, usize),
+        loops: &mut Loops,
     ) {
         use Java::*;
 
-        let (mut nesting, depth, mut lambda) = get_nesting_from_map(node, nesting_map);
+        let (mut nesting, depth, mut lambda) = *levels;
+        let label = (node.kind_id() == LabeledStatement)
+            .then(|| node.first_child(|id| id == Identifier))
+            .flatten();
+        loops.enter::<Self>(
+            node,
+            is_java_loop(node),
+            node.kind_id() == SwitchExpression,
+            label,
+        );
 
         match node.kind_id().into() {
             IfStatement => {
@@ -469,12 +662,22 @@ impl Cognitive for JavaCode {
                     increase_nesting(stats,&mut nesting, depth, lambda);
                 }
             }
-            ForStatement | WhileStatement | DoStatement | SwitchBlock | CatchClause => {
+            ForStatement | EnhancedForStatement | WhileStatement | DoStatement | SwitchBlock
+            | CatchClause | TernaryExpression => {
                 increase_nesting(stats,&mut nesting, depth, lambda);
             }
             Else /* else-if also */ => {
                 increment_by_one(stats);
             }
+            BreakStatement => {
+                // A break inside a switch leaves the switch
+                let label = node.first_child(|id| id == Identifier);
+                increment_loop_jump(stats, loops, code, true, label);
+            }
+            ContinueStatement => {
+                let label = node.first_child(|id| id == Identifier);
+                increment_loop_jump(stats, loops, code, false, label);
+            }
             UnaryExpression => {
                 stats.boolean_seq.not_operator(node.kind_id());
             }
@@ -486,15 +689,142 @@ impl Cognitive for JavaCode {
             }
             _ => {}
         }
-        nesting_map.insert(node.id(), (nesting, depth, lambda));
+        *levels = (nesting, depth, lambda);
+    }
+}
+
+fn is_go_loop(node: &Node) -> bool {
+    node.kind_id() == Go::ForStatement
+}
+
+impl Cognitive for GoCode {
+    fn compute(
+        node: &Node,
+        code: &[u8],
+        stats: &mut Stats,
+        levels: &mut (usize, usize, usize),
+        loops: &mut Loops,
+    ) {
+        use crate::languages::Go::*;
+
+        let (mut nesting, depth, mut lambda) = *levels;
+        let label = (node.kind_id() == LabeledStatement)
+            .then(|| node.child_by_field_name("label"))
+            .flatten();
+        loops.enter::<Self>(
+            node,
+            is_go_loop(node),
+            matches!(
+                node.kind_id().into(),
+                ExpressionSwitchStatement | TypeSwitchStatement | SelectStatement
+            ),
+            label,
+        );
+
+        match node.kind_id().into() {
+            IfStatement if !Self::is_else_if(node) => {
+                increase_nesting(stats,&mut nesting, depth, lambda);
+            }
+            ForStatement | ExpressionSwitchStatement | TypeSwitchStatement | SelectStatement => {
+                increase_nesting(stats,&mut nesting, depth, lambda);
+            }
+            Else /* else-if also */ | GotoStatement => {
+                increment_by_one(stats);
+            }
+            BreakStatement | ContinueStatement => {
+                let label = node.first_child(|id| id == LabelName);
+                if label.is_some() {
+                    increment_by_one(stats);
+                }
+                // A break inside a switch or a select leaves it
+                increment_loop_jump(stats, loops, code, node.kind_id() == BreakStatement, label);
+            }
+            UnaryExpression => {
+                stats.boolean_seq.not_operator(node.kind_id());
+            }
+            BinaryExpression => {
+                compute_booleans::<language_go::Go>(node, stats, AMPAMP, PIPEPIPE);
+            }
+            FuncLiteral => {
+                lambda += 1;
+            }
+            _ => {}
+        }
+        *levels = (nesting, depth, lambda);
+    }
+}
+
+fn is_csharp_loop(node: &Node) -> bool {
+    matches!(
+        node.kind_id().into(),
+        Csharp::ForStatement
+            | Csharp::ForeachStatement
+            | Csharp::WhileStatement
+            | Csharp::DoStatement
+    )
+}
+
+impl Cognitive for CsharpCode {
+    fn compute(
+        node: &Node,
+        code: &[u8],
+        stats: &mut Stats,
+        levels: &mut (usize, usize, usize),
+        loops: &mut Loops,
+    ) {
+        use Csharp::*;
+
+        let (mut nesting, mut depth, mut lambda) = *levels;
+        loops.enter::<Self>(
+            node,
+            is_csharp_loop(node),
+            node.kind_id() == SwitchStatement,
+            None,
+        );
+
+        match node.kind_id().into() {
+            IfStatement if !Self::is_else_if(node) => {
+                increase_nesting(stats,&mut nesting, depth, lambda);
+            }
+            ForStatement | ForeachStatement | WhileStatement | DoStatement | SwitchStatement
+            | SwitchExpression | CatchClause | ConditionalExpression => {
+                increase_nesting(stats,&mut nesting, depth, lambda);
+            }
+            GotoStatement | Else /* else-if also */ => {
+                increment_by_one(stats);
+            }
+            BreakStatement => {
+                // A break inside a switch leaves the switch
+                increment_loop_jump(stats, loops, code, true, None);
+            }
+            ContinueStatement => {
+                increment_loop_jump(stats, loops, code, false, None);
+            }
+            PrefixUnaryExpression => {
+                stats.boolean_seq.not_operator(node.kind_id());
+            }
+            BinaryExpression => {
+                compute_booleans::<language_csharp::Csharp>(node, stats, AMPAMP, PIPEPIPE);
+            }
+            LocalFunctionStatement => {
+                // A local function is always nested in another function
+                nesting = 0;
+                depth += 1;
+            }
+            LambdaExpression | AnonymousMethodExpression => {
+                lambda += 1;
+            }
+            _ => {}
+        }
+        *levels = (nesting, depth, lambda);
     }
 }
 
-implement_metric_trait!(Cognitive, PreprocCode, CcommentCode, KotlinCode);
+implement_metric_trait!(Cognitive, PreprocCode, CcommentCode, HclCode, KotlinCode);
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::check_metrics;
+    use crate::tools::{check_func_space, check_metrics};
 
     use super::*;
 
@@ -1459,21 +1789,89 @@ mod tests {
         );
     }
 
+    #[test]
+    fn javascript_async_function() {
+        // As in the SonarSource specification, `await` does not
+        // increase the complexity, while the control flow around it does
+        check_metrics::<JavascriptParser>(
+            "async function fetchAll(urls) {
+                 const results = [];
+                 for (const url of urls) { // +1
+                     try {
+                         const response = await fetch(url);
+                         if (!response.ok) { // +2 (nesting = 1)
+                             continue;
+                         }
+                         results.push(await response.json());
+                     } catch (error) { // +2 (nesting = 1)
+                         if (error.retry) { // +3 (nesting = 2)
+                             await retry(url);
+                         }
+                     }
+                 }
+                 return results;
+             }",
+            "foo.js",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cognitive,
+                    @r###"
+                    {
+                      "sum": 8.0,
+                      "average": 8.0,
+                      "min": 0.0,
+                      "max": 8.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_async_function() {
+        check_metrics::<PythonParser>(
+            "async def fetch_all(session, urls):
+                 results = []
+                 async for url in urls:  # +1
+                     try:
+                         async with session.get(url) as response:
+                             results.append(await response.json())
+                     except TimeoutError:  # +2 (nesting = 1)
+                         if retry:  # +3 (nesting = 2)
+                             await fetch(session, url)
+                 return results",
+            "foo.py",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cognitive,
+                    @r###"
+                    {
+                      "sum": 6.0,
+                      "average": 6.0,
+                      "min": 0.0,
+                      "max": 6.0
+                    }"###
+                );
+            },
+        );
+    }
+
     #[test]
     fn rust_break_continue() {
-        // Only labeled break and continue statements are considered
+        // Labeled break and continue statements cost +1,
+        // and +1 for each loop enclosing the loop they jump out of
         check_metrics::<RustParser>(
             "fn f() {
                  'tens: for ten in 0..3 { // +1
                      '_units: for unit in 0..=9 { // +2 (nesting = 1)
                          if unit % 2 == 0 { // +3 (nesting = 2)
-                             continue;
+                             continue; // +1 (loop depth = 2)
                          } else if unit == 5 { // +1
-                             continue 'tens; // +1
+                             continue 'tens; // +1 (+1 label, loop depth = 1)
                          } else if unit == 6 { // +1
-                             break;
+                             break; // +1 (loop depth = 2)
                          } else { // +1
-                             break 'tens; // +1
+                             break 'tens; // +1 (+1 label, loop depth = 1)
                          }
                      }
                  }
@@ -1484,10 +1882,10 @@ mod tests {
                     metric.cognitive,
                     @r###"
                     {
-                      "sum": 11.0,
-                      "average": 11.0,
+                      "sum": 13.0,
+                      "average": 13.0,
                       "min": 0.0,
-                      "max": 11.0
+                      "max": 13.0
                     }"###
                 );
             },
@@ -1557,6 +1955,233 @@ mod tests {
         );
     }
 
+    #[test]
+    fn c_nested_switch() {
+        check_func_space::<CppParser, _>(
+            "void flat(int a, int b) {
+                 switch (a) { // +1
+                     case 1:
+                         break;
+                 }
+                 switch (b) { // +1
+                     case 1:
+                         break;
+                 }
+             }
+             void nested(int a, int b) {
+                 switch (a) { // +1
+                     case 1:
+                         switch (b) { // +2 (nesting = 1)
+                             case 1:
+                                 break;
+                         }
+                         break;
+                 }
+             }",
+            "foo.c",
+            |func_space| {
+                let flat = &func_space.spaces[0].metrics.cognitive;
+                let nested = &func_space.spaces[1].metrics.cognitive;
+                assert_eq!(flat.cognitive(), 2.);
+                assert_eq!(nested.cognitive(), 3.);
+            },
+        );
+    }
+
+    #[test]
+    fn python_nested_match() {
+        check_func_space::<PythonParser, _>(
+            "def flat(a, b):
+                 match a:  # +1
+                     case 1:
+                         pass
+                 match b:  # +1
+                     case 1:
+                         pass
+
+             def nested(a, b):
+                 match a:  # +1
+                     case 1:
+                         match b:  # +2 (nesting = 1)
+                             case 1:
+                                 pass",
+            "foo.py",
+            |func_space| {
+                let flat = &func_space.spaces[0].metrics.cognitive;
+                let nested = &func_space.spaces[1].metrics.cognitive;
+                assert_eq!(flat.cognitive(), 2.);
+                assert_eq!(nested.cognitive(), 3.);
+            },
+        );
+    }
+
+    #[test]
+    fn rust_match_nested_if() {
+        check_func_space::<RustParser, _>(
+            "fn flat(a: u8, b: bool) -> u8 {
+                 match a { // +1
+                     0 => 1,
+                     _ => 2,
+                 }
+             }
+             fn nested(a: u8, b: bool) -> u8 {
+                 match a { // +1
+                     0 => if b { 1 } else { 3 }, // +3 (+2 nesting = 1, +1 else)
+                     _ => 2,
+                 }
+             }",
+            "foo.rs",
+            |func_space| {
+                let flat = &func_space.spaces[0].metrics.cognitive;
+                let nested = &func_space.spaces[1].metrics.cognitive;
+                assert_eq!(flat.cognitive(), 1.);
+                assert_eq!(nested.cognitive(), 4.);
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_switch_nested_if() {
+        check_func_space::<JavascriptParser, _>(
+            "function flat(a, b) {
+                 switch (a) { // +1
+                     case 1:
+                         return 1;
+                 }
+                 if (b) { // +1
+                     return 2;
+                 }
+             }
+             function nested(a, b) {
+                 switch (a) { // +1
+                     case 1:
+                         if (b) { // +2 (nesting = 1)
+                             return 2;
+                         }
+                         return 1;
+                 }
+             }",
+            "foo.js",
+            |func_space| {
+                let flat = &func_space.spaces[0].metrics.cognitive;
+                let nested = &func_space.spaces[1].metrics.cognitive;
+                assert_eq!(flat.cognitive(), 2.);
+                assert_eq!(nested.cognitive(), 3.);
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_ternary_chain() {
+        let source = "function single(a) {
+                          return a ? 1 : 2; // +1
+                      }
+                      function chain(a, b, c) {
+                          return a ? 1 // +1
+                              : b ? 2 // +2 (nesting = 1)
+                              : c ? 3 // +3 (nesting = 2)
+                              : 4;
+                      }
+                      function nested(a, b) {
+                          return a ? (b ? 1 : 2) : 3; // +1, +2 (nesting = 1)
+                      }";
+        let check = |func_space: FuncSpace| {
+            let cognitive: Vec<_> = func_space
+                .spaces
+                .iter()
+                .map(|space| space.metrics.cognitive.cognitive())
+                .collect();
+            assert_eq!(cognitive, [1., 6., 3.]);
+        };
+
+        check_func_space::<JavascriptParser, _>(source, "foo.js", check);
+        check_func_space::<TypescriptParser, _>(source, "foo.ts", check);
+    }
+
+    #[test]
+    fn rust_try_operators() {
+        check_func_space::<RustParser, _>(
+            "fn f(path: &str) -> Result<u32, Error> {
+                 let text = read(path)?; // +1
+                 if text.is_empty() { // +1
+                     return Ok(parse(default()?)?); // +2 (no nesting)
+                 }
+                 Ok(parse(&text)?) // +1
+             }",
+            "foo.rs",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics;
+                assert_eq!(f.cognitive.cognitive(), 5.);
+                // Each `?` is a path too, besides the function itself
+                assert_eq!(f.cyclomatic.cyclomatic(), 6.);
+            },
+        );
+    }
+
+    #[test]
+    fn c_loop_jumps() {
+        check_metrics::<CppParser>(
+            "void single(int n) {
+                 for (int i = 0; i < n; ++i) { // +1
+                     if (i % 2) { // +2 (nesting = 1)
+                         continue;
+                     }
+                 }
+             }
+             void nested(int n) {
+                 for (int i = 0; i < n; ++i) { // +1
+                     for (int j = 0; j < n; ++j) { // +2 (nesting = 1)
+                         if (j % 2) { // +3 (nesting = 2)
+                             continue; // +1 (loop depth = 2)
+                         }
+                         switch (j) { // +3 (nesting = 2)
+                         case 1:
+                             break; // Leaves the switch
+                         }
+                     }
+                 }
+             }",
+            "foo.c",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cognitive,
+                    @r###"
+                    {
+                      "sum": 13.0,
+                      "average": 6.5,
+                      "min": 0.0,
+                      "max": 10.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn c_ternary() {
+        check_metrics::<CppParser>(
+            "int flat(int a) {
+                 return a ? 1 : 2; // +1
+             }
+             int nested(int a, int b) {
+                 return a ? (b ? 1 : 2) : 3; // +3 (+1 nesting)
+             }",
+            "foo.c",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cognitive,
+                    @r###"
+                    {
+                      "sum": 4.0,
+                      "average": 2.0,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
     #[test]
     fn mozjs_switch() {
         check_metrics::<MozjsParser>(
@@ -1615,6 +2240,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn python_loop_jumps() {
+        check_metrics::<PythonParser>(
+            "def single(n):
+                 for i in n:  # +1
+                     if i:  # +2 (nesting = 1)
+                         continue
+             def nested(n):
+                 for i in n:  # +1
+                     for j in n:  # +2 (nesting = 1)
+                         if j:  # +3 (nesting = 2)
+                             continue  # +1 (loop depth = 2)
+                         break  # +1 (loop depth = 2)",
+            "foo.py",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cognitive,
+                    @r###"
+                    {
+                      "sum": 11.0,
+                      "average": 5.5,
+                      "min": 0.0,
+                      "max": 8.0
+                    }"###
+                );
+            },
+        );
+    }
+
     #[test]
     fn python_nested_functions_lambdas() {
         check_metrics::<PythonParser>(
@@ -1927,4 +2581,337 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn java_ternary() {
+        check_metrics::<JavaParser>(
+            "class X {
+              int flat(boolean a) {
+                return a ? 1 : 2; // +1
+              }
+              int nested(boolean a, boolean b) {
+                return a ? 1 : b ? 2 : 3; // +3 (+1 nesting)
+              }
+            }",
+            "foo.java",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.cognitive,
+                    @r###"
+                    {
+                      "sum": 4.0,
+                      "average": 2.0,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+    #[test]
+    fn python_elif_ladder() {
+        check_func_space::<PythonParser, _>(
+            "def ladder(a):
+                 if a == 1:  # +1
+                     return 1
+                 elif a == 2:  # +1
+                     return 2
+                 elif a == 3:  # +1
+                     return 3
+                 elif a == 4:  # +1
+                     return 4
+
+             def nested(a):
+                 if a > 0:  # +1
+                     if a > 1:  # +2 (nesting = 1)
+                         if a > 2:  # +3 (nesting = 2)
+                             if a > 3:  # +4 (nesting = 3)
+                                 return 4
+
+             def nested_in_else(a):
+                 if a > 0:  # +1
+                     return 1
+                 else:  # +1
+                     if a < -1:  # +2 (nesting = 1)
+                         return 2",
+            "foo.py",
+            |func_space| {
+                let ladder = &func_space.spaces[0].metrics.cognitive;
+                let nested = &func_space.spaces[1].metrics.cognitive;
+                let nested_in_else = &func_space.spaces[2].metrics.cognitive;
+                assert_eq!(ladder.cognitive(), 4.);
+                assert_eq!(nested.cognitive(), 10.);
+                assert_eq!(nested_in_else.cognitive(), 4.);
+            },
+        );
+    }
+
+    #[test]
+    fn c_else_if_ladder() {
+        check_func_space::<CppParser, _>(
+            "int ladder(int a) {
+                 if (a == 1) { // +1
+                     return 1;
+                 } else if (a == 2) { // +1
+                     return 2;
+                 } else if (a == 3) { // +1
+                     return 3;
+                 } else if (a == 4) { // +1
+                     return 4;
+                 }
+                 return 0;
+             }
+             int nested(int a) {
+                 if (a > 0) { // +1
+                     if (a > 1) { // +2 (nesting = 1)
+                         if (a > 2) { // +3 (nesting = 2)
+                             if (a > 3) { // +4 (nesting = 3)
+                                 return 4;
+                             }
+                         }
+                     }
+                 }
+                 return 0;
+             }
+             int nested_in_else(int a) {
+                 if (a > 0) { // +1
+                     return 1;
+                 } else { // +1
+                     if (a < -1) { // +2 (nesting = 1)
+                         return 2;
+                     }
+                 }
+                 return 0;
+             }",
+            "foo.c",
+            |func_space| {
+                let ladder = &func_space.spaces[0].metrics.cognitive;
+                let nested = &func_space.spaces[1].metrics.cognitive;
+                let nested_in_else = &func_space.spaces[2].metrics.cognitive;
+                assert_eq!(ladder.cognitive(), 4.);
+                assert_eq!(nested.cognitive(), 10.);
+                assert_eq!(nested_in_else.cognitive(), 4.);
+            },
+        );
+    }
+
+    // Only the leading `if` of a chain is increased by its nesting, while
+    // each `else if` and the final `else` cost +1, as a flat structure
+    #[test]
+    fn rust_nested_else_if_chain() {
+        check_func_space::<RustParser, _>(
+            "fn f(v: &[i32]) -> i32 {
+                 let mut r = 0;
+                 for a in v { // +1
+                     if *a == 1 { // +2 (nesting = 1)
+                         r += 1;
+                     } else if *a == 2 { // +1
+                         r += 2;
+                     } else if *a == 3 { // +1
+                         r += 3;
+                     } else if *a == 4 { // +1
+                         r += 4;
+                     } else { // +1
+                         r -= 1;
+                     }
+                 }
+                 r
+             }",
+            "foo.rs",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics;
+                assert_eq!(f.cognitive.cognitive(), 7.);
+                // Each branch is a further path
+                assert_eq!(f.cyclomatic.cyclomatic(), 6.);
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_nested_else_if_chain() {
+        check_func_space::<JavascriptParser, _>(
+            "function f(v) {
+                 let r = 0;
+                 for (const a of v) { // +1
+                     if (a === 1) { // +2 (nesting = 1)
+                         r += 1;
+                     } else if (a === 2) { // +1
+                         r += 2;
+                     } else if (a === 3) { // +1
+                         r += 3;
+                     } else if (a === 4) { // +1
+                         r += 4;
+                     } else { // +1
+                         r -= 1;
+                     }
+                 }
+                 return r;
+             }",
+            "foo.js",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics;
+                assert_eq!(f.cognitive.cognitive(), 7.);
+                assert_eq!(f.cyclomatic.cyclomatic(), 6.);
+            },
+        );
+    }
+
+    #[test]
+    fn tsx_nested_else_if_chain() {
+        check_func_space::<TsxParser, _>(
+            "function f(v: number[]): number {
+                 let r = 0;
+                 for (const a of v) { // +1
+                     if (a === 1) { // +2 (nesting = 1)
+                         r += 1;
+                     } else if (a === 2) { // +1
+                         r += 2;
+                     } else if (a === 3) { // +1
+                         r += 3;
+                     } else if (a === 4) { // +1
+                         r += 4;
+                     } else { // +1
+                         r -= 1;
+                     }
+                 }
+                 return r;
+             }",
+            "foo.tsx",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics;
+                assert_eq!(f.cognitive.cognitive(), 7.);
+                assert_eq!(f.cyclomatic.cyclomatic(), 6.);
+            },
+        );
+    }
+
+    #[test]
+    fn java_nested_else_if_chain() {
+        check_func_space::<JavaParser, _>(
+            "class A {
+                 int f(int[] v) {
+                     int r = 0;
+                     for (int a : v) { // +1
+                         if (a == 1) { // +2 (nesting = 1)
+                             r += 1;
+                         } else if (a == 2) { // +1
+                             r += 2;
+                         } else if (a == 3) { // +1
+                             r += 3;
+                         } else if (a == 4) { // +1
+                             r += 4;
+                         } else { // +1
+                             r -= 1;
+                         }
+                     }
+                     return r;
+                 }
+             }",
+            "foo.java",
+            |func_space| {
+                let f = &func_space.spaces[0].spaces[0].metrics;
+                assert_eq!(f.cognitive.cognitive(), 7.);
+                assert_eq!(f.cyclomatic.cyclomatic(), 6.);
+            },
+        );
+    }
+
+    #[test]
+    fn go_else_if_chain_and_switch() {
+        check_func_space::<GoParser, _>(
+            "func f(v []int) int {
+                 r := 0
+                 for _, a := range v { // +1
+                     if a == 1 { // +2 (nesting = 1)
+                         r += 1
+                     } else if a == 2 { // +1
+                         r += 2
+                     } else { // +1
+                         r -= 1
+                     }
+                 }
+                 switch r { // +1
+                 case 0:
+                     return 0
+                 }
+                 return r
+             }",
+            "foo.go",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics;
+                assert_eq!(f.cognitive.cognitive(), 6.);
+            },
+        );
+    }
+
+    #[test]
+    fn go_labeled_break() {
+        check_func_space::<GoParser, _>(
+            "func f(m [][]int) {
+             outer:
+                 for _, row := range m { // +1
+                     for _, x := range row { // +2 (nesting = 1)
+                         if x < 0 && x > -10 { // +4 (nesting = 2, +1 &&)
+                             break outer // +1 (+1 label, loop depth = 1)
+                         }
+                     }
+                 }
+             }",
+            "foo.go",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics;
+                assert_eq!(f.cognitive.cognitive(), 8.);
+            },
+        );
+    }
+
+    #[test]
+    fn java_labeled_loop_jumps() {
+        check_func_space::<JavaParser, _>(
+            "class A {
+                 void f(int[][] m) {
+                     outer:
+                     for (int[] row : m) { // +1
+                         for (int x : row) { // +2 (nesting = 1)
+                             for (int y : row) { // +3 (nesting = 2)
+                                 if (y < 0) { // +4 (nesting = 3)
+                                     continue outer; // loop depth = 1
+                                 }
+                                 if (y > 0) { // +4 (nesting = 3)
+                                     continue; // +2 (loop depth = 3)
+                                 }
+                             }
+                         }
+                     }
+                 }
+             }",
+            "foo.java",
+            |func_space| {
+                let f = &func_space.spaces[0].spaces[0].metrics;
+                assert_eq!(f.cognitive.cognitive(), 16.);
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_loop_in_switch() {
+        check_func_space::<JavascriptParser, _>(
+            "function f(v, k) {
+                 for (const a of v) { // +1
+                     switch (k) { // +2 (nesting = 1)
+                         case 1:
+                             for (const b of a) { // +3 (nesting = 2)
+                                 break; // +1 (loop depth = 2)
+                             }
+                             break; // Leaves the switch
+                     }
+                 }
+             }",
+            "foo.js",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics;
+                assert_eq!(f.cognitive.cognitive(), 7.);
+            },
+        );
+    }
 }
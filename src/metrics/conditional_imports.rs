@@ -0,0 +1,352 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `ConditionalImports` metric.
+///
+/// This metric counts the imports of a file which depend on the
+/// control flow, so the dependencies of a module cannot be known
+/// by reading its header only:
+///
+/// - the conditional imports, such as an `import` inside an `if` or
+///   a `try` statement in Python, a `require` inside an `if`
+///   statement in JavaScript, or a `using` inside an `#if` in C#
+/// - the dynamic imports, such as `import("x")` in JavaScript,
+///   `importlib.import_module("x")` in Python or
+///   `Assembly.Load("x")` in C#
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    conditional: usize,
+    conditional_sum: usize,
+    dynamic: usize,
+    dynamic_sum: usize,
+    enabled: bool,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("conditional_imports", 3)?;
+        st.serialize_field("conditional", &self.conditional_sum())?;
+        st.serialize_field("dynamic", &self.dynamic_sum())?;
+        st.serialize_field("total", &self.total())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "conditional: {}, dynamic: {}, total: {}",
+            self.conditional_sum(),
+            self.dynamic_sum(),
+            self.total()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `ConditionalImports` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.conditional_sum += other.conditional_sum;
+        self.dynamic_sum += other.dynamic_sum;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the number of conditional imports of a space
+    pub fn conditional(&self) -> f64 {
+        self.conditional as f64
+    }
+
+    /// Returns the sum of the conditional imports
+    pub fn conditional_sum(&self) -> f64 {
+        self.conditional_sum as f64
+    }
+
+    /// Returns the number of dynamic imports of a space
+    pub fn dynamic(&self) -> f64 {
+        self.dynamic as f64
+    }
+
+    /// Returns the sum of the dynamic imports
+    pub fn dynamic_sum(&self) -> f64 {
+        self.dynamic_sum as f64
+    }
+
+    /// Returns the sum of the conditional and the dynamic imports
+    pub fn total(&self) -> f64 {
+        self.conditional_sum() + self.dynamic_sum()
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.conditional_sum += self.conditional;
+        self.dynamic_sum += self.dynamic;
+    }
+
+    // Enables the `ConditionalImports` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `ConditionalImports` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait ConditionalImports
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+// Returns true when a node is contained in one of the `conditions` nodes
+fn is_conditional(node: &Node, conditions: &[u16]) -> bool {
+    let mut parent = node.parent();
+    while let Some(node) = parent {
+        if conditions.contains(&node.kind_id()) {
+            return true;
+        }
+        parent = node.parent();
+    }
+    false
+}
+
+impl ConditionalImports for PythonCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Python::*;
+
+        match node.kind_id().into() {
+            ImportStatement | ImportFromStatement
+                if is_conditional(node, &[IfStatement as u16, TryStatement as u16]) =>
+            {
+                stats.conditional += 1;
+            }
+            Call => {
+                let Some(function) = node.child_by_field_name("function") else {
+                    return;
+                };
+                let name = match function.kind_id().into() {
+                    Attribute => function.child_by_field_name("attribute"),
+                    _ => Some(function),
+                };
+                if name
+                    .and_then(|name| name.utf8_text(code))
+                    .is_some_and(|name| matches!(name, "__import__" | "import_module"))
+                {
+                    stats.dynamic += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+macro_rules! js_conditional_imports {
+    ($lang:ident) => {
+        fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+            use $lang::*;
+
+            if node.kind_id() != CallExpression {
+                return;
+            }
+            let Some(function) = node.child_by_field_name("function") else {
+                return;
+            };
+            match function.kind_id().into() {
+                Import => stats.dynamic += 1,
+                Identifier
+                    if function.utf8_text(code) == Some("require")
+                        && is_conditional(
+                            node,
+                            &[
+                                IfStatement as u16,
+                                SwitchStatement as u16,
+                                TryStatement as u16,
+                                TernaryExpression as u16,
+                            ],
+                        ) =>
+                {
+                    stats.conditional += 1;
+                }
+                _ => {}
+            }
+        }
+    };
+}
+
+impl ConditionalImports for MozjsCode {
+    js_conditional_imports!(Mozjs);
+}
+
+impl ConditionalImports for JavascriptCode {
+    js_conditional_imports!(Javascript);
+}
+
+impl ConditionalImports for TypescriptCode {
+    js_conditional_imports!(Typescript);
+}
+
+impl ConditionalImports for TsxCode {
+    js_conditional_imports!(Tsx);
+}
+
+impl ConditionalImports for CsharpCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Csharp::*;
+
+        match node.kind_id().into() {
+            // The `#elif` and `#else` branches are contained in the `#if`
+            UsingDirective if is_conditional(node, &[PreprocIf as u16]) => {
+                stats.conditional += 1;
+            }
+            InvocationExpression => {
+                let Some(function) = node
+                    .child_by_field_name("function")
+                    .filter(|function| function.kind_id() == MemberAccessExpression)
+                else {
+                    return;
+                };
+                let text = |field| {
+                    function
+                        .child_by_field_name(field)
+                        .and_then(|node| node.utf8_text(code))
+                };
+                if text("expression") == Some("Assembly")
+                    && matches!(text("name"), Some("Load" | "LoadFrom" | "LoadFile"))
+                {
+                    stats.dynamic += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+implement_metric_trait!(
+    ConditionalImports,
+    RustCode,
+    CppCode,
+    JavaCode,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics_with_options;
+
+    use super::*;
+
+    fn conditional_imports_options() -> MetricsOptions {
+        MetricsOptions {
+            conditional_imports: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn python_conditional_imports() {
+        check_metrics_with_options::<PythonParser>(
+            "import os
+             import importlib
+             if os.name == 'nt':
+                 import winreg  # +1 conditional
+             else:
+                 from posix import uname  # +1 conditional
+             try:
+                 import ujson as json  # +1 conditional
+             except ImportError:
+                 import json  # +1 conditional
+             def load(name):
+                 return importlib.import_module(name)  # +1 dynamic",
+            "foo.py",
+            &conditional_imports_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.conditional_imports,
+                    @r###"
+                    {
+                      "conditional": 4.0,
+                      "dynamic": 1.0,
+                      "total": 5.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_conditional_imports() {
+        check_metrics_with_options::<JavascriptParser>(
+            "const fs = require('fs');
+             if (process.env.DEBUG) {
+                 const debug = require('debug'); // +1 conditional
+             }
+             async function load() {
+                 return import('./module.js'); // +1 dynamic
+             }",
+            "foo.js",
+            &conditional_imports_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.conditional_imports,
+                    @r###"
+                    {
+                      "conditional": 1.0,
+                      "dynamic": 1.0,
+                      "total": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn csharp_conditional_imports() {
+        check_metrics_with_options::<CsharpParser>(
+            "using System;
+             using System.Reflection;
+             #if NET8_0
+             using System.Text.Json; // +1 conditional
+             #else
+             using Newtonsoft.Json; // +1 conditional
+             #endif
+             class Loader {
+                 Assembly Load(string path) {
+                     return Assembly.LoadFrom(path); // +1 dynamic
+                 }
+             }",
+            "foo.cs",
+            &conditional_imports_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.conditional_imports,
+                    @r###"
+                    {
+                      "conditional": 2.0,
+                      "dynamic": 1.0,
+                      "total": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -1,5 +1,6 @@
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
 use crate::checker::Checker;
@@ -7,13 +8,29 @@ use crate::macros::implement_metric_trait;
 use crate::*;
 
 /// The `Cyclomatic` metric.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
+    #[serde(with = "crate::cache::float_bits")]
     cyclomatic_sum: f64,
+    #[serde(with = "crate::cache::float_bits")]
     cyclomatic: f64,
     n: usize,
+    #[serde(with = "crate::cache::float_bits")]
     cyclomatic_max: f64,
+    #[serde(with = "crate::cache::float_bits")]
     cyclomatic_min: f64,
+    #[serde(with = "crate::cache::float_bits")]
+    cyclomatic_modified_sum: f64,
+    #[serde(with = "crate::cache::float_bits")]
+    cases: f64,
+    #[serde(with = "crate::cache::float_bits")]
+    switches: f64,
+    // The number of spaces for each `Cyclomatic` value, keyed by the bits
+    // of the value, which are ordered as the non-negative values are
+    counts: BTreeMap<u64, usize>,
+    values: Vec<f64>,
+    collect_values: bool,
 }
 
 impl Default for Stats {
@@ -24,6 +41,12 @@ impl Default for Stats {
             n: 1,
             cyclomatic_max: 0.,
             cyclomatic_min: f64::MAX,
+            cyclomatic_modified_sum: 0.,
+            cases: 0.,
+            switches: 0.,
+            counts: BTreeMap::new(),
+            values: Vec::new(),
+            collect_values: false,
         }
     }
 }
@@ -33,11 +56,19 @@ impl Serialize for Stats {
     where
         S: Serializer,
     {
-        let mut st = serializer.serialize_struct("cyclomatic", 4)?;
+        let len = if self.collect_values { 7 } else { 6 };
+        let mut st = serializer.serialize_struct("cyclomatic", len)?;
         st.serialize_field("sum", &self.cyclomatic_sum())?;
         st.serialize_field("average", &self.cyclomatic_average())?;
         st.serialize_field("min", &self.cyclomatic_min())?;
         st.serialize_field("max", &self.cyclomatic_max())?;
+        st.serialize_field("std_dev", &self.cyclomatic_std_dev())?;
+        st.serialize_field("median", &self.cyclomatic_median())?;
+        if self.collect_values {
+            st.serialize_field("values", &self.values)?;
+        } else {
+            st.skip_field("values")?;
+        }
         st.end()
     }
 }
@@ -46,11 +77,13 @@ impl fmt::Display for Stats {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "sum: {}, average: {}, min: {}, max: {}",
+            "sum: {}, average: {}, min: {}, max: {}, std_dev: {}, median: {}",
             self.cyclomatic_sum(),
             self.cyclomatic_average(),
             self.cyclomatic_min(),
-            self.cyclomatic_max()
+            self.cyclomatic_max(),
+            self.cyclomatic_std_dev(),
+            self.cyclomatic_median()
         )
     }
 }
@@ -63,7 +96,15 @@ impl Stats {
         self.cyclomatic_min = self.cyclomatic_min.min(other.cyclomatic_min);
 
         self.cyclomatic_sum += other.cyclomatic_sum;
+        self.cyclomatic_modified_sum += other.cyclomatic_modified_sum;
         self.n += other.n;
+
+        for (value, count) in &other.counts {
+            *self.counts.entry(*value).or_default() += count;
+        }
+        if self.collect_values {
+            self.values.extend(&other.values);
+        }
     }
 
     /// Returns the `Cyclomatic` metric value
@@ -75,6 +116,30 @@ impl Stats {
         self.cyclomatic_sum
     }
 
+    /// Returns the modified `Cyclomatic` metric value
+    ///
+    /// In the modified complexity, a whole switch, such as a `match`
+    /// expression, is a single decision point rather than one for
+    /// each of its cases.
+    pub fn cyclomatic_modified(&self) -> f64 {
+        self.cyclomatic - self.cases + self.switches
+    }
+    /// Returns the sum of the modified `Cyclomatic` metric values
+    pub fn cyclomatic_modified_sum(&self) -> f64 {
+        self.cyclomatic_modified_sum
+    }
+
+    /// Returns the `Cyclomatic` values of all the spaces contained
+    /// in a space, itself included, such as to compute their percentiles
+    ///
+    /// The values are collected and serialized only when the
+    /// [`MetricsOptions::cyclomatic_values`] option is set.
+    ///
+    /// [`MetricsOptions::cyclomatic_values`]: crate::MetricsOptions::cyclomatic_values
+    pub fn cyclomatic_values(&self) -> &[f64] {
+        &self.values
+    }
+
     /// Returns the `Cyclomatic` metric average value
     ///
     /// This value is computed dividing the `Cyclomatic` value for the
@@ -82,31 +147,121 @@ impl Stats {
     pub fn cyclomatic_average(&self) -> f64 {
         self.cyclomatic_sum() / self.n as f64
     }
+    /// Returns the standard deviation of the `Cyclomatic` values
+    /// of the spaces
+    pub fn cyclomatic_std_dev(&self) -> f64 {
+        let n = self.spaces();
+        if n == 0 {
+            return 0.;
+        }
+        let n = n as f64;
+        let mean = self
+            .counts()
+            .map(|(value, count)| value * count as f64)
+            .sum::<f64>()
+            / n;
+        let variance = self
+            .counts()
+            .map(|(value, count)| (value - mean).powi(2) * count as f64)
+            .sum::<f64>()
+            / n;
+        variance.sqrt()
+    }
+    /// Returns the median of the `Cyclomatic` values of the spaces
+    pub fn cyclomatic_median(&self) -> f64 {
+        let n = self.spaces();
+        if n == 0 {
+            return 0.;
+        }
+        // The value at the given position in the sorted values
+        let nth = |position: usize| {
+            let mut seen = 0;
+            self.counts()
+                .find(|(_, count)| {
+                    seen += count;
+                    position < seen
+                })
+                .map_or(0., |(value, _)| value)
+        };
+        let middle = n / 2;
+        if n.is_multiple_of(2) {
+            (nth(middle - 1) + nth(middle)) / 2.
+        } else {
+            nth(middle)
+        }
+    }
     /// Returns the `Cyclomatic` maximum value
     pub fn cyclomatic_max(&self) -> f64 {
         self.cyclomatic_max
     }
     /// Returns the `Cyclomatic` minimum value
+    ///
+    /// The value is `0` when no space has been computed nor merged.
     pub fn cyclomatic_min(&self) -> f64 {
+        if self.counts.is_empty() {
+            return 0.;
+        }
         self.cyclomatic_min
     }
     #[inline(always)]
     pub(crate) fn compute_sum(&mut self) {
         self.cyclomatic_sum += self.cyclomatic;
+        self.cyclomatic_modified_sum += self.cyclomatic_modified();
+    }
+    #[inline(always)]
+    fn add_cases(&mut self, cases: f64) {
+        self.cyclomatic += cases;
+        self.cases += cases;
+    }
+    #[inline(always)]
+    fn add_switch(&mut self) {
+        self.switches += 1.;
     }
     #[inline(always)]
     pub(crate) fn compute_minmax(&mut self) {
         self.cyclomatic_max = self.cyclomatic_max.max(self.cyclomatic);
         self.cyclomatic_min = self.cyclomatic_min.min(self.cyclomatic);
+        *self.counts.entry(self.cyclomatic.to_bits()).or_default() += 1;
+        if self.collect_values {
+            self.values.push(self.cyclomatic);
+        }
         self.compute_sum();
     }
+    #[inline(always)]
+    pub(crate) fn collect_values(&mut self) {
+        self.collect_values = true;
+    }
+    #[inline(always)]
+    fn spaces(&self) -> usize {
+        self.counts.values().sum()
+    }
+    #[inline(always)]
+    fn counts(&self) -> impl Iterator<Item = (f64, usize)> + '_ {
+        self.counts
+            .iter()
+            .map(|(value, count)| (f64::from_bits(*value), *count))
+    }
 }
 
 pub trait Cyclomatic
 where
     Self: Checker,
 {
+    const IMPLEMENTED: bool = true;
+
     fn compute(node: &Node, stats: &mut Stats);
+
+    /// Counts the suspension points of a generator, such as a Python `yield`,
+    /// as further paths of a function.
+    ///
+    /// This is called only when [`MetricsOptions::count_yields`] is set.
+    ///
+    /// [`MetricsOptions::count_yields`]: crate::MetricsOptions::count_yields
+    fn compute_yield(_node: &Node, _stats: &mut Stats) {}
+
+    /// Counts the decision points identified by a name rather than by
+    /// a node kind, such as the `count` meta-argument of an `HCL` block.
+    fn compute_named(_node: &Node, _code: &[u8], _stats: &mut Stats) {}
 }
 
 impl Cyclomatic for PythonCode {
@@ -114,9 +269,26 @@ impl Cyclomatic for PythonCode {
         use Python::*;
 
         match node.kind_id().into() {
+            // `With` is the keyword, so a `with` statement is counted
+            // once whatever the number of its context managers
             If | Elif | For | While | Except | With | Assert | And | Or => {
                 stats.cyclomatic += 1.;
             }
+            Case => {
+                // The wildcard `case _:` is not counted, as the default
+                // case of a switch, while its guard is counted by `If`
+                let wildcard = node.next_sibling().is_some_and(|pattern| {
+                    pattern.kind_id() == CasePattern
+                        && pattern.child_count() == 1
+                        && pattern.is_child(UNDERSCORE as u16)
+                });
+                if !wildcard {
+                    stats.add_cases(1.);
+                }
+            }
+            MatchStatement => {
+                stats.add_switch();
+            }
             Else => {
                 if node.has_ancestors(
                     |node| matches!(node.kind_id().into(), ForStatement | WhileStatement),
@@ -128,6 +300,13 @@ impl Cyclomatic for PythonCode {
             _ => {}
         }
     }
+
+    fn compute_yield(node: &Node, stats: &mut Stats) {
+        // Both `yield` and `yield from` expressions
+        if matches!(node.kind_id().into(), Python::Yield) {
+            stats.cyclomatic += 1.;
+        }
+    }
 }
 
 impl Cyclomatic for MozjsCode {
@@ -135,9 +314,17 @@ impl Cyclomatic for MozjsCode {
         use Mozjs::*;
 
         match node.kind_id().into() {
-            If | For | While | Case | Catch | TernaryExpression | AMPAMP | PIPEPIPE => {
+            // The optional chaining `?.` is not counted, as it guards
+            // a member access instead of selecting among alternatives
+            If | For | While | Catch | TernaryExpression | AMPAMP | PIPEPIPE | QMARKQMARK => {
                 stats.cyclomatic += 1.;
             }
+            Case => {
+                stats.add_cases(1.);
+            }
+            Switch => {
+                stats.add_switch();
+            }
             _ => {}
         }
     }
@@ -148,9 +335,17 @@ impl Cyclomatic for JavascriptCode {
         use Javascript::*;
 
         match node.kind_id().into() {
-            If | For | While | Case | Catch | TernaryExpression | AMPAMP | PIPEPIPE => {
+            // The optional chaining `?.` is not counted, as it guards
+            // a member access instead of selecting among alternatives
+            If | For | While | Catch | TernaryExpression | AMPAMP | PIPEPIPE | QMARKQMARK => {
                 stats.cyclomatic += 1.;
             }
+            Case => {
+                stats.add_cases(1.);
+            }
+            Switch => {
+                stats.add_switch();
+            }
             _ => {}
         }
     }
@@ -161,9 +356,17 @@ impl Cyclomatic for TypescriptCode {
         use Typescript::*;
 
         match node.kind_id().into() {
-            If | For | While | Case | Catch | TernaryExpression | AMPAMP | PIPEPIPE => {
+            // The optional chaining `?.` is not counted, as it guards
+            // a member access instead of selecting among alternatives
+            If | For | While | Catch | TernaryExpression | AMPAMP | PIPEPIPE | QMARKQMARK => {
                 stats.cyclomatic += 1.;
             }
+            Case => {
+                stats.add_cases(1.);
+            }
+            Switch => {
+                stats.add_switch();
+            }
             _ => {}
         }
     }
@@ -174,9 +377,17 @@ impl Cyclomatic for TsxCode {
         use Tsx::*;
 
         match node.kind_id().into() {
-            If | For | While | Case | Catch | TernaryExpression | AMPAMP | PIPEPIPE => {
+            // The optional chaining `?.` is not counted, as it guards
+            // a member access instead of selecting among alternatives
+            If | For | While | Catch | TernaryExpression | AMPAMP | PIPEPIPE | QMARKQMARK => {
                 stats.cyclomatic += 1.;
             }
+            Case => {
+                stats.add_cases(1.);
+            }
+            Switch => {
+                stats.add_switch();
+            }
             _ => {}
         }
     }
@@ -187,9 +398,15 @@ impl Cyclomatic for RustCode {
         use Rust::*;
 
         match node.kind_id().into() {
-            If | For | While | Loop | MatchArm | MatchArm2 | TryExpression | AMPAMP | PIPEPIPE => {
+            If | For | While | Loop | TryExpression | AMPAMP | PIPEPIPE => {
                 stats.cyclomatic += 1.;
             }
+            MatchArm | MatchArm2 => {
+                stats.add_cases(1.);
+            }
+            Match => {
+                stats.add_switch();
+            }
             _ => {}
         }
     }
@@ -200,9 +417,15 @@ impl Cyclomatic for CppCode {
         use Cpp::*;
 
         match node.kind_id().into() {
-            If | For | While | Case | Catch | ConditionalExpression | AMPAMP | PIPEPIPE => {
+            If | For | While | Catch | ConditionalExpression | AMPAMP | PIPEPIPE => {
                 stats.cyclomatic += 1.;
             }
+            Case => {
+                stats.add_cases(1.);
+            }
+            Switch => {
+                stats.add_switch();
+            }
             _ => {}
         }
     }
@@ -213,19 +436,198 @@ impl Cyclomatic for JavaCode {
         use Java::*;
 
         match node.kind_id().into() {
-            If | For | While | Case | Catch | TernaryExpression | AMPAMP | PIPEPIPE => {
+            If | For | While | Catch | TernaryExpression | AMPAMP | PIPEPIPE => {
+                stats.cyclomatic += 1.;
+            }
+            SwitchLabel => {
+                // Each label of a multi-label case, such as `case A, B ->`,
+                // is a path, as `case A: case B:`, while the default label
+                // is not counted
+                let mut children = node.children();
+                if children.next().is_some_and(|first| first.kind_id() == Case) {
+                    let labels = 1 + children.filter(|child| child.kind_id() == COMMA).count();
+                    stats.add_cases(labels as f64);
+                }
+            }
+            Switch => {
+                stats.add_switch();
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Cyclomatic for KotlinCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Kotlin::*;
+
+        match node.kind_id().into() {
+            // The `while` keyword is shared by `while` and `do-while` loops,
+            // while the `if` keyword is the one of both statements and
+            // expressions, such as the value of an assignment
+            If | For | While | Catch | QMARKCOLON | AMPAMP | PIPEPIPE => {
                 stats.cyclomatic += 1.;
             }
+            WhenEntry => {
+                // Each condition of a `when` branch is a path, as each label
+                // of a switch case, while the `else` branch is not counted
+                // as the default case of a switch
+                let mut children = node.children();
+                if children.next().is_some_and(|first| first.kind_id() != Else) {
+                    let conditions = 1 + children
+                        .take_while(|child| child.kind_id() != DASHGT)
+                        .filter(|child| child.kind_id() == COMMA)
+                        .count();
+                    stats.add_cases(conditions as f64);
+                }
+            }
+            When => {
+                stats.add_switch();
+            }
             _ => {}
         }
     }
 }
 
-implement_metric_trait!(Cyclomatic, KotlinCode, PreprocCode, CcommentCode);
+impl Cyclomatic for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Csharp::*;
+
+        match node.kind_id().into() {
+            // The `while` keyword is shared by `while` and `do-while` loops,
+            // the `??=` assignment is a null-coalescing one, and the `and`
+            // and `or` patterns combine conditions as `&&` and `||`
+            If
+            | For
+            | Foreach
+            | While
+            | Catch
+            | CatchFilterClause
+            | ConditionalExpression
+            | QMARKQMARK
+            | QMARKQMARKEQ
+            | AMPAMP
+            | PIPEPIPE
+            | And
+            | Or => {
+                stats.cyclomatic += 1.;
+            }
+            SwitchSection => {
+                // Each label of a section, such as `case A: case B:`, is a path,
+                // while the default label is not counted
+                let labels = node
+                    .children()
+                    .filter(|child| child.kind_id() == Case)
+                    .count();
+                stats.add_cases(labels as f64);
+            }
+            SwitchExpressionArm => {
+                // The discard arm of a switch expression is its default case,
+                // unless it is guarded by a `when` clause
+                let is_default = node
+                    .child(0)
+                    .is_some_and(|pattern| pattern.kind_id() == Discard)
+                    && node.first_child(|id| id == WhenClause).is_none();
+                if !is_default {
+                    stats.add_cases(1.);
+                }
+            }
+            SwitchStatement | SwitchExpression => {
+                stats.add_switch();
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Cyclomatic for HclCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Hcl::*;
+
+        match node.kind_id().into() {
+            // The `for` expressions and the template directives iterate
+            // or branch, as does the `if` filter of a `for` expression
+            Conditional | ForExpr | ForCond | TemplateIf | TemplateFor | AMPAMP | PIPEPIPE => {
+                stats.cyclomatic += 1.;
+            }
+            _ => {}
+        }
+    }
+
+    fn compute_named(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Hcl::*;
+
+        let is_named = |node: &Node, names: &[&str]| {
+            node.child(0)
+                .and_then(|name| name.utf8_text(code))
+                .is_some_and(|name| names.contains(&name))
+        };
+        let repeated = match node.kind_id().into() {
+            // The `count` and `for_each` meta-arguments repeat a block,
+            // except for the `for_each` of a `dynamic` block, already counted
+            Attribute => {
+                is_named(node, &["count"])
+                    || (is_named(node, &["for_each"])
+                        && !node
+                            .get_parent(2)
+                            .is_some_and(|block| is_named(&block, &["dynamic"])))
+            }
+            // A `dynamic` block is repeated for each element of a collection
+            Block => is_named(node, &["dynamic"]),
+            _ => false,
+        };
+        if repeated {
+            stats.cyclomatic += 1.;
+        }
+    }
+}
+
+impl Cyclomatic for GoCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use crate::languages::Go::*;
+
+        match node.kind_id().into() {
+            // The `for` keyword is shared by all the loops
+            If | For | AMPAMP | PIPEPIPE => {
+                stats.cyclomatic += 1.;
+            }
+            ExpressionCase | TypeCase => {
+                // Each value of a multi-value case, such as `case A, B:`,
+                // is a path, while the default case is not counted
+                let values = node
+                    .children()
+                    .skip(1)
+                    .take_while(|child| child.kind_id() != COLON)
+                    .map(|child| match child.kind_id().into() {
+                        ExpressionList => child
+                            .children()
+                            .filter(|child| child.kind_id() != COMMA)
+                            .count(),
+                        COMMA => 0,
+                        _ => 1,
+                    })
+                    .sum::<usize>();
+                stats.add_cases(values as f64);
+            }
+            CommunicationCase => {
+                stats.add_cases(1.);
+            }
+            // The `switch` keyword is shared by expression and type switches
+            Switch | Select => {
+                stats.add_switch();
+            }
+            _ => {}
+        }
+    }
+}
+
+implement_metric_trait!(Cyclomatic, PreprocCode, CcommentCode);
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::check_metrics;
+    use std::path::PathBuf;
+
+    use crate::tools::{check_func_space, check_metrics};
 
     use super::*;
 
@@ -247,7 +649,9 @@ mod tests {
                       "sum": 6.0,
                       "average": 3.0,
                       "min": 1.0,
-                      "max": 5.0
+                      "max": 5.0,
+                      "std_dev": 2.0,
+                      "median": 3.0
                     }"###
                 );
             },
@@ -271,13 +675,57 @@ mod tests {
                       "sum": 4.0,
                       "average": 2.0,
                       "min": 1.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "std_dev": 1.0,
+                      "median": 2.0
                     }"###
                 );
             },
         );
     }
 
+    #[test]
+    fn python_with_items() {
+        check_metrics::<PythonParser>(
+            "def f(): # +2 (+1 unit space)
+                with open('a') as a:  # +1
+                    pass
+            def g(): # +1
+                with open('a') as a, open('b') as b:  # +1
+                    pass",
+            "foo.py",
+            |metric| {
+                // nspace = 3 (2 functions and unit)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 5.0,
+                      "average": 1.6666666666666667,
+                      "min": 1.0,
+                      "max": 2.0,
+                      "std_dev": 0.4714045207910317,
+                      "median": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_default_parameters() {
+        check_func_space::<PythonParser, _>(
+            "def f(x=compute() if cond else 0, y=a or b): # +3
+                 return x",
+            "foo.py",
+            |func_space| {
+                // The branches of the default values belong to the function
+                assert_eq!(func_space.spaces[0].metrics.cyclomatic.cyclomatic(), 3.);
+                assert_eq!(func_space.metrics.cyclomatic.cyclomatic(), 1.);
+            },
+        );
+    }
+
     #[test]
     fn rust_1_level_nesting() {
         check_metrics::<RustParser>(
@@ -299,13 +747,37 @@ mod tests {
                       "sum": 5.0,
                       "average": 2.5,
                       "min": 1.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "std_dev": 1.5,
+                      "median": 2.5
                     }"###
                 );
             },
         );
     }
 
+    #[test]
+    fn javascript_nullish_coalescing() {
+        fn check<T: ParserTrait>(file: &str) {
+            check_func_space::<T, _>(
+                "function f(a, b) { // +1
+                     const c = a ?? b; // +1
+                     return c?.d ?? a?.e; // +1
+                 }",
+                file,
+                |func_space| {
+                    let f = &func_space.spaces[0].metrics.cyclomatic;
+                    assert_eq!(f.cyclomatic(), 3.);
+                },
+            );
+        }
+
+        check::<MozjsParser>("foo.js");
+        check::<JavascriptParser>("foo.js");
+        check::<TypescriptParser>("foo.ts");
+        check::<TsxParser>("foo.tsx");
+    }
+
     #[test]
     fn c_switch() {
         check_metrics::<CppParser>(
@@ -335,7 +807,9 @@ mod tests {
                       "sum": 5.0,
                       "average": 2.5,
                       "min": 1.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "std_dev": 1.5,
+                      "median": 2.5
                     }"###
                 );
             },
@@ -367,7 +841,9 @@ mod tests {
                       "sum": 5.0,
                       "average": 2.5,
                       "min": 1.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "std_dev": 1.5,
+                      "median": 2.5
                     }"###
                 );
             },
@@ -409,7 +885,9 @@ mod tests {
                       "sum": 7.0,
                       "average": 3.5,
                       "min": 3.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "std_dev": 0.5,
+                      "median": 3.5
                     }"###
                 );
             },
@@ -455,7 +933,9 @@ mod tests {
                       "sum": 7.0,
                       "average": 3.5,
                       "min": 3.0,
-                      "max": 4.0
+                      "max": 4.0,
+                      "std_dev": 0.5,
+                      "median": 3.5
                     }"###
                 );
             },
@@ -493,7 +973,9 @@ mod tests {
                       "sum": 9.0,
                       "average": 2.25,
                       "min": 1.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "std_dev": 0.82915619758885,
+                      "median": 2.5
                     }"###
                 );
             },
@@ -546,7 +1028,9 @@ mod tests {
                       "sum": 11.0,
                       "average": 2.2,
                       "min": 1.0,
-                      "max": 3.0
+                      "max": 3.0,
+                      "std_dev": 0.9797958971132712,
+                      "median": 3.0
                     }"###
                 );
             },
@@ -594,7 +1078,756 @@ mod tests {
                       "sum": 10.0,
                       "average": 1.25,
                       "min": 1.0,
-                      "max": 2.0
+                      "max": 2.0,
+                      "std_dev": 0.4330127018922193,
+                      "median": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_switch_colon_and_arrow() {
+        let colon = "class A {
+                         int f(int x) { // +1
+                             switch (x) { // +0 (+1 modified)
+                                 case 1: // +1
+                                 case 2: // +1
+                                     return 3;
+                                 case 4: // +1
+                                     return 5;
+                                 default:
+                                     return 0;
+                             }
+                         }
+                     }";
+        let arrow = "class A {
+                         int f(int x) { // +1
+                             return switch (x) { // +0 (+1 modified)
+                                 case 1, 2 -> 3; // +2
+                                 case 4 -> 5; // +1
+                                 default -> 0;
+                             };
+                         }
+                     }";
+        for source in [colon, arrow] {
+            check_func_space::<JavaParser, _>(source, "foo.java", |func_space| {
+                let f = &func_space.spaces[0].spaces[0].metrics.cyclomatic;
+                assert_eq!(f.cyclomatic(), 4.);
+                assert_eq!(f.cyclomatic_modified(), 2.);
+            });
+        }
+    }
+
+    #[test]
+    fn java_instanceof_pattern() {
+        check_metrics::<JavaParser>(
+            "
+            class A { // +2 (+1 unit space)
+                public boolean isEmptyString(Object o) { // +1
+                    if (o instanceof String s && s.isEmpty()) { // +2 (+1 &&)
+                        return true;
+                    }
+                    return o instanceof String t && t.isBlank(); // +1
+                }
+            }",
+            "foo.java",
+            |metric| {
+                // The pattern binding does not add any branch by itself:
+                // only the enclosing `if` and the `&&` operators are counted
+                // nspace = 3 (unit, class and 1 method)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 6.0,
+                      "average": 2.0,
+                      "min": 1.0,
+                      "max": 4.0,
+                      "std_dev": 1.4142135623730951,
+                      "median": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_if_let_while_let() {
+        check_metrics::<RustParser>(
+            "fn f(a: Option<i32>, b: Option<i32>, mut it: std::vec::IntoIter<i32>) { // +1
+                 if let Some(x) = a { // +1
+                     println!(\"{}\", x);
+                 } else if let Some(y) = b { // +1
+                     println!(\"{}\", y);
+                 }
+                 if let Some(x) = a && let Some(y) = b { // +2 (+1 &&)
+                     println!(\"{}\", x + y);
+                 }
+                 while let Some(x) = it.next() { // +1
+                     println!(\"{}\", x);
+                 }
+             }",
+            "foo.rs",
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 7.0,
+                      "average": 3.5,
+                      "min": 1.0,
+                      "max": 6.0,
+                      "std_dev": 2.5,
+                      "median": 3.5
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn c_switch_modified() {
+        check_func_space::<CppParser, _>(
+            "void f(int x) { // +1
+                 switch (x) { // +1 modified
+                     case 1: // +1
+                         printf(\"one\");
+                         break;
+                     case 2: // +1
+                         printf(\"two\");
+                         break;
+                     case 3: // +1
+                         printf(\"three\");
+                         break;
+                     default:
+                         printf(\"all\");
+                         break;
+                 }
+             }",
+            "foo.c",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.cyclomatic;
+                assert_eq!(f.cyclomatic(), 4.);
+                assert_eq!(f.cyclomatic_modified(), 2.);
+
+                let unit = &func_space.metrics.cyclomatic;
+                assert_eq!(unit.cyclomatic_sum(), 5.);
+                assert_eq!(unit.cyclomatic_modified_sum(), 3.);
+            },
+        );
+    }
+
+    #[test]
+    fn rust_match_modified() {
+        check_func_space::<RustParser, _>(
+            "fn f(x: Option<i32>) -> i32 { // +1
+                 match x { // +1 modified
+                     Some(0) => 0, // +1
+                     Some(y) => { // +1
+                         if y > 0 { // +1
+                             y
+                         } else {
+                             -y
+                         }
+                     }
+                     None => -1, // +1
+                 }
+             }",
+            "foo.rs",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.cyclomatic;
+                assert_eq!(f.cyclomatic(), 5.);
+                assert_eq!(f.cyclomatic_modified(), 3.);
+            },
+        );
+    }
+
+    #[test]
+    fn kotlin_when() {
+        check_metrics::<KotlinParser>(
+            "fun f(x: Int): Int { // +1 (unit space)
+                 when (x) {
+                     1, 2 -> return 1 // +2
+                     3 -> return 3 // +1
+                     else -> return 0
+                 }
+             }",
+            "foo.kt",
+            |metric| {
+                // nspace = 1 (unit, Kotlin functions are not spaces)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 4.0,
+                      "average": 4.0,
+                      "min": 4.0,
+                      "max": 4.0,
+                      "std_dev": 0.0,
+                      "median": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn kotlin_loops() {
+        check_metrics::<KotlinParser>(
+            "fun f(x: Int, y: Int?) { // +1 (unit space)
+                 for (i in 0..x) { // +1
+                     if (i > 0 && i < 5) { // +2 (+1 &&)
+                         println(y ?: 0) // +1
+                     }
+                 }
+                 while (x > 0) { // +1
+                 }
+                 do {
+                 } while (x > 0 || y == null) // +2 (+1 ||)
+             }",
+            "foo.kt",
+            |metric| {
+                // nspace = 1 (unit, Kotlin functions are not spaces)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 8.0,
+                      "average": 8.0,
+                      "min": 8.0,
+                      "max": 8.0,
+                      "std_dev": 0.0,
+                      "median": 8.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn kotlin_try_catch() {
+        check_metrics::<KotlinParser>(
+            "fun f(s: String): Int { // +1 (unit space)
+                 try {
+                     return s.toInt()
+                 } catch (e: NumberFormatException) { // +1
+                     return -1
+                 } catch (e: Exception) { // +1
+                     return -2
+                 } finally {
+                     println(s)
+                 }
+             }",
+            "foo.kt",
+            |metric| {
+                // nspace = 1 (unit, Kotlin functions are not spaces)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 3.0,
+                      "average": 3.0,
+                      "min": 3.0,
+                      "max": 3.0,
+                      "std_dev": 0.0,
+                      "median": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn kotlin_if_expression() {
+        check_metrics::<KotlinParser>(
+            "fun f(c: Boolean, a: Int, b: Int): Int { // +1 (unit space)
+                 val x = if (c) a else b // +1
+                 return g(if (a > b) a else b) + x // +1
+             }",
+            "foo.kt",
+            |metric| {
+                // nspace = 1 (unit, Kotlin functions are not spaces)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 3.0,
+                      "average": 3.0,
+                      "min": 3.0,
+                      "max": 3.0,
+                      "std_dev": 0.0,
+                      "median": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+    #[test]
+    fn python_match_statement() {
+        check_metrics::<PythonParser>(
+            "def f(command):  # +1
+                 match command:
+                     case 'start':  # +1
+                         return 1
+                     case 'stop' | 'quit':  # +1
+                         return 2
+                     case ['go', direction]:  # +1
+                         return 3
+                     case _:
+                         return 0",
+            "foo.py",
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 5.0,
+                      "average": 2.5,
+                      "min": 1.0,
+                      "max": 4.0,
+                      "std_dev": 1.5,
+                      "median": 2.5
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_generator_yields() {
+        let path = PathBuf::from("foo.py");
+        let source = "def f(a): # +2 (+1 unit space)
+    yield a # +1 when counting yields
+    yield from range(a) # +1 when counting yields";
+        let parser = PythonParser::new(source.as_bytes().to_vec(), &path, None);
+
+        let default = metrics(&parser, &path).unwrap();
+        assert_eq!(default.metrics.cyclomatic.cyclomatic_sum(), 2.);
+
+        let options = MetricsOptions {
+            count_yields: true,
+            ..Default::default()
+        };
+        let with_yields = metrics_with_options(&parser, &path, &options).unwrap();
+        assert_eq!(with_yields.metrics.cyclomatic.cyclomatic_sum(), 4.);
+        assert_eq!(with_yields.spaces[0].metrics.cyclomatic.cyclomatic(), 3.);
+    }
+
+    #[test]
+    fn cyclomatic_values() {
+        let path = PathBuf::from("foo.py");
+        let source = "def f(a): # +1
+    if a: # +1
+        return 1
+    def g(b): # +1
+        return b or 1 # +1
+    return g
+
+def h(): # +1
+    pass";
+        let parser = PythonParser::new(source.as_bytes().to_vec(), &path, None);
+
+        // The values are collected only when asked for
+        let default = metrics(&parser, &path).unwrap();
+        assert!(default.metrics.cyclomatic.cyclomatic_values().is_empty());
+        insta::assert_json_snapshot!(
+            default.metrics.cyclomatic,
+            @r###"
+            {
+              "sum": 6.0,
+              "average": 1.5,
+              "min": 1.0,
+              "max": 2.0,
+              "std_dev": 0.5,
+              "median": 1.5
+            }"###
+        );
+
+        let options = MetricsOptions {
+            cyclomatic_values: true,
+            ..Default::default()
+        };
+        let space = metrics_with_options(&parser, &path, &options).unwrap();
+        let f = &space.spaces[0].metrics.cyclomatic;
+        assert_eq!(f.cyclomatic_values(), [2., 2.]);
+        // The values of the nested spaces precede the one of their space
+        insta::assert_json_snapshot!(
+            space.metrics.cyclomatic,
+            @r###"
+            {
+              "sum": 6.0,
+              "average": 1.5,
+              "min": 1.0,
+              "max": 2.0,
+              "std_dev": 0.5,
+              "median": 1.5,
+              "values": [
+                2.0,
+                2.0,
+                1.0,
+                1.0
+              ]
+            }"###
+        );
+    }
+
+    #[test]
+    fn std_dev_and_median() {
+        check_metrics::<PythonParser>(
+            "def f(a): # 2
+                 if a:
+                     pass
+
+             def g(a): # 3
+                 if a and a:
+                     pass
+
+             def h(a): # 4
+                 if a and a and a:
+                     pass
+
+             def i(a): # 5
+                 if a and a and a and a:
+                     pass",
+            "foo.py",
+            |metric| {
+                // The values are 1 (unit), 2, 3, 4 and 5
+                assert_eq!(metric.cyclomatic.cyclomatic_median(), 3.);
+                assert_eq!(metric.cyclomatic.cyclomatic_std_dev(), 2f64.sqrt());
+            },
+        );
+    }
+
+    #[test]
+    fn comment_only_min() {
+        for source in ["", "// A comment without any code"] {
+            check_metrics::<RustParser>(source, "foo.rs", |metric| {
+                assert_eq!(metric.cyclomatic.cyclomatic_min(), 1.);
+                assert_eq!(metric.cyclomatic.cyclomatic_max(), 1.);
+            });
+        }
+    }
+
+    #[test]
+    fn no_values_min() {
+        let stats = Stats::default();
+        assert_eq!(stats.cyclomatic_min(), 0.);
+        assert_eq!(stats.cyclomatic_max(), 0.);
+    }
+
+    #[test]
+    fn rust_question_mark_in_closure() {
+        check_func_space::<RustParser, _>(
+            "fn f(v: Vec<Option<i32>>) -> Option<i32> { // +1
+                 let g = |x: Option<i32>| -> Option<i32> { Some(x? + 1) }; // +2 (closure)
+                 v.into_iter().map(g).sum::<Option<i32>>()?; // +1 (function)
+                 None
+             }",
+            "foo.rs",
+            |func_space| {
+                let function = &func_space.spaces[0];
+                let closure = &function.spaces[0];
+                assert_eq!(function.metrics.cyclomatic.cyclomatic(), 2.);
+                assert_eq!(closure.metrics.cyclomatic.cyclomatic(), 2.);
+            },
+        );
+    }
+    #[test]
+    fn hcl_conditional() {
+        check_metrics::<HclParser>(
+            "locals {
+                 size = var.large ? 4 : 1 # +1
+                 name = var.prod && var.eu ? \"a\" : \"b\" # +2
+             }",
+            "foo.tf",
+            |metric| {
+                // nspace = 1 (unit, HCL has no functions)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 4.0,
+                      "average": 4.0,
+                      "min": 4.0,
+                      "max": 4.0,
+                      "std_dev": 0.0,
+                      "median": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn hcl_for_each() {
+        check_metrics::<HclParser>(
+            "resource \"aws_s3_bucket\" \"b\" {
+                 for_each = toset([for n in var.names : lower(n) if n != \"\"]) # +3
+             }
+             resource \"aws_instance\" \"i\" {
+                 count = 2 # +1
+             }",
+            "foo.tf",
+            |metric| {
+                // nspace = 1 (unit, HCL has no functions)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 5.0,
+                      "average": 5.0,
+                      "min": 5.0,
+                      "max": 5.0,
+                      "std_dev": 0.0,
+                      "median": 5.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn hcl_dynamic_block() {
+        check_metrics::<HclParser>(
+            "resource \"aws_security_group\" \"g\" {
+                 dynamic \"ingress\" { # +1
+                     for_each = var.ports # Counted by the dynamic block
+                     content {
+                         from_port = ingress.value
+                     }
+                 }
+             }",
+            "foo.tf",
+            |metric| {
+                // nspace = 1 (unit, HCL has no functions)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 2.0,
+                      "min": 2.0,
+                      "max": 2.0,
+                      "std_dev": 0.0,
+                      "median": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn go_conditions_and_loops() {
+        check_metrics::<GoParser>(
+            "func f(a, b int) int { // +2 (+1 unit space)
+                 if a > b && b > 0 { // +2 (+1 &&)
+                     return a
+                 }
+                 for i := 0; i < b || a < 0; i++ { // +2 (+1 ||)
+                 }
+                 return b
+             }",
+            "foo.go",
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 6.0,
+                      "average": 3.0,
+                      "min": 1.0,
+                      "max": 5.0,
+                      "std_dev": 2.0,
+                      "median": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn go_switches_and_select() {
+        check_metrics::<GoParser>(
+            "func f(x interface{}, ch chan int) { // +2 (+1 unit space)
+                 switch x {
+                 case 1, 2: // +2
+                 case 3: // +1
+                 default:
+                 }
+                 switch v := x.(type) {
+                 case int, string: // +2
+                 default:
+                     _ = v
+                 }
+                 select {
+                 case n := <-ch: // +1
+                     _ = n
+                 case ch <- 1: // +1
+                 }
+             }",
+            "foo.go",
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 9.0,
+                      "average": 4.5,
+                      "min": 1.0,
+                      "max": 8.0,
+                      "std_dev": 3.5,
+                      "median": 4.5
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn csharp_switch_expression() {
+        check_metrics::<CsharpParser>(
+            "class A { // +2 (+1 unit space)
+                 string F(int x) { // +1
+                     return x switch {
+                         0 => \"zero\", // +1
+                         1 or 2 => \"small\", // +2 (+1 or)
+                         _ when x > 100 => \"huge\", // +1
+                         _ => \"other\",
+                     };
+                 }
+             }",
+            "foo.cs",
+            |metric| {
+                // nspace = 3 (unit, class and method)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 7.0,
+                      "average": 2.3333333333333335,
+                      "min": 1.0,
+                      "max": 5.0,
+                      "std_dev": 1.8856180831641267,
+                      "median": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn csharp_linq_query() {
+        check_metrics::<CsharpParser>(
+            "class A { // +2 (+1 unit space)
+                 int[] F(int[] v) { // +1
+                     var q = from n in v
+                             where n > 0 && n < 10 // +1
+                             select v.Where(m => m > n || m < 0).Count(); // +1
+                     return v.Select(n => v.Count(m => m == n) > 1 ? n : -n).ToArray(); // +1
+                 }
+             }",
+            "foo.cs",
+            |metric| {
+                // nspace = 3 (unit, class and method)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 6.0,
+                      "average": 2.0,
+                      "min": 1.0,
+                      "max": 4.0,
+                      "std_dev": 1.4142135623730951,
+                      "median": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn csharp_try_catch_filters() {
+        check_metrics::<CsharpParser>(
+            "class A { // +2 (+1 unit space)
+                 void F(string s) { // +1
+                     try {
+                         Parse(s ?? \"\"); // +1
+                     } catch (FormatException e) when (e.Message != null) { // +2 (+1 when)
+                         Log(e);
+                     } catch (ArgumentException) { // +1
+                         throw;
+                     } finally {
+                         Done();
+                     }
+                 }
+             }",
+            "foo.cs",
+            |metric| {
+                // nspace = 3 (unit, class and method)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 7.0,
+                      "average": 2.3333333333333335,
+                      "min": 1.0,
+                      "max": 5.0,
+                      "std_dev": 1.8856180831641267,
+                      "median": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn csharp_loops_and_switch() {
+        check_metrics::<CsharpParser>(
+            "class A { // +2 (+1 unit space)
+                 int F(int[] v, int x) { // +1
+                     foreach (var n in v) { // +1
+                         if (n > x) { // +1
+                             x = n;
+                         } else if (n < 0) { // +1
+                             x--;
+                         }
+                     }
+                     for (int i = 0; i < x; i++) { // +1
+                     }
+                     while (x > 0) { // +1
+                         x /= 2;
+                     }
+                     do {
+                         x++;
+                     } while (x < 3); // +1
+                     switch (x) {
+                         case 1: // +1
+                         case 2: // +1
+                             return 1;
+                         default:
+                             return x;
+                     }
+                 }
+             }",
+            "foo.cs",
+            |metric| {
+                // nspace = 3 (unit, class and method)
+                insta::assert_json_snapshot!(
+                    metric.cyclomatic,
+                    @r###"
+                    {
+                      "sum": 11.0,
+                      "average": 3.6666666666666665,
+                      "min": 1.0,
+                      "max": 9.0,
+                      "std_dev": 3.7712361663282534,
+                      "median": 1.0
                     }"###
                 );
             },
@@ -0,0 +1,433 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The category of a decision point of a function/method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    /// A conditional branch, such as an `if` or a ternary expression
+    Branch,
+    /// A loop, such as a `for` or a `while`
+    Loop,
+    /// A multi-way branch, such as a `switch` or a `match`
+    Switch,
+    /// An exception handler, such as a `catch` or an `except` clause
+    Catch,
+}
+
+/// The `Decisions` metric.
+///
+/// This metric counts the decision points of a function/method
+/// by [`Decision`] category, and exposes the ratio of its branches to
+/// its loops, which characterizes a function as branch-heavy or
+/// loop-heavy, and the number of distinct categories it uses.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    branches: usize,
+    branches_sum: usize,
+    loops: usize,
+    loops_sum: usize,
+    switches: usize,
+    switches_sum: usize,
+    catches: usize,
+    catches_sum: usize,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("decisions", 6)?;
+        st.serialize_field("branches", &self.branches_sum())?;
+        st.serialize_field("loops", &self.loops_sum())?;
+        st.serialize_field("switches", &self.switches_sum())?;
+        st.serialize_field("catches", &self.catches_sum())?;
+        st.serialize_field("ratio", &self.branches_loops_ratio())?;
+        st.serialize_field("distinct", &self.distinct())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "branches: {}, loops: {}, switches: {}, catches: {}, ratio: {}, distinct: {}",
+            self.branches_sum(),
+            self.loops_sum(),
+            self.switches_sum(),
+            self.catches_sum(),
+            self.branches_loops_ratio(),
+            self.distinct()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Decisions` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.branches_sum += other.branches_sum;
+        self.loops_sum += other.loops_sum;
+        self.switches_sum += other.switches_sum;
+        self.catches_sum += other.catches_sum;
+    }
+
+    /// Returns the number of branches of a space
+    pub fn branches(&self) -> f64 {
+        self.branches as f64
+    }
+
+    /// Returns the sum of the branches
+    pub fn branches_sum(&self) -> f64 {
+        self.branches_sum as f64
+    }
+
+    /// Returns the number of loops of a space
+    pub fn loops(&self) -> f64 {
+        self.loops as f64
+    }
+
+    /// Returns the sum of the loops
+    pub fn loops_sum(&self) -> f64 {
+        self.loops_sum as f64
+    }
+
+    /// Returns the number of switches of a space
+    pub fn switches(&self) -> f64 {
+        self.switches as f64
+    }
+
+    /// Returns the sum of the switches
+    pub fn switches_sum(&self) -> f64 {
+        self.switches_sum as f64
+    }
+
+    /// Returns the number of exception handlers of a space
+    pub fn catches(&self) -> f64 {
+        self.catches as f64
+    }
+
+    /// Returns the sum of the exception handlers
+    pub fn catches_sum(&self) -> f64 {
+        self.catches_sum as f64
+    }
+
+    /// Returns the ratio of the branches to the loops of a space,
+    /// its nested spaces excluded
+    ///
+    /// When there are no loops, the ratio is the number of branches.
+    pub fn branches_loops_ratio(&self) -> f64 {
+        self.branches() / self.loops.max(1) as f64
+    }
+
+    /// Returns the number of distinct [`Decision`] categories used
+    /// by a space, its nested spaces included
+    ///
+    /// A function using all the categories, such as a branch, a loop,
+    /// a switch and an exception handler, is doing a lot.
+    pub fn distinct(&self) -> f64 {
+        [
+            self.branches_sum,
+            self.loops_sum,
+            self.switches_sum,
+            self.catches_sum,
+        ]
+        .iter()
+        .filter(|&&count| count > 0)
+        .count() as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn add(&mut self, decision: Decision) {
+        match decision {
+            Decision::Branch => self.branches += 1,
+            Decision::Loop => self.loops += 1,
+            Decision::Switch => self.switches += 1,
+            Decision::Catch => self.catches += 1,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.branches_sum += self.branches;
+        self.loops_sum += self.loops;
+        self.switches_sum += self.switches;
+        self.catches_sum += self.catches;
+    }
+}
+
+pub trait Decisions
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+impl Decisions for PythonCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Python::*;
+
+        let decision = match node.kind_id().into() {
+            IfStatement | ElifClause | ConditionalExpression | IfClause => Decision::Branch,
+            ForStatement | WhileStatement | ForInClause => Decision::Loop,
+            MatchStatement => Decision::Switch,
+            ExceptClause | ExceptGroupClause => Decision::Catch,
+            _ => return,
+        };
+        stats.add(decision);
+    }
+}
+
+macro_rules! js_decisions {
+    ($lang:ident) => {
+        fn compute(node: &Node, stats: &mut Stats) {
+            use $lang::*;
+
+            let decision = match node.kind_id().into() {
+                IfStatement | TernaryExpression => Decision::Branch,
+                ForStatement | ForInStatement | WhileStatement | DoStatement => Decision::Loop,
+                SwitchStatement => Decision::Switch,
+                CatchClause => Decision::Catch,
+                _ => return,
+            };
+            stats.add(decision);
+        }
+    };
+}
+
+impl Decisions for MozjsCode {
+    js_decisions!(Mozjs);
+}
+
+impl Decisions for JavascriptCode {
+    js_decisions!(Javascript);
+}
+
+impl Decisions for TypescriptCode {
+    js_decisions!(Typescript);
+}
+
+impl Decisions for TsxCode {
+    js_decisions!(Tsx);
+}
+
+impl Decisions for RustCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Rust::*;
+
+        let decision = match node.kind_id().into() {
+            IfExpression => Decision::Branch,
+            ForExpression | WhileExpression | LoopExpression => Decision::Loop,
+            MatchExpression => Decision::Switch,
+            _ => return,
+        };
+        stats.add(decision);
+    }
+}
+
+impl Decisions for CppCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Cpp::*;
+
+        let decision = match node.kind_id().into() {
+            IfStatement | ConditionalExpression => Decision::Branch,
+            ForStatement | ForRangeLoop | WhileStatement | DoStatement => Decision::Loop,
+            SwitchStatement => Decision::Switch,
+            CatchClause => Decision::Catch,
+            _ => return,
+        };
+        stats.add(decision);
+    }
+}
+
+impl Decisions for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Java::*;
+
+        let decision = match node.kind_id().into() {
+            IfStatement | TernaryExpression => Decision::Branch,
+            ForStatement | EnhancedForStatement | WhileStatement | DoStatement => Decision::Loop,
+            SwitchExpression => Decision::Switch,
+            CatchClause => Decision::Catch,
+            _ => return,
+        };
+        stats.add(decision);
+    }
+}
+
+impl Decisions for KotlinCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Kotlin::*;
+
+        let decision = match node.kind_id().into() {
+            IfExpression => Decision::Branch,
+            ForStatement | WhileStatement | DoWhileStatement => Decision::Loop,
+            WhenExpression => Decision::Switch,
+            CatchBlock => Decision::Catch,
+            _ => return,
+        };
+        stats.add(decision);
+    }
+}
+
+impl Decisions for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Csharp::*;
+
+        let decision = match node.kind_id().into() {
+            IfStatement | ConditionalExpression => Decision::Branch,
+            ForStatement | ForeachStatement | WhileStatement | DoStatement => Decision::Loop,
+            SwitchStatement | SwitchExpression => Decision::Switch,
+            CatchClause => Decision::Catch,
+            _ => return,
+        };
+        stats.add(decision);
+    }
+}
+
+implement_metric_trait!(Decisions, HclCode, PreprocCode, CcommentCode, GoCode);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::{check_func_space, check_metrics};
+
+    use super::*;
+
+    #[test]
+    fn python_decisions() {
+        check_metrics::<PythonParser>(
+            "def f(a, b):
+                 for x in a:  # +1 loop
+                     if x:  # +1 branch
+                         pass
+                     elif b:  # +1 branch
+                         pass
+                 while b:  # +1 loop
+                     b = b - 1 if b else 0  # +1 branch
+                 if a:  # +1 branch
+                     pass",
+            "foo.py",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.decisions,
+                    @r###"
+                    {
+                      "branches": 4.0,
+                      "loops": 2.0,
+                      "switches": 0.0,
+                      "catches": 0.0,
+                      "ratio": 0.0,
+                      "distinct": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_decisions() {
+        check_func_space::<JavascriptParser, _>(
+            "function f(a) {
+                 try {
+                     switch (a) { // +1 switch
+                         case 1:
+                             return a ? 1 : 2; // +1 branch
+                     }
+                 } catch (e) { // +1 catch
+                 }
+                 return 0;
+             }",
+            "foo.js",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.decisions;
+                assert_eq!(f.branches(), 1.);
+                assert_eq!(f.loops(), 0.);
+                assert_eq!(f.switches(), 1.);
+                assert_eq!(f.catches(), 1.);
+                // Without loops, the ratio is the number of branches
+                assert_eq!(f.branches_loops_ratio(), 1.);
+            },
+        );
+    }
+
+    #[test]
+    fn rust_decisions() {
+        check_func_space::<RustParser, _>(
+            "fn f(v: &[u32]) {
+                 for x in v { // +1 loop
+                     if *x > 0 { // +1 branch
+                     } else if let 1 = x { // +1 branch
+                     }
+                 }
+                 loop { // +1 loop
+                     match v.len() { // +1 switch
+                         0 => break,
+                         _ => {}
+                     }
+                 }
+             }",
+            "foo.rs",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.decisions;
+                assert_eq!(f.branches(), 2.);
+                assert_eq!(f.loops(), 2.);
+                assert_eq!(f.switches(), 1.);
+                assert_eq!(f.branches_loops_ratio(), 1.);
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_nested_ratio() {
+        check_func_space::<JavascriptParser, _>(
+            "function f(a) {
+                 for (const x of a) { // +1 loop
+                     a.forEach(function (y) {
+                         if (x) {} // +1 branch
+                         if (y) {} // +1 branch
+                     });
+                 }
+             }",
+            "foo.js",
+            |func_space| {
+                let f = &func_space.spaces[0];
+                let g = &f.spaces[0];
+                // The branches of the nested function are not counted
+                // in the ratio of the outer one, but they are summed
+                assert_eq!(f.metrics.decisions.branches_loops_ratio(), 0.);
+                assert_eq!(f.metrics.decisions.branches_sum(), 2.);
+                assert_eq!(g.metrics.decisions.branches_loops_ratio(), 2.);
+            },
+        );
+    }
+
+    #[test]
+    fn python_distinct_decisions() {
+        check_func_space::<PythonParser, _>(
+            "def f(a):
+                 if a:  # +1 branch
+                     pass
+                 for x in a:  # +1 loop
+                     if x:
+                         pass
+                 try:
+                     pass
+                 except ValueError:  # +1 catch
+                     pass",
+            "foo.py",
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.decisions;
+                assert_eq!(f.branches(), 2.);
+                assert_eq!(f.distinct(), 3.);
+            },
+        );
+    }
+}
@@ -0,0 +1,384 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `EarlyReturns` metric.
+///
+/// This metric counts the number of early returns of a function/method,
+/// that is the return statements placed before the last statement of
+/// its body, as in guard clauses.
+///
+/// A return statement which is or is contained in the last statement
+/// of a body is not counted.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    early_returns: usize,
+    early_returns_sum: usize,
+    early_returns_min: usize,
+    early_returns_max: usize,
+    space_count: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            early_returns: 0,
+            early_returns_sum: 0,
+            early_returns_min: usize::MAX,
+            early_returns_max: 0,
+            space_count: 1,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("early_returns", 4)?;
+        st.serialize_field("sum", &self.early_returns_sum())?;
+        st.serialize_field("average", &self.early_returns_average())?;
+        st.serialize_field("min", &self.early_returns_min())?;
+        st.serialize_field("max", &self.early_returns_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}",
+            self.early_returns_sum(),
+            self.early_returns_average(),
+            self.early_returns_min(),
+            self.early_returns_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `EarlyReturns` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.early_returns_max = self.early_returns_max.max(other.early_returns_max);
+        self.early_returns_min = self.early_returns_min.min(other.early_returns_min);
+        self.early_returns_sum += other.early_returns_sum;
+        self.space_count += other.space_count;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `EarlyReturns` metric value of a space
+    pub fn early_returns(&self) -> f64 {
+        self.early_returns as f64
+    }
+
+    /// Returns the `EarlyReturns` metric sum value
+    pub fn early_returns_sum(&self) -> f64 {
+        self.early_returns_sum as f64
+    }
+
+    /// Returns the `EarlyReturns` metric average value
+    ///
+    /// This value is computed dividing the sum of the `EarlyReturns`
+    /// values for the number of spaces.
+    pub fn early_returns_average(&self) -> f64 {
+        self.early_returns_sum() / self.space_count as f64
+    }
+
+    /// Returns the `EarlyReturns` metric minimum value
+    pub fn early_returns_min(&self) -> f64 {
+        self.early_returns_min as f64
+    }
+
+    /// Returns the `EarlyReturns` metric maximum value
+    pub fn early_returns_max(&self) -> f64 {
+        self.early_returns_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.early_returns_sum += self.early_returns;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.early_returns_max = self.early_returns_max.max(self.early_returns);
+        self.early_returns_min = self.early_returns_min.min(self.early_returns);
+        self.compute_sum();
+    }
+
+    // Enables the `EarlyReturns` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `EarlyReturns` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+/// The body of the function/closure containing a node,
+/// which its children receive.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Body {
+    // The start of the last statement of the body
+    last_statement: Option<usize>,
+}
+
+pub trait EarlyReturns
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    /// Computes the metric on a node.
+    ///
+    /// `body` contains the body of the function/closure containing
+    /// the parent of the node, and it is updated to the one containing
+    /// the node itself, which its children receive.
+    fn compute(node: &Node, stats: &mut Stats, body: &mut Body);
+}
+
+// Counts a return statement when it ends before the last statement
+// of the body of the function/closure containing it.
+#[inline(always)]
+fn compute_early_return<T: Checker>(
+    node: &Node,
+    stats: &mut Stats,
+    body: &mut Body,
+    is_return: bool,
+) {
+    if T::is_func(node) || T::is_closure(node) {
+        let last = node.child_by_field_name("body").and_then(|body| {
+            body.children()
+                .filter(|child| child.is_named() && !T::is_comment(child))
+                .last()
+        });
+        body.last_statement = last.map(|last| last.start_byte());
+    } else if is_return
+        && body
+            .last_statement
+            .is_some_and(|last| node.end_byte() <= last)
+    {
+        stats.early_returns += 1;
+    }
+}
+
+impl EarlyReturns for PythonCode {
+    fn compute(node: &Node, stats: &mut Stats, body: &mut Body) {
+        let is_return = matches!(node.kind_id().into(), Python::ReturnStatement);
+        compute_early_return::<Self>(node, stats, body, is_return);
+    }
+}
+
+impl EarlyReturns for MozjsCode {
+    fn compute(node: &Node, stats: &mut Stats, body: &mut Body) {
+        let is_return = matches!(node.kind_id().into(), Mozjs::ReturnStatement);
+        compute_early_return::<Self>(node, stats, body, is_return);
+    }
+}
+
+impl EarlyReturns for JavascriptCode {
+    fn compute(node: &Node, stats: &mut Stats, body: &mut Body) {
+        let is_return = matches!(node.kind_id().into(), Javascript::ReturnStatement);
+        compute_early_return::<Self>(node, stats, body, is_return);
+    }
+}
+
+impl EarlyReturns for TypescriptCode {
+    fn compute(node: &Node, stats: &mut Stats, body: &mut Body) {
+        let is_return = matches!(node.kind_id().into(), Typescript::ReturnStatement);
+        compute_early_return::<Self>(node, stats, body, is_return);
+    }
+}
+
+impl EarlyReturns for TsxCode {
+    fn compute(node: &Node, stats: &mut Stats, body: &mut Body) {
+        let is_return = matches!(node.kind_id().into(), Tsx::ReturnStatement);
+        compute_early_return::<Self>(node, stats, body, is_return);
+    }
+}
+
+impl EarlyReturns for RustCode {
+    fn compute(node: &Node, stats: &mut Stats, body: &mut Body) {
+        let is_return = matches!(node.kind_id().into(), Rust::ReturnExpression);
+        compute_early_return::<Self>(node, stats, body, is_return);
+    }
+}
+
+impl EarlyReturns for CppCode {
+    fn compute(node: &Node, stats: &mut Stats, body: &mut Body) {
+        let is_return = matches!(node.kind_id().into(), Cpp::ReturnStatement);
+        compute_early_return::<Self>(node, stats, body, is_return);
+    }
+}
+
+impl EarlyReturns for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats, body: &mut Body) {
+        let is_return = matches!(node.kind_id().into(), Java::ReturnStatement);
+        compute_early_return::<Self>(node, stats, body, is_return);
+    }
+}
+
+impl EarlyReturns for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats, body: &mut Body) {
+        let is_return = matches!(node.kind_id().into(), Csharp::ReturnStatement);
+        compute_early_return::<Self>(node, stats, body, is_return);
+    }
+}
+
+implement_metric_trait!(
+    EarlyReturns,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics_with_options;
+
+    use super::*;
+
+    fn early_returns_options() -> MetricsOptions {
+        MetricsOptions {
+            early_returns: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn python_guard_clauses() {
+        check_metrics_with_options::<PythonParser>(
+            "def f(a, b):
+                 if a is None:
+                     return 0 # +1
+                 if b is None:
+                     return 1 # +1
+                 c = a + b
+                 return c",
+            "foo.py",
+            &early_returns_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.early_returns,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_guard_clauses() {
+        check_metrics_with_options::<RustParser>(
+            "fn f(a: Option<i32>, b: Option<i32>) -> i32 {
+                 let Some(a) = a else {
+                     return 0; // +1
+                 };
+                 if b.is_none() {
+                     return a; // +1
+                 }
+                 // A final return
+                 return a + b.unwrap();
+             }",
+            "foo.rs",
+            &early_returns_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.early_returns,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_closure_returns() {
+        check_metrics_with_options::<RustParser>(
+            "fn f(v: &[i32]) -> i32 {
+                 let g = |a: i32| {
+                     if a < 0 {
+                         return 0; // +1
+                     }
+                     a
+                 };
+                 if v.is_empty() {
+                     return 0; // +1
+                 }
+                 return g(v[0]);
+             }",
+            "foo.rs",
+            &early_returns_options(),
+            |metric| {
+                // nspace = 3 (unit, function and closure)
+                insta::assert_json_snapshot!(
+                    metric.early_returns,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 0.6666666666666666,
+                      "min": 0.0,
+                      "max": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_returns_in_last_statement() {
+        check_metrics_with_options::<JavaParser>(
+            "class A {
+                int f(int a) {
+                    if (a > 0) {
+                        return 1;
+                    } else {
+                        return 2;
+                    }
+                }
+            }",
+            "foo.java",
+            &early_returns_options(),
+            |metric| {
+                // nspace = 3 (unit, class and method)
+                insta::assert_json_snapshot!(
+                    metric.early_returns,
+                    @r###"
+                    {
+                      "sum": 0.0,
+                      "average": 0.0,
+                      "min": 0.0,
+                      "max": 0.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `EnumVariants` metric.
+///
+/// This metric counts the variants of the enums defined in a space,
+/// and the ones among them carrying data, such as `B(u8)` or `C { x: u8 }`,
+/// since every data-carrying variant has to be destructured by the code
+/// matching the enum.
+///
+/// The largest number of variants of a single enum is reported too.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    enums: usize,
+    enums_sum: usize,
+    variants: usize,
+    variants_sum: usize,
+    data_variants: usize,
+    data_variants_sum: usize,
+    variants_max: usize,
+    enabled: bool,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("enum_variants", 4)?;
+        st.serialize_field("enums", &self.enums_sum())?;
+        st.serialize_field("variants", &self.variants_sum())?;
+        st.serialize_field("data", &self.data_variants_sum())?;
+        st.serialize_field("max", &self.variants_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "enums: {}, variants: {}, data: {}, max: {}",
+            self.enums_sum(),
+            self.variants_sum(),
+            self.data_variants_sum(),
+            self.variants_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `EnumVariants` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.enums_sum += other.enums_sum;
+        self.variants_sum += other.variants_sum;
+        self.data_variants_sum += other.data_variants_sum;
+        self.variants_max = self.variants_max.max(other.variants_max);
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the number of enums defined in a space
+    pub fn enums(&self) -> f64 {
+        self.enums as f64
+    }
+
+    /// Returns the sum of the enums
+    pub fn enums_sum(&self) -> f64 {
+        self.enums_sum as f64
+    }
+
+    /// Returns the number of variants of the enums defined in a space
+    pub fn variants(&self) -> f64 {
+        self.variants as f64
+    }
+
+    /// Returns the sum of the variants
+    pub fn variants_sum(&self) -> f64 {
+        self.variants_sum as f64
+    }
+
+    /// Returns the number of variants carrying data of the enums
+    /// defined in a space
+    pub fn data_variants(&self) -> f64 {
+        self.data_variants as f64
+    }
+
+    /// Returns the sum of the variants carrying data
+    pub fn data_variants_sum(&self) -> f64 {
+        self.data_variants_sum as f64
+    }
+
+    /// Returns the largest number of variants of a single enum
+    pub fn variants_max(&self) -> f64 {
+        self.variants_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.enums_sum += self.enums;
+        self.variants_sum += self.variants;
+        self.data_variants_sum += self.data_variants;
+    }
+
+    // Enables the `EnumVariants` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `EnumVariants` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait EnumVariants
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+impl EnumVariants for RustCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if node.kind_id() != Rust::EnumVariantList {
+            return;
+        }
+
+        let mut variants = 0;
+        for variant in node.children() {
+            if variant.kind_id() != Rust::EnumVariant {
+                continue;
+            }
+            variants += 1;
+            // A tuple or a struct variant, while `A = 1` is a discriminant
+            if variant.child_by_field_name("body").is_some() {
+                stats.data_variants += 1;
+            }
+        }
+        stats.enums += 1;
+        stats.variants += variants;
+        stats.variants_max = stats.variants_max.max(variants);
+    }
+}
+
+impl EnumVariants for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if node.kind_id() != Csharp::EnumMemberDeclarationList {
+            return;
+        }
+
+        // The members of an enum carry no data, but they can be
+        // declared in `#if` directives, so they are searched deeper
+        let mut variants = 0;
+        node.act_on_node(&mut |node| {
+            if node.kind_id() == Csharp::EnumMemberDeclaration {
+                variants += 1;
+            }
+        });
+        stats.enums += 1;
+        stats.variants += variants;
+        stats.variants_max = stats.variants_max.max(variants);
+    }
+}
+
+implement_metric_trait!(
+    EnumVariants,
+    PythonCode,
+    MozjsCode,
+    JavascriptCode,
+    TypescriptCode,
+    TsxCode,
+    CppCode,
+    JavaCode,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics_with_options;
+
+    use super::*;
+
+    fn enum_variants_options() -> MetricsOptions {
+        MetricsOptions {
+            enum_variants: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rust_enum_variants() {
+        check_metrics_with_options::<RustParser>(
+            "enum Shape {
+                 Empty,
+                 Circle(f64), // data
+                 Rect { w: f64, h: f64 }, // data
+                 Point(i32, i32), // data
+                 Unknown = 10,
+             }
+             fn f() {
+                 enum Local { A, B(u8) }
+             }",
+            "foo.rs",
+            &enum_variants_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.enum_variants,
+                    @r###"
+                    {
+                      "enums": 2.0,
+                      "variants": 7.0,
+                      "data": 4.0,
+                      "max": 5.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn csharp_enum_variants() {
+        check_metrics_with_options::<CsharpParser>(
+            "enum Color {
+                 Red,
+                 Green = 2,
+                 Blue,
+             #if EXTENDED
+                 Purple
+             #endif
+             }
+             class C {
+                 enum State { On, Off }
+             }",
+            "foo.cs",
+            &enum_variants_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.enum_variants,
+                    @r###"
+                    {
+                      "enums": 2.0,
+                      "variants": 6.0,
+                      "data": 0.0,
+                      "max": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `ExceptionTypes` metric.
+///
+/// This metric counts the number of distinct exception types handled
+/// by the `catch`/`except` clauses of a function/method.
+///
+/// The types are compared by their text, so the same type written
+/// with and without its qualification is counted twice. The clauses
+/// catching any exception, such as `catch (...)` in C++ or a bare
+/// `except` in Python, are not counted.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    types: HashSet<String>,
+    exception_types_sum: usize,
+    exception_types_min: usize,
+    exception_types_max: usize,
+    space_count: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            types: HashSet::new(),
+            exception_types_sum: 0,
+            exception_types_min: usize::MAX,
+            exception_types_max: 0,
+            space_count: 1,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("exception_types", 4)?;
+        st.serialize_field("sum", &self.exception_types_sum())?;
+        st.serialize_field("average", &self.exception_types_average())?;
+        st.serialize_field("min", &self.exception_types_min())?;
+        st.serialize_field("max", &self.exception_types_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}",
+            self.exception_types_sum(),
+            self.exception_types_average(),
+            self.exception_types_min(),
+            self.exception_types_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `ExceptionTypes` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.exception_types_max = self.exception_types_max.max(other.exception_types_max);
+        self.exception_types_min = self.exception_types_min.min(other.exception_types_min);
+        self.exception_types_sum += other.exception_types_sum;
+        self.space_count += other.space_count;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `ExceptionTypes` metric value of a space
+    pub fn exception_types(&self) -> f64 {
+        self.types.len() as f64
+    }
+
+    /// Returns the `ExceptionTypes` metric sum value
+    pub fn exception_types_sum(&self) -> f64 {
+        self.exception_types_sum as f64
+    }
+
+    /// Returns the `ExceptionTypes` metric average value
+    ///
+    /// This value is computed dividing the sum of the `ExceptionTypes`
+    /// values for the number of spaces.
+    pub fn exception_types_average(&self) -> f64 {
+        self.exception_types_sum() / self.space_count as f64
+    }
+
+    /// Returns the `ExceptionTypes` metric minimum value
+    pub fn exception_types_min(&self) -> f64 {
+        self.exception_types_min as f64
+    }
+
+    /// Returns the `ExceptionTypes` metric maximum value
+    pub fn exception_types_max(&self) -> f64 {
+        self.exception_types_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.exception_types_sum += self.types.len();
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.exception_types_max = self.exception_types_max.max(self.types.len());
+        self.exception_types_min = self.exception_types_min.min(self.types.len());
+        self.compute_sum();
+    }
+
+    // Enables the `ExceptionTypes` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `ExceptionTypes` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait ExceptionTypes
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+#[inline(always)]
+fn insert_type(node: &Node, code: &[u8], stats: &mut Stats) {
+    if let Some(name) = node.utf8_text(code) {
+        stats
+            .types
+            .insert(name.split_whitespace().collect::<Vec<_>>().join(" "));
+    }
+}
+
+impl ExceptionTypes for PythonCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        if node.kind_id() != Python::ExceptClause {
+            return;
+        }
+        // except, [value [as alias]], :, block
+        let Some(value) = node
+            .child(1)
+            .filter(|value| value.kind_id() != Python::COLON)
+        else {
+            return;
+        };
+        let value = if value.kind_id() == Python::AsPattern {
+            match value.child(0) {
+                Some(value) => value,
+                None => return,
+            }
+        } else {
+            value
+        };
+
+        if value.kind_id() == Python::Tuple {
+            for typ in value.children().filter(|typ| typ.is_named()) {
+                insert_type(&typ, code, stats);
+            }
+        } else {
+            insert_type(&value, code, stats);
+        }
+    }
+}
+
+impl ExceptionTypes for CppCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        if node.kind_id() != Cpp::CatchClause {
+            return;
+        }
+        let Some(parameters) = node.child_by_field_name("parameters") else {
+            return;
+        };
+        for parameter in parameters.children() {
+            if parameter.kind_id() != Cpp::ParameterDeclaration {
+                continue;
+            }
+            if let Some(typ) = parameter.child_by_field_name("type") {
+                insert_type(&typ, code, stats);
+            }
+        }
+    }
+}
+
+impl ExceptionTypes for JavaCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        // The alternatives of a multi-catch are the children of its type
+        if node.kind_id() == Java::CatchType {
+            for typ in node.children().filter(|typ| typ.is_named()) {
+                insert_type(&typ, code, stats);
+            }
+        }
+    }
+}
+
+impl ExceptionTypes for CsharpCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        // A general `catch` clause has no declaration
+        if node.kind_id() == Csharp::CatchDeclaration
+            && let Some(typ) = node.child_by_field_name("type")
+        {
+            insert_type(&typ, code, stats);
+        }
+    }
+}
+
+implement_metric_trait!(
+    ExceptionTypes,
+    MozjsCode,
+    JavascriptCode,
+    TypescriptCode,
+    TsxCode,
+    RustCode,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::{check_func_space_with_options, check_metrics_with_options};
+
+    use super::*;
+
+    fn exception_types_options() -> MetricsOptions {
+        MetricsOptions {
+            exception_types: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn java_exception_types() {
+        check_func_space_with_options::<JavaParser, _>(
+            "class A {
+                 void f() {
+                     try {
+                         g();
+                     } catch (IOException e) { // +1
+                         h();
+                     } catch (IllegalStateException | IOException e) { // +1
+                         h();
+                     }
+                     try {
+                         g();
+                     } catch (final IllegalStateException e) {
+                         h();
+                     }
+                 }
+             }",
+            "foo.java",
+            &exception_types_options(),
+            |func_space| {
+                let method = &func_space.spaces[0].spaces[0];
+                assert_eq!(method.metrics.exception_types.exception_types(), 2.);
+            },
+        );
+    }
+
+    #[test]
+    fn python_exception_types() {
+        check_metrics_with_options::<PythonParser>(
+            "def f():
+                 try:
+                     g()
+                 except (ValueError, os.error) as e: # +2
+                     pass
+                 except KeyError: # +1
+                     pass
+                 except ValueError:
+                     pass
+                 except:
+                     pass",
+            "foo.py",
+            &exception_types_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.exception_types,
+                    @r###"
+                    {
+                      "sum": 3.0,
+                      "average": 1.5,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_exception_types() {
+        check_metrics_with_options::<CppParser>(
+            "void f() {
+                 try {
+                     g();
+                 } catch (const std::exception& e) { // +1
+                 } catch (int) { // +1
+                 } catch (...) {
+                 }
+             }
+             void h() {
+                 try {
+                     g();
+                 } catch (const std::exception& e) { // +1
+                 }
+             }",
+            "foo.cpp",
+            &exception_types_options(),
+            |metric| {
+                // nspace = 3 (2 funcs and unit)
+                insta::assert_json_snapshot!(
+                    metric.exception_types,
+                    @r###"
+                    {
+                      "sum": 3.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -1,5 +1,5 @@
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::checker::Checker;
@@ -10,7 +10,8 @@ use crate::*;
 ///
 /// This metric counts the number of possible exit points
 /// from a function/method.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
     exit: usize,
     exit_sum: usize,
@@ -111,7 +112,32 @@ pub trait Exit
 where
     Self: Checker,
 {
+    const IMPLEMENTED: bool = true;
+
     fn compute(node: &Node, stats: &mut Stats);
+
+    /// Counts the implicit exit of a function falling off the end of its
+    /// body, so that every function has at least one exit.
+    ///
+    /// This is called only when [`MetricsOptions::implicit_exits`] is set.
+    ///
+    /// [`MetricsOptions::implicit_exits`]: crate::MetricsOptions::implicit_exits
+    fn compute_implicit(_node: &Node, _stats: &mut Stats) {}
+}
+
+// Returns the last statement of the body of a function, if any
+fn last_statement<'a, T: Checker>(node: &Node<'a>) -> Option<Node<'a>> {
+    node.child_by_field_name("body")?
+        .children()
+        .filter(|child| child.is_named() && !T::is_comment(child))
+        .last()
+}
+
+// Returns true when a function falls off the end of its body,
+// that is when its last statement is not a `return`
+fn falls_through<T: Checker>(node: &Node, return_statement: u16) -> bool {
+    T::is_func(node)
+        && last_statement::<T>(node).is_none_or(|last| last.kind_id() != return_statement)
 }
 
 impl Exit for PythonCode {
@@ -120,6 +146,12 @@ impl Exit for PythonCode {
             stats.exit += 1;
         }
     }
+
+    fn compute_implicit(node: &Node, stats: &mut Stats) {
+        if falls_through::<Self>(node, Python::ReturnStatement as u16) {
+            stats.exit += 1;
+        }
+    }
 }
 
 impl Exit for MozjsCode {
@@ -128,6 +160,12 @@ impl Exit for MozjsCode {
             stats.exit += 1;
         }
     }
+
+    fn compute_implicit(node: &Node, stats: &mut Stats) {
+        if falls_through::<Self>(node, Mozjs::ReturnStatement as u16) {
+            stats.exit += 1;
+        }
+    }
 }
 
 impl Exit for JavascriptCode {
@@ -136,6 +174,12 @@ impl Exit for JavascriptCode {
             stats.exit += 1;
         }
     }
+
+    fn compute_implicit(node: &Node, stats: &mut Stats) {
+        if falls_through::<Self>(node, Javascript::ReturnStatement as u16) {
+            stats.exit += 1;
+        }
+    }
 }
 
 impl Exit for TypescriptCode {
@@ -144,6 +188,12 @@ impl Exit for TypescriptCode {
             stats.exit += 1;
         }
     }
+
+    fn compute_implicit(node: &Node, stats: &mut Stats) {
+        if falls_through::<Self>(node, Typescript::ReturnStatement as u16) {
+            stats.exit += 1;
+        }
+    }
 }
 
 impl Exit for TsxCode {
@@ -152,6 +202,12 @@ impl Exit for TsxCode {
             stats.exit += 1;
         }
     }
+
+    fn compute_implicit(node: &Node, stats: &mut Stats) {
+        if falls_through::<Self>(node, Tsx::ReturnStatement as u16) {
+            stats.exit += 1;
+        }
+    }
 }
 
 impl Exit for RustCode {
@@ -164,6 +220,24 @@ impl Exit for RustCode {
             stats.exit += 1;
         }
     }
+
+    fn compute_implicit(node: &Node, stats: &mut Stats) {
+        // The trailing expression of a function returning a value
+        // is already counted as its exit
+        if !Self::is_func(node) || node.child_by_field_name("return_type").is_some() {
+            return;
+        }
+        let returns = last_statement::<Self>(node).is_some_and(|last| {
+            let last = match last.kind_id().into() {
+                Rust::ExpressionStatement => last.child(0),
+                _ => Some(last),
+            };
+            last.is_some_and(|last| last.kind_id() == Rust::ReturnExpression)
+        });
+        if !returns {
+            stats.exit += 1;
+        }
+    }
 }
 
 impl Exit for CppCode {
@@ -172,6 +246,12 @@ impl Exit for CppCode {
             stats.exit += 1;
         }
     }
+
+    fn compute_implicit(node: &Node, stats: &mut Stats) {
+        if falls_through::<Self>(node, Cpp::ReturnStatement as u16) {
+            stats.exit += 1;
+        }
+    }
 }
 
 impl Exit for JavaCode {
@@ -180,13 +260,47 @@ impl Exit for JavaCode {
             stats.exit += 1;
         }
     }
+
+    fn compute_implicit(node: &Node, stats: &mut Stats) {
+        if falls_through::<Self>(node, Java::ReturnStatement as u16) {
+            stats.exit += 1;
+        }
+    }
 }
 
-implement_metric_trait!(Exit, KotlinCode, PreprocCode, CcommentCode);
+impl Exit for GoCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if matches!(node.kind_id().into(), Go::ReturnStatement) {
+            stats.exit += 1;
+        }
+    }
+
+    fn compute_implicit(node: &Node, stats: &mut Stats) {
+        if falls_through::<Self>(node, Go::ReturnStatement as u16) {
+            stats.exit += 1;
+        }
+    }
+}
+
+impl Exit for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if matches!(node.kind_id().into(), Csharp::ReturnStatement) {
+            stats.exit += 1;
+        }
+    }
+
+    fn compute_implicit(node: &Node, stats: &mut Stats) {
+        if falls_through::<Self>(node, Csharp::ReturnStatement as u16) {
+            stats.exit += 1;
+        }
+    }
+}
+
+implement_metric_trait!(Exit, HclCode, KotlinCode, PreprocCode, CcommentCode);
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::check_metrics;
+    use crate::tools::{check_func_space, check_metrics};
 
     use super::*;
 
@@ -241,6 +355,25 @@ mod tests {
         });
     }
 
+    #[test]
+    fn rust_question_mark_in_closure() {
+        check_func_space::<RustParser, _>(
+            "fn f(a: Option<i32>, v: Vec<Option<i32>>) -> Option<i32> { // +1 (function)
+                 let g = |x: Option<i32>| -> Option<i32> { Some(x? + a?) }; // +2 (closure)
+                 Some(v.into_iter().map(g).sum::<Option<i32>>()? + a?) // +2 (function)
+             }",
+            "foo.rs",
+            |func_space| {
+                let function = &func_space.spaces[0];
+                let closure = &function.spaces[0];
+                assert_eq!(function.metrics.nexits.exit(), 3.);
+                assert_eq!(closure.metrics.nexits.exit(), 2.);
+                // The exits of the closure are added to the ones of the function
+                assert_eq!(function.metrics.nexits.exit_sum(), 5.);
+            },
+        );
+    }
+
     #[test]
     fn c_no_exit() {
         check_metrics::<CppParser>("int a = 42;", "foo.c", |metric| {
@@ -420,4 +553,85 @@ mod tests {
             },
         );
     }
+
+    // Returns the exits of the unit space without and with the implicit ones
+    fn implicit_exits<T: ParserTrait>(source: &str, filename: &str) -> (f64, f64) {
+        let path = std::path::PathBuf::from(filename);
+        let parser = T::new(source.as_bytes().to_vec(), &path, None);
+        let default = metrics(&parser, &path).unwrap();
+        let options = MetricsOptions {
+            implicit_exits: true,
+            ..Default::default()
+        };
+        let implicit = metrics_with_options(&parser, &path, &options).unwrap();
+        (
+            default.metrics.nexits.exit_sum(),
+            implicit.metrics.nexits.exit_sum(),
+        )
+    }
+
+    #[test]
+    fn rust_implicit_exits() {
+        let exits = implicit_exits::<RustParser>(
+            "fn f(a: i32) -> i32 { // +1 (trailing expression)
+                 a + 1
+             }
+             fn g(a: i32) { // +1 implicit
+                 println!(\"{}\", a);
+             }
+             fn h(a: i32) {
+                 return; // +1
+             }",
+            "foo.rs",
+        );
+        assert_eq!(exits, (2., 3.));
+    }
+
+    #[test]
+    fn c_implicit_exits() {
+        let exits = implicit_exits::<CppParser>(
+            "void f(int *a) { // +1 implicit
+                 *a = 1;
+             }
+             int g(int a) {
+                 if (a) {
+                     return 1; // +1
+                 }
+                 return 0; // +1
+             }",
+            "foo.c",
+        );
+        assert_eq!(exits, (2., 3.));
+    }
+
+    #[test]
+    fn python_implicit_exits() {
+        let exits = implicit_exits::<PythonParser>(
+            "def f(a):  # +1 implicit
+    if a:
+        return 1  # +1
+    print(a)
+def g():
+    return 1  # +1",
+            "foo.py",
+        );
+        assert_eq!(exits, (2., 3.));
+    }
+
+    #[test]
+    fn go_implicit_exits() {
+        let exits = implicit_exits::<GoParser>(
+            "func f(a int) { // +1 implicit
+                 a++
+             }
+             func g(a int) int {
+                 if a > 0 {
+                     return 1 // +1
+                 }
+                 return 0 // +1
+             }",
+            "foo.go",
+        );
+        assert_eq!(exits, (2., 3.));
+    }
 }
@@ -0,0 +1,512 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `ExprNesting` metric.
+///
+/// This metric computes the maximum nesting depth of the expressions
+/// contained in a function/method, so `f(g(h(i(x))))` has a depth of 4.
+///
+/// A call is nested into another one only when it appears among its
+/// arguments, hence method chains such as `a.b().c()` are not considered
+/// as nested expressions.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    expr_nesting: usize,
+    expr_nesting_sum: usize,
+    expr_nesting_min: usize,
+    expr_nesting_max: usize,
+    space_count: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            expr_nesting: 0,
+            expr_nesting_sum: 0,
+            expr_nesting_min: usize::MAX,
+            expr_nesting_max: 0,
+            space_count: 1,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("expr_nesting", 3)?;
+        st.serialize_field("average", &self.expr_nesting_average())?;
+        st.serialize_field("min", &self.expr_nesting_min())?;
+        st.serialize_field("max", &self.expr_nesting_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "average: {}, min: {}, max: {}",
+            self.expr_nesting_average(),
+            self.expr_nesting_min(),
+            self.expr_nesting_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `ExprNesting` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.expr_nesting_max = self.expr_nesting_max.max(other.expr_nesting_max);
+        self.expr_nesting_min = self.expr_nesting_min.min(other.expr_nesting_min);
+        self.expr_nesting_sum += other.expr_nesting_sum;
+        self.space_count += other.space_count;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `ExprNesting` metric value of a space
+    pub fn expr_nesting(&self) -> f64 {
+        self.expr_nesting as f64
+    }
+
+    /// Returns the `ExprNesting` metric average value
+    ///
+    /// This value is computed dividing the sum of the `ExprNesting`
+    /// values for the number of spaces.
+    pub fn expr_nesting_average(&self) -> f64 {
+        self.expr_nesting_sum as f64 / self.space_count as f64
+    }
+
+    /// Returns the `ExprNesting` metric minimum value
+    pub fn expr_nesting_min(&self) -> f64 {
+        self.expr_nesting_min as f64
+    }
+
+    /// Returns the `ExprNesting` metric maximum value
+    pub fn expr_nesting_max(&self) -> f64 {
+        self.expr_nesting_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.expr_nesting_sum += self.expr_nesting;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.expr_nesting_max = self.expr_nesting_max.max(self.expr_nesting);
+        self.expr_nesting_min = self.expr_nesting_min.min(self.expr_nesting);
+        self.compute_sum();
+    }
+
+    // Enables the `ExprNesting` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `ExprNesting` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+/// The expression nesting of a node, which its children receive.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Depth {
+    // The number of expressions nesting a node
+    depth: usize,
+    // The id of the arguments of a call, together with their depth
+    arguments: Option<(usize, usize)>,
+    // The operator of an expression
+    operator: Option<u16>,
+}
+
+pub trait ExprNesting
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    /// Computes the metric on a node.
+    ///
+    /// `depth` contains the expression nesting of the parent of the node,
+    /// and it is updated to the one of the node itself, which its children
+    /// receive.
+    fn compute(node: &Node, stats: &mut Stats, depth: &mut Depth);
+}
+
+// Computes the nesting depth of an expression from the one of its parent,
+// which is reset at the boundary of the function/closure containing it.
+//
+// `is_operator` matches the expressions which nest all their children,
+// while `is_call` matches the expressions which only nest their arguments.
+// The operands of a chain of the same operator, such as `a + b + c`,
+// are at the same level.
+#[inline(always)]
+fn compute_depth<T: Checker>(
+    node: &Node,
+    stats: &mut Stats,
+    depth: &mut Depth,
+    is_operator: fn(u16) -> bool,
+    is_call: fn(u16) -> bool,
+) {
+    let parent = *depth;
+    *depth = Depth {
+        depth: parent.depth,
+        ..Default::default()
+    };
+
+    if T::is_func(node) || T::is_closure(node) {
+        depth.depth = 0;
+        return;
+    }
+    if let Some((id, arguments)) = parent.arguments
+        && id == node.id()
+    {
+        depth.depth = arguments;
+    }
+
+    let id = node.kind_id();
+    if is_operator(id) {
+        let operator = node
+            .child_by_field_name("operator")
+            .map(|operator| operator.kind_id());
+        if operator.is_none() || operator != parent.operator {
+            depth.depth += 1;
+        }
+        depth.operator = operator;
+        stats.expr_nesting = stats.expr_nesting.max(depth.depth);
+    } else if is_call(id) {
+        stats.expr_nesting = stats.expr_nesting.max(depth.depth + 1);
+        depth.arguments = node
+            .child_by_field_name("arguments")
+            .map(|arguments| (arguments.id(), depth.depth + 1));
+    }
+}
+
+impl ExprNesting for PythonCode {
+    fn compute(node: &Node, stats: &mut Stats, depth: &mut Depth) {
+        use Python::*;
+
+        compute_depth::<Self>(
+            node,
+            stats,
+            depth,
+            |id| {
+                matches!(
+                    id.into(),
+                    BinaryOperator
+                        | BooleanOperator
+                        | ComparisonOperator
+                        | UnaryOperator
+                        | NotOperator
+                        | ConditionalExpression
+                )
+            },
+            |id| id == Call,
+        );
+    }
+}
+
+impl ExprNesting for MozjsCode {
+    fn compute(node: &Node, stats: &mut Stats, depth: &mut Depth) {
+        use Mozjs::*;
+
+        compute_depth::<Self>(
+            node,
+            stats,
+            depth,
+            |id| {
+                matches!(
+                    id.into(),
+                    BinaryExpression | UnaryExpression | UpdateExpression | TernaryExpression
+                )
+            },
+            |id| id == CallExpression,
+        );
+    }
+}
+
+impl ExprNesting for JavascriptCode {
+    fn compute(node: &Node, stats: &mut Stats, depth: &mut Depth) {
+        use Javascript::*;
+
+        compute_depth::<Self>(
+            node,
+            stats,
+            depth,
+            |id| {
+                matches!(
+                    id.into(),
+                    BinaryExpression | UnaryExpression | UpdateExpression | TernaryExpression
+                )
+            },
+            |id| id == CallExpression,
+        );
+    }
+}
+
+impl ExprNesting for TypescriptCode {
+    fn compute(node: &Node, stats: &mut Stats, depth: &mut Depth) {
+        use Typescript::*;
+
+        compute_depth::<Self>(
+            node,
+            stats,
+            depth,
+            |id| {
+                matches!(
+                    id.into(),
+                    BinaryExpression | UnaryExpression | UpdateExpression | TernaryExpression
+                )
+            },
+            |id| id == CallExpression,
+        );
+    }
+}
+
+impl ExprNesting for TsxCode {
+    fn compute(node: &Node, stats: &mut Stats, depth: &mut Depth) {
+        use Tsx::*;
+
+        compute_depth::<Self>(
+            node,
+            stats,
+            depth,
+            |id| {
+                matches!(
+                    id.into(),
+                    BinaryExpression | UnaryExpression | UpdateExpression | TernaryExpression
+                )
+            },
+            |id| id == CallExpression,
+        );
+    }
+}
+
+impl ExprNesting for RustCode {
+    fn compute(node: &Node, stats: &mut Stats, depth: &mut Depth) {
+        use Rust::*;
+
+        compute_depth::<Self>(
+            node,
+            stats,
+            depth,
+            |id| matches!(id.into(), BinaryExpression | UnaryExpression),
+            |id| id == CallExpression,
+        );
+    }
+}
+
+impl ExprNesting for CppCode {
+    fn compute(node: &Node, stats: &mut Stats, depth: &mut Depth) {
+        use Cpp::*;
+
+        compute_depth::<Self>(
+            node,
+            stats,
+            depth,
+            |id| {
+                matches!(
+                    id.into(),
+                    BinaryExpression | UnaryExpression | UpdateExpression | ConditionalExpression
+                )
+            },
+            |id| id == CallExpression,
+        );
+    }
+}
+
+impl ExprNesting for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats, depth: &mut Depth) {
+        use Java::*;
+
+        compute_depth::<Self>(
+            node,
+            stats,
+            depth,
+            |id| {
+                matches!(
+                    id.into(),
+                    BinaryExpression | UnaryExpression | UpdateExpression | TernaryExpression
+                )
+            },
+            |id| id == MethodInvocation,
+        );
+    }
+}
+
+impl ExprNesting for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats, depth: &mut Depth) {
+        use Csharp::*;
+
+        compute_depth::<Self>(
+            node,
+            stats,
+            depth,
+            |id| {
+                matches!(
+                    id.into(),
+                    BinaryExpression
+                        | PrefixUnaryExpression
+                        | PostfixUnaryExpression
+                        | ConditionalExpression
+                )
+            },
+            |id| id == InvocationExpression,
+        );
+    }
+}
+
+implement_metric_trait!(
+    ExprNesting,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics_with_options;
+
+    use super::*;
+
+    fn expr_nesting_options() -> MetricsOptions {
+        MetricsOptions {
+            expr_nesting: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rust_nested_calls() {
+        check_metrics_with_options::<RustParser>(
+            "fn f(x: i32) -> i32 {
+                 f(g(h(i(x)))) // 4
+             }",
+            "foo.rs",
+            &expr_nesting_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.expr_nesting,
+                    @r###"
+                    {
+                      "average": 2.0,
+                      "min": 0.0,
+                      "max": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_method_chain() {
+        check_metrics_with_options::<RustParser>(
+            "fn f(x: Vec<i32>) -> i32 {
+                 x.iter().map(g).sum() + 1 // 1
+             }",
+            "foo.rs",
+            &expr_nesting_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.expr_nesting,
+                    @r###"
+                    {
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_operator_chain() {
+        check_metrics_with_options::<RustParser>(
+            "fn f(a: i32, b: i32, c: i32, d: i32) -> i32 {
+                 a + b + c + d // 1
+             }
+             fn g(a: i32, b: i32, c: i32, d: i32) -> i32 {
+                 a + b * c - d // 3
+             }",
+            "foo.rs",
+            &expr_nesting_options(),
+            |metric| {
+                // nspace = 3 (unit and 2 functions)
+                insta::assert_json_snapshot!(
+                    metric.expr_nesting,
+                    @r###"
+                    {
+                      "average": 1.3333333333333333,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_nested_operators() {
+        check_metrics_with_options::<PythonParser>(
+            "def f(a, b, c):
+                 return g(a + (b * (c - 1))) # 4",
+            "foo.py",
+            &expr_nesting_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.expr_nesting,
+                    @r###"
+                    {
+                      "average": 2.0,
+                      "min": 0.0,
+                      "max": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_nested_calls() {
+        check_metrics_with_options::<JavaParser>(
+            "class A {
+                int f(int x) {
+                    return f(g(h(i(x)))); // 4
+                }
+            }",
+            "foo.java",
+            &expr_nesting_options(),
+            |metric| {
+                // nspace = 3 (unit, class and method)
+                insta::assert_json_snapshot!(
+                    metric.expr_nesting,
+                    @r###"
+                    {
+                      "average": 1.3333333333333333,
+                      "min": 0.0,
+                      "max": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `Goroutines` metric.
+///
+/// This metric counts the `go` statements of a space, since every
+/// goroutine they launch runs concurrently with its caller, adding
+/// a concurrency complexity which is not reflected by the other metrics.
+///
+/// A launched anonymous function, as in `go func() { ... }()`,
+/// is a space of its own, so its launch is counted by the space
+/// containing the `go` statement, while its branches are counted
+/// by the anonymous function.
+///
+/// The largest number of launches of a single space is reported too.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    goroutines: usize,
+    goroutines_sum: usize,
+    goroutines_max: usize,
+    enabled: bool,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("goroutines", 2)?;
+        st.serialize_field("sum", &self.goroutines_sum())?;
+        st.serialize_field("max", &self.goroutines_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, max: {}",
+            self.goroutines_sum(),
+            self.goroutines_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Goroutines` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.goroutines_sum += other.goroutines_sum;
+        self.goroutines_max = self.goroutines_max.max(other.goroutines_max);
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the number of goroutines launched by a space
+    pub fn goroutines(&self) -> f64 {
+        self.goroutines as f64
+    }
+
+    /// Returns the sum of the launched goroutines
+    pub fn goroutines_sum(&self) -> f64 {
+        self.goroutines_sum as f64
+    }
+
+    /// Returns the largest number of goroutines launched by a single space
+    pub fn goroutines_max(&self) -> f64 {
+        self.goroutines_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.goroutines_sum += self.goroutines;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.goroutines_max = self.goroutines_max.max(self.goroutines);
+        self.compute_sum();
+    }
+
+    // Enables the `Goroutines` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `Goroutines` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait Goroutines
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+impl Goroutines for GoCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if node.kind_id() == Go::GoStatement {
+            stats.goroutines += 1;
+        }
+    }
+}
+
+implement_metric_trait!(
+    Goroutines,
+    PythonCode,
+    MozjsCode,
+    JavascriptCode,
+    TypescriptCode,
+    TsxCode,
+    RustCode,
+    CppCode,
+    JavaCode,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    CsharpCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::{check_func_space_with_options, check_metrics_with_options};
+
+    use super::*;
+
+    fn goroutines_options() -> MetricsOptions {
+        MetricsOptions {
+            goroutines: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn go_goroutines() {
+        check_metrics_with_options::<GoParser>(
+            "package main
+
+             func serve(jobs []int, done chan bool) {
+                 go func() {
+                     for _, j := range jobs {
+                         if j > 0 {
+                             process(j)
+                         }
+                     }
+                     done <- true
+                 }()
+                 go log(len(jobs))
+             }
+
+             func wait(done chan bool) {
+                 <-done
+             }",
+            "foo.go",
+            &goroutines_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.goroutines,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn go_goroutine_spaces() {
+        check_func_space_with_options::<GoParser, _>(
+            "package main
+
+             func serve(jobs []int) {
+                 go func() {
+                     for _, j := range jobs {
+                         if j > 0 {
+                             process(j)
+                         }
+                     }
+                 }()
+                 go func() {
+                     if len(jobs) == 0 {
+                         return
+                     }
+                 }()
+             }",
+            "foo.go",
+            &goroutines_options(),
+            |func_space| {
+                let serve = &func_space.spaces[0];
+                assert_eq!(serve.name.as_deref(), Some("serve"));
+                assert_eq!(serve.metrics.goroutines.goroutines(), 2.);
+                assert_eq!(serve.spaces.len(), 2);
+
+                // The branches of the goroutines are attributed to their
+                // anonymous functions, which launch no goroutines
+                let (first, second) = (&serve.spaces[0], &serve.spaces[1]);
+                assert_eq!(first.metrics.goroutines.goroutines(), 0.);
+                assert_eq!(second.metrics.goroutines.goroutines(), 0.);
+                assert_eq!(first.metrics.cyclomatic.cyclomatic(), 3.);
+                assert_eq!(second.metrics.cyclomatic.cyclomatic(), 2.);
+                assert_eq!(serve.metrics.cyclomatic.cyclomatic(), 1.);
+                assert_eq!(func_space.metrics.goroutines.goroutines_sum(), 2.);
+            },
+        );
+    }
+
+    #[test]
+    fn go_goroutines_disabled() {
+        check_metrics_with_options::<GoParser>(
+            "package main
+
+             func f() {
+                 go g()
+             }",
+            "foo.go",
+            &MetricsOptions::default(),
+            |metric| {
+                assert!(metric.goroutines.is_disabled());
+            },
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::checker::Checker;
@@ -11,15 +11,19 @@ use crate::macros::implement_metric_trait;
 use crate::*;
 
 /// The `Halstead` metric suite.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
     u_operators: u64,
     operators: u64,
     u_operands: u64,
     operands: u64,
+    operator_frequencies: Option<HashMap<u16, u64>>,
+    operand_frequencies: Option<HashMap<String, u64>>,
 }
 
 /// Specifies the type of nodes accepted by the `Halstead` metric.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HalsteadType {
     /// The node is an `Halstead` operator
     Operator,
@@ -29,6 +33,48 @@ pub enum HalsteadType {
     Unknown,
 }
 
+/// Overrides the `Halstead` type assigned to the nodes of some kinds.
+///
+/// Node kinds are identified by their tree-sitter names, such as `else`
+/// or `lifetime`, hence they are specific to each language.
+///
+/// # Examples
+///
+/// ```
+/// use rust_code_analysis::LANG;
+/// use rust_code_analysis::halstead::{HalsteadOverrides, HalsteadType};
+///
+/// let mut overrides = HalsteadOverrides::new();
+///
+/// // Count the `else` keyword as a Rust operator
+/// overrides.set(LANG::Rust, "else", HalsteadType::Operator);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct HalsteadOverrides {
+    types: HashMap<LANG, HashMap<String, HalsteadType>>,
+}
+
+impl HalsteadOverrides {
+    /// Creates an empty set of overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assigns the `Halstead` type `typ` to the nodes of kind `kind`
+    /// in the language `lang`.
+    pub fn set(&mut self, lang: LANG, kind: &str, typ: HalsteadType) -> &mut Self {
+        self.types
+            .entry(lang)
+            .or_default()
+            .insert(kind.to_string(), typ);
+        self
+    }
+
+    pub(crate) fn get(&self, lang: LANG) -> Option<&HashMap<String, HalsteadType>> {
+        self.types.get(&lang)
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct HalsteadMaps<'a> {
     pub(crate) operators: HashMap<u16, u64>,
@@ -58,6 +104,16 @@ impl<'a> HalsteadMaps<'a> {
         stats.u_operands = self.operands.len() as u64;
         stats.operands = self.operands.values().sum::<u64>();
     }
+
+    pub(crate) fn retain_frequencies(&self, stats: &mut Stats) {
+        stats.operator_frequencies = Some(self.operators.clone());
+        stats.operand_frequencies = Some(
+            self.operands
+                .iter()
+                .map(|(operand, count)| (String::from_utf8_lossy(operand).into_owned(), *count))
+                .collect(),
+        );
+    }
 }
 
 impl Serialize for Stats {
@@ -65,7 +121,7 @@ impl Serialize for Stats {
     where
         S: Serializer,
     {
-        let mut st = serializer.serialize_struct("halstead", 14)?;
+        let mut st = serializer.serialize_struct("halstead", 15)?;
         st.serialize_field("n1", &self.u_operators())?;
         st.serialize_field("N1", &self.operators())?;
         st.serialize_field("n2", &self.u_operands())?;
@@ -80,6 +136,7 @@ impl Serialize for Stats {
         st.serialize_field("effort", &self.effort())?;
         st.serialize_field("time", &self.time())?;
         st.serialize_field("bugs", &self.bugs())?;
+        st.serialize_field("delivered_bugs", &self.delivered_bugs())?;
         st.end()
     }
 }
@@ -101,7 +158,8 @@ impl fmt::Display for Stats {
              level: {}, \
              effort: {}, \
              time: {}, \
-             bugs: {}",
+             bugs: {}, \
+             delivered bugs: {}",
             self.u_operators(),
             self.operators(),
             self.u_operands(),
@@ -116,6 +174,7 @@ impl fmt::Display for Stats {
             self.effort(),
             self.time(),
             self.bugs(),
+            self.delivered_bugs(),
         )
     }
 }
@@ -147,6 +206,28 @@ impl Stats {
         self.operands as f64
     }
 
+    /// Returns the number of occurrences of each distinct operator,
+    /// identified by the kind of its node, such as `Rust::PLUS`
+    ///
+    /// The frequencies are only retained when the
+    /// [`MetricsOptions::halstead_frequencies`] option is set.
+    ///
+    /// [`MetricsOptions::halstead_frequencies`]: crate::MetricsOptions::halstead_frequencies
+    pub fn operator_frequencies(&self) -> Option<&HashMap<u16, u64>> {
+        self.operator_frequencies.as_ref()
+    }
+
+    /// Returns the number of occurrences of each distinct operand,
+    /// identified by its spelling
+    ///
+    /// The frequencies are only retained when the
+    /// [`MetricsOptions::halstead_frequencies`] option is set.
+    ///
+    /// [`MetricsOptions::halstead_frequencies`]: crate::MetricsOptions::halstead_frequencies
+    pub fn operand_frequencies(&self) -> Option<&HashMap<String, u64>> {
+        self.operand_frequencies.as_ref()
+    }
+
     /// Returns the program length
     #[inline(always)]
     pub fn length(&self) -> f64 {
@@ -246,12 +327,29 @@ impl Stats {
         // Source: https://docs.lib.purdue.edu/cgi/viewcontent.cgi?article=1145&context=cstech
         self.effort().powf(2. / 3.) / 3000.
     }
+
+    /// Returns the estimated number of delivered bugs computed
+    /// from the volume, as `V / 3000`.
+    ///
+    /// It is an alternative formula of [`bugs`], which is still
+    /// based on the same number of mental discriminations.
+    /// Its value is 0 for a space without operators and operands.
+    ///
+    /// [`bugs`]: Stats::bugs
+    #[inline(always)]
+    pub fn delivered_bugs(&self) -> f64 {
+        // The volume of an empty space is NaN, since its vocabulary is 0
+        let volume = self.volume();
+        if volume > 0. { volume / 3000. } else { 0. }
+    }
 }
 
 pub trait Halstead
 where
     Self: Checker,
 {
+    const IMPLEMENTED: bool = true;
+
     fn compute<'a>(node: &Node<'a>, code: &'a [u8], halstead_maps: &mut HalsteadMaps<'a>);
 }
 
@@ -266,7 +364,17 @@ fn compute_halstead<'a, T: Getter>(
     code: &'a [u8],
     halstead_maps: &mut HalsteadMaps<'a>,
 ) {
-    match T::get_op_type(node) {
+    compute_halstead_type(node, code, T::get_op_type(node), halstead_maps);
+}
+
+#[inline(always)]
+pub(crate) fn compute_halstead_type<'a>(
+    node: &Node<'a>,
+    code: &'a [u8],
+    typ: HalsteadType,
+    halstead_maps: &mut HalsteadMaps<'a>,
+) {
+    match typ {
         HalsteadType::Operator => {
             *halstead_maps.operators.entry(node.kind_id()).or_insert(0) += 1;
         }
@@ -328,11 +436,25 @@ impl Halstead for JavaCode {
     }
 }
 
-implement_metric_trait!(Halstead, KotlinCode, PreprocCode, CcommentCode);
+impl Halstead for GoCode {
+    fn compute<'a>(node: &Node<'a>, code: &'a [u8], halstead_maps: &mut HalsteadMaps<'a>) {
+        compute_halstead::<Self>(node, code, halstead_maps);
+    }
+}
+
+impl Halstead for CsharpCode {
+    fn compute<'a>(node: &Node<'a>, code: &'a [u8], halstead_maps: &mut HalsteadMaps<'a>) {
+        compute_halstead::<Self>(node, code, halstead_maps);
+    }
+}
+
+implement_metric_trait!(Halstead, HclCode, KotlinCode, PreprocCode, CcommentCode);
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::check_metrics;
+    use std::path::PathBuf;
+
+    use crate::tools::{check_func_space, check_metrics};
 
     use super::*;
 
@@ -368,7 +490,8 @@ mod tests {
                       "level": 0.5,
                       "effort": 150.56842503028855,
                       "time": 8.364912501682698,
-                      "bugs": 0.0094341190071077
+                      "bugs": 0.0094341190071077,
+                      "delivered_bugs": 0.025094737505048093
                     }"###
                 );
             },
@@ -412,7 +535,8 @@ mod tests {
                       "level": 0.1234567901234568,
                       "effort": 1445.1449400735075,
                       "time": 80.28583000408375,
-                      "bugs": 0.04260752914034329
+                      "bugs": 0.04260752914034329,
+                      "delivered_bugs": 0.059470985188210194
                     }"###
                 );
             },
@@ -448,7 +572,8 @@ mod tests {
                       "level": 0.12,
                       "effort": 1345.177045923802,
                       "time": 74.7320581068779,
-                      "bugs": 0.040619232256751396
+                      "bugs": 0.040619232256751396,
+                      "delivered_bugs": 0.05380708183695208
                     }"###
                 );
             },
@@ -485,7 +610,8 @@ mod tests {
                       "level": 0.10476190476190476,
                       "effort": 1886.699983875422,
                       "time": 104.81666577085679,
-                      "bugs": 0.05089564733125986
+                      "bugs": 0.05089564733125986,
+                      "delivered_bugs": 0.0658847613416814
                     }"###
                 );
             },
@@ -522,7 +648,8 @@ mod tests {
                       "level": 0.10476190476190476,
                       "effort": 1886.699983875422,
                       "time": 104.81666577085679,
-                      "bugs": 0.05089564733125986
+                      "bugs": 0.05089564733125986,
+                      "delivered_bugs": 0.0658847613416814
                     }"###
                 );
             },
@@ -559,7 +686,8 @@ mod tests {
                       "level": 0.10476190476190476,
                       "effort": 1886.699983875422,
                       "time": 104.81666577085679,
-                      "bugs": 0.05089564733125986
+                      "bugs": 0.05089564733125986,
+                      "delivered_bugs": 0.0658847613416814
                     }"###
                 );
             },
@@ -596,7 +724,8 @@ mod tests {
                       "level": 0.10476190476190476,
                       "effort": 1886.699983875422,
                       "time": 104.81666577085679,
-                      "bugs": 0.05089564733125986
+                      "bugs": 0.05089564733125986,
+                      "delivered_bugs": 0.0658847613416814
                     }"###
                 );
             },
@@ -623,7 +752,8 @@ mod tests {
                       "level": null,
                       "effort": null,
                       "time": null,
-                      "bugs": null
+                      "bugs": null,
+                      "delivered_bugs": 0.0
                     }"###
             );
         });
@@ -653,7 +783,8 @@ mod tests {
                       "level": 1.0,
                       "effort": 4.754887502163468,
                       "time": 0.26416041678685936,
-                      "bugs": 0.0009425525573729414
+                      "bugs": 0.0009425525573729414,
+                      "delivered_bugs": 0.001584962500721156
                     }"###
                 );
             },
@@ -692,10 +823,141 @@ mod tests {
                       "level": 0.1090909090909091,
                       "effort": 1921.2717890295687,
                       "time": 106.73732161275382,
-                      "bugs": 0.05151550353617788
+                      "bugs": 0.05151550353617788,
+                      "delivered_bugs": 0.06986442869198432
                     }"###
                 );
             },
         );
     }
+
+    #[test]
+    fn rust_overridden_operator() {
+        let path = std::path::PathBuf::from("foo.rs");
+        let source = "fn f(a: bool) -> i32 { if a { 1 } else { 2 } }";
+        let compute = |options: &MetricsOptions| {
+            let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+            metrics_with_options(&parser, &path, options)
+                .unwrap()
+                .metrics
+                .halstead
+        };
+
+        let default = compute(&MetricsOptions::default());
+
+        let mut options = MetricsOptions::default();
+        options
+            .halstead_overrides
+            .set(LANG::Rust, "else", HalsteadType::Operator);
+        let overridden = compute(&options);
+
+        // `else` is not counted by default, so it becomes a new distinct operator
+        assert_eq!(overridden.u_operators(), default.u_operators() + 1.);
+        assert_eq!(overridden.operators(), default.operators() + 1.);
+        assert_eq!(overridden.vocabulary(), default.vocabulary() + 1.);
+        assert_eq!(overridden.u_operands(), default.u_operands());
+    }
+
+    #[test]
+    fn rust_generics_and_lifetimes() {
+        check_metrics::<RustParser>(
+            "fn f<'a, T: Clone>(x: &'a T) -> T {
+                 h::<T>(x)
+             }",
+            "foo.rs",
+            |metric| {
+                // unique operators: fn, <, ,, >, (, &, ->, {, ::
+                // operators: fn, <, ,, >, (, &, ->, {, ::, <, >, (
+                // unique operands: f, 'a, T, Clone, x, h
+                // operands: f, 'a, T, Clone, x, 'a, T, T, h, T, x
+                insta::assert_json_snapshot!(
+                    metric.halstead,
+                    @r###"
+                    {
+                      "n1": 9.0,
+                      "N1": 12.0,
+                      "n2": 6.0,
+                      "N2": 11.0,
+                      "length": 23.0,
+                      "estimated_program_length": 44.039100017307746,
+                      "purity_ratio": 1.9147434790133802,
+                      "vocabulary": 15.0,
+                      "volume": 89.85848369899593,
+                      "difficulty": 8.25,
+                      "level": 0.12121212121212122,
+                      "effort": 741.3324905167163,
+                      "time": 41.185138362039794,
+                      "bugs": 0.02730365377655398,
+                      "delivered_bugs": 0.02995282789966531
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_delivered_bugs() {
+        check_func_space::<PythonParser, _>(
+            "def f(a):
+                 return a + 1
+             def g():
+                 pass",
+            "foo.py",
+            |func_space| {
+                // operators: def, return, +
+                // operands: f, a, a, 1
+                // volume: (3 + 4) * log2(3 + 3) = 18.094737505048094
+                let f = &func_space.spaces[0].metrics.halstead;
+                assert_eq!(f.volume(), 18.094737505048094);
+                // 18.094737505048094 / 3000
+                assert_eq!(f.delivered_bugs(), 0.006031579168349364);
+            },
+        );
+
+        // Without operands, the volume is not defined
+        check_func_space::<PythonParser, _>("", "foo.py", |func_space| {
+            let halstead = &func_space.metrics.halstead;
+            assert!(halstead.volume().is_nan());
+            assert_eq!(halstead.delivered_bugs(), 0.);
+        });
+    }
+
+    #[test]
+    fn rust_frequencies() {
+        let path = PathBuf::from("foo.rs");
+        let source = "fn f(a: i32) -> i32 {
+            let b = a + a * 2;
+            b + 1
+        }";
+        let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+
+        let default = metrics(&parser, &path).unwrap();
+        assert!(default.metrics.halstead.operator_frequencies().is_none());
+        assert!(default.metrics.halstead.operand_frequencies().is_none());
+
+        let options = MetricsOptions {
+            halstead_frequencies: true,
+            ..Default::default()
+        };
+        let space = metrics_with_options(&parser, &path, &options).unwrap();
+        let f = &space.spaces[0].metrics.halstead;
+
+        let operators = f.operator_frequencies().unwrap();
+        assert_eq!(operators[&(Rust::PLUS as u16)], 2);
+        assert_eq!(operators[&(Rust::STAR as u16)], 1);
+        assert_eq!(operators[&(Rust::Let as u16)], 1);
+        assert_eq!(operators[&(Rust::EQ as u16)], 1);
+        assert_eq!(operators.values().sum::<u64>() as f64, f.operators());
+
+        let operands = f.operand_frequencies().unwrap();
+        assert_eq!(operands["a"], 3);
+        assert_eq!(operands["b"], 2);
+        assert_eq!(operands["2"], 1);
+        assert_eq!(operands["1"], 1);
+        assert_eq!(operands.len() as f64, f.u_operands());
+
+        // The frequencies are not serialized
+        let json = serde_json::to_value(f).unwrap();
+        assert_eq!(json.as_object().unwrap().len(), 15);
+    }
 }
@@ -0,0 +1,382 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `LiteralNesting` metric.
+///
+/// This metric computes the maximum nesting depth of the literal data
+/// structures contained in a function/method, such as the objects and
+/// the arrays of a configuration, so `{"a": [{"b": [1]}]}` has a depth of 4.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    literal_nesting: usize,
+    literal_nesting_sum: usize,
+    literal_nesting_min: usize,
+    literal_nesting_max: usize,
+    space_count: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            literal_nesting: 0,
+            literal_nesting_sum: 0,
+            literal_nesting_min: usize::MAX,
+            literal_nesting_max: 0,
+            space_count: 1,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("literal_nesting", 3)?;
+        st.serialize_field("average", &self.literal_nesting_average())?;
+        st.serialize_field("min", &self.literal_nesting_min())?;
+        st.serialize_field("max", &self.literal_nesting_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "average: {}, min: {}, max: {}",
+            self.literal_nesting_average(),
+            self.literal_nesting_min(),
+            self.literal_nesting_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `LiteralNesting` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.literal_nesting_max = self.literal_nesting_max.max(other.literal_nesting_max);
+        self.literal_nesting_min = self.literal_nesting_min.min(other.literal_nesting_min);
+        self.literal_nesting_sum += other.literal_nesting_sum;
+        self.space_count += other.space_count;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `LiteralNesting` metric value of a space
+    pub fn literal_nesting(&self) -> f64 {
+        self.literal_nesting as f64
+    }
+
+    /// Returns the `LiteralNesting` metric average value
+    ///
+    /// This value is computed dividing the sum of the `LiteralNesting`
+    /// values for the number of spaces.
+    pub fn literal_nesting_average(&self) -> f64 {
+        self.literal_nesting_sum as f64 / self.space_count as f64
+    }
+
+    /// Returns the `LiteralNesting` metric minimum value
+    pub fn literal_nesting_min(&self) -> f64 {
+        self.literal_nesting_min as f64
+    }
+
+    /// Returns the `LiteralNesting` metric maximum value
+    pub fn literal_nesting_max(&self) -> f64 {
+        self.literal_nesting_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.literal_nesting_sum += self.literal_nesting;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.literal_nesting_max = self.literal_nesting_max.max(self.literal_nesting);
+        self.literal_nesting_min = self.literal_nesting_min.min(self.literal_nesting);
+        self.compute_sum();
+    }
+
+    // Enables the `LiteralNesting` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `LiteralNesting` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait LiteralNesting
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+// Computes the nesting depth of a literal walking up its ancestors
+// until the boundary of the function/closure containing it.
+#[inline(always)]
+fn compute_depth<T: Checker>(node: &Node, stats: &mut Stats, is_literal: fn(u16) -> bool) {
+    if !is_literal(node.kind_id()) {
+        return;
+    }
+
+    let mut depth = 1;
+    let mut child = *node;
+    while let Some(parent) = child.parent() {
+        if T::is_func(&parent) || T::is_closure(&parent) {
+            break;
+        }
+        if is_literal(parent.kind_id()) {
+            depth += 1;
+        }
+        child = parent;
+    }
+
+    stats.literal_nesting = stats.literal_nesting.max(depth);
+}
+
+impl LiteralNesting for PythonCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Python::*;
+
+        compute_depth::<Self>(node, stats, |id| {
+            matches!(id.into(), Dictionary | List | Tuple | Set)
+        });
+    }
+}
+
+impl LiteralNesting for MozjsCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Mozjs::*;
+
+        compute_depth::<Self>(node, stats, |id| matches!(id.into(), Object | Array));
+    }
+}
+
+impl LiteralNesting for JavascriptCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Javascript::*;
+
+        compute_depth::<Self>(node, stats, |id| matches!(id.into(), Object | Array));
+    }
+}
+
+impl LiteralNesting for TypescriptCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Typescript::*;
+
+        compute_depth::<Self>(node, stats, |id| matches!(id.into(), Object | Array));
+    }
+}
+
+impl LiteralNesting for TsxCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Tsx::*;
+
+        compute_depth::<Self>(node, stats, |id| matches!(id.into(), Object | Array));
+    }
+}
+
+impl LiteralNesting for RustCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Rust::*;
+
+        compute_depth::<Self>(node, stats, |id| {
+            matches!(
+                id.into(),
+                ArrayExpression | TupleExpression | StructExpression
+            )
+        });
+    }
+}
+
+impl LiteralNesting for CppCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_depth::<Self>(node, stats, |id| id == Cpp::InitializerList);
+    }
+}
+
+impl LiteralNesting for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_depth::<Self>(node, stats, |id| id == Java::ArrayInitializer);
+    }
+}
+
+impl LiteralNesting for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Csharp::*;
+
+        compute_depth::<Self>(node, stats, |id| {
+            matches!(
+                id.into(),
+                InitializerExpression | AnonymousObjectCreationExpression | TupleExpression
+            )
+        });
+    }
+}
+
+implement_metric_trait!(
+    LiteralNesting,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics_with_options;
+
+    use super::*;
+
+    fn literal_nesting_options() -> MetricsOptions {
+        MetricsOptions {
+            literal_nesting: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn python_nested_literal() {
+        check_metrics_with_options::<PythonParser>(
+            "def config():
+                 return {  # 1
+                     'servers': [  # 2
+                         {  # 3
+                             'ports': [80, 443],  # 4
+                             'name': 'a',
+                         },
+                     ],
+                     'debug': (True, False),  # 2
+                 }",
+            "foo.py",
+            &literal_nesting_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.literal_nesting,
+                    @r###"
+                    {
+                      "average": 2.0,
+                      "min": 0.0,
+                      "max": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_nested_literal() {
+        check_metrics_with_options::<JavascriptParser>(
+            "function config() {
+                 return {servers: [{ports: [80, 443]}]}; // 4
+             }
+             function flat() {
+                 return [1, 2, 3]; // 1
+             }",
+            "foo.js",
+            &literal_nesting_options(),
+            |metric| {
+                // nspace = 3 (2 functions and unit)
+                insta::assert_json_snapshot!(
+                    metric.literal_nesting,
+                    @r###"
+                    {
+                      "average": 1.6666666666666667,
+                      "min": 0.0,
+                      "max": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_nested_literal() {
+        check_metrics_with_options::<RustParser>(
+            "fn f() -> Config {
+                 Config { // 1
+                     ports: [(80, true), (443, false)], // 3
+                     name: String::new(),
+                 }
+             }",
+            "foo.rs",
+            &literal_nesting_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.literal_nesting,
+                    @r###"
+                    {
+                      "average": 1.5,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn closure_literal() {
+        // A closure is a space of its own, so its literals
+        // do not nest into the ones containing it
+        check_metrics_with_options::<JavascriptParser>(
+            "function f() {
+                 return [[() => [[1]]]]; // 2
+             }",
+            "foo.js",
+            &literal_nesting_options(),
+            |metric| {
+                // nspace = 3 (function, closure and unit)
+                insta::assert_json_snapshot!(
+                    metric.literal_nesting,
+                    @r###"
+                    {
+                      "average": 1.3333333333333333,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_nested_literal() {
+        check_metrics_with_options::<CppParser>(
+            "void f() {
+                 int a[2][2][2] = {{{1, 2}, {3, 4}}, {{5, 6}, {7, 8}}}; // 3
+             }",
+            "foo.cpp",
+            &literal_nesting_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.literal_nesting,
+                    @r###"
+                    {
+                      "average": 1.5,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+}
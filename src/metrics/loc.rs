@@ -1,15 +1,16 @@
 use std::collections::HashSet;
 
 use crate::checker::Checker;
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::macros::implement_metric_trait;
 use crate::*;
 
 /// The `SLoc` metric suite.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Sloc {
     start: usize,
     end: usize,
@@ -72,7 +73,8 @@ impl Sloc {
 }
 
 /// The `PLoc` metric suite.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Ploc {
     lines: HashSet<usize>,
     ploc_min: usize,
@@ -130,7 +132,8 @@ impl Ploc {
 }
 
 /// The `CLoc` metric suite.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Cloc {
     only_comment_lines: usize,
     code_comment_lines: usize,
@@ -191,7 +194,8 @@ impl Cloc {
 }
 
 /// The `LLoc` metric suite.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Lloc {
     logical_lines: usize,
     lloc_min: usize,
@@ -246,11 +250,16 @@ impl Lloc {
 }
 
 /// The `Loc` metric suite.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
+    #[serde(with = "Sloc")]
     sloc: Sloc,
+    #[serde(with = "Ploc")]
     ploc: Ploc,
+    #[serde(with = "Cloc")]
     cloc: Cloc,
+    #[serde(with = "Lloc")]
     lloc: Lloc,
     space_count: usize,
     blank_min: usize,
@@ -505,6 +514,8 @@ pub trait Loc
 where
     Self: Checker,
 {
+    const IMPLEMENTED: bool = true;
+
     fn compute(node: &Node, stats: &mut Stats, is_func_space: bool, is_unit: bool);
 }
 
@@ -562,6 +573,17 @@ fn check_comment_ends_on_code_line(stats: &mut Stats, start_code_line: usize) {
     }
 }
 
+#[inline(always)]
+// Adds all the lines of a string literal spanning many lines to the
+// code lines, blank ones included, since they are part of its value
+// and not blank lines of the code.
+fn add_string_lines(stats: &mut Stats, start: usize, end: usize) {
+    check_comment_ends_on_code_line(stats, start);
+    (start..=end).for_each(|line| {
+        stats.ploc.lines.insert(line);
+    });
+}
+
 impl Loc for PythonCode {
     fn compute(node: &Node, stats: &mut Stats, is_func_space: bool, is_unit: bool) {
         use Python::*;
@@ -577,9 +599,8 @@ impl Loc for PythonCode {
                 let parent = node.parent().unwrap();
                 if let ExpressionStatement = parent.kind_id().into() {
                     add_cloc_lines(stats, start, end);
-                } else if parent.start_row() != start {
-                    check_comment_ends_on_code_line(stats, start);
-                    stats.ploc.lines.insert(start);
+                } else {
+                    add_string_lines(stats, start, end);
                 }
             }
             Statement
@@ -625,6 +646,9 @@ impl Loc for MozjsCode {
             Comment => {
                 add_cloc_lines(stats, start, end);
             }
+            TemplateString => {
+                add_string_lines(stats, start, end);
+            }
             ExpressionStatement | ExportStatement | ImportStatement | StatementBlock
             | IfStatement | SwitchStatement | ForStatement | ForInStatement | WhileStatement
             | DoStatement | TryStatement | WithStatement | BreakStatement | ContinueStatement
@@ -651,6 +675,9 @@ impl Loc for JavascriptCode {
             Comment => {
                 add_cloc_lines(stats, start, end);
             }
+            TemplateString => {
+                add_string_lines(stats, start, end);
+            }
             ExpressionStatement | ExportStatement | ImportStatement | StatementBlock
             | IfStatement | SwitchStatement | ForStatement | ForInStatement | WhileStatement
             | DoStatement | TryStatement | WithStatement | BreakStatement | ContinueStatement
@@ -677,6 +704,9 @@ impl Loc for TypescriptCode {
             Comment => {
                 add_cloc_lines(stats, start, end);
             }
+            TemplateString => {
+                add_string_lines(stats, start, end);
+            }
             ExpressionStatement | ExportStatement | ImportStatement | StatementBlock
             | IfStatement | SwitchStatement | ForStatement | ForInStatement | WhileStatement
             | DoStatement | TryStatement | WithStatement | BreakStatement | ContinueStatement
@@ -703,6 +733,9 @@ impl Loc for TsxCode {
             Comment => {
                 add_cloc_lines(stats, start, end);
             }
+            TemplateString => {
+                add_string_lines(stats, start, end);
+            }
             ExpressionStatement | ExportStatement | ImportStatement | StatementBlock
             | IfStatement | SwitchStatement | ForStatement | ForInStatement | WhileStatement
             | DoStatement | TryStatement | WithStatement | BreakStatement | ContinueStatement
@@ -725,9 +758,7 @@ impl Loc for RustCode {
         let (start, end) = init(node, stats, is_func_space, is_unit);
 
         match node.kind_id().into() {
-            StringLiteral
-            | RawStringLiteral
-            | Block
+            Block
             | SourceFile
             | SLASH
             | SLASHSLASH
@@ -738,6 +769,9 @@ impl Loc for RustCode {
             | DocComment
             | InnerDocCommentMarker
             | BANG => {}
+            StringLiteral | RawStringLiteral => {
+                add_string_lines(stats, start, end);
+            }
             BlockComment => {
                 add_cloc_lines(stats, start, end);
             }
@@ -752,14 +786,19 @@ impl Loc for RustCode {
                 };
                 add_cloc_lines(stats, start, end);
             }
-            Statement
-            | EmptyStatement
-            | ExpressionStatement
-            | LetDeclaration
-            | AssignmentExpression
-            | CompoundAssignmentExpr => {
+            Statement | EmptyStatement | ExpressionStatement | LetDeclaration => {
                 stats.lloc.logical_lines += 1;
             }
+            AssignmentExpression | CompoundAssignmentExpr => {
+                // An assignment followed by `;` is already counted
+                // as an expression statement
+                if node
+                    .parent()
+                    .is_none_or(|parent| parent.kind_id() != ExpressionStatement)
+                {
+                    stats.lloc.logical_lines += 1;
+                }
+            }
             _ => {
                 check_comment_ends_on_code_line(stats, start);
                 stats.ploc.lines.insert(start);
@@ -775,8 +814,10 @@ impl Loc for CppCode {
         let (start, end) = init(node, stats, is_func_space, is_unit);
 
         match node.kind_id().into() {
-            RawStringLiteral | StringLiteral | DeclarationList | FieldDeclarationList
-            | TranslationUnit => {}
+            DeclarationList | FieldDeclarationList | TranslationUnit => {}
+            RawStringLiteral | StringLiteral => {
+                add_string_lines(stats, start, end);
+            }
             Comment => {
                 add_cloc_lines(stats, start, end);
             }
@@ -830,6 +871,9 @@ impl Loc for JavaCode {
             LineComment | BlockComment => {
                 add_cloc_lines(stats, start, end);
             }
+            StringLiteral => {
+                add_string_lines(stats, start, end);
+            }
             AssertStatement | BreakStatement | ContinueStatement | DoStatement
             | EnhancedForStatement | ExpressionStatement | ForStatement | IfStatement
             | ReturnStatement | SwitchExpression | ThrowStatement | TryStatement
@@ -856,11 +900,114 @@ impl Loc for JavaCode {
     }
 }
 
-implement_metric_trait!(Loc, PreprocCode, CcommentCode, KotlinCode);
+impl Loc for GoCode {
+    fn compute(node: &Node, stats: &mut Stats, is_func_space: bool, is_unit: bool) {
+        use crate::languages::Go::*;
+
+        let (start, end) = init(node, stats, is_func_space, is_unit);
+
+        match node.kind_id().into() {
+            SourceFile => {}
+            Comment => {
+                add_cloc_lines(stats, start, end);
+            }
+            RawStringLiteral | InterpretedStringLiteral => {
+                add_string_lines(stats, start, end);
+            }
+            IfStatement
+            | ForStatement
+            | ExpressionSwitchStatement
+            | TypeSwitchStatement
+            | SelectStatement
+            | ReturnStatement
+            | BreakStatement
+            | ContinueStatement
+            | GotoStatement
+            | FallthroughStatement
+            | GoStatement
+            | DeferStatement
+            | VarDeclaration
+            | ConstDeclaration => {
+                stats.lloc.logical_lines += 1;
+            }
+            ExpressionStatement | SendStatement | IncStatement | DecStatement
+            | AssignmentStatement | ShortVarDeclaration => {
+                // The simple statements in the header of a statement,
+                // such as the initializer of a `for` loop, are not counted
+                if node.parent().is_none_or(|parent| {
+                    !matches!(
+                        parent.kind_id().into(),
+                        ForClause
+                            | IfStatement
+                            | ExpressionSwitchStatement
+                            | TypeSwitchStatement
+                            | CommunicationCase
+                    )
+                }) {
+                    stats.lloc.logical_lines += 1;
+                }
+            }
+            _ => {
+                check_comment_ends_on_code_line(stats, start);
+                stats.ploc.lines.insert(start);
+            }
+        }
+    }
+}
+
+impl Loc for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats, is_func_space: bool, is_unit: bool) {
+        use Csharp::*;
+
+        let (start, end) = init(node, stats, is_func_space, is_unit);
+        // LLOC in C# is counted for statements only, while the initializer
+        // of a for loop is a variable declaration and not a statement
+        match node.kind_id().into() {
+            CompilationUnit => {}
+            Comment => {
+                add_cloc_lines(stats, start, end);
+            }
+            StringLiteral
+            | VerbatimStringLiteral
+            | RawStringLiteral
+            | InterpolatedStringExpression => {
+                add_string_lines(stats, start, end);
+            }
+            BreakStatement
+            | CheckedStatement
+            | ContinueStatement
+            | DoStatement
+            | ExpressionStatement
+            | FixedStatement
+            | ForStatement
+            | ForeachStatement
+            | GotoStatement
+            | IfStatement
+            | LocalDeclarationStatement
+            | LockStatement
+            | ReturnStatement
+            | SwitchStatement
+            | ThrowStatement
+            | TryStatement
+            | UnsafeStatement
+            | UsingStatement
+            | WhileStatement
+            | YieldStatement => {
+                stats.lloc.logical_lines += 1;
+            }
+            _ => {
+                check_comment_ends_on_code_line(stats, start);
+                stats.ploc.lines.insert(start);
+            }
+        }
+    }
+}
+
+implement_metric_trait!(Loc, PreprocCode, CcommentCode, HclCode, KotlinCode);
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::check_metrics;
+    use crate::tools::{check_func_space, check_metrics};
 
     use super::*;
 
@@ -3481,4 +3628,182 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn python_multiline_string_blank() {
+        // The blank lines of a string are code lines,
+        // while the ones of a docstring are comment lines
+        check_func_space::<PythonParser, _>(
+            "def f():
+    s = \"\"\"a
+
+b\"\"\"
+    \"\"\"
+    doc
+
+    \"\"\"
+    return s",
+            "foo.py",
+            |func_space| {
+                let loc = &func_space.spaces[0].metrics.loc;
+                assert_eq!(loc.sloc(), 9.);
+                assert_eq!(loc.ploc(), 5.);
+                assert_eq!(loc.cloc(), 4.);
+                assert_eq!(loc.blank(), 0.);
+            },
+        );
+    }
+
+    #[test]
+    fn multiline_string_and_block_comment_blank() {
+        let source = "function f() {
+    const s = `a
+
+b`;
+    /* c
+
+       d */
+    return s;
+}";
+        let check = |func_space: FuncSpace| {
+            let loc = &func_space.spaces[0].metrics.loc;
+            assert_eq!(loc.sloc(), 9.);
+            assert_eq!(loc.ploc(), 6.);
+            assert_eq!(loc.cloc(), 3.);
+            assert_eq!(loc.blank(), 0.);
+        };
+        check_func_space::<JavascriptParser, _>(source, "foo.js", check);
+        check_func_space::<RustParser, _>(
+            "fn f() -> &'static str {
+    let s = \"a
+
+b\";
+    /* c
+
+       d */
+    s
+}",
+            "foo.rs",
+            check,
+        );
+        check_func_space::<CppParser, _>(
+            "const char *f() {
+    const char *s = R\"(a
+
+b)\";
+    /* c
+
+       d */
+    return s;
+}",
+            "foo.cpp",
+            check,
+        );
+    }
+
+    #[test]
+    fn python_statements_lloc() {
+        check_metrics::<PythonParser>("a = 1; b = 2", "foo.py", |metric| {
+            // Two statements on the same line
+            assert_eq!(metric.loc.lloc(), 2.);
+            assert_eq!(metric.loc.ploc(), 1.);
+        });
+        check_metrics::<PythonParser>(
+            "f(1,
+               2,
+               3)",
+            "foo.py",
+            |metric| {
+                // A statement spanning three lines
+                assert_eq!(metric.loc.lloc(), 1.);
+                assert_eq!(metric.loc.ploc(), 3.);
+            },
+        );
+    }
+
+    #[test]
+    fn statements_lloc() {
+        let check = |metric: CodeMetrics| {
+            // `a = 1; b = 2;` on one line and a call spanning three lines
+            assert_eq!(metric.loc.lloc(), 3.);
+        };
+        check_metrics::<CppParser>(
+            "void g() {
+                 a = 1; b = 2;
+                 f(1,
+                   2,
+                   3);
+             }",
+            "foo.cpp",
+            check,
+        );
+        check_metrics::<RustParser>(
+            "fn g() {
+                 a = 1; b = 2;
+                 f(1,
+                   2,
+                   3);
+             }",
+            "foo.rs",
+            check,
+        );
+        check_metrics::<JavaParser>(
+            "class A {
+                 void g() {
+                     a = 1; b = 2;
+                     f(1,
+                       2,
+                       3);
+                 }
+             }",
+            "foo.java",
+            check,
+        );
+    }
+
+    #[test]
+    fn go_lloc() {
+        check_metrics::<GoParser>(
+            "package main
+
+             // Sum returns the sum
+             func Sum(v []int) int {
+                 s := 0 /* total */
+                 for _, x := range v {
+                     s += x
+                 }
+                 return s
+             }",
+            "foo.go",
+            |metric| {
+                // Spaces: 2
+                insta::assert_json_snapshot!(
+                    metric.loc,
+                    @r###"
+                    {
+                      "sloc": 10.0,
+                      "ploc": 8.0,
+                      "lloc": 4.0,
+                      "cloc": 2.0,
+                      "blank": 1.0,
+                      "sloc_average": 5.0,
+                      "ploc_average": 4.0,
+                      "lloc_average": 2.0,
+                      "cloc_average": 1.0,
+                      "blank_average": 0.5,
+                      "sloc_min": 7.0,
+                      "sloc_max": 7.0,
+                      "cloc_min": 1.0,
+                      "cloc_max": 1.0,
+                      "ploc_min": 7.0,
+                      "ploc_max": 7.0,
+                      "lloc_min": 4.0,
+                      "lloc_max": 4.0,
+                      "blank_min": 0.0,
+                      "blank_max": 0.0
+                    }"###
+                );
+            },
+        );
+    }
 }
@@ -1,5 +1,5 @@
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use super::cyclomatic;
@@ -11,14 +11,34 @@ use crate::macros::implement_metric_trait;
 
 use crate::*;
 
+/// The formula used to compute the `Mi` metric.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MiVariant {
+    /// The original formula, whose value is not normalized
+    #[default]
+    Original,
+    /// The derivative formula employed by the Software Engineering Institute
+    Sei,
+    /// The derivative formula employed by Microsoft Visual Studio,
+    /// normalized between 0 and 100
+    VisualStudio,
+}
+
 /// The `Mi` metric.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
+    #[serde(with = "crate::cache::float_bits")]
     halstead_length: f64,
+    #[serde(with = "crate::cache::float_bits")]
     halstead_vocabulary: f64,
+    #[serde(with = "crate::cache::float_bits")]
     halstead_volume: f64,
+    #[serde(with = "crate::cache::float_bits")]
     cyclomatic: f64,
+    #[serde(with = "crate::cache::float_bits")]
     sloc: f64,
+    #[serde(with = "crate::cache::float_bits")]
     comments_percentage: f64,
 }
 
@@ -50,6 +70,15 @@ impl fmt::Display for Stats {
 impl Stats {
     pub(crate) fn merge(&mut self, _other: &Stats) {}
 
+    /// Returns the `Mi` metric calculated using the formula of `variant`.
+    pub fn mi(&self, variant: MiVariant) -> f64 {
+        match variant {
+            MiVariant::Original => self.mi_original(),
+            MiVariant::Sei => self.mi_sei(),
+            MiVariant::VisualStudio => self.mi_visual_studio(),
+        }
+    }
+
     /// Returns the `Mi` metric calculated using the original formula.
     ///
     /// Its value can be negative.
@@ -87,6 +116,8 @@ pub trait Mi
 where
     Self: Checker,
 {
+    const IMPLEMENTED: bool = true;
+
     fn compute(
         loc: &loc::Stats,
         cyclomatic: &cyclomatic::Stats,
@@ -114,7 +145,10 @@ implement_metric_trait!(
     PreprocCode,
     CcommentCode,
     JavaCode,
-    KotlinCode
+    HclCode,
+    KotlinCode,
+    GoCode,
+    CsharpCode
 );
 
 #[cfg(test)]
@@ -144,4 +178,30 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn mi_variants() {
+        let stats = Stats {
+            halstead_volume: 1000.,
+            cyclomatic: 10.,
+            sloc: 100.,
+            comments_percentage: 0.2,
+            ..Default::default()
+        };
+
+        // 171 - 5.2 * ln(1000) - 0.23 * 10 - 16.2 * ln(100)
+        // = 171 - 35.9203 - 2.3 - 74.6038
+        let original = stats.mi(MiVariant::Original);
+        assert!((original - 58.1759).abs() < 1e-4, "{original}");
+        assert_eq!(original, stats.mi_original());
+
+        // 58.1759 * 100 / 171
+        let visual_studio = stats.mi(MiVariant::VisualStudio);
+        assert!((visual_studio - 34.0210).abs() < 1e-4, "{visual_studio}");
+
+        // 171 - 5.2 * log2(1000) - 0.23 * 10 - 16.2 * log2(100)
+        // + 50 * sin(sqrt(0.2 * 2.4))
+        let sei = stats.mi(MiVariant::Sei);
+        assert!((sei - 41.1829).abs() < 1e-4, "{sei}");
+    }
 }
@@ -1,12 +1,31 @@
 pub mod abc;
+pub mod bare_ifs;
+pub mod captures;
+pub mod cfg;
+pub mod chain_length;
 pub mod cognitive;
+pub mod conditional_imports;
 pub mod cyclomatic;
+pub mod decisions;
+pub mod early_returns;
+pub mod enum_variants;
+pub mod exception_types;
 pub mod exit;
+pub mod expr_nesting;
+pub mod goroutines;
 pub mod halstead;
+pub mod literal_nesting;
 pub mod loc;
 pub mod mi;
 pub mod nargs;
+pub mod negations;
+pub mod nlocals;
 pub mod nom;
 pub mod npa;
 pub mod npm;
+pub mod referenced_types;
+pub mod statement_count;
+pub mod template_complexity;
+pub mod type_complexity;
+pub mod unsafe_code;
 pub mod wmc;
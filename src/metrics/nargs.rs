@@ -1,5 +1,5 @@
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::checker::Checker;
@@ -10,7 +10,12 @@ use crate::*;
 ///
 /// This metric counts the number of arguments
 /// of functions/closures.
-#[derive(Debug, Clone)]
+///
+/// The arguments are also split into the required ones, the ones with
+/// a default value and the variadic ones, such as the `Python` `*args`,
+/// since the last two do not have to be passed by the callers.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
     fn_nargs: usize,
     closure_nargs: usize,
@@ -22,6 +27,10 @@ pub struct Stats {
     closure_nargs_max: usize,
     total_functions: usize,
     total_closures: usize,
+    defaulted_nargs: usize,
+    defaulted_nargs_sum: usize,
+    variadic_nargs: usize,
+    variadic_nargs_sum: usize,
 }
 
 impl Default for Stats {
@@ -37,6 +46,10 @@ impl Default for Stats {
             closure_nargs_max: 0,
             total_functions: 0,
             total_closures: 0,
+            defaulted_nargs: 0,
+            defaulted_nargs_sum: 0,
+            variadic_nargs: 0,
+            variadic_nargs_sum: 0,
         }
     }
 }
@@ -89,6 +102,8 @@ impl Stats {
         self.fn_nargs_max = self.fn_nargs_max.max(other.fn_nargs_max);
         self.fn_nargs_sum += other.fn_nargs_sum;
         self.closure_nargs_sum += other.closure_nargs_sum;
+        self.defaulted_nargs_sum += other.defaulted_nargs_sum;
+        self.variadic_nargs_sum += other.variadic_nargs_sum;
     }
 
     /// Returns the number of function arguments in a space.
@@ -142,6 +157,45 @@ impl Stats {
     pub fn nargs_average(&self) -> f64 {
         self.nargs_total() / (self.total_functions + self.total_closures).max(1) as f64
     }
+    /// Returns the number of required arguments of the functions
+    /// and closures in a space.
+    #[inline(always)]
+    pub fn required_args(&self) -> f64 {
+        (self.fn_nargs + self.closure_nargs - self.defaulted_nargs - self.variadic_nargs) as f64
+    }
+
+    /// Returns the number of required arguments sum in a space.
+    #[inline(always)]
+    pub fn required_args_sum(&self) -> f64 {
+        self.nargs_total() - self.defaulted_args_sum() - self.variadic_args_sum()
+    }
+
+    /// Returns the number of arguments with a default value
+    /// of the functions and closures in a space.
+    #[inline(always)]
+    pub fn defaulted_args(&self) -> f64 {
+        self.defaulted_nargs as f64
+    }
+
+    /// Returns the number of arguments with a default value sum in a space.
+    #[inline(always)]
+    pub fn defaulted_args_sum(&self) -> f64 {
+        self.defaulted_nargs_sum as f64
+    }
+
+    /// Returns the number of variadic arguments of the functions
+    /// and closures in a space.
+    #[inline(always)]
+    pub fn variadic_args(&self) -> f64 {
+        self.variadic_nargs as f64
+    }
+
+    /// Returns the number of variadic arguments sum in a space.
+    #[inline(always)]
+    pub fn variadic_args_sum(&self) -> f64 {
+        self.variadic_nargs_sum as f64
+    }
+
     /// Returns the minimum number of function arguments in a space.
     #[inline(always)]
     pub fn fn_args_min(&self) -> f64 {
@@ -166,6 +220,8 @@ impl Stats {
     pub(crate) fn compute_sum(&mut self) {
         self.closure_nargs_sum += self.closure_nargs;
         self.fn_nargs_sum += self.fn_nargs;
+        self.defaulted_nargs_sum += self.defaulted_nargs;
+        self.variadic_nargs_sum += self.variadic_nargs;
     }
     #[inline(always)]
     pub(crate) fn compute_minmax(&mut self) {
@@ -182,12 +238,22 @@ impl Stats {
 }
 
 #[inline(always)]
-fn compute_args<T: Checker>(node: &Node, nargs: &mut usize) {
+fn compute_args<T: Checker>(node: &Node, stats: &mut Stats, closure: bool) {
     if let Some(params) = node.child_by_field_name("parameters") {
         let node_params = params;
         node_params.act_on_child(&mut |n| {
-            if !T::is_non_arg(n) {
-                *nargs += 1;
+            if T::is_non_arg(n) {
+                return;
+            }
+            if closure {
+                stats.closure_nargs += 1;
+            } else {
+                stats.fn_nargs += 1;
+            }
+            if T::is_defaulted_arg(n) {
+                stats.defaulted_nargs += 1;
+            } else if T::is_variadic_arg(n) {
+                stats.variadic_nargs += 1;
             }
         });
     }
@@ -198,14 +264,16 @@ where
     Self: Checker,
     Self: std::marker::Sized,
 {
+    const IMPLEMENTED: bool = true;
+
     fn compute(node: &Node, stats: &mut Stats) {
         if Self::is_func(node) {
-            compute_args::<Self>(node, &mut stats.fn_nargs);
+            compute_args::<Self>(node, stats, false);
             return;
         }
 
         if Self::is_closure(node) {
-            compute_args::<Self>(node, &mut stats.closure_nargs);
+            compute_args::<Self>(node, stats, true);
         }
     }
 }
@@ -215,7 +283,7 @@ impl NArgs for CppCode {
         if Self::is_func(node) {
             if let Some(declarator) = node.child_by_field_name("declarator") {
                 let new_node = declarator;
-                compute_args::<Self>(&new_node, &mut stats.fn_nargs);
+                compute_args::<Self>(&new_node, stats, false);
             }
             return;
         }
@@ -223,12 +291,82 @@ impl NArgs for CppCode {
         if Self::is_closure(node) {
             if let Some(declarator) = node.child_by_field_name("declarator") {
                 let new_node = declarator;
-                compute_args::<Self>(&new_node, &mut stats.closure_nargs);
+                compute_args::<Self>(&new_node, stats, true);
+            }
+        }
+    }
+}
+
+impl NArgs for GoCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        let closure = Self::is_closure(node);
+        if !closure && !Self::is_func(node) {
+            return;
+        }
+        // The receiver of a method and the results are not arguments
+        let Some(params) = node.child_by_field_name("parameters") else {
+            return;
+        };
+
+        for param in params.children() {
+            let args = match param.kind_id().into() {
+                // A declaration such as `a, b int` declares several arguments,
+                // while the arguments of a declaration such as `func(int)`
+                // have no names
+                Go::ParameterDeclaration => param
+                    .children()
+                    .filter(|child| child.kind_id() == Go::Identifier)
+                    .count()
+                    .max(1),
+                Go::VariadicParameterDeclaration => {
+                    stats.variadic_nargs += 1;
+                    1
+                }
+                _ => continue,
+            };
+            if closure {
+                stats.closure_nargs += args;
+            } else {
+                stats.fn_nargs += args;
             }
         }
     }
 }
 
+impl NArgs for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        let closure = Self::is_closure(node);
+        if !closure && !Self::is_func(node) {
+            return;
+        }
+        let Some(params) = node.child_by_field_name("parameters") else {
+            return;
+        };
+
+        // The parameter of a lambda such as `x => x + 1` is not in a list
+        let args = if params.kind_id() == Csharp::ImplicitParameter {
+            1
+        } else {
+            params
+                .children()
+                .filter(|param| !Self::is_non_arg(param))
+                .inspect(|param| {
+                    if Self::is_defaulted_arg(param) {
+                        stats.defaulted_nargs += 1;
+                    } else if Self::is_variadic_arg(param) {
+                        stats.variadic_nargs += 1;
+                    }
+                })
+                .count()
+        };
+        if closure {
+            stats.closure_nargs += args;
+        } else {
+            stats.fn_nargs += args;
+        }
+    }
+}
+
 implement_metric_trait!(
     [NArgs],
     PythonCode,
@@ -240,6 +378,7 @@ implement_metric_trait!(
     PreprocCode,
     CcommentCode,
     JavaCode,
+    HclCode,
     KotlinCode
 );
 
@@ -947,4 +1086,71 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn python_defaulted_and_variadic_args() {
+        check_metrics::<PythonParser>(
+            "def f(a, b=1, *args, **kw):
+                 pass
+             def g(a, *, b, c: int = 3):
+                 pass",
+            "foo.py",
+            |metric| {
+                // The keyword separator of g is not an argument
+                assert_eq!(metric.nargs.fn_args_sum(), 7.);
+                assert_eq!(metric.nargs.required_args_sum(), 3.);
+                assert_eq!(metric.nargs.defaulted_args_sum(), 2.);
+                assert_eq!(metric.nargs.variadic_args_sum(), 2.);
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_defaulted_and_variadic_args() {
+        check_metrics::<CppParser>(
+            "void g(int a, int b = 2) {}
+             int log(const char *format, ...) { return 0; }",
+            "foo.cpp",
+            |metric| {
+                assert_eq!(metric.nargs.fn_args_sum(), 4.);
+                assert_eq!(metric.nargs.required_args_sum(), 2.);
+                assert_eq!(metric.nargs.defaulted_args_sum(), 1.);
+                assert_eq!(metric.nargs.variadic_args_sum(), 1.);
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_defaulted_and_variadic_args() {
+        check_metrics::<TypescriptParser>(
+            "function f(a: number, b = 1, c?: number, ...rest: number[]) {}",
+            "foo.ts",
+            |metric| {
+                assert_eq!(metric.nargs.fn_args_sum(), 4.);
+                assert_eq!(metric.nargs.required_args_sum(), 1.);
+                assert_eq!(metric.nargs.defaulted_args_sum(), 2.);
+                assert_eq!(metric.nargs.variadic_args_sum(), 1.);
+            },
+        );
+    }
+
+    #[test]
+    fn go_variadic_args() {
+        check_metrics::<GoParser>(
+            "func (s *S) f(a, b int, rest ...string) (int, error) {
+                 g := func(x, y int) {}
+                 g(a, b)
+                 return 0, nil
+             }",
+            "foo.go",
+            |metric| {
+                // The receiver and the results are not arguments
+                assert_eq!(metric.nargs.fn_args_sum(), 3.);
+                assert_eq!(metric.nargs.closure_args_sum(), 2.);
+                assert_eq!(metric.nargs.required_args_sum(), 4.);
+                assert_eq!(metric.nargs.defaulted_args_sum(), 0.);
+                assert_eq!(metric.nargs.variadic_args_sum(), 1.);
+            },
+        );
+    }
 }
@@ -0,0 +1,371 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `Negations` metric.
+///
+/// This metric counts the number of boolean negations of a function/method,
+/// such as `!a` or `not a`, which make the conditions harder to read.
+///
+/// A double negation, such as `!!a` or `!(!a)`, is a negation of another
+/// negation, so `!!a` counts two negations and one double negation.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    negations: usize,
+    negations_sum: usize,
+    negations_min: usize,
+    negations_max: usize,
+    double_negations: usize,
+    double_negations_sum: usize,
+    space_count: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            negations: 0,
+            negations_sum: 0,
+            negations_min: usize::MAX,
+            negations_max: 0,
+            double_negations: 0,
+            double_negations_sum: 0,
+            space_count: 1,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("negations", 5)?;
+        st.serialize_field("sum", &self.negations_sum())?;
+        st.serialize_field("average", &self.negations_average())?;
+        st.serialize_field("min", &self.negations_min())?;
+        st.serialize_field("max", &self.negations_max())?;
+        st.serialize_field("double", &self.double_negations_sum())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}, double: {}",
+            self.negations_sum(),
+            self.negations_average(),
+            self.negations_min(),
+            self.negations_max(),
+            self.double_negations_sum()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `Negations` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.negations_max = self.negations_max.max(other.negations_max);
+        self.negations_min = self.negations_min.min(other.negations_min);
+        self.negations_sum += other.negations_sum;
+        self.double_negations_sum += other.double_negations_sum;
+        self.space_count += other.space_count;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `Negations` metric value of a space
+    pub fn negations(&self) -> f64 {
+        self.negations as f64
+    }
+
+    /// Returns the `Negations` metric sum value
+    pub fn negations_sum(&self) -> f64 {
+        self.negations_sum as f64
+    }
+
+    /// Returns the `Negations` metric average value
+    ///
+    /// This value is computed dividing the sum of the `Negations`
+    /// values for the number of spaces.
+    pub fn negations_average(&self) -> f64 {
+        self.negations_sum() / self.space_count as f64
+    }
+
+    /// Returns the `Negations` metric minimum value
+    pub fn negations_min(&self) -> f64 {
+        self.negations_min as f64
+    }
+
+    /// Returns the `Negations` metric maximum value
+    pub fn negations_max(&self) -> f64 {
+        self.negations_max as f64
+    }
+
+    /// Returns the number of double negations of a space
+    pub fn double_negations(&self) -> f64 {
+        self.double_negations as f64
+    }
+
+    /// Returns the sum of the double negations
+    pub fn double_negations_sum(&self) -> f64 {
+        self.double_negations_sum as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.negations_sum += self.negations;
+        self.double_negations_sum += self.double_negations;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.negations_max = self.negations_max.max(self.negations);
+        self.negations_min = self.negations_min.min(self.negations);
+        self.compute_sum();
+    }
+
+    // Enables the `Negations` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `Negations` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait Negations
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+// Counts a negation, and a double negation when its operand,
+// without the parentheses around it, is a negation too.
+#[inline(always)]
+fn compute_negation(
+    node: &Node,
+    stats: &mut Stats,
+    is_negation: fn(&Node) -> bool,
+    is_parenthesized: fn(u16) -> bool,
+) {
+    if !is_negation(node) {
+        return;
+    }
+    stats.negations += 1;
+
+    let mut operand = node.child(node.child_count().saturating_sub(1));
+    while let Some(parenthesized) = operand.filter(|o| is_parenthesized(o.kind_id())) {
+        operand = parenthesized.child(1);
+    }
+    if operand.is_some_and(|operand| is_negation(&operand)) {
+        stats.double_negations += 1;
+    }
+}
+
+impl Negations for PythonCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_negation(
+            node,
+            stats,
+            |node| node.kind_id() == Python::NotOperator,
+            |id| id == Python::ParenthesizedExpression,
+        );
+    }
+}
+
+macro_rules! unary_negation {
+    ($lang:ident) => {
+        fn compute(node: &Node, stats: &mut Stats) {
+            // The `!` token is also used elsewhere, such as in Rust macros
+            compute_negation(
+                node,
+                stats,
+                |node| {
+                    node.kind_id() == $lang::UnaryExpression
+                        && node.child(0).is_some_and(|op| op.kind_id() == $lang::BANG)
+                },
+                |id| id == $lang::ParenthesizedExpression,
+            );
+        }
+    };
+}
+
+impl Negations for MozjsCode {
+    unary_negation!(Mozjs);
+}
+
+impl Negations for JavascriptCode {
+    unary_negation!(Javascript);
+}
+
+impl Negations for TypescriptCode {
+    unary_negation!(Typescript);
+}
+
+impl Negations for TsxCode {
+    unary_negation!(Tsx);
+}
+
+impl Negations for RustCode {
+    unary_negation!(Rust);
+}
+
+impl Negations for JavaCode {
+    unary_negation!(Java);
+}
+
+impl Negations for CppCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Cpp::*;
+
+        compute_negation(
+            node,
+            stats,
+            |node| {
+                matches!(node.kind_id().into(), UnaryExpression | UnaryExpression2)
+                    && node
+                        .child(0)
+                        .is_some_and(|op| matches!(op.kind_id().into(), BANG | Not))
+            },
+            |id| {
+                matches!(
+                    id.into(),
+                    ParenthesizedExpression | ParenthesizedExpression2
+                )
+            },
+        );
+    }
+}
+
+impl Negations for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        // The postfix `!` is the null-forgiving operator and not a negation
+        compute_negation(
+            node,
+            stats,
+            |node| {
+                node.kind_id() == Csharp::PrefixUnaryExpression
+                    && node.child(0).is_some_and(|op| op.kind_id() == Csharp::BANG)
+            },
+            |id| id == Csharp::ParenthesizedExpression,
+        );
+    }
+}
+
+implement_metric_trait!(
+    Negations,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::{check_func_space_with_options, check_metrics_with_options};
+
+    use super::*;
+
+    fn negations_options() -> MetricsOptions {
+        MetricsOptions {
+            negations: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn javascript_negations() {
+        check_metrics_with_options::<JavascriptParser>(
+            "function f(a, b) {
+                 if (!a) { // +1
+                     return !!b; // +2 (double)
+                 }
+                 return a != b;
+             }",
+            "foo.js",
+            &negations_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.negations,
+                    @r###"
+                    {
+                      "sum": 3.0,
+                      "average": 1.5,
+                      "min": 0.0,
+                      "max": 3.0,
+                      "double": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_negations() {
+        check_func_space_with_options::<RustParser, _>(
+            "fn f(a: bool, b: bool) -> bool {
+                 // The arguments of a macro are not parsed
+                 println!(\"{}\", !a);
+                 let c = !a; // +1
+                 !(!c && !b) // +3
+             }",
+            "foo.rs",
+            &negations_options(),
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.negations;
+                assert_eq!(f.negations(), 4.);
+                // `!a && !b` is not a negation
+                assert_eq!(f.double_negations(), 0.);
+            },
+        );
+    }
+
+    #[test]
+    fn python_negations() {
+        check_func_space_with_options::<PythonParser, _>(
+            "def f(a, b):
+                 if not (not a):  # +2 (double)
+                     return not b  # +1",
+            "foo.py",
+            &negations_options(),
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.negations;
+                assert_eq!(f.negations(), 3.);
+                assert_eq!(f.double_negations(), 1.);
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_negations() {
+        check_func_space_with_options::<CppParser, _>(
+            "bool f(bool a) {
+                 return !(not a); // +2 (double)
+             }",
+            "foo.cpp",
+            &negations_options(),
+            |func_space| {
+                let f = &func_space.spaces[0].metrics.negations;
+                assert_eq!(f.negations(), 2.);
+                assert_eq!(f.double_negations(), 1.);
+            },
+        );
+    }
+}
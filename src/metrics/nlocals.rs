@@ -0,0 +1,412 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `NLocals` metric.
+///
+/// This metric counts the number of local variables declared
+/// in a function/method.
+///
+/// Only the languages requiring an explicit declaration of variables,
+/// such as `let` in Rust, are supported.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    nlocals: usize,
+    nlocals_sum: usize,
+    nlocals_min: usize,
+    nlocals_max: usize,
+    space_count: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            nlocals: 0,
+            nlocals_sum: 0,
+            nlocals_min: usize::MAX,
+            nlocals_max: 0,
+            space_count: 1,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("nlocals", 4)?;
+        st.serialize_field("sum", &self.nlocals_sum())?;
+        st.serialize_field("average", &self.nlocals_average())?;
+        st.serialize_field("min", &self.nlocals_min())?;
+        st.serialize_field("max", &self.nlocals_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}",
+            self.nlocals_sum(),
+            self.nlocals_average(),
+            self.nlocals_min(),
+            self.nlocals_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `NLocals` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.nlocals_max = self.nlocals_max.max(other.nlocals_max);
+        self.nlocals_min = self.nlocals_min.min(other.nlocals_min);
+        self.nlocals_sum += other.nlocals_sum;
+        self.space_count += other.space_count;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `NLocals` metric value of a space
+    pub fn nlocals(&self) -> f64 {
+        self.nlocals as f64
+    }
+
+    /// Returns the `NLocals` metric sum value
+    pub fn nlocals_sum(&self) -> f64 {
+        self.nlocals_sum as f64
+    }
+
+    /// Returns the `NLocals` metric average value
+    ///
+    /// This value is computed dividing the sum of the `NLocals`
+    /// values for the number of spaces.
+    pub fn nlocals_average(&self) -> f64 {
+        self.nlocals_sum() / self.space_count as f64
+    }
+
+    /// Returns the `NLocals` metric minimum value
+    pub fn nlocals_min(&self) -> f64 {
+        self.nlocals_min as f64
+    }
+
+    /// Returns the `NLocals` metric maximum value
+    pub fn nlocals_max(&self) -> f64 {
+        self.nlocals_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.nlocals_sum += self.nlocals;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.nlocals_max = self.nlocals_max.max(self.nlocals);
+        self.nlocals_min = self.nlocals_min.min(self.nlocals);
+        self.compute_sum();
+    }
+
+    // Enables the `NLocals` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `NLocals` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait NLocals
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+#[inline(always)]
+fn count_children(node: &Node, is_variable: impl Fn(&Node) -> bool) -> usize {
+    node.children().filter(|child| is_variable(child)).count()
+}
+
+macro_rules! js_nlocals {
+    ($lang:ident) => {
+        fn compute(node: &Node, stats: &mut Stats) {
+            if matches!(
+                node.kind_id().into(),
+                $lang::VariableDeclaration | $lang::LexicalDeclaration
+            ) {
+                stats.nlocals +=
+                    count_children(node, |child| child.kind_id() == $lang::VariableDeclarator);
+            }
+        }
+    };
+}
+
+impl NLocals for MozjsCode {
+    js_nlocals!(Mozjs);
+}
+
+impl NLocals for JavascriptCode {
+    js_nlocals!(Javascript);
+}
+
+impl NLocals for TypescriptCode {
+    js_nlocals!(Typescript);
+}
+
+impl NLocals for TsxCode {
+    js_nlocals!(Tsx);
+}
+
+impl NLocals for RustCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Rust::*;
+
+        if node.kind_id() != LetDeclaration {
+            return;
+        }
+        let Some(pattern) = node.child_by_field_name("pattern") else {
+            return;
+        };
+
+        // Each name bound by a pattern, such as `(a, b)`, is a variable,
+        // while the paths of structs and enums variants are not
+        pattern.act_on_node(&mut |n| {
+            let is_binding = match n.kind_id().into() {
+                Identifier => n
+                    .parent()
+                    .is_none_or(|parent| match parent.kind_id().into() {
+                        ScopedIdentifier => false,
+                        TupleStructPattern | StructPattern => parent
+                            .child_by_field_name("type")
+                            .is_none_or(|path| path.id() != n.id()),
+                        _ => true,
+                    }),
+                ShorthandFieldIdentifier => true,
+                _ => false,
+            };
+            if is_binding {
+                stats.nlocals += 1;
+            }
+        });
+    }
+}
+
+impl NLocals for CppCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Cpp::*;
+
+        // Declarations of functions, such as `int f();`, are not counted
+        if node.kind_id() == Declaration {
+            stats.nlocals += count_children(node, |child| {
+                matches!(
+                    child.kind_id().into(),
+                    InitDeclarator
+                        | Identifier
+                        | PointerDeclarator
+                        | ReferenceDeclarator
+                        | ArrayDeclarator
+                )
+            });
+        }
+    }
+}
+
+impl NLocals for JavaCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        if node.kind_id() == Java::LocalVariableDeclaration {
+            stats.nlocals +=
+                count_children(node, |child| child.kind_id() == Java::VariableDeclarator);
+        }
+    }
+}
+
+impl NLocals for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Csharp::*;
+
+        // The declarations of the fields are not local
+        if node.kind_id() == VariableDeclaration
+            && node.parent().is_none_or(|parent| {
+                !matches!(
+                    parent.kind_id().into(),
+                    FieldDeclaration | EventFieldDeclaration
+                )
+            })
+        {
+            stats.nlocals += count_children(node, |child| child.kind_id() == VariableDeclarator);
+        }
+    }
+}
+
+implement_metric_trait!(
+    NLocals,
+    PythonCode,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::{check_func_space_with_options, check_metrics_with_options};
+
+    use super::*;
+
+    fn nlocals_options() -> MetricsOptions {
+        MetricsOptions {
+            nlocals: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rust_locals() {
+        check_metrics_with_options::<RustParser>(
+            "fn f(v: Option<(i32, i32)>) {
+                 let a = 1; // +1
+                 let (b, mut c) = (2, 3); // +2
+                 let Some((d, e)) = v else { return; }; // +2
+                 let S { f, g: h } = s; // +2
+             }",
+            "foo.rs",
+            &nlocals_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.nlocals,
+                    @r###"
+                    {
+                      "sum": 7.0,
+                      "average": 3.5,
+                      "min": 0.0,
+                      "max": 7.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_locals() {
+        check_metrics_with_options::<CppParser>(
+            "int f() {
+                 int a = 1, *b, c[2]; // +3
+                 int g(); // +0
+                 return a;
+             }",
+            "foo.cpp",
+            &nlocals_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.nlocals,
+                    @r###"
+                    {
+                      "sum": 3.0,
+                      "average": 1.5,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_args_locals_ratio() {
+        check_func_space_with_options::<JavascriptParser, _>(
+            "function f(a, b, c) {
+                 let d = a, e = b; // +2
+                 const g = c; // +1
+                 for (let i = 0; i < a; i++) { // +1
+                     var j = i, k = i; // +2
+                 }
+                 return d + e + g;
+             }
+             function h(a) {
+                 return a;
+             }",
+            "foo.js",
+            &nlocals_options(),
+            |func_space| {
+                let f = &func_space.spaces[0].metrics;
+                assert_eq!(f.nlocals.nlocals(), 6.);
+                assert_eq!(f.args_locals_ratio(), Some(0.5));
+                // No local variables
+                assert_eq!(func_space.spaces[1].metrics.args_locals_ratio(), None);
+            },
+        );
+    }
+
+    #[test]
+    fn java_locals() {
+        check_metrics_with_options::<JavaParser>(
+            "class A {
+                int f(int a) {
+                    int b = a, c = a; // +2
+                    for (int i = 0; i < a; i++) { // +1
+                        int j = i; // +1
+                    }
+                    return b + c;
+                }
+            }",
+            "foo.java",
+            &nlocals_options(),
+            |metric| {
+                // nspace = 3 (unit, class and method)
+                insta::assert_json_snapshot!(
+                    metric.nlocals,
+                    @r###"
+                    {
+                      "sum": 4.0,
+                      "average": 1.3333333333333333,
+                      "min": 0.0,
+                      "max": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_locals() {
+        check_metrics_with_options::<JavascriptParser>(
+            "function f() {
+                 var a = 1, b; // +2
+                 const { c, d } = o; // +1
+                 let e; // +1
+             }",
+            "foo.js",
+            &nlocals_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.nlocals,
+                    @r###"
+                    {
+                      "sum": 4.0,
+                      "average": 2.0,
+                      "min": 0.0,
+                      "max": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+}
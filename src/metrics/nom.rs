@@ -1,5 +1,5 @@
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::checker::Checker;
@@ -7,8 +7,26 @@ use crate::macros::implement_metric_trait;
 
 use crate::*;
 
+/// The kinds of methods excluded from the `Nom` count.
+///
+/// The exclusions are applied to the `Java`, `JavaScript` and
+/// `TypeScript` methods.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NomExclusions {
+    /// Excludes the constructors
+    pub constructors: bool,
+    /// Excludes the property accessors
+    ///
+    /// In `Java`, the accessors are the methods following the
+    /// `getX`, `isX` and `setX` naming conventions.
+    pub accessors: bool,
+    /// Excludes the static methods
+    pub static_methods: bool,
+}
+
 /// The `Nom` metric suite.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
     functions: usize,
     closures: usize,
@@ -190,6 +208,8 @@ pub trait Nom
 where
     Self: Checker,
 {
+    const IMPLEMENTED: bool = true;
+
     fn compute(node: &Node, stats: &mut Stats) {
         if Self::is_func(node) {
             stats.functions += 1;
@@ -199,26 +219,115 @@ where
             stats.closures += 1;
         }
     }
+
+    /// Checks whether a function must not be counted
+    /// according to the given exclusions.
+    fn is_excluded(_node: &Node, _code: &[u8], _exclusions: &NomExclusions) -> bool {
+        false
+    }
+}
+
+macro_rules! js_is_excluded {
+    ($lang:ident) => {
+        fn is_excluded(node: &Node, code: &[u8], exclusions: &NomExclusions) -> bool {
+            if node.kind_id() != $lang::MethodDefinition {
+                return false;
+            }
+
+            let is_constructor = node
+                .child_by_field_name("name")
+                .is_some_and(|name| &code[name.start_byte()..name.end_byte()] == b"constructor");
+
+            (exclusions.constructors && is_constructor)
+                || (exclusions.accessors
+                    && node
+                        .children()
+                        .any(|child| matches!(child.kind_id().into(), $lang::Get | $lang::Set)))
+                || (exclusions.static_methods
+                    && node
+                        .children()
+                        .any(|child| child.kind_id() == $lang::Static))
+        }
+    };
+}
+
+impl Nom for MozjsCode {
+    js_is_excluded!(Mozjs);
+}
+
+impl Nom for JavascriptCode {
+    js_is_excluded!(Javascript);
+}
+
+impl Nom for TypescriptCode {
+    js_is_excluded!(Typescript);
+}
+
+impl Nom for TsxCode {
+    js_is_excluded!(Tsx);
+}
+
+impl Nom for JavaCode {
+    fn is_excluded(node: &Node, code: &[u8], exclusions: &NomExclusions) -> bool {
+        match node.kind_id().into() {
+            Java::ConstructorDeclaration => exclusions.constructors,
+            Java::MethodDeclaration => {
+                let is_static = node.children().any(|child| {
+                    child.kind_id() == Java::Modifiers
+                        && child
+                            .children()
+                            .any(|modifier| modifier.kind_id() == Java::Static)
+                });
+
+                (exclusions.static_methods && is_static)
+                    || (exclusions.accessors && is_java_accessor(node, code))
+            }
+            _ => false,
+        }
+    }
+}
+
+// Checks whether a method is a getter, `getX()` or `isX()`,
+// or a setter, `setX(x)`.
+fn is_java_accessor(node: &Node, code: &[u8]) -> bool {
+    let Some(name) = node.child_by_field_name("name") else {
+        return false;
+    };
+    let name = &code[name.start_byte()..name.end_byte()];
+    let params = node.child_by_field_name("parameters").map_or(0, |params| {
+        params
+            .children()
+            .filter(|param| param.kind_id() == Java::FormalParameter)
+            .count()
+    });
+
+    let follows = |prefix: &[u8]| {
+        name.strip_prefix(prefix)
+            .and_then(|rest| rest.first())
+            .is_some_and(|c| c.is_ascii_uppercase())
+    };
+
+    ((follows(b"get") || follows(b"is")) && params == 0) || (follows(b"set") && params == 1)
 }
 
 implement_metric_trait!(
     [Nom],
     PythonCode,
-    MozjsCode,
-    JavascriptCode,
-    TypescriptCode,
-    TsxCode,
     CppCode,
     RustCode,
     PreprocCode,
     CcommentCode,
-    JavaCode,
-    KotlinCode
+    HclCode,
+    KotlinCode,
+    GoCode,
+    CsharpCode
 );
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::check_metrics;
+    use std::path::PathBuf;
+
+    use crate::tools::{check_func_space, check_metrics};
 
     use super::*;
 
@@ -751,4 +860,148 @@ mod tests {
             },
         );
     }
+
+    fn functions_with<T: ParserTrait>(
+        source: &str,
+        filename: &str,
+        exclusions: NomExclusions,
+    ) -> f64 {
+        let path = PathBuf::from(filename);
+        let parser = T::new(source.as_bytes().to_vec(), &path, None);
+        let options = MetricsOptions {
+            nom_exclusions: exclusions,
+            ..Default::default()
+        };
+        let space = metrics_with_options(&parser, &path, &options).unwrap();
+        space.metrics.nom.functions_sum()
+    }
+
+    #[test]
+    fn java_nom_exclusions() {
+        let source = "class Point {
+                private int x;
+                private boolean visible;
+                Point(int x) { this.x = x; }
+                int getX() { return x; }
+                boolean isVisible() { return visible; }
+                void move(int dx) { x += dx; }
+                static Point origin() { return new Point(0); }
+                int getOffset(int base) { return x - base; }
+            }";
+        let count = |exclusions| functions_with::<JavaParser>(source, "foo.java", exclusions);
+
+        assert_eq!(count(NomExclusions::default()), 6.);
+        assert_eq!(
+            count(NomExclusions {
+                constructors: true,
+                ..Default::default()
+            }),
+            5.
+        );
+        // getOffset takes a parameter, so it is not a getter
+        assert_eq!(
+            count(NomExclusions {
+                accessors: true,
+                ..Default::default()
+            }),
+            4.
+        );
+        assert_eq!(
+            count(NomExclusions {
+                static_methods: true,
+                ..Default::default()
+            }),
+            5.
+        );
+        assert_eq!(
+            count(NomExclusions {
+                constructors: true,
+                accessors: true,
+                static_methods: true,
+            }),
+            2.
+        );
+    }
+
+    #[test]
+    fn typescript_nom_exclusions() {
+        let source = "class Point {
+                constructor(private x: number) {}
+                get x(): number { return this.x; }
+                set x(value: number) { this.x = value; }
+                static origin(): Point { return new Point(0); }
+                move(dx: number) { this.x += dx; }
+            }";
+        let count = |exclusions| functions_with::<TypescriptParser>(source, "foo.ts", exclusions);
+
+        assert_eq!(count(NomExclusions::default()), 5.);
+        assert_eq!(
+            count(NomExclusions {
+                constructors: true,
+                ..Default::default()
+            }),
+            4.
+        );
+        assert_eq!(
+            count(NomExclusions {
+                accessors: true,
+                ..Default::default()
+            }),
+            3.
+        );
+        assert_eq!(
+            count(NomExclusions {
+                static_methods: true,
+                ..Default::default()
+            }),
+            4.
+        );
+    }
+
+    #[test]
+    fn rust_functions_and_closures_nom() {
+        check_func_space::<RustParser, _>(
+            "fn inc(v: &[i32]) -> Vec<i32> { // +1 function
+                 v.iter().map(|x| x + 1).collect() // +1 closure
+             }
+             fn dec(x: i32) -> i32 { // +1 function
+                 x - 1
+             }",
+            "foo.rs",
+            |func_space| {
+                let nom = &func_space.metrics.nom;
+                assert_eq!(nom.functions_sum(), 2.);
+                assert_eq!(nom.closures_sum(), 1.);
+                assert_eq!(nom.total(), 3.);
+
+                // The closure belongs to the function containing it
+                let inc = &func_space.spaces[0].metrics.nom;
+                assert_eq!(inc.functions_sum(), 1.);
+                assert_eq!(inc.closures_sum(), 1.);
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_functions_and_closures_nom() {
+        check_func_space::<JavascriptParser, _>(
+            "function inc(v) { // +1 function
+                 return v.map(x => x + 1); // +1 closure
+             }
+             function dec(x) { // +1 function
+                 return x - 1;
+             }",
+            "foo.js",
+            |func_space| {
+                let nom = &func_space.metrics.nom;
+                assert_eq!(nom.functions_sum(), 2.);
+                assert_eq!(nom.closures_sum(), 1.);
+                assert_eq!(nom.total(), 3.);
+
+                let inc = &func_space.spaces[0].metrics.nom;
+                assert_eq!(inc.functions_sum(), 1.);
+                assert_eq!(inc.closures_sum(), 1.);
+            },
+        );
+    }
 }
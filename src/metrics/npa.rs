@@ -1,5 +1,5 @@
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::checker::Checker;
@@ -12,7 +12,10 @@ use crate::*;
 ///
 /// This metric counts the number of public attributes
 /// of classes/interfaces.
-#[derive(Clone, Debug, Default)]
+///
+/// The fields of Rust structs are counted as the attributes of a class.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
     class_npa: usize,
     interface_npa: usize,
@@ -200,11 +203,135 @@ pub trait Npa
 where
     Self: Checker,
 {
-    fn compute(node: &Node, stats: &mut Stats);
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+impl Npa for PythonCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Python::*;
+
+        // Enables the `Npa` metric if computing stats of a module or a class
+        if matches!(node.kind_id().into(), Module | ClassDefinition) && stats.is_disabled() {
+            stats.is_class_space = true;
+        }
+
+        if node.kind_id() != ClassDefinition {
+            return;
+        }
+
+        // The class attributes are assigned in the body of the class
+        for name in node
+            .child_by_field_name("body")
+            .iter()
+            .flat_map(|body| body.children())
+            .filter(|node| node.kind_id() == ExpressionStatement)
+            .filter_map(|statement| statement.child(0))
+            .filter(|expression| expression.kind_id() == Assignment)
+            .filter_map(|assignment| assignment.child_by_field_name("left"))
+            .filter(|left| left.kind_id() == Identifier)
+        {
+            stats.class_na += 1;
+            // By convention, a name starting with an underscore is private,
+            // except for the ones of special attributes, such as `__slots__`
+            if name.utf8_text(code).is_some_and(|name| {
+                !name.starts_with('_') || (name.starts_with("__") && name.ends_with("__"))
+            }) {
+                stats.class_npa += 1;
+            }
+        }
+    }
+}
+
+macro_rules! ts_npa {
+    ($code: ident, $lang: ident) => {
+        impl Npa for $code {
+            fn compute(node: &Node, _code: &[u8], stats: &mut Stats) {
+                use $lang::*;
+
+                // Enables the `Npa` metric if computing stats of a module, a class or an interface
+                if matches!(
+                    node.kind_id().into(),
+                    Program | Class | ClassDeclaration | InterfaceDeclaration
+                ) && stats.is_disabled()
+                {
+                    stats.is_class_space = true;
+                }
+
+                match node.kind_id().into() {
+                    ClassBody => {
+                        for field in node
+                            .children()
+                            .filter(|node| node.kind_id() == PublicFieldDefinition)
+                        {
+                            stats.class_na += 1;
+                            // A field is public unless it is `private`, `protected` or `#`-named
+                            if field
+                                .first_child(|id| id == AccessibilityModifier)
+                                .is_none_or(|modifier| {
+                                    modifier.first_child(|id| id == Public).is_some()
+                                })
+                                && field
+                                    .first_child(|id| id == PrivatePropertyIdentifier)
+                                    .is_none()
+                            {
+                                stats.class_npa += 1;
+                            }
+                        }
+                    }
+                    // All properties in an interface are public
+                    InterfaceBody => {
+                        let properties = node
+                            .children()
+                            .filter(|node| node.kind_id() == PropertySignature)
+                            .count();
+                        stats.interface_na += properties;
+                        stats.interface_npa += properties;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+ts_npa!(TypescriptCode, Typescript);
+ts_npa!(TsxCode, Tsx);
+
+impl Npa for RustCode {
+    fn compute(node: &Node, _code: &[u8], stats: &mut Stats) {
+        use Rust::*;
+
+        // Enables the `Npa` metric if computing stats of a module, an impl or a trait
+        if matches!(node.kind_id().into(), SourceFile | ImplItem | TraitItem) && stats.is_disabled()
+        {
+            stats.is_class_space = true;
+        }
+
+        // The fields of enum variants are as visible as their enum
+        if node.kind_id() == FieldDeclarationList
+            && node
+                .parent()
+                .is_some_and(|item| matches!(item.kind_id().into(), StructItem | UnionItem))
+        {
+            for field in node
+                .children()
+                .filter(|node| node.kind_id() == FieldDeclaration)
+            {
+                stats.class_na += 1;
+                // Any visibility modifier, even a restricted one such as
+                // `pub(crate)`, makes a field part of the API of its module
+                if field.first_child(|id| id == VisibilityModifier).is_some() {
+                    stats.class_npa += 1;
+                }
+            }
+        }
+    }
 }
 
 impl Npa for JavaCode {
-    fn compute(node: &Node, stats: &mut Stats) {
+    fn compute(node: &Node, _code: &[u8], stats: &mut Stats) {
         use Java::*;
 
         // Enables the `Npa` metric if computing stats of a class space
@@ -255,18 +382,68 @@ impl Npa for JavaCode {
     }
 }
 
+impl Npa for CsharpCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Csharp::*;
+
+        // Enables the `Npa` metric if computing stats of a class space
+        if Self::is_func_space(node) && stats.is_disabled() {
+            stats.is_class_space = true;
+        }
+
+        // The members of namespaces, classes and interfaces are all
+        // in a declaration list
+        if node.kind_id() != DeclarationList {
+            return;
+        }
+        // A field declaration may declare several fields, while the
+        // properties are the attributes exposed by the C# types
+        let attributes = node.children().filter_map(|member| {
+            let count = match member.kind_id().into() {
+                FieldDeclaration => member
+                    .children()
+                    .filter(|child| child.kind_id() == VariableDeclaration)
+                    .flat_map(|declaration| declaration.children())
+                    .filter(|declarator| declarator.kind_id() == VariableDeclarator)
+                    .count(),
+                PropertyDeclaration => 1,
+                _ => return Option::None,
+            };
+            // The members of an interface are implicitly public
+            Some((count, Self::is_public_func(&member, code)))
+        });
+        match node.parent().map(|parent| parent.kind_id().into()) {
+            Some(ClassDeclaration | StructDeclaration | RecordDeclaration) => {
+                for (count, public) in attributes {
+                    stats.class_na += count;
+                    if public {
+                        stats.class_npa += count;
+                    }
+                }
+            }
+            Some(InterfaceDeclaration) => {
+                for (count, public) in attributes {
+                    stats.interface_na += count;
+                    if public {
+                        stats.interface_npa += count;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 implement_metric_trait!(
     Npa,
-    PythonCode,
     MozjsCode,
     JavascriptCode,
-    TypescriptCode,
-    TsxCode,
-    RustCode,
     CppCode,
     PreprocCode,
     CcommentCode,
-    KotlinCode
+    HclCode,
+    KotlinCode,
+    GoCode
 );
 
 #[cfg(test)]
@@ -733,4 +910,100 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn python_class_attributes() {
+        check_metrics::<PythonParser>(
+            "class C:
+    a = 1           # +1
+    _b = 2
+    __slots__ = ()  # +1
+    c: int = 3      # +1",
+            "foo.py",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npa,
+                    @r###"
+                    {
+                      "classes": 3.0,
+                      "interfaces": 0.0,
+                      "class_attributes": 4.0,
+                      "interface_attributes": 0.0,
+                      "classes_average": 0.75,
+                      "interfaces_average": null,
+                      "total": 3.0,
+                      "total_attributes": 4.0,
+                      "average": 0.75
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_class_and_interface() {
+        check_metrics::<TypescriptParser>(
+            "interface I {
+                x: number;          // +1
+                y: string;          // +1
+            }
+            class C {
+                public a = 1;       // +1
+                b = 2;              // +1
+                private c = 3;
+                protected d = 4;
+                #e = 5;
+            }",
+            "foo.ts",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npa,
+                    @r###"
+                    {
+                      "classes": 2.0,
+                      "interfaces": 2.0,
+                      "class_attributes": 5.0,
+                      "interface_attributes": 2.0,
+                      "classes_average": 0.4,
+                      "interfaces_average": 1.0,
+                      "total": 4.0,
+                      "total_attributes": 7.0,
+                      "average": 0.5714285714285714
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_struct_fields() {
+        check_metrics::<RustParser>(
+            "pub struct S {
+                pub a: i32,         // +1
+                pub(crate) b: i32,  // +1
+                c: i32,
+            }
+            enum E {
+                V { x: i32 },
+            }",
+            "foo.rs",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npa,
+                    @r###"
+                    {
+                      "classes": 2.0,
+                      "interfaces": 0.0,
+                      "class_attributes": 3.0,
+                      "interface_attributes": 0.0,
+                      "classes_average": 0.6666666666666666,
+                      "interfaces_average": null,
+                      "total": 2.0,
+                      "total_attributes": 3.0,
+                      "average": 0.6666666666666666
+                    }"###
+                );
+            },
+        );
+    }
 }
@@ -1,5 +1,5 @@
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::checker::Checker;
@@ -12,7 +12,12 @@ use crate::*;
 ///
 /// This metric counts the number of public methods
 /// of classes/interfaces.
-#[derive(Clone, Debug, Default)]
+///
+/// The free functions of a module are counted as the methods
+/// of a class, so a Rust, Python or TypeScript module is treated
+/// as the class of its functions.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
     class_npm: usize,
     interface_npm: usize,
@@ -200,11 +205,178 @@ pub trait Npm
 where
     Self: Checker,
 {
-    fn compute(node: &Node, stats: &mut Stats);
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+impl Npm for PythonCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Python::*;
+
+        // Enables the `Npm` metric if computing stats of a module or a class
+        if matches!(node.kind_id().into(), Module | ClassDefinition) && stats.is_disabled() {
+            stats.is_class_space = true;
+        }
+
+        let body = match node.kind_id().into() {
+            Module => *node,
+            ClassDefinition => match node.child_by_field_name("body") {
+                Some(body) => body,
+                Option::None => return,
+            },
+            _ => return,
+        };
+
+        for function in body
+            .children()
+            .filter_map(|node| match node.kind_id().into() {
+                FunctionDefinition => Some(node),
+                DecoratedDefinition => node
+                    .child_by_field_name("definition")
+                    .filter(|definition| Self::is_func(definition)),
+                _ => Option::None,
+            })
+        {
+            stats.class_nm += 1;
+            // Public by the leading underscore convention
+            if Self::is_public_func(&function, code) {
+                stats.class_npm += 1;
+            }
+        }
+    }
+}
+
+macro_rules! ts_npm {
+    ($code: ident, $lang: ident) => {
+        impl Npm for $code {
+            fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+                use $lang::*;
+
+                // Enables the `Npm` metric if computing stats of a module, a class or an interface
+                if matches!(
+                    node.kind_id().into(),
+                    Program | Class | ClassDeclaration | InterfaceDeclaration
+                ) && stats.is_disabled()
+                {
+                    stats.is_class_space = true;
+                }
+
+                match node.kind_id().into() {
+                    Program => {
+                        for function in node
+                            .children()
+                            .filter_map(|node| {
+                                if node.kind_id() == ExportStatement {
+                                    node.child_by_field_name("declaration")
+                                } else {
+                                    Some(node)
+                                }
+                            })
+                            .filter(|node| {
+                                matches!(
+                                    node.kind_id().into(),
+                                    FunctionDeclaration | GeneratorFunctionDeclaration
+                                )
+                            })
+                        {
+                            stats.class_nm += 1;
+                            // A function is public if it is exported
+                            if Self::is_public_func(&function, code) {
+                                stats.class_npm += 1;
+                            }
+                        }
+                    }
+                    ClassBody => {
+                        for method in node
+                            .children()
+                            .filter(|node| node.kind_id() == MethodDefinition)
+                        {
+                            stats.class_nm += 1;
+                            // A method is public unless it is `private`, `protected` or `#`-named
+                            if method
+                                .first_child(|id| id == AccessibilityModifier)
+                                .is_none_or(|modifier| {
+                                    modifier.first_child(|id| id == Public).is_some()
+                                })
+                                && method
+                                    .first_child(|id| id == PrivatePropertyIdentifier)
+                                    .is_none()
+                            {
+                                stats.class_npm += 1;
+                            }
+                        }
+                    }
+                    // All methods in an interface are public
+                    InterfaceBody => {
+                        let methods = node
+                            .children()
+                            .filter(|node| node.kind_id() == MethodSignature)
+                            .count();
+                        stats.interface_nm += methods;
+                        stats.interface_npm += methods;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+ts_npm!(TypescriptCode, Typescript);
+ts_npm!(TsxCode, Tsx);
+
+impl Npm for RustCode {
+    fn compute(node: &Node, _code: &[u8], stats: &mut Stats) {
+        use Rust::*;
+
+        // Enables the `Npm` metric if computing stats of a module, an impl or a trait
+        if matches!(node.kind_id().into(), SourceFile | ImplItem | TraitItem) && stats.is_disabled()
+        {
+            stats.is_class_space = true;
+        }
+
+        match node.kind_id().into() {
+            // All methods of a trait are as visible as the trait
+            DeclarationList
+                if node
+                    .parent()
+                    .is_some_and(|item| item.kind_id() == TraitItem) =>
+            {
+                let methods = node
+                    .children()
+                    .filter(|node| {
+                        matches!(node.kind_id().into(), FunctionItem | FunctionSignatureItem)
+                    })
+                    .count();
+                stats.interface_nm += methods;
+                stats.interface_npm += methods;
+            }
+            SourceFile | DeclarationList => {
+                // The methods implementing a trait are as visible as the trait
+                let is_trait_impl = node.parent().is_some_and(|item| {
+                    item.kind_id() == ImplItem && item.child_by_field_name("trait").is_some()
+                });
+                for function in node.children().filter(|node| Self::is_func(node)) {
+                    stats.class_nm += 1;
+                    // Any visibility modifier, even a restricted one such as
+                    // `pub(crate)`, makes a function part of the API of its module
+                    if is_trait_impl
+                        || function
+                            .first_child(|id| id == VisibilityModifier)
+                            .is_some()
+                    {
+                        stats.class_npm += 1;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
 }
 
 impl Npm for JavaCode {
-    fn compute(node: &Node, stats: &mut Stats) {
+    fn compute(node: &Node, _code: &[u8], stats: &mut Stats) {
         use Java::*;
 
         // Enables the `Npm` metric if computing stats of a class space
@@ -246,18 +418,53 @@ impl Npm for JavaCode {
     }
 }
 
+impl Npm for CsharpCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Csharp::*;
+
+        // Enables the `Npm` metric if computing stats of a class space
+        if Self::is_func_space(node) && stats.is_disabled() {
+            stats.is_class_space = true;
+        }
+
+        // The members of namespaces, classes and interfaces are all
+        // in a declaration list
+        if node.kind_id() != DeclarationList {
+            return;
+        }
+        let methods = node.children().filter(|node| Self::is_func(node));
+        match node.parent().map(|parent| parent.kind_id().into()) {
+            Some(ClassDeclaration | StructDeclaration | RecordDeclaration) => {
+                for method in methods {
+                    stats.class_nm += 1;
+                    if Self::is_public_func(&method, code) {
+                        stats.class_npm += 1;
+                    }
+                }
+            }
+            Some(InterfaceDeclaration) => {
+                for method in methods {
+                    stats.interface_nm += 1;
+                    if Self::is_public_func(&method, code) {
+                        stats.interface_npm += 1;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 implement_metric_trait!(
     Npm,
-    PythonCode,
     MozjsCode,
     JavascriptCode,
-    TypescriptCode,
-    TsxCode,
-    RustCode,
     CppCode,
     PreprocCode,
     CcommentCode,
-    KotlinCode
+    HclCode,
+    KotlinCode,
+    GoCode
 );
 
 #[cfg(test)]
@@ -705,4 +912,163 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn java_public_and_private_methods() {
+        check_metrics::<JavaParser>(
+            "class X {
+                public void a() {}  // +1
+                private void b() {}
+            }",
+            "foo.java",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npm,
+                    @r###"
+                    {
+                      "classes": 1.0,
+                      "interfaces": 0.0,
+                      "class_methods": 2.0,
+                      "interface_methods": 0.0,
+                      "classes_average": 0.5,
+                      "interfaces_average": null,
+                      "total": 1.0,
+                      "total_methods": 2.0,
+                      "average": 0.5
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn python_functions_and_methods() {
+        check_metrics::<PythonParser>(
+            "def a(): pass                # +1
+def _b(): pass
+class C:
+    def __init__(self): pass    # +1
+    def m(self): pass           # +1
+    def _n(self): pass
+    @property
+    def p(self): pass           # +1",
+            "foo.py",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npm,
+                    @r###"
+                    {
+                      "classes": 4.0,
+                      "interfaces": 0.0,
+                      "class_methods": 6.0,
+                      "interface_methods": 0.0,
+                      "classes_average": 0.6666666666666666,
+                      "interfaces_average": null,
+                      "total": 4.0,
+                      "total_methods": 6.0,
+                      "average": 0.6666666666666666
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_functions_classes_and_interfaces() {
+        check_metrics::<TypescriptParser>(
+            "export function a() {}  // +1
+            function b() {}
+            interface I {
+                m(): void;          // +1
+            }
+            class C {
+                constructor() {}    // +1
+                public m() {}       // +1
+                private n() {}
+                protected o() {}
+                #p() {}
+            }",
+            "foo.ts",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npm,
+                    @r###"
+                    {
+                      "classes": 3.0,
+                      "interfaces": 1.0,
+                      "class_methods": 7.0,
+                      "interface_methods": 1.0,
+                      "classes_average": 0.42857142857142855,
+                      "interfaces_average": 1.0,
+                      "total": 4.0,
+                      "total_methods": 8.0,
+                      "average": 0.5
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_module_functions() {
+        check_metrics::<RustParser>(
+            "pub fn a() {}   // +1
+            pub fn b() {}   // +1
+            fn c() {}",
+            "foo.rs",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npm,
+                    @r###"
+                    {
+                      "classes": 2.0,
+                      "interfaces": 0.0,
+                      "class_methods": 3.0,
+                      "interface_methods": 0.0,
+                      "classes_average": 0.6666666666666666,
+                      "interfaces_average": null,
+                      "total": 2.0,
+                      "total_methods": 3.0,
+                      "average": 0.6666666666666666
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_impls_and_traits() {
+        check_metrics::<RustParser>(
+            "struct S;
+            impl S {
+                pub(crate) fn a(&self) {}   // +1
+                fn b(&self) {}
+            }
+            trait T {
+                fn c(&self);                // +1
+                fn d(&self) {}              // +1
+            }
+            impl T for S {
+                fn c(&self) {}              // +1
+            }",
+            "foo.rs",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.npm,
+                    @r###"
+                    {
+                      "classes": 2.0,
+                      "interfaces": 2.0,
+                      "class_methods": 3.0,
+                      "interface_methods": 2.0,
+                      "classes_average": 0.6666666666666666,
+                      "interfaces_average": 1.0,
+                      "total": 4.0,
+                      "total_methods": 5.0,
+                      "average": 0.8
+                    }"###
+                );
+            },
+        );
+    }
 }
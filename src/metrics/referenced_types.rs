@@ -0,0 +1,376 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `ReferencedTypes` metric.
+///
+/// This metric counts the number of distinct types referenced in the
+/// signature and in the body of a function/method, as a measure of its
+/// coupling with the rest of the code.
+///
+/// Only the named types are counted, while the primitive ones, such as
+/// `int` or `bool`, are not. The types are compared by their text, so
+/// the same type written with and without its qualification is counted
+/// twice. Dynamically-typed languages are not supported.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    types: HashSet<String>,
+    referenced_types_sum: usize,
+    referenced_types_min: usize,
+    referenced_types_max: usize,
+    space_count: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            types: HashSet::new(),
+            referenced_types_sum: 0,
+            referenced_types_min: usize::MAX,
+            referenced_types_max: 0,
+            space_count: 1,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("referenced_types", 4)?;
+        st.serialize_field("sum", &self.referenced_types_sum())?;
+        st.serialize_field("average", &self.referenced_types_average())?;
+        st.serialize_field("min", &self.referenced_types_min())?;
+        st.serialize_field("max", &self.referenced_types_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}",
+            self.referenced_types_sum(),
+            self.referenced_types_average(),
+            self.referenced_types_min(),
+            self.referenced_types_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `ReferencedTypes` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.referenced_types_max = self.referenced_types_max.max(other.referenced_types_max);
+        self.referenced_types_min = self.referenced_types_min.min(other.referenced_types_min);
+        self.referenced_types_sum += other.referenced_types_sum;
+        self.space_count += other.space_count;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `ReferencedTypes` metric value of a space
+    pub fn referenced_types(&self) -> f64 {
+        self.types.len() as f64
+    }
+
+    /// Returns the `ReferencedTypes` metric sum value
+    pub fn referenced_types_sum(&self) -> f64 {
+        self.referenced_types_sum as f64
+    }
+
+    /// Returns the `ReferencedTypes` metric average value
+    ///
+    /// This value is computed dividing the sum of the `ReferencedTypes`
+    /// values for the number of spaces.
+    pub fn referenced_types_average(&self) -> f64 {
+        self.referenced_types_sum() / self.space_count as f64
+    }
+
+    /// Returns the `ReferencedTypes` metric minimum value
+    pub fn referenced_types_min(&self) -> f64 {
+        self.referenced_types_min as f64
+    }
+
+    /// Returns the `ReferencedTypes` metric maximum value
+    pub fn referenced_types_max(&self) -> f64 {
+        self.referenced_types_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.referenced_types_sum += self.types.len();
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.referenced_types_max = self.referenced_types_max.max(self.types.len());
+        self.referenced_types_min = self.referenced_types_min.min(self.types.len());
+        self.compute_sum();
+    }
+
+    // Enables the `ReferencedTypes` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `ReferencedTypes` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait ReferencedTypes
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+#[inline(always)]
+fn insert_type(node: &Node, code: &[u8], stats: &mut Stats) {
+    if let Some(name) = node.utf8_text(code) {
+        stats.types.insert(name.to_string());
+    }
+}
+
+macro_rules! referenced_type {
+    ($lang:ident, $($scoped:ident)|+) => {
+        fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+            use $lang::*;
+
+            // A qualified type is counted as a whole, so its last
+            // name is skipped
+            let is_scoped = |node: &Node| matches!(node.kind_id().into(), $($scoped)|+);
+            if matches!(node.kind_id().into(), TypeIdentifier $(| $scoped)+)
+                && !node.parent().is_some_and(|parent| is_scoped(&parent))
+            {
+                insert_type(node, code, stats);
+            }
+        }
+    };
+}
+
+impl ReferencedTypes for RustCode {
+    referenced_type!(Rust, ScopedTypeIdentifier | ScopedTypeIdentifier2);
+}
+
+impl ReferencedTypes for JavaCode {
+    referenced_type!(Java, ScopedTypeIdentifier);
+}
+
+impl ReferencedTypes for TypescriptCode {
+    referenced_type!(Typescript, NestedTypeIdentifier);
+}
+
+impl ReferencedTypes for TsxCode {
+    referenced_type!(Tsx, NestedTypeIdentifier);
+}
+
+impl ReferencedTypes for CppCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        if node.kind_id() == Cpp::TypeIdentifier {
+            insert_type(node, code, stats);
+        }
+    }
+}
+
+impl ReferencedTypes for CsharpCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        use Csharp::*;
+
+        // C# has no dedicated node for the names of the types, so a name
+        // is a type when it is in the position of one
+        let Some(parent) = node.parent() else {
+            return;
+        };
+        let is_type = matches!(parent.kind_id().into(), TypeArgumentList | BaseList)
+            || ["type", "returns"].iter().any(|field| {
+                parent
+                    .child_by_field_name(field)
+                    .is_some_and(|typ| typ.id() == node.id())
+            });
+        if !is_type {
+            return;
+        }
+
+        // A qualified type is counted as a whole, while a generic type
+        // is counted by its name, as its arguments are types on their own
+        match node.kind_id().into() {
+            Identifier | QualifiedName => insert_type(node, code, stats),
+            GenericName => {
+                if let Some(name) = node.child(0) {
+                    insert_type(&name, code, stats);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+implement_metric_trait!(
+    ReferencedTypes,
+    PythonCode,
+    MozjsCode,
+    JavascriptCode,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics_with_options;
+
+    use super::*;
+
+    fn referenced_types_options() -> MetricsOptions {
+        MetricsOptions {
+            referenced_types: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn java_referenced_types() {
+        check_metrics_with_options::<JavaParser>(
+            "class A {
+                List<String> f(Map<String, Integer> m, int n) {
+                    List<String> l = new ArrayList<>(); // ArrayList
+                    return l;
+                }
+            }",
+            "foo.java",
+            &referenced_types_options(),
+            |metric| {
+                // List, String, Map, Integer, ArrayList
+                insta::assert_json_snapshot!(
+                    metric.referenced_types,
+                    @r###"
+                    {
+                      "sum": 5.0,
+                      "average": 1.6666666666666667,
+                      "min": 0.0,
+                      "max": 5.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn java_three_types() {
+        check_metrics_with_options::<JavaParser>(
+            "class A {
+                void f(Foo foo) {
+                    Bar bar = foo.bar();
+                    Baz.run(bar, new Foo());
+                    java.util.Date date = null;
+                }
+            }",
+            "foo.java",
+            &referenced_types_options(),
+            |metric| {
+                // Foo, Bar and java.util.Date, while Baz is an identifier
+                insta::assert_json_snapshot!(
+                    metric.referenced_types,
+                    @r###"
+                    {
+                      "sum": 3.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_referenced_types() {
+        check_metrics_with_options::<RustParser>(
+            "fn f(v: Vec<Foo>, n: usize) -> Option<std::io::Error> {
+                 let x: Foo = Foo::new();
+                 None
+             }",
+            "foo.rs",
+            &referenced_types_options(),
+            |metric| {
+                // Vec, Foo, Option and std::io::Error, while usize is primitive
+                insta::assert_json_snapshot!(
+                    metric.referenced_types,
+                    @r###"
+                    {
+                      "sum": 4.0,
+                      "average": 2.0,
+                      "min": 0.0,
+                      "max": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_referenced_types() {
+        check_metrics_with_options::<CppParser>(
+            "Bar f(const Foo &foo, int n) {
+                 Bar b;
+                 return b;
+             }",
+            "foo.cpp",
+            &referenced_types_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.referenced_types,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_referenced_types() {
+        check_metrics_with_options::<TypescriptParser>(
+            "function f(a: Foo, b: ns.Bar, c: number): Foo {
+                 return a;
+             }",
+            "foo.ts",
+            &referenced_types_options(),
+            |metric| {
+                // Foo and ns.Bar, while number is predefined
+                insta::assert_json_snapshot!(
+                    metric.referenced_types,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 1.0,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,365 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `StatementCount` metric.
+///
+/// This metric counts the number of statements of each function/method,
+/// so that the average size of the functions of a file can be evaluated.
+///
+/// Only the statements contained in functions and closures are counted,
+/// while the ones of other spaces, such as the top-level statements
+/// of a script, are not.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    statements: usize,
+    statements_sum: usize,
+    statements_min: usize,
+    statements_max: usize,
+    is_function: bool,
+    functions: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            statements: 0,
+            statements_sum: 0,
+            statements_min: usize::MAX,
+            statements_max: 0,
+            is_function: false,
+            functions: 0,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("statement_count", 4)?;
+        st.serialize_field("sum", &self.statement_count_sum())?;
+        st.serialize_field("average", &self.statement_count_average())?;
+        st.serialize_field("min", &self.statement_count_min())?;
+        st.serialize_field("max", &self.statement_count_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}",
+            self.statement_count_sum(),
+            self.statement_count_average(),
+            self.statement_count_min(),
+            self.statement_count_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `StatementCount` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.statements_max = self.statements_max.max(other.statements_max);
+        self.statements_min = self.statements_min.min(other.statements_min);
+        self.statements_sum += other.statements_sum;
+        self.functions += other.functions;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `StatementCount` metric value of a space
+    pub fn statement_count(&self) -> f64 {
+        self.statements as f64
+    }
+
+    /// Returns the `StatementCount` metric sum value
+    pub fn statement_count_sum(&self) -> f64 {
+        self.statements_sum as f64
+    }
+
+    /// Returns the `StatementCount` metric average value
+    ///
+    /// This value is computed dividing the sum of the `StatementCount`
+    /// values for the number of functions.
+    pub fn statement_count_average(&self) -> f64 {
+        self.statement_count_sum() / self.functions as f64
+    }
+
+    /// Returns the `StatementCount` metric minimum value
+    pub fn statement_count_min(&self) -> f64 {
+        if self.functions == 0 {
+            0.
+        } else {
+            self.statements_min as f64
+        }
+    }
+
+    /// Returns the `StatementCount` metric maximum value
+    pub fn statement_count_max(&self) -> f64 {
+        self.statements_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.statements_sum += self.statements;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        if self.is_function {
+            self.statements_max = self.statements_max.max(self.statements);
+            self.statements_min = self.statements_min.min(self.statements);
+            self.functions += 1;
+            self.compute_sum();
+        }
+    }
+
+    // Enables the `StatementCount` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `StatementCount` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+// Returns whether a node is a statement, looking at the grammar names
+// shared by the supported languages: all the `*_statement` kinds except
+// for the `C/C++` blocks and case labels, and the declarations
+// of local variables.
+fn is_statement(node: &Node) -> bool {
+    match node.kind() {
+        "compound_statement" | "case_statement" => false,
+        "declaration"
+        | "let_declaration"
+        | "local_variable_declaration"
+        | "lexical_declaration"
+        | "variable_declaration" => true,
+        kind => kind.ends_with("_statement"),
+    }
+}
+
+pub trait StatementCount
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, stats: &mut Stats) {
+        // A function is the first node computed in its own space
+        if Self::is_func(node) || Self::is_closure(node) {
+            stats.is_function = true;
+        } else if is_statement(node) {
+            stats.statements += 1;
+        }
+    }
+}
+
+impl StatementCount for PythonCode {}
+
+impl StatementCount for MozjsCode {}
+
+impl StatementCount for JavascriptCode {}
+
+impl StatementCount for TypescriptCode {}
+
+impl StatementCount for TsxCode {}
+
+impl StatementCount for RustCode {}
+
+impl StatementCount for CppCode {}
+
+impl StatementCount for JavaCode {}
+
+impl StatementCount for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        // The declarations of the local variables are wrapped in a statement,
+        // while the initializer of a for loop is not a statement
+        if Self::is_func(node) || Self::is_closure(node) {
+            stats.is_function = true;
+        } else if is_statement(node) && node.kind_id() != Csharp::VariableDeclaration {
+            stats.statements += 1;
+        }
+    }
+}
+
+implement_metric_trait!(
+    StatementCount,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics_with_options;
+
+    use super::*;
+
+    fn statement_count_options() -> MetricsOptions {
+        MetricsOptions {
+            statement_count: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn python_statements() {
+        check_metrics_with_options::<PythonParser>(
+            "def f(a):
+                 b = a + 1  # +1
+                 print(b)  # +1
+                 return b  # +1
+             def g(a):
+                 if a:  # +1
+                     a += 1  # +1
+                 for i in range(a):  # +1
+                     print(i)  # +1
+                 while a:  # +1
+                     a -= 1  # +1
+                 return a  # +1
+             print(f(1))",
+            "foo.py",
+            &statement_count_options(),
+            |metric| {
+                // The statement outside of the functions is not counted
+                insta::assert_json_snapshot!(
+                    metric.statement_count,
+                    @r###"
+                    {
+                      "sum": 10.0,
+                      "average": 5.0,
+                      "min": 3.0,
+                      "max": 7.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_statements() {
+        check_metrics_with_options::<RustParser>(
+            "fn f(a: i32) -> i32 {
+                 let b = a + 1; // +1
+                 let c = |x: i32| {
+                     println!(\"{}\", x); // +1
+                     x
+                 }; // +1
+                 if b > 0 { // +1
+                     c(b);  // +1
+                 }
+                 b
+             }",
+            "foo.rs",
+            &statement_count_options(),
+            |metric| {
+                // The closure is a function too
+                insta::assert_json_snapshot!(
+                    metric.statement_count,
+                    @r###"
+                    {
+                      "sum": 5.0,
+                      "average": 2.5,
+                      "min": 1.0,
+                      "max": 4.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn c_statements() {
+        check_metrics_with_options::<CppParser>(
+            "int f(int a) {
+                 int b = a; // +1
+                 switch (b) { // +1
+                 case 0:
+                     return 1; // +1
+                 }
+                 { // A block is not a statement
+                     b++; // +1
+                 }
+                 return b; // +1
+             }",
+            "foo.c",
+            &statement_count_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.statement_count,
+                    @r###"
+                    {
+                      "sum": 5.0,
+                      "average": 5.0,
+                      "min": 5.0,
+                      "max": 5.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn javascript_statements() {
+        check_metrics_with_options::<JavascriptParser>(
+            "function f(a) {
+                 const b = a + 1; // +1
+                 var c = b; // +1
+                 return () => c; // +1
+             }
+             let d = f(1);",
+            "foo.js",
+            &statement_count_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.statement_count,
+                    @r###"
+                    {
+                      "sum": 3.0,
+                      "average": 1.5,
+                      "min": 0.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn no_functions() {
+        check_metrics_with_options::<PythonParser>(
+            "a = 1\nprint(a)",
+            "foo.py",
+            &statement_count_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.statement_count,
+                    @r###"
+                {
+                  "sum": 0.0,
+                  "average": null,
+                  "min": 0.0,
+                  "max": 0.0
+                }"###
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `TemplateComplexity` metric.
+///
+/// This metric counts the compile-time conditions of each `C++`
+/// function template, that is its `requires` clauses and its
+/// `std::enable_if` conditions, which select the instantiations of
+/// the template as the branches of a function select its paths.
+///
+/// These conditions are not counted by the `Cyclomatic` metric, which
+/// only measures the runtime paths of a function.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    conditions: usize,
+    conditions_sum: usize,
+    conditions_min: usize,
+    conditions_max: usize,
+    is_function: bool,
+    functions: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            conditions: 0,
+            conditions_sum: 0,
+            conditions_min: usize::MAX,
+            conditions_max: 0,
+            is_function: false,
+            functions: 0,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("template_complexity", 4)?;
+        st.serialize_field("sum", &self.template_complexity_sum())?;
+        st.serialize_field("average", &self.template_complexity_average())?;
+        st.serialize_field("min", &self.template_complexity_min())?;
+        st.serialize_field("max", &self.template_complexity_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}",
+            self.template_complexity_sum(),
+            self.template_complexity_average(),
+            self.template_complexity_min(),
+            self.template_complexity_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `TemplateComplexity` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.conditions_max = self.conditions_max.max(other.conditions_max);
+        self.conditions_min = self.conditions_min.min(other.conditions_min);
+        self.conditions_sum += other.conditions_sum;
+        self.functions += other.functions;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `TemplateComplexity` metric value of a space
+    pub fn template_complexity(&self) -> f64 {
+        self.conditions as f64
+    }
+
+    /// Returns the `TemplateComplexity` metric sum value
+    pub fn template_complexity_sum(&self) -> f64 {
+        self.conditions_sum as f64
+    }
+
+    /// Returns the `TemplateComplexity` metric average value
+    ///
+    /// This value is computed dividing the sum of the `TemplateComplexity`
+    /// values for the number of functions.
+    pub fn template_complexity_average(&self) -> f64 {
+        self.template_complexity_sum() / self.functions as f64
+    }
+
+    /// Returns the `TemplateComplexity` metric minimum value
+    pub fn template_complexity_min(&self) -> f64 {
+        if self.functions == 0 {
+            0.
+        } else {
+            self.conditions_min as f64
+        }
+    }
+
+    /// Returns the `TemplateComplexity` metric maximum value
+    pub fn template_complexity_max(&self) -> f64 {
+        self.conditions_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.conditions_sum += self.conditions;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        if self.is_function {
+            self.conditions_max = self.conditions_max.max(self.conditions);
+            self.conditions_min = self.conditions_min.min(self.conditions);
+            self.functions += 1;
+            self.compute_sum();
+        }
+    }
+
+    // Enables the `TemplateComplexity` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `TemplateComplexity` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait TemplateComplexity
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats);
+}
+
+// Returns whether a node is a `requires` clause or
+// an `enable_if`/`enable_if_t` type.
+fn is_cpp_condition(node: &Node, code: &[u8]) -> bool {
+    match node.kind_id().into() {
+        Cpp::RequiresClause => true,
+        Cpp::TemplateType => node
+            .child_by_field_name("name")
+            .and_then(|name| name.utf8_text(code))
+            .is_some_and(|name| matches!(name, "enable_if" | "enable_if_t")),
+        _ => false,
+    }
+}
+
+// Returns whether a template declares a function, possibly
+// through a nested template, as a member of a class template.
+fn declares_function(template: &Node) -> bool {
+    template.children().any(|child| {
+        CppCode::is_func(&child)
+            || (child.kind_id() == Cpp::TemplateDeclaration && declares_function(&child))
+    })
+}
+
+fn is_template_header(node: &Node) -> bool {
+    matches!(
+        node.kind_id().into(),
+        Cpp::TemplateParameterList | Cpp::RequiresClause
+    )
+}
+
+// Returns whether a node is contained in the header of a function template,
+// which precedes the function in the tree.
+fn in_function_template_header(node: &Node) -> bool {
+    let mut node = *node;
+    while let Some(parent) = node.parent() {
+        if parent.kind_id() == Cpp::TemplateDeclaration && is_template_header(&node) {
+            return declares_function(&parent);
+        }
+        node = parent;
+    }
+    false
+}
+
+impl TemplateComplexity for CppCode {
+    fn compute(node: &Node, code: &[u8], stats: &mut Stats) {
+        if Self::is_func(node) {
+            // A function is the first node computed in its own space,
+            // so the conditions of its templates are counted here
+            stats.is_function = true;
+            let mut parent = node.parent();
+            while let Some(template) = parent.filter(|p| p.kind_id() == Cpp::TemplateDeclaration) {
+                let mut stack: Vec<_> = template.children().filter(is_template_header).collect();
+                while let Some(node) = stack.pop() {
+                    if is_cpp_condition(&node, code) {
+                        stats.conditions += 1;
+                    }
+                    stack.extend(node.children());
+                }
+                parent = template.parent();
+            }
+        } else if is_cpp_condition(node, code) && !in_function_template_header(node) {
+            stats.conditions += 1;
+        }
+    }
+}
+
+implement_metric_trait!(
+    TemplateComplexity,
+    PythonCode,
+    MozjsCode,
+    JavascriptCode,
+    TypescriptCode,
+    TsxCode,
+    RustCode,
+    JavaCode,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode,
+    CsharpCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::{check_func_space_with_options, check_metrics_with_options};
+
+    use super::*;
+
+    fn template_complexity_options() -> MetricsOptions {
+        MetricsOptions {
+            template_complexity: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn cpp_requires_clause() {
+        check_func_space_with_options::<CppParser, _>(
+            "template <typename T>
+             requires std::integral<T> // +1
+             T f(T x) {
+                 if (x > 0) {
+                     return x;
+                 }
+                 return -x;
+             }",
+            "foo.cpp",
+            &template_complexity_options(),
+            |func_space| {
+                let f = &func_space.spaces[0].metrics;
+                assert_eq!(f.template_complexity.template_complexity(), 1.);
+                // The runtime complexity is unchanged
+                assert_eq!(f.cyclomatic.cyclomatic(), 2.);
+                assert_eq!(
+                    func_space.metrics.template_complexity.template_complexity(),
+                    0.
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_template_conditions() {
+        check_metrics_with_options::<CppParser>(
+            "template <typename T, typename = std::enable_if_t<std::is_integral_v<T>>> // +1
+             T f(T x) requires (sizeof(T) > 1) { // +1
+                 return x;
+             }
+             template <typename T>
+             typename std::enable_if<true, T>::type g(T x) { // +1
+                 return x;
+             }
+             template <typename T>
+             T h(T x) {
+                 return x;
+             }
+             int i() {
+                 return 0;
+             }",
+            "foo.cpp",
+            &template_complexity_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.template_complexity,
+                    @r###"
+                    {
+                      "sum": 3.0,
+                      "average": 0.75,
+                      "min": 0.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_class_template() {
+        check_metrics_with_options::<CppParser>(
+            "template <typename T>
+             requires std::integral<T> // Not a function template
+             class A {
+                 T f(T x) requires (sizeof(T) > 1) { // +1
+                     return x;
+                 }
+             };",
+            "foo.cpp",
+            &template_complexity_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.template_complexity,
+                    @r###"
+                    {
+                      "sum": 1.0,
+                      "average": 1.0,
+                      "min": 1.0,
+                      "max": 1.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `TypeComplexity` metric.
+///
+/// This metric counts the number of members of the union types and
+/// the number of enum cases declared in a space. In C#, which has no
+/// union types, the type parameters of the generic declarations, such
+/// as `T` in `class C<T>`, are counted instead.
+///
+/// Type-level constructs do not add paths to a code, so this metric
+/// is kept separate from the `Cyclomatic` one.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    type_complexity: usize,
+    type_complexity_sum: usize,
+    type_complexity_min: usize,
+    type_complexity_max: usize,
+    space_count: usize,
+    enabled: bool,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            type_complexity: 0,
+            type_complexity_sum: 0,
+            type_complexity_min: usize::MAX,
+            type_complexity_max: 0,
+            space_count: 1,
+            enabled: false,
+        }
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("type_complexity", 4)?;
+        st.serialize_field("sum", &self.type_complexity_sum())?;
+        st.serialize_field("average", &self.type_complexity_average())?;
+        st.serialize_field("min", &self.type_complexity_min())?;
+        st.serialize_field("max", &self.type_complexity_max())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sum: {}, average: {}, min: {}, max: {}",
+            self.type_complexity_sum(),
+            self.type_complexity_average(),
+            self.type_complexity_min(),
+            self.type_complexity_max()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `TypeComplexity` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.type_complexity_max = self.type_complexity_max.max(other.type_complexity_max);
+        self.type_complexity_min = self.type_complexity_min.min(other.type_complexity_min);
+        self.type_complexity_sum += other.type_complexity_sum;
+        self.space_count += other.space_count;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the `TypeComplexity` metric value of a space
+    pub fn type_complexity(&self) -> f64 {
+        self.type_complexity as f64
+    }
+
+    /// Returns the `TypeComplexity` metric sum value
+    pub fn type_complexity_sum(&self) -> f64 {
+        self.type_complexity_sum as f64
+    }
+
+    /// Returns the `TypeComplexity` metric average value
+    ///
+    /// This value is computed dividing the sum of the `TypeComplexity`
+    /// values for the number of spaces.
+    pub fn type_complexity_average(&self) -> f64 {
+        self.type_complexity_sum() / self.space_count as f64
+    }
+
+    /// Returns the `TypeComplexity` metric minimum value
+    pub fn type_complexity_min(&self) -> f64 {
+        self.type_complexity_min as f64
+    }
+
+    /// Returns the `TypeComplexity` metric maximum value
+    pub fn type_complexity_max(&self) -> f64 {
+        self.type_complexity_max as f64
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.type_complexity_sum += self.type_complexity;
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_minmax(&mut self) {
+        self.type_complexity_max = self.type_complexity_max.max(self.type_complexity);
+        self.type_complexity_min = self.type_complexity_min.min(self.type_complexity);
+        self.compute_sum();
+    }
+
+    // Enables the `TypeComplexity` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `TypeComplexity` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait TypeComplexity
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+macro_rules! compute_type_complexity {
+    ($lang:ident, $node:ident, $stats:ident) => {
+        match $node.kind_id().into() {
+            // `A | B | C` is parsed as `(A | B) | C`, so only the members
+            // which are not union types themselves are counted
+            $lang::UnionType => {
+                $stats.type_complexity += $node
+                    .children()
+                    .filter(|child| {
+                        child.is_named()
+                            && !matches!(child.kind_id().into(), $lang::UnionType | $lang::Comment)
+                    })
+                    .count();
+            }
+            $lang::EnumBody => {
+                $stats.type_complexity += $node
+                    .children()
+                    .filter(|child| {
+                        matches!(
+                            child.kind_id().into(),
+                            $lang::PropertyIdentifier | $lang::EnumAssignment
+                        )
+                    })
+                    .count();
+            }
+            _ => {}
+        }
+    };
+}
+
+impl TypeComplexity for TypescriptCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_type_complexity!(Typescript, node, stats);
+    }
+}
+
+impl TypeComplexity for TsxCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        compute_type_complexity!(Tsx, node, stats);
+    }
+}
+
+impl TypeComplexity for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Csharp::*;
+
+        match node.kind_id().into() {
+            TypeParameterList => {
+                stats.type_complexity += node
+                    .children()
+                    .filter(|child| child.kind_id() == TypeParameter)
+                    .count();
+            }
+            EnumMemberDeclaration => stats.type_complexity += 1,
+            _ => {}
+        }
+    }
+}
+
+implement_metric_trait!(
+    TypeComplexity,
+    PythonCode,
+    MozjsCode,
+    JavascriptCode,
+    RustCode,
+    CppCode,
+    JavaCode,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::check_metrics_with_options;
+
+    use super::*;
+
+    fn type_complexity_options() -> MetricsOptions {
+        MetricsOptions {
+            type_complexity: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn typescript_union() {
+        check_metrics_with_options::<TypescriptParser>(
+            "type T = 'a' | 'b' | 'c' | number | null; // +5",
+            "foo.ts",
+            &type_complexity_options(),
+            |metric| {
+                // nspace = 1 (unit)
+                insta::assert_json_snapshot!(
+                    metric.type_complexity,
+                    @r###"
+                    {
+                      "sum": 5.0,
+                      "average": 5.0,
+                      "min": 5.0,
+                      "max": 5.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_enum_and_function() {
+        check_metrics_with_options::<TypescriptParser>(
+            "enum E { A, B = 2, C } // +3
+             function f(x: string | undefined): number { // +2
+                 return 0;
+             }",
+            "foo.ts",
+            &type_complexity_options(),
+            |metric| {
+                // nspace = 2 (func and unit)
+                insta::assert_json_snapshot!(
+                    metric.type_complexity,
+                    @r###"
+                    {
+                      "sum": 5.0,
+                      "average": 2.5,
+                      "min": 2.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn tsx_union() {
+        check_metrics_with_options::<TsxParser>(
+            "type T = | 'a' | 'b'; // +2",
+            "foo.tsx",
+            &type_complexity_options(),
+            |metric| {
+                // nspace = 1 (unit)
+                insta::assert_json_snapshot!(
+                    metric.type_complexity,
+                    @r###"
+                    {
+                      "sum": 2.0,
+                      "average": 2.0,
+                      "min": 2.0,
+                      "max": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn csharp_generics_and_enum() {
+        check_metrics_with_options::<CsharpParser>(
+            "enum E { A, B = 2, C } // +3
+             class Cache<K, V> where K : IComparable<K> { // +2
+                 V Get<T>(K key, T hint) { // +1
+                     return default;
+                 }
+             }",
+            "foo.cs",
+            &type_complexity_options(),
+            |metric| {
+                // nspace = 3 (unit, class and method)
+                insta::assert_json_snapshot!(
+                    metric.type_complexity,
+                    @r###"
+                    {
+                      "sum": 6.0,
+                      "average": 2.0,
+                      "min": 1.0,
+                      "max": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::checker::Checker;
+use crate::macros::implement_metric_trait;
+use crate::*;
+
+/// The `UnsafeCode` metric.
+///
+/// This metric counts the `unsafe` blocks and the `unsafe` functions
+/// of a space, whose soundness is not checked by the compiler, so they
+/// are a risk to be reviewed rather than a source of complexity.
+///
+/// An `unsafe` function of a trait is counted even without a body,
+/// since its callers have to uphold its contract. In C#, the `unsafe`
+/// statements and the methods, constructors, operators and local functions
+/// declared `unsafe` are counted.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(remote = "Self")]
+pub struct Stats {
+    blocks: usize,
+    blocks_sum: usize,
+    functions: usize,
+    functions_sum: usize,
+    enabled: bool,
+}
+
+impl Serialize for Stats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("unsafe_code", 3)?;
+        st.serialize_field("blocks", &self.blocks_sum())?;
+        st.serialize_field("functions", &self.functions_sum())?;
+        st.serialize_field("total", &self.total())?;
+        st.end()
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "blocks: {}, functions: {}, total: {}",
+            self.blocks_sum(),
+            self.functions_sum(),
+            self.total()
+        )
+    }
+}
+
+impl Stats {
+    /// Merges a second `UnsafeCode` metric into the first one
+    pub fn merge(&mut self, other: &Stats) {
+        self.blocks_sum += other.blocks_sum;
+        self.functions_sum += other.functions_sum;
+        self.enabled |= other.enabled;
+    }
+
+    /// Returns the number of `unsafe` blocks of a space
+    pub fn blocks(&self) -> f64 {
+        self.blocks as f64
+    }
+
+    /// Returns the sum of the `unsafe` blocks
+    pub fn blocks_sum(&self) -> f64 {
+        self.blocks_sum as f64
+    }
+
+    /// Returns the number of `unsafe` functions of a space
+    pub fn functions(&self) -> f64 {
+        self.functions as f64
+    }
+
+    /// Returns the sum of the `unsafe` functions
+    pub fn functions_sum(&self) -> f64 {
+        self.functions_sum as f64
+    }
+
+    /// Returns the sum of the `unsafe` blocks and functions
+    pub fn total(&self) -> f64 {
+        self.blocks_sum() + self.functions_sum()
+    }
+
+    #[inline(always)]
+    pub(crate) fn compute_sum(&mut self) {
+        self.blocks_sum += self.blocks;
+        self.functions_sum += self.functions;
+    }
+
+    // Enables the `UnsafeCode` metric, which is only computed on demand
+    #[inline(always)]
+    pub(crate) fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    // Checks if the `UnsafeCode` metric is disabled
+    #[inline(always)]
+    pub(crate) fn is_disabled(&self) -> bool {
+        !self.enabled
+    }
+}
+
+pub trait UnsafeCode
+where
+    Self: Checker,
+{
+    const IMPLEMENTED: bool = true;
+
+    fn compute(node: &Node, stats: &mut Stats);
+}
+
+impl UnsafeCode for RustCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Rust::*;
+
+        match node.kind_id().into() {
+            UnsafeBlock => stats.blocks += 1,
+            FunctionModifiers => {
+                // The modifiers of a function pointer type, such as
+                // `unsafe fn()`, do not declare a function
+                let is_function = node.parent().is_some_and(|parent| {
+                    matches!(
+                        parent.kind_id().into(),
+                        FunctionItem | FunctionSignatureItem
+                    )
+                });
+                if is_function && node.children().any(|child| child.kind_id() == Unsafe) {
+                    stats.functions += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl UnsafeCode for CsharpCode {
+    fn compute(node: &Node, stats: &mut Stats) {
+        use Csharp::*;
+
+        match node.kind_id().into() {
+            UnsafeStatement => stats.blocks += 1,
+            // The modifiers of a type, such as an `unsafe class`,
+            // do not declare a function
+            Modifier
+                if node.first_child(|id| id == Unsafe).is_some()
+                    && node.parent().is_some_and(|parent| Self::is_func(&parent)) =>
+            {
+                stats.functions += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
+implement_metric_trait!(
+    UnsafeCode,
+    PythonCode,
+    MozjsCode,
+    JavascriptCode,
+    TypescriptCode,
+    TsxCode,
+    CppCode,
+    JavaCode,
+    HclCode,
+    KotlinCode,
+    PreprocCode,
+    CcommentCode,
+    GoCode
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::tools::{check_func_space_with_options, check_metrics_with_options};
+
+    use super::*;
+
+    fn unsafe_code_options() -> MetricsOptions {
+        MetricsOptions {
+            unsafe_code: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rust_unsafe_code() {
+        check_metrics_with_options::<RustParser>(
+            "unsafe fn read(p: *const u8) -> u8 { // +1 function
+                 *p
+             }
+             fn first(v: &[u8]) -> u8 {
+                 unsafe { read(v.as_ptr()) } // +1 block
+             }
+             fn second(v: &[u8], f: unsafe fn(*const u8) -> u8) -> u8 {
+                 unsafe { f(v.as_ptr().add(1)) } // +1 block
+             }",
+            "foo.rs",
+            &unsafe_code_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.unsafe_code,
+                    @r###"
+                    {
+                      "blocks": 2.0,
+                      "functions": 1.0,
+                      "total": 3.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn rust_unsafe_trait_function() {
+        check_func_space_with_options::<RustParser, _>(
+            "trait Raw {
+                 unsafe fn get(&self) -> u8; // +1 function
+                 fn len(&self) -> usize;
+             }",
+            "foo.rs",
+            &unsafe_code_options(),
+            |func_space| {
+                let trait_space = &func_space.spaces[0].metrics.unsafe_code;
+                assert_eq!(trait_space.functions(), 1.);
+                assert_eq!(trait_space.blocks(), 0.);
+            },
+        );
+    }
+
+    #[test]
+    fn csharp_unsafe_code() {
+        check_metrics_with_options::<CsharpParser>(
+            "unsafe class Buffer {
+                 unsafe void Copy(byte* src, byte* dst) { // +1 function
+                     *dst = *src;
+                 }
+                 int First(byte[] bytes) {
+                     unsafe { // +1 block
+                         fixed (byte* p = bytes) {
+                             return *p;
+                         }
+                     }
+                 }
+             }",
+            "foo.cs",
+            &unsafe_code_options(),
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.unsafe_code,
+                    @r###"
+                    {
+                      "blocks": 1.0,
+                      "functions": 1.0,
+                      "total": 2.0
+                    }"###
+                );
+            },
+        );
+    }
+}
@@ -1,5 +1,5 @@
-use serde::Serialize;
 use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 use crate::checker::Checker;
@@ -18,12 +18,18 @@ use crate::*;
 ///
 /// Original paper and definition:
 /// <https://www.researchgate.net/publication/3187649_Kemerer_CF_A_metric_suite_for_object_oriented_design_IEEE_Trans_Softw_Eng_206_476-493>
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct Stats {
+    #[serde(with = "crate::cache::float_bits")]
     cyclomatic: f64,
+    #[serde(with = "crate::cache::float_bits")]
     class_wmc: f64,
+    #[serde(with = "crate::cache::float_bits")]
     interface_wmc: f64,
+    #[serde(with = "crate::cache::float_bits")]
     class_wmc_sum: f64,
+    #[serde(with = "crate::cache::float_bits")]
     interface_wmc_sum: f64,
     space_kind: SpaceKind,
 }
@@ -62,7 +68,8 @@ impl Stats {
         // into the `Wmc` metric value of a class or interface
         if let Function = other.space_kind {
             match self.space_kind {
-                Class => self.class_wmc += other.cyclomatic,
+                // The methods of a `C++` struct are the ones of a class
+                Class | Struct => self.class_wmc += other.cyclomatic,
                 Interface => self.interface_wmc += other.cyclomatic,
                 _ => {}
             }
@@ -121,22 +128,54 @@ pub trait Wmc
 where
     Self: Checker,
 {
+    const IMPLEMENTED: bool = true;
+
     fn compute(space_kind: SpaceKind, cyclomatic: &cyclomatic::Stats, stats: &mut Stats);
 }
 
+// Saves the kind of a space and the cyclomatic complexity of a method,
+// which is merged into its class when the method space is finalized
+fn compute_wmc(space_kind: SpaceKind, cyclomatic: &cyclomatic::Stats, stats: &mut Stats) {
+    use SpaceKind::*;
+
+    if let Unit | Class | Struct | Interface | Function = space_kind {
+        if stats.space_kind == Unknown {
+            stats.space_kind = space_kind;
+        }
+        if space_kind == Function {
+            // Saves the cyclomatic complexity of the method
+            stats.cyclomatic = cyclomatic.cyclomatic_sum();
+        }
+    }
+}
+
 impl Wmc for JavaCode {
     fn compute(space_kind: SpaceKind, cyclomatic: &cyclomatic::Stats, stats: &mut Stats) {
-        use SpaceKind::*;
+        compute_wmc(space_kind, cyclomatic, stats);
+    }
+}
 
-        if let Unit | Class | Interface | Function = space_kind {
-            if stats.space_kind == Unknown {
-                stats.space_kind = space_kind;
-            }
-            if space_kind == Function {
-                // Saves the cyclomatic complexity of the method
-                stats.cyclomatic = cyclomatic.cyclomatic_sum();
-            }
-        }
+impl Wmc for CppCode {
+    fn compute(space_kind: SpaceKind, cyclomatic: &cyclomatic::Stats, stats: &mut Stats) {
+        compute_wmc(space_kind, cyclomatic, stats);
+    }
+}
+
+impl Wmc for TypescriptCode {
+    fn compute(space_kind: SpaceKind, cyclomatic: &cyclomatic::Stats, stats: &mut Stats) {
+        compute_wmc(space_kind, cyclomatic, stats);
+    }
+}
+
+impl Wmc for TsxCode {
+    fn compute(space_kind: SpaceKind, cyclomatic: &cyclomatic::Stats, stats: &mut Stats) {
+        compute_wmc(space_kind, cyclomatic, stats);
+    }
+}
+
+impl Wmc for CsharpCode {
+    fn compute(space_kind: SpaceKind, cyclomatic: &cyclomatic::Stats, stats: &mut Stats) {
+        compute_wmc(space_kind, cyclomatic, stats);
     }
 }
 
@@ -145,18 +184,17 @@ implement_metric_trait!(
     PythonCode,
     MozjsCode,
     JavascriptCode,
-    TypescriptCode,
-    TsxCode,
     RustCode,
-    CppCode,
     PreprocCode,
     CcommentCode,
-    KotlinCode
+    HclCode,
+    KotlinCode,
+    GoCode
 );
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::check_metrics;
+    use crate::tools::{check_func_space, check_metrics};
 
     use super::*;
 
@@ -669,4 +707,116 @@ mod tests {
             },
         );
     }
+
+    // The example of the `java_real_class` test of the `Cyclomatic` metric
+    #[test]
+    fn java_real_class() {
+        check_func_space::<JavaParser, _>(
+            "public class Matrix { // wmc = 9
+                private int[][] m = new int[5][5];
+
+                public void init() { // +1
+                    for (int i = 0; i < m.length; i++) { // +1
+                        for (int j = 0; j < m[i].length; j++) { // +1
+                            m[i][j] = i * j;
+                        }
+                    }
+                }
+                public int compute(int i, int j) { // +1
+                    try {
+                        return m[i][j] / m[j][i];
+                    } catch (ArithmeticException e) { // +1
+                        return -1;
+                    } catch (ArrayIndexOutOfBoundsException e) { // +1
+                        return -2;
+                    }
+                }
+                public void print(int result) { // +1
+                    switch (result) {
+                        case -1: // +1
+                            System.out.println(\"Division by zero\");
+                            break;
+                        case -2: // +1
+                            System.out.println(\"Wrong index number\");
+                            break;
+                        default:
+                            System.out.println(\"The result is \" + result);
+                    }
+                }
+            }",
+            "foo.java",
+            |func_space| {
+                let class = &func_space.spaces[0];
+                let methods: f64 = class
+                    .spaces
+                    .iter()
+                    .map(|method| method.metrics.cyclomatic.cyclomatic())
+                    .sum();
+                assert_eq!(class.spaces.len(), 3);
+                assert_eq!(methods, 9.);
+                assert_eq!(class.metrics.wmc.class_wmc(), methods);
+                assert_eq!(func_space.metrics.wmc.total_wmc(), methods);
+            },
+        );
+    }
+
+    #[test]
+    fn cpp_classes() {
+        check_metrics::<CppParser>(
+            "class A { // wmc = 3
+             public:
+                 int f(int a) { // +1
+                     return a > 0 ? a : -a; // +1
+                 }
+                 void g() {} // +1
+             };
+             struct B { // wmc = 2
+                 bool h(bool a, bool b) { // +1
+                     return a && b; // +1
+                 }
+             };",
+            "foo.cpp",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.wmc,
+                    @r###"
+                    {
+                      "classes": 5.0,
+                      "interfaces": 0.0,
+                      "total": 5.0
+                    }"###
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn typescript_class_and_interface() {
+        check_metrics::<TypescriptParser>(
+            "interface Shape {
+                 area(): number;
+             }
+             class Square implements Shape { // wmc = 3
+                 constructor(private side: number) {} // +1
+                 area(): number { // +1
+                     if (this.side < 0) { // +1
+                         return 0;
+                     }
+                     return this.side * this.side;
+                 }
+             }",
+            "foo.ts",
+            |metric| {
+                insta::assert_json_snapshot!(
+                    metric.wmc,
+                    @r###"
+                    {
+                      "classes": 3.0,
+                      "interfaces": 0.0,
+                      "total": 3.0
+                    }"###
+                );
+            },
+        );
+    }
 }
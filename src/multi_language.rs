@@ -0,0 +1,144 @@
+use std::path::Path;
+
+use serde::{Serialize, Serializer};
+
+use crate::langs::LANG;
+use crate::spaces::{CodeMetrics, FuncSpace};
+
+/// The function spaces of a code computed by the parser of a language.
+#[derive(Debug, Clone, Serialize)]
+pub struct LanguageSpace {
+    /// The language of the parser, serialized as its name
+    #[serde(serialize_with = "serialize_language")]
+    pub language: LANG,
+    /// The unit space computed by the parser
+    pub space: FuncSpace,
+}
+
+/// The function spaces of a file analyzed by the parsers
+/// of many languages, such as an `HTML` file embedding
+/// `JavaScript` code.
+#[derive(Debug, Clone, Serialize)]
+pub struct MultiLanguageSpace {
+    /// The name of the file
+    pub name: Option<String>,
+    /// The metrics of all languages merged together
+    ///
+    /// If `None`, the file has not been analyzed by any parser
+    pub metrics: Option<CodeMetrics>,
+    /// The function spaces computed by each parser,
+    /// in the order of the passes
+    pub spaces: Vec<LanguageSpace>,
+}
+
+pub(crate) fn serialize_language<S: Serializer>(
+    language: &LANG,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(language.get_name())
+}
+
+/// Merges the function spaces computed by the parsers of many
+/// languages on the same file into a single result, where each
+/// unit space is tagged with its language.
+///
+/// The lines of each unit space are the ones of the file, as long as
+/// each pass analyzes a code where everything outside its language
+/// is blanked, as done by [`extract_vue_script`].
+///
+/// [`extract_vue_script`]: crate::extract_vue_script
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{get_function_spaces, merge_language_spaces, LANG};
+///
+/// let path = Path::new("foo.html");
+/// let js = get_function_spaces(&LANG::Javascript, b"function f() {}".to_vec(), path, None)
+///     .unwrap();
+///
+/// let merged = merge_language_spaces(path, vec![(LANG::Javascript, js)]);
+/// assert_eq!(merged.spaces[0].language, LANG::Javascript);
+/// ```
+pub fn merge_language_spaces(
+    path: &Path,
+    passes: impl IntoIterator<Item = (LANG, FuncSpace)>,
+) -> MultiLanguageSpace {
+    let mut metrics: Option<CodeMetrics> = None;
+    let spaces = passes
+        .into_iter()
+        .map(|(language, space)| {
+            match metrics.as_mut() {
+                Some(metrics) => metrics.merge(&space.metrics),
+                None => metrics = Some(space.metrics.clone()),
+            }
+            LanguageSpace { language, space }
+        })
+        .collect();
+
+    MultiLanguageSpace {
+        name: path.to_str().map(|name| name.to_string()),
+        metrics,
+        spaces,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::langs::get_function_spaces;
+
+    use super::*;
+
+    #[test]
+    fn merge_two_languages() {
+        // There is no `SQL` parser, so the embedded code is `Python`
+        let path = Path::new("page.html");
+        let js = "function f(a) {\n    if (a) {}\n}\n\n";
+        let py = "\n\n\ndef g(a):\n    return a or 1\n";
+
+        let passes = [(LANG::Javascript, js), (LANG::Python, py)].map(|(language, code)| {
+            let space = get_function_spaces(&language, code.as_bytes().to_vec(), path, None);
+            (language, space.unwrap())
+        });
+        let merged = merge_language_spaces(path, passes);
+
+        assert_eq!(merged.name.as_deref(), Some("page.html"));
+        let tagged: Vec<_> = merged
+            .spaces
+            .iter()
+            .map(|tagged| {
+                let function = &tagged.space.spaces[0];
+                (
+                    tagged.language,
+                    function.name.as_deref(),
+                    function.start_line,
+                )
+            })
+            .collect();
+        assert_eq!(
+            tagged,
+            [
+                (LANG::Javascript, Some("f"), 1),
+                (LANG::Python, Some("g"), 4)
+            ]
+        );
+
+        let metrics = merged.metrics.unwrap();
+        assert_eq!(metrics.cyclomatic.cyclomatic_sum(), 6.);
+        assert_eq!(metrics.nom.functions_sum(), 2.);
+
+        let json = serde_json::to_value(&merged.spaces).unwrap();
+        assert_eq!(json[0]["language"], "javascript");
+        assert_eq!(json[1]["language"], "python");
+    }
+
+    #[test]
+    fn no_passes() {
+        let merged = merge_language_spaces(Path::new("page.html"), []);
+
+        assert!(merged.metrics.is_none());
+        assert!(merged.spaces.is_empty());
+    }
+}
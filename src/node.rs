@@ -1,6 +1,6 @@
 use tree_sitter::Node as OtherNode;
 use tree_sitter::Tree as OtherTree;
-use tree_sitter::{Parser, TreeCursor};
+use tree_sitter::{InputEdit, Parser, TreeCursor};
 
 use crate::checker::Checker;
 use crate::traits::{LanguageInfo, Search};
@@ -15,9 +15,23 @@ impl Tree {
             .set_language(&T::get_lang().get_ts_language())
             .unwrap();
 
+        Self::with_parser(&mut parser, code)
+    }
+
+    pub(crate) fn with_parser(parser: &mut Parser, code: &[u8]) -> Self {
         Self(parser.parse(code, None).unwrap())
     }
 
+    // Reparses an edited code reusing the nodes of this tree which were
+    // not touched by the edit, hence the tree must have been edited first
+    pub(crate) fn reparse(&self, parser: &mut Parser, code: &[u8]) -> Self {
+        Self(parser.parse(code, Some(&self.0)).unwrap())
+    }
+
+    pub(crate) fn edit(&mut self, edit: &InputEdit) {
+        self.0.edit(edit);
+    }
+
     pub(crate) fn get_root(&self) -> Node {
         Node(self.0.root_node())
     }
@@ -46,6 +60,10 @@ impl<'a> Node<'a> {
         self.0.kind_id()
     }
 
+    pub(crate) fn is_named(&self) -> bool {
+        self.0.is_named()
+    }
+
     pub(crate) fn utf8_text(&self, data: &'a [u8]) -> Option<&'a str> {
         self.0.utf8_text(data).ok()
     }
@@ -108,7 +126,7 @@ impl<'a> Node<'a> {
         self.0.child_count()
     }
 
-    pub(crate) fn child_by_field_name(&self, name: &str) -> Option<Node> {
+    pub(crate) fn child_by_field_name(&self, name: &str) -> Option<Node<'a>> {
         self.0.child_by_field_name(name).map(Node)
     }
 
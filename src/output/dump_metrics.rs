@@ -2,16 +2,35 @@ use std::io::Write;
 use termcolor::{Color, ColorChoice, StandardStream, StandardStreamLock};
 
 use crate::abc;
+use crate::bare_ifs;
+use crate::captures;
+use crate::cfg;
+use crate::chain_length;
 use crate::cognitive;
+use crate::conditional_imports;
 use crate::cyclomatic;
+use crate::decisions;
+use crate::early_returns;
+use crate::enum_variants;
+use crate::exception_types;
 use crate::exit;
+use crate::expr_nesting;
+use crate::goroutines;
 use crate::halstead;
+use crate::literal_nesting;
 use crate::loc;
 use crate::mi;
 use crate::nargs;
+use crate::negations;
+use crate::nlocals;
 use crate::nom;
 use crate::npa;
 use crate::npm;
+use crate::referenced_types;
+use crate::statement_count;
+use crate::template_complexity;
+use crate::type_complexity;
+use crate::unsafe_code;
 use crate::wmc;
 
 use crate::spaces::{CodeMetrics, FuncSpace};
@@ -114,7 +133,37 @@ fn dump_metrics(
     dump_abc(&metrics.abc, &prefix, false, stdout)?;
     dump_wmc(&metrics.wmc, &prefix, false, stdout)?;
     dump_npm(&metrics.npm, &prefix, false, stdout)?;
-    dump_npa(&metrics.npa, &prefix, true, stdout)
+    dump_npa(&metrics.npa, &prefix, false, stdout)?;
+    dump_expr_nesting(&metrics.expr_nesting, &prefix, false, stdout)?;
+    dump_cfg(&metrics.cfg, &prefix, false, stdout)?;
+    dump_early_returns(&metrics.early_returns, &prefix, false, stdout)?;
+    dump_type_complexity(&metrics.type_complexity, &prefix, false, stdout)?;
+    dump_captures(&metrics.captures, &prefix, false, stdout)?;
+    dump_nlocals(&metrics.nlocals, &prefix, false, stdout)?;
+    dump_bare_ifs(&metrics.bare_ifs, &prefix, false, stdout)?;
+    dump_exception_types(&metrics.exception_types, &prefix, false, stdout)?;
+    dump_chain_length(&metrics.chain_length, &prefix, false, stdout)?;
+    dump_statement_count(&metrics.statement_count, &prefix, false, stdout)?;
+    dump_referenced_types(&metrics.referenced_types, &prefix, false, stdout)?;
+    dump_template_complexity(&metrics.template_complexity, &prefix, false, stdout)?;
+    dump_literal_nesting(&metrics.literal_nesting, &prefix, false, stdout)?;
+    dump_negations(&metrics.negations, &prefix, false, stdout)?;
+    // The metrics computed on demand may be missing, so a metric
+    // is the last one when all the following ones are disabled
+    let unsafe_code_last = metrics.goroutines.is_disabled();
+    let conditional_imports_last = unsafe_code_last && metrics.unsafe_code.is_disabled();
+    let enum_variants_last = conditional_imports_last && metrics.conditional_imports.is_disabled();
+    let decisions_last = enum_variants_last && metrics.enum_variants.is_disabled();
+    dump_decisions(&metrics.decisions, &prefix, decisions_last, stdout)?;
+    dump_enum_variants(&metrics.enum_variants, &prefix, enum_variants_last, stdout)?;
+    dump_conditional_imports(
+        &metrics.conditional_imports,
+        &prefix,
+        conditional_imports_last,
+        stdout,
+    )?;
+    dump_unsafe_code(&metrics.unsafe_code, &prefix, unsafe_code_last, stdout)?;
+    dump_goroutines(&metrics.goroutines, &prefix, true, stdout)
 }
 
 fn dump_cognitive(
@@ -193,7 +242,14 @@ fn dump_halstead(
     dump_value("level", stats.level(), &prefix, false, stdout)?;
     dump_value("effort", stats.effort(), &prefix, false, stdout)?;
     dump_value("time", stats.time(), &prefix, false, stdout)?;
-    dump_value("bugs", stats.bugs(), &prefix, true, stdout)
+    dump_value("bugs", stats.bugs(), &prefix, false, stdout)?;
+    dump_value(
+        "delivered bugs",
+        stats.delivered_bugs(),
+        &prefix,
+        true,
+        stdout,
+    )
 }
 
 fn dump_loc(
@@ -423,6 +479,563 @@ fn dump_npa(
     dump_value("average", stats.total_cda(), &prefix, true, stdout)
 }
 
+fn dump_expr_nesting(
+    stats: &expr_nesting::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "expr_nesting")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value(
+        "average",
+        stats.expr_nesting_average(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("min", stats.expr_nesting_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.expr_nesting_max(), &prefix, true, stdout)
+}
+
+fn dump_cfg(
+    stats: &cfg::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "cfg")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("sum", stats.cfg_sum(), &prefix, false, stdout)?;
+    dump_value("average", stats.cfg_average(), &prefix, false, stdout)?;
+    dump_value("min", stats.cfg_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.cfg_max(), &prefix, true, stdout)
+}
+
+fn dump_early_returns(
+    stats: &early_returns::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "early_returns")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("sum", stats.early_returns_sum(), &prefix, false, stdout)?;
+    dump_value(
+        "average",
+        stats.early_returns_average(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("min", stats.early_returns_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.early_returns_max(), &prefix, true, stdout)
+}
+
+fn dump_type_complexity(
+    stats: &type_complexity::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "type_complexity")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("sum", stats.type_complexity_sum(), &prefix, false, stdout)?;
+    dump_value(
+        "average",
+        stats.type_complexity_average(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("min", stats.type_complexity_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.type_complexity_max(), &prefix, true, stdout)
+}
+
+fn dump_captures(
+    stats: &captures::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "captures")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("sum", stats.captures_sum(), &prefix, false, stdout)?;
+    dump_value("average", stats.captures_average(), &prefix, false, stdout)?;
+    dump_value("min", stats.captures_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.captures_max(), &prefix, true, stdout)
+}
+
+fn dump_nlocals(
+    stats: &nlocals::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "nlocals")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("sum", stats.nlocals_sum(), &prefix, false, stdout)?;
+    dump_value("average", stats.nlocals_average(), &prefix, false, stdout)?;
+    dump_value("min", stats.nlocals_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.nlocals_max(), &prefix, true, stdout)
+}
+
+fn dump_bare_ifs(
+    stats: &bare_ifs::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "bare_ifs")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("sum", stats.bare_ifs_sum(), &prefix, false, stdout)?;
+    dump_value("average", stats.bare_ifs_average(), &prefix, false, stdout)?;
+    dump_value("min", stats.bare_ifs_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.bare_ifs_max(), &prefix, true, stdout)
+}
+
+fn dump_exception_types(
+    stats: &exception_types::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "exception_types")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("sum", stats.exception_types_sum(), &prefix, false, stdout)?;
+    dump_value(
+        "average",
+        stats.exception_types_average(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("min", stats.exception_types_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.exception_types_max(), &prefix, true, stdout)
+}
+
+fn dump_chain_length(
+    stats: &chain_length::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "chain_length")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value(
+        "average",
+        stats.chain_length_average(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("min", stats.chain_length_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.chain_length_max(), &prefix, true, stdout)
+}
+
+fn dump_statement_count(
+    stats: &statement_count::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "statement_count")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("sum", stats.statement_count_sum(), &prefix, false, stdout)?;
+    dump_value(
+        "average",
+        stats.statement_count_average(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("min", stats.statement_count_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.statement_count_max(), &prefix, true, stdout)
+}
+
+fn dump_referenced_types(
+    stats: &referenced_types::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "referenced_types")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("sum", stats.referenced_types_sum(), &prefix, false, stdout)?;
+    dump_value(
+        "average",
+        stats.referenced_types_average(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("min", stats.referenced_types_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.referenced_types_max(), &prefix, true, stdout)
+}
+
+fn dump_template_complexity(
+    stats: &template_complexity::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "template_complexity")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value(
+        "sum",
+        stats.template_complexity_sum(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value(
+        "average",
+        stats.template_complexity_average(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value(
+        "min",
+        stats.template_complexity_min(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value(
+        "max",
+        stats.template_complexity_max(),
+        &prefix,
+        true,
+        stdout,
+    )
+}
+
+fn dump_literal_nesting(
+    stats: &literal_nesting::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "literal_nesting")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value(
+        "average",
+        stats.literal_nesting_average(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("min", stats.literal_nesting_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.literal_nesting_max(), &prefix, true, stdout)
+}
+
+fn dump_negations(
+    stats: &negations::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "negations")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("sum", stats.negations_sum(), &prefix, false, stdout)?;
+    dump_value("average", stats.negations_average(), &prefix, false, stdout)?;
+    dump_value("min", stats.negations_min(), &prefix, false, stdout)?;
+    dump_value("max", stats.negations_max(), &prefix, false, stdout)?;
+    dump_value(
+        "double",
+        stats.double_negations_sum(),
+        &prefix,
+        true,
+        stdout,
+    )
+}
+
+fn dump_decisions(
+    stats: &decisions::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "decisions")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("branches", stats.branches_sum(), &prefix, false, stdout)?;
+    dump_value("loops", stats.loops_sum(), &prefix, false, stdout)?;
+    dump_value("switches", stats.switches_sum(), &prefix, false, stdout)?;
+    dump_value("catches", stats.catches_sum(), &prefix, false, stdout)?;
+    dump_value(
+        "ratio",
+        stats.branches_loops_ratio(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("distinct", stats.distinct(), &prefix, true, stdout)
+}
+
+fn dump_enum_variants(
+    stats: &enum_variants::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "enum_variants")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("enums", stats.enums_sum(), &prefix, false, stdout)?;
+    dump_value("variants", stats.variants_sum(), &prefix, false, stdout)?;
+    dump_value("data", stats.data_variants_sum(), &prefix, false, stdout)?;
+    dump_value("max", stats.variants_max(), &prefix, true, stdout)
+}
+
+fn dump_conditional_imports(
+    stats: &conditional_imports::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "conditional_imports")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value(
+        "conditional",
+        stats.conditional_sum(),
+        &prefix,
+        false,
+        stdout,
+    )?;
+    dump_value("dynamic", stats.dynamic_sum(), &prefix, false, stdout)?;
+    dump_value("total", stats.total(), &prefix, true, stdout)
+}
+
+fn dump_unsafe_code(
+    stats: &unsafe_code::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "unsafe_code")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("blocks", stats.blocks_sum(), &prefix, false, stdout)?;
+    dump_value("functions", stats.functions_sum(), &prefix, false, stdout)?;
+    dump_value("total", stats.total(), &prefix, true, stdout)
+}
+
+fn dump_goroutines(
+    stats: &goroutines::Stats,
+    prefix: &str,
+    last: bool,
+    stdout: &mut StandardStreamLock,
+) -> std::io::Result<()> {
+    if stats.is_disabled() {
+        return Ok(());
+    }
+
+    let (pref_child, pref) = if last { ("   ", "`- ") } else { ("|  ", "|- ") };
+
+    color(stdout, Color::Blue)?;
+    write!(stdout, "{prefix}{pref}")?;
+
+    intense_color(stdout, Color::Green)?;
+    writeln!(stdout, "goroutines")?;
+
+    let prefix = format!("{prefix}{pref_child}");
+    dump_value("sum", stats.goroutines_sum(), &prefix, false, stdout)?;
+    dump_value("max", stats.goroutines_max(), &prefix, true, stdout)
+}
+
 fn dump_value(
     name: &str,
     val: f64,
@@ -0,0 +1,90 @@
+use crate::spaces::{FuncSpace, SpaceKind};
+
+/// Returns the functions of a code as folded stacks, the format read
+/// by flamegraph tools, so that its complexity hotspots can be visualized.
+///
+/// Each function is a line containing the names of the spaces enclosing it,
+/// from the unit space to the function itself, separated by `;` and followed
+/// by its `Cyclomatic` complexity, such as `foo.rs;Impl;method 3`.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use rust_code_analysis::{folded_stacks, metrics, ParserTrait, RustParser};
+///
+/// let source_code = "fn f(x: bool) { if x {} }";
+/// let path = PathBuf::from("foo.rs");
+/// let parser = RustParser::new(source_code.as_bytes().to_vec(), &path, None);
+/// let space = metrics(&parser, &path).unwrap();
+///
+/// assert_eq!(folded_stacks(&space), "foo.rs;f 2\n");
+/// ```
+pub fn folded_stacks(space: &FuncSpace) -> String {
+    let mut folded = String::new();
+    let mut stack = vec![(space, frame(space))];
+
+    while let Some((space, frames)) = stack.pop() {
+        if matches!(space.kind, SpaceKind::Function | SpaceKind::TraitDefault) {
+            folded.push_str(&format!(
+                "{frames} {}\n",
+                space.metrics.cyclomatic.cyclomatic() as usize
+            ));
+        }
+        // Reversed, so the spaces are written in the order of the code
+        for subspace in space.spaces.iter().rev() {
+            stack.push((subspace, format!("{frames};{}", frame(subspace))));
+        }
+    }
+
+    folded
+}
+
+// The `;` and whitespaces separate the frames and the weight of a stack,
+// so they cannot be contained in a frame.
+fn frame(space: &FuncSpace) -> String {
+    space
+        .name
+        .as_deref()
+        .unwrap_or("<unknown>")
+        .replace(';', ":")
+        .replace(char::is_whitespace, "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{JavaParser, ParserTrait, metrics};
+
+    use super::*;
+
+    #[test]
+    fn java_folded_stacks() {
+        let path = PathBuf::from("foo.java");
+        let source = "class A {
+            void f(int x) {
+                if (x > 0) {
+                    x++;
+                }
+            }
+            class B {
+                int g(int x) {
+                    return x > 0 && x < 10 ? 1 : 0;
+                }
+            }
+            void h() {}
+        }";
+        let parser = JavaParser::new(source.as_bytes().to_vec(), &path, None);
+        let space = metrics(&parser, &path).unwrap();
+
+        assert_eq!(
+            folded_stacks(&space),
+            "foo.java;A;f 2
+foo.java;A;B;g 3
+foo.java;A;h 1
+"
+        );
+    }
+}
@@ -6,3 +6,6 @@ pub use dump_metrics::*;
 
 pub(crate) mod dump_ops;
 pub use dump_ops::*;
+
+pub(crate) mod folded;
+pub use folded::*;
@@ -1,19 +1,39 @@
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use crate::abc::Abc;
+use crate::bare_ifs::BareIfs;
+use crate::captures::Captures;
+use crate::cfg::Cfg;
+use crate::chain_length::ChainLength;
 use crate::checker::Checker;
 use crate::cognitive::Cognitive;
+use crate::conditional_imports::ConditionalImports;
 use crate::cyclomatic::Cyclomatic;
+use crate::decisions::Decisions;
+use crate::early_returns::EarlyReturns;
+use crate::enum_variants::EnumVariants;
+use crate::exception_types::ExceptionTypes;
 use crate::exit::Exit;
+use crate::expr_nesting::ExprNesting;
+use crate::goroutines::Goroutines;
 use crate::halstead::Halstead;
+use crate::literal_nesting::LiteralNesting;
 use crate::loc::Loc;
 use crate::mi::Mi;
 use crate::nargs::NArgs;
+use crate::negations::Negations;
+use crate::nlocals::NLocals;
 use crate::nom::Nom;
 use crate::npa::Npa;
 use crate::npm::Npm;
+use crate::referenced_types::ReferencedTypes;
+use crate::statement_count::StatementCount;
+use crate::template_complexity::TemplateComplexity;
+use crate::type_complexity::TypeComplexity;
+use crate::unsafe_code::UnsafeCode;
 use crate::wmc::Wmc;
 
 use crate::alterator::Alterator;
@@ -23,6 +43,7 @@ use crate::c_macro;
 use crate::langs::*;
 use crate::node::{Node, Tree};
 use crate::preproc::{PreprocResults, get_macros};
+use crate::session::{Edit, EditError};
 use crate::traits::*;
 
 #[derive(Debug)]
@@ -42,13 +63,177 @@ pub struct Parser<
         + Nom
         + Npa
         + Npm
-        + Wmc,
+        + Wmc
+        + ExprNesting
+        + Cfg
+        + EarlyReturns
+        + TypeComplexity
+        + Captures
+        + NLocals
+        + BareIfs
+        + ExceptionTypes
+        + ChainLength
+        + StatementCount
+        + ReferencedTypes
+        + TemplateComplexity
+        + LiteralNesting
+        + Negations
+        + Decisions
+        + EnumVariants
+        + ConditionalImports
+        + UnsafeCode
+        + Goroutines,
 > {
     code: Vec<u8>,
     tree: Tree,
     phantom: PhantomData<T>,
 }
 
+/// A reusable parser handle.
+///
+/// Creating a parser and setting its language has a cost which becomes
+/// noticeable when many files are analyzed in a tight loop.
+/// A handle owns a single parser: it can be created once and then passed
+/// to the functions which parse a code, so that the same parser is reused
+/// each time.
+///
+/// The language of the parser is changed on demand, hence the
+/// handle should be reused for codes written in the same language
+/// to avoid any further setup. A [`ParserPool`] keeps a handle
+/// for each language instead.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{get_function_spaces_with_handle, ParserHandle, LANG};
+///
+/// let mut handle = ParserHandle::new(LANG::Cpp);
+///
+/// for source_code in ["int a = 42;", "int b = 0;"] {
+///     let path = Path::new("foo.c");
+///     let source_as_vec = source_code.as_bytes().to_vec();
+///
+///     get_function_spaces_with_handle(&mut handle, source_as_vec, &path, None).unwrap();
+/// }
+/// ```
+///
+/// [`ParserPool`]: struct.ParserPool.html
+pub struct ParserHandle {
+    lang: LANG,
+    parser: tree_sitter::Parser,
+}
+
+impl ParserHandle {
+    /// Creates a new parser handle for the given language.
+    pub fn new(lang: LANG) -> Self {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&lang.get_ts_language()).unwrap();
+
+        Self { lang, parser }
+    }
+
+    /// Returns the language currently set on the handle.
+    pub fn language(&self) -> LANG {
+        self.lang
+    }
+
+    fn parse<T: LanguageInfo>(&mut self, code: &[u8]) -> Tree {
+        let lang = T::get_lang();
+        if self.lang != lang {
+            self.parser.set_language(&lang.get_ts_language()).unwrap();
+            self.lang = lang;
+        }
+        Tree::with_parser(&mut self.parser, code)
+    }
+
+    fn reparse<T: LanguageInfo>(&mut self, code: &[u8], old: &Tree) -> Tree {
+        let lang = T::get_lang();
+        if self.lang != lang {
+            self.parser.set_language(&lang.get_ts_language()).unwrap();
+            self.lang = lang;
+        }
+        old.reparse(&mut self.parser, code)
+    }
+}
+
+impl std::fmt::Debug for ParserHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ParserHandle")
+            .field("lang", &self.lang)
+            .finish()
+    }
+}
+
+/// A pool of parser handles, shared among threads.
+///
+/// A handle is checked out of the pool for a language, used to parse
+/// some codes, and then checked back in, so that the next checkout
+/// for the same language reuses its parser without any setup.
+/// A new handle is created only when all the ones of a language
+/// are checked out.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{get_function_spaces_with_handle, ParserPool, LANG};
+///
+/// let pool = ParserPool::new();
+///
+/// for (lang, source_code) in [(LANG::Cpp, "int a = 42;"), (LANG::Rust, "fn f() {}")] {
+///     let path = Path::new("foo");
+///     let source_as_vec = source_code.as_bytes().to_vec();
+///
+///     let mut handle = pool.checkout(lang);
+///     get_function_spaces_with_handle(&mut handle, source_as_vec, &path, None).unwrap();
+///     pool.checkin(handle);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct ParserPool {
+    handles: Mutex<HashMap<LANG, Vec<ParserHandle>>>,
+}
+
+impl ParserPool {
+    /// Creates an empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks out a handle for the given language, reusing an idle
+    /// one of the pool when available.
+    pub fn checkout(&self, lang: LANG) -> ParserHandle {
+        self.handles
+            .lock()
+            .unwrap()
+            .get_mut(&lang)
+            .and_then(|handles| handles.pop())
+            .unwrap_or_else(|| ParserHandle::new(lang))
+    }
+
+    /// Checks a handle back in the pool, for the language it is set to.
+    pub fn checkin(&self, handle: ParserHandle) {
+        self.handles
+            .lock()
+            .unwrap()
+            .entry(handle.lang)
+            .or_default()
+            .push(handle);
+    }
+
+    /// Returns the number of idle handles of the given language.
+    pub fn idle(&self, lang: LANG) -> usize {
+        self.handles
+            .lock()
+            .unwrap()
+            .get(&lang)
+            .map_or(0, |handles| handles.len())
+    }
+}
+
 type FilterFn = dyn Fn(&Node) -> bool;
 
 pub struct Filter {
@@ -111,7 +296,26 @@ impl<
         + Nom
         + Npa
         + Npm
-        + Wmc,
+        + Wmc
+        + ExprNesting
+        + Cfg
+        + EarlyReturns
+        + TypeComplexity
+        + Captures
+        + NLocals
+        + BareIfs
+        + ExceptionTypes
+        + ChainLength
+        + StatementCount
+        + ReferencedTypes
+        + TemplateComplexity
+        + LiteralNesting
+        + Negations
+        + Decisions
+        + EnumVariants
+        + ConditionalImports
+        + UnsafeCode
+        + Goroutines,
 > ParserTrait for Parser<T>
 {
     type Checker = T;
@@ -128,6 +332,25 @@ impl<
     type Abc = T;
     type Npm = T;
     type Npa = T;
+    type ExprNesting = T;
+    type Cfg = T;
+    type EarlyReturns = T;
+    type TypeComplexity = T;
+    type Captures = T;
+    type NLocals = T;
+    type BareIfs = T;
+    type ExceptionTypes = T;
+    type ChainLength = T;
+    type StatementCount = T;
+    type ReferencedTypes = T;
+    type TemplateComplexity = T;
+    type LiteralNesting = T;
+    type Negations = T;
+    type Decisions = T;
+    type EnumVariants = T;
+    type ConditionalImports = T;
+    type UnsafeCode = T;
+    type Goroutines = T;
 
     fn new(code: Vec<u8>, path: &Path, pr: Option<Arc<PreprocResults>>) -> Self {
         let fake_code = get_fake_code::<T>(&code, path, pr);
@@ -146,6 +369,35 @@ impl<
         }
     }
 
+    fn with_handle(
+        code: Vec<u8>,
+        path: &Path,
+        pr: Option<Arc<PreprocResults>>,
+        handle: &mut ParserHandle,
+    ) -> Self {
+        let fake_code = get_fake_code::<T>(&code, path, pr);
+        let code = if let Some(fake) = fake_code {
+            fake
+        } else {
+            code
+        };
+
+        let tree = handle.parse::<T>(&code);
+
+        Self {
+            code,
+            tree,
+            phantom: PhantomData,
+        }
+    }
+
+    fn edit(&mut self, edit: &Edit, handle: &mut ParserHandle) -> Result<(), EditError> {
+        let input_edit = edit.apply(&mut self.code)?;
+        self.tree.edit(&input_edit);
+        self.tree = handle.reparse::<T>(&self.code, &self.tree);
+        Ok(())
+    }
+
     #[inline(always)]
     fn get_language(&self) -> LANG {
         T::get_lang()
@@ -191,3 +443,86 @@ impl<
         Filter { filters: res }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::{get_function_spaces, get_function_spaces_with_handle};
+
+    #[test]
+    fn reused_handle() {
+        let path = PathBuf::from("foo.rs");
+        let mut handle = ParserHandle::new(LANG::Rust);
+
+        for i in 0..100 {
+            let source = format!(
+                "fn f{i}(a: i32) -> i32 {{
+                     if a > {i} {{ return a; }}
+                     a + {i}
+                 }}"
+            );
+            let fresh = get_function_spaces(&LANG::Rust, source.clone().into_bytes(), &path, None);
+            let reused =
+                get_function_spaces_with_handle(&mut handle, source.into_bytes(), &path, None);
+
+            assert_eq!(
+                serde_json::to_value(fresh).unwrap(),
+                serde_json::to_value(reused).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn handle_language_change() {
+        let mut handle = ParserHandle::new(LANG::Rust);
+
+        let path = PathBuf::from("foo.py");
+        let source = b"def f(a):\n    return a\n".to_vec();
+        let fresh = get_function_spaces(&LANG::Python, source.clone(), &path, None);
+        let parser = PythonParser::with_handle(source, &path, None, &mut handle);
+        let reused = crate::metrics(&parser, &path);
+
+        assert_eq!(handle.language(), LANG::Python);
+        assert_eq!(
+            serde_json::to_value(fresh).unwrap(),
+            serde_json::to_value(reused).unwrap()
+        );
+    }
+
+    #[test]
+    fn pooled_handles() {
+        let pool = ParserPool::new();
+        let path = PathBuf::from("foo.rs");
+        let source = b"fn f(a: i32) -> i32 { a }".to_vec();
+
+        let mut first = pool.checkout(LANG::Rust);
+        let mut second = pool.checkout(LANG::Rust);
+        let fresh = get_function_spaces(&LANG::Rust, source.clone(), &path, None);
+        for handle in [&mut first, &mut second] {
+            let pooled = get_function_spaces_with_handle(handle, source.clone(), &path, None);
+            assert_eq!(
+                serde_json::to_value(&fresh).unwrap(),
+                serde_json::to_value(pooled).unwrap()
+            );
+        }
+        pool.checkin(first);
+        pool.checkin(second);
+        assert_eq!(pool.idle(LANG::Rust), 2);
+
+        // An idle handle is reused, while another language needs a new one
+        let handle = pool.checkout(LANG::Rust);
+        assert_eq!(pool.idle(LANG::Rust), 1);
+        pool.checkin(handle);
+        let handle = pool.checkout(LANG::Python);
+        assert_eq!(handle.language(), LANG::Python);
+        assert_eq!(pool.idle(LANG::Rust), 2);
+
+        // A handle is checked in for the language it is set to
+        let mut handle = pool.checkout(LANG::Rust);
+        PythonParser::with_handle(b"a = 1".to_vec(), &path, None, &mut handle);
+        pool.checkin(handle);
+        assert_eq!((pool.idle(LANG::Rust), pool.idle(LANG::Python)), (1, 1));
+    }
+}
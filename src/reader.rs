@@ -0,0 +1,91 @@
+use std::io::{Error, ErrorKind, Read, Result};
+use std::path::Path;
+
+use crate::langs::*;
+use crate::spaces::FuncSpace;
+use crate::tools::{guess_language_from_content, remove_blank_lines};
+
+/// Computes the function spaces data of a code read from a reader,
+/// such as the standard input.
+///
+/// `path` is only used to name the unit space, such as `<stdin>`.
+/// When `language` is `None`, the language is guessed from the code
+/// as done by [`guess_language_from_content`].
+///
+/// Returns an error of kind [`ErrorKind::InvalidData`] when the
+/// language cannot be guessed or the code cannot be parsed.
+///
+/// [`guess_language_from_content`]: crate::guess_language_from_content
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::io;
+/// use std::path::Path;
+///
+/// use rust_code_analysis::get_function_spaces_from_reader;
+///
+/// let space = get_function_spaces_from_reader(io::stdin(), Path::new("<stdin>"), None).unwrap();
+/// ```
+pub fn get_function_spaces_from_reader<R: Read>(
+    mut reader: R,
+    path: &Path,
+    language: Option<LANG>,
+) -> Result<FuncSpace> {
+    let mut source = Vec::new();
+    reader.read_to_end(&mut source)?;
+    remove_blank_lines(&mut source);
+
+    let language = language
+        .or_else(|| guess_language_from_content(&source))
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                "the language of the code cannot be guessed",
+            )
+        })?;
+
+    get_function_spaces(&language, source, path, None).ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("the code cannot be parsed as {}", language.get_name()),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn python_without_hint() {
+        let source = "import os\n\ndef f(a):\n    if a:\n        return os.getcwd()\n";
+        let space =
+            get_function_spaces_from_reader(source.as_bytes(), Path::new("<stdin>"), None).unwrap();
+
+        assert_eq!(space.name.as_deref(), Some("<stdin>"));
+        assert_eq!(space.spaces[0].name.as_deref(), Some("f"));
+        assert_eq!(space.spaces[0].metrics.cyclomatic.cyclomatic(), 2.);
+    }
+
+    #[test]
+    fn language_hint() {
+        let source = "fn f() {}\n";
+        let space = get_function_spaces_from_reader(
+            source.as_bytes(),
+            Path::new("<stdin>"),
+            Some(LANG::Rust),
+        )
+        .unwrap();
+
+        assert_eq!(space.spaces[0].name.as_deref(), Some("f"));
+    }
+
+    #[test]
+    fn undetected_language() {
+        let error = get_function_spaces_from_reader(&b"a = 1\n"[..], Path::new("<stdin>"), None)
+            .unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+}
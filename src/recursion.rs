@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use petgraph::{algo::kosaraju_scc, graph::DiGraph};
+use serde::Serialize;
+
+use crate::traits::*;
+
+use crate::checker::Checker;
+use crate::getter::Getter;
+use crate::node::Node;
+
+/// A group of functions calling each other, directly or through
+/// the other functions of the group.
+#[derive(Debug, Serialize)]
+pub struct MutualRecursion {
+    /// The names of the functions of the group, sorted
+    pub functions: Vec<String>,
+}
+
+// Returns the name of the function called by a call node,
+// that is the last identifier of its callee, so `f` for
+// `self.f()`, `a::f::<T>()` or `obj.f()`.
+fn callee_name<'a>(call: &Node<'a>, code: &'a [u8]) -> Option<&'a str> {
+    let callee = call
+        .child_by_field_name("function")
+        .or_else(|| call.child_by_field_name("name"))?;
+    let text = callee.utf8_text(code)?;
+    // Without the generic arguments
+    let text = text.split('<').next()?;
+
+    text.rsplit(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .find(|name| !name.is_empty())
+}
+
+/// Detects the groups of mutually recursive functions of a file,
+/// such as a function `a` calling a function `b` which calls `a`.
+///
+/// A call graph is built from the names of the called functions,
+/// hence the functions sharing the same name, such as the methods of
+/// different classes, are considered as a single one. The calls of a
+/// closure are attributed to the function containing it.
+///
+/// The directly recursive functions, which only call themselves,
+/// are not reported.
+///
+/// Returns a vector containing a [`MutualRecursion`] for each group,
+/// sorted by the names of the functions.
+///
+/// [`MutualRecursion`]: struct.MutualRecursion.html
+pub fn mutual_recursions<T: ParserTrait>(parser: &T) -> Vec<MutualRecursion> {
+    let root = parser.get_root();
+    let code = parser.get_code();
+    let mut graph = DiGraph::<&str, ()>::new();
+    let mut functions = HashMap::new();
+
+    root.act_on_node(&mut |n| {
+        if T::Checker::is_func(n)
+            && let Some(name) = T::Getter::get_func_name(n, code)
+        {
+            functions
+                .entry(name)
+                .or_insert_with(|| graph.add_node(name));
+        }
+    });
+
+    root.act_on_node(&mut |n| {
+        if !T::Checker::is_call(n) {
+            return;
+        }
+        let Some(callee) = callee_name(n, code).and_then(|name| functions.get(name)) else {
+            return;
+        };
+
+        let mut function = n.parent();
+        while let Some(parent) = function {
+            if T::Checker::is_func(&parent) {
+                break;
+            }
+            function = parent.parent();
+        }
+        let Some(caller) = function
+            .and_then(|function| T::Getter::get_func_name(&function, code))
+            .and_then(|name| functions.get(name))
+        else {
+            return;
+        };
+
+        graph.update_edge(*caller, *callee, ());
+    });
+
+    let mut recursions: Vec<_> = kosaraju_scc(&graph)
+        .into_iter()
+        .filter(|component| component.len() > 1)
+        .map(|component| {
+            let mut functions: Vec<_> = component
+                .into_iter()
+                .map(|node| graph[node].to_string())
+                .collect();
+            functions.sort();
+            MutualRecursion { functions }
+        })
+        .collect();
+    recursions.sort_by(|a, b| a.functions.cmp(&b.functions));
+
+    recursions
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{JavaParser, PythonParser, RustParser};
+
+    use super::*;
+
+    fn check<T: ParserTrait>(source: &str, filename: &str) -> Vec<Vec<String>> {
+        let path = PathBuf::from(filename);
+        let parser = T::new(source.as_bytes().to_vec(), &path, None);
+        mutual_recursions(&parser)
+            .into_iter()
+            .map(|recursion| recursion.functions)
+            .collect()
+    }
+
+    #[test]
+    fn python_mutual_recursion() {
+        let recursions = check::<PythonParser>(
+            "def is_even(n):
+                 return True if n == 0 else is_odd(n - 1)
+
+             def is_odd(n):
+                 return False if n == 0 else is_even(n - 1)
+
+             def factorial(n):
+                 return 1 if n == 0 else n * factorial(n - 1)
+
+             def main():
+                 print(is_even(factorial(3)))",
+            "foo.py",
+        );
+
+        // `factorial` is only directly recursive
+        assert_eq!(recursions, [["is_even", "is_odd"]]);
+    }
+
+    #[test]
+    fn rust_mutual_recursion() {
+        let recursions = check::<RustParser>(
+            "fn a(n: u32) { if n > 0 { self::b::<u32>(n - 1); } }
+             fn b(n: u32) { c(n); }
+             fn c(n: u32) { let f = || a(n); f(); }
+             fn d() { a(1); }",
+            "foo.rs",
+        );
+
+        assert_eq!(recursions, [["a", "b", "c"]]);
+    }
+
+    #[test]
+    fn java_mutual_recursion() {
+        let recursions = check::<JavaParser>(
+            "class A {
+                 int ping(int n) { return n == 0 ? 0 : this.pong(n - 1); }
+                 int pong(int n) { return n == 0 ? 1 : ping(n - 1); }
+                 int other(int n) { return ping(n); }
+             }",
+            "foo.java",
+        );
+
+        assert_eq!(recursions, [["ping", "pong"]]);
+    }
+
+    #[test]
+    fn no_mutual_recursion() {
+        let recursions = check::<PythonParser>(
+            "def f():
+                 return g()
+
+             def g():
+                 return 1",
+            "foo.py",
+        );
+
+        assert!(recursions.is_empty());
+    }
+}
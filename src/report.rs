@@ -0,0 +1,175 @@
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::langs::LANG;
+use crate::multi_language::serialize_language;
+use crate::spaces::{FuncSpace, SpaceKind};
+
+/// The metrics of a function normalized to be the same for all languages,
+/// so that the results of many languages can be aggregated together
+/// without handling the quirks of each one.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReportEntry {
+    /// The path of the file containing the function
+    pub path: PathBuf,
+    /// The language of the file, serialized as its name
+    #[serde(serialize_with = "serialize_language")]
+    pub language: LANG,
+    /// The name of the function
+    pub function: Option<String>,
+    /// The first line of the function
+    pub start_line: usize,
+    /// The last line of the function
+    pub end_line: usize,
+    /// The `Cyclomatic` complexity of the function
+    pub cyclomatic: f64,
+    /// The `Cognitive` complexity of the function
+    pub cognitive: f64,
+    /// The number of source lines of the function
+    pub sloc: f64,
+    /// The number of arguments of the function
+    pub args: f64,
+    /// The number of exit points of the function
+    pub exits: f64,
+    /// The `Halstead` volume of the function
+    pub halstead_volume: f64,
+    /// The `Maintainability Index` of the function,
+    /// as computed by Visual Studio
+    pub mi: f64,
+}
+
+impl ReportEntry {
+    fn new(path: &Path, language: LANG, space: &FuncSpace) -> Self {
+        let metrics = &space.metrics;
+        Self {
+            path: path.to_path_buf(),
+            language,
+            function: space.name.clone(),
+            start_line: space.start_line,
+            end_line: space.end_line,
+            cyclomatic: metrics.cyclomatic.cyclomatic(),
+            cognitive: metrics.cognitive.cognitive(),
+            sloc: metrics.loc.sloc(),
+            args: metrics.nargs.fn_args(),
+            exits: metrics.nexits.exit(),
+            halstead_volume: metrics.halstead.volume(),
+            mi: metrics.mi.mi_visual_studio(),
+        }
+    }
+}
+
+/// Returns a [`ReportEntry`] for each function contained
+/// in the unit space of a file, nested ones included.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{get_function_spaces, report_entries, LANG};
+///
+/// let path = Path::new("foo.rs");
+/// let space = get_function_spaces(&LANG::Rust, b"fn f(x: bool) { if x {} }".to_vec(), path, None)
+///     .unwrap();
+///
+/// let entries = report_entries(path, LANG::Rust, &space);
+/// assert_eq!(entries[0].function.as_deref(), Some("f"));
+/// assert_eq!(entries[0].cyclomatic, 2.);
+/// ```
+pub fn report_entries(path: &Path, language: LANG, space: &FuncSpace) -> Vec<ReportEntry> {
+    let mut entries = Vec::new();
+    let mut stack = vec![space];
+
+    while let Some(space) = stack.pop() {
+        if matches!(space.kind, SpaceKind::Function | SpaceKind::TraitDefault) {
+            entries.push(ReportEntry::new(path, language, space));
+        }
+        // Reversed, so the functions are in the order of the code
+        stack.extend(space.spaces.iter().rev());
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use crate::langs::get_function_spaces;
+
+    use super::*;
+
+    fn entries(language: LANG, file: &str, source: &str) -> Vec<ReportEntry> {
+        let path = Path::new(file);
+        let space = get_function_spaces(&language, source.as_bytes().to_vec(), path, None).unwrap();
+        report_entries(path, language, &space)
+    }
+
+    fn keys(entry: &ReportEntry) -> Vec<String> {
+        match serde_json::to_value(entry).unwrap() {
+            Value::Object(map) => map.keys().cloned().collect(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn rust_and_python() {
+        let rust = entries(
+            LANG::Rust,
+            "foo.rs",
+            "fn f(a: bool, b: bool) -> i32 {
+                 if a && b {
+                     return 1;
+                 }
+                 0
+             }",
+        );
+        let python = entries(
+            LANG::Python,
+            "foo.py",
+            "def f(a, b):
+                 if a and b:
+                     return 1
+                 return 0",
+        );
+
+        assert_eq!(rust.len(), 1);
+        assert_eq!(python.len(), 1);
+        let (rust, python) = (&rust[0], &python[0]);
+
+        // The entries have the same shape and the same common metrics
+        assert_eq!(keys(rust), keys(python));
+        assert_eq!(
+            (rust.function.as_deref(), python.function.as_deref()),
+            (Some("f"), Some("f"))
+        );
+        assert_eq!((rust.cyclomatic, python.cyclomatic), (3., 3.));
+        assert_eq!((rust.args, python.args), (2., 2.));
+
+        let rust = serde_json::to_value(rust).unwrap();
+        let python = serde_json::to_value(python).unwrap();
+        assert_eq!(rust["language"], "rust");
+        assert_eq!(python["language"], "python");
+    }
+
+    #[test]
+    fn nested_functions() {
+        let entries = entries(
+            LANG::Python,
+            "foo.py",
+            "class A:
+                 def f(self):
+                     def g():
+                         pass
+                 def h(self):
+                     pass",
+        );
+        let names: Vec<_> = entries
+            .iter()
+            .map(|entry| entry.function.as_deref().unwrap())
+            .collect();
+
+        assert_eq!(names, ["f", "g", "h"]);
+    }
+}
@@ -0,0 +1,159 @@
+use serde::Serialize;
+
+use crate::spaces::FuncSpace;
+
+/// The weights of the metrics combined into a complexity score.
+///
+/// The default weights are:
+///
+/// - `cyclomatic`: 1.0
+/// - `cognitive`: 1.0
+/// - `nesting`: 2.0
+/// - `length`: 0.1
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ScoreWeights {
+    /// The weight of the `Cyclomatic` complexity
+    pub cyclomatic: f64,
+    /// The weight of the `Cognitive` complexity
+    pub cognitive: f64,
+    /// The weight of the maximum nesting level of the statements
+    pub nesting: f64,
+    /// The weight of the number of source lines
+    pub length: f64,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            cyclomatic: 1.,
+            cognitive: 1.,
+            nesting: 2.,
+            length: 0.1,
+        }
+    }
+}
+
+impl FuncSpace {
+    /// Returns a complexity score of a space, so that spaces can be sorted
+    /// by a single number.
+    ///
+    /// The score is the weighted sum of the `Cyclomatic` complexity,
+    /// the `Cognitive` complexity, the maximum nesting level of the
+    /// statements, such as conditions and loops, and the number of
+    /// source lines of the space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_code_analysis::{metrics, ParserTrait, RustParser, ScoreWeights};
+    ///
+    /// let source_code = "fn f(x: bool) { if x {} }";
+    /// let path = PathBuf::from("foo.rs");
+    /// let parser = RustParser::new(source_code.as_bytes().to_vec(), &path, None);
+    /// let space = metrics(&parser, &path).unwrap();
+    ///
+    /// space.spaces[0].complexity_score(&ScoreWeights::default());
+    /// ```
+    pub fn complexity_score(&self, weights: &ScoreWeights) -> f64 {
+        let metrics = &self.metrics;
+
+        weights.cyclomatic * metrics.cyclomatic.cyclomatic()
+            + weights.cognitive * metrics.cognitive.cognitive()
+            + weights.nesting * metrics.cognitive.nesting_max()
+            + weights.length * metrics.loc.sloc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{ParserTrait, RustParser, metrics};
+
+    use super::*;
+
+    fn function_space() -> FuncSpace {
+        let path = PathBuf::from("foo.rs");
+        let source = "fn f(x: i32) -> i32 {
+                if x > 0 {
+                    if x > 10 {
+                        return g(h(x));
+                    }
+                }
+                0
+            }";
+        let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+        let mut space = metrics(&parser, &path).unwrap();
+        space.spaces.remove(0)
+    }
+
+    #[test]
+    fn default_score() {
+        let space = function_space();
+        let metrics = &space.metrics;
+
+        // cyclomatic = 3, cognitive = 3, nesting = 2, sloc = 8
+        assert_eq!(metrics.cyclomatic.cyclomatic(), 3.);
+        assert_eq!(metrics.cognitive.cognitive(), 3.);
+        assert_eq!(metrics.cognitive.nesting_max(), 2.);
+        assert_eq!(metrics.loc.sloc(), 8.);
+        assert_eq!(
+            space.complexity_score(&ScoreWeights::default()),
+            3. + 3. + 2. * 2. + 0.1 * 8.
+        );
+    }
+
+    #[test]
+    fn increased_weight() {
+        let space = function_space();
+        let weights = ScoreWeights::default();
+        let score = space.complexity_score(&weights);
+
+        // Increasing a weight by 1 increases the score by the value of its metric
+        let heavier = ScoreWeights {
+            cognitive: weights.cognitive + 1.,
+            ..weights
+        };
+        assert_eq!(
+            space.complexity_score(&heavier),
+            score + space.metrics.cognitive.cognitive()
+        );
+
+        let nesting_only = ScoreWeights {
+            cyclomatic: 0.,
+            cognitive: 0.,
+            nesting: 1.,
+            length: 0.,
+        };
+        assert_eq!(space.complexity_score(&nesting_only), 2.);
+    }
+
+    #[test]
+    fn statement_nesting() {
+        let path = PathBuf::from("foo.rs");
+        let source = "fn f(x: i32) -> i32 {
+                let y = g(h(i(j(x))));
+                for i in 0..y {
+                    while x > i {
+                        if x > 10 {
+                            return 1;
+                        }
+                    }
+                }
+                0
+            }";
+        let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+        let space = &metrics(&parser, &path).unwrap().spaces[0];
+
+        // The nested calls do not count, unlike the nested statements
+        let nesting_only = ScoreWeights {
+            cyclomatic: 0.,
+            cognitive: 0.,
+            nesting: 1.,
+            length: 0.,
+        };
+        assert_eq!(space.complexity_score(&nesting_only), 3.);
+    }
+}
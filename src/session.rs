@@ -0,0 +1,305 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use tree_sitter::{InputEdit, Point};
+
+use crate::parser::ParserHandle;
+use crate::spaces::{FuncSpace, MetricsOptions, metrics_with_options};
+use crate::traits::ParserTrait;
+
+/// An edit of a code, replacing a range of bytes with a new text.
+///
+/// An insertion has an empty range, while a deletion has an empty text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Edit {
+    /// The offset of the first replaced byte.
+    pub start_byte: usize,
+    /// The offset following the last replaced byte.
+    pub old_end_byte: usize,
+    /// The text replacing the range.
+    pub text: Vec<u8>,
+}
+
+impl Edit {
+    /// Creates an edit replacing the bytes in `start_byte..old_end_byte`
+    /// with `text`.
+    pub fn new<T: Into<Vec<u8>>>(start_byte: usize, old_end_byte: usize, text: T) -> Self {
+        Self {
+            start_byte,
+            old_end_byte,
+            text: text.into(),
+        }
+    }
+
+    // Applies the edit to a code, returning its description for the tree
+    //
+    // The code is left untouched if the range of the edit is not valid.
+    pub(crate) fn apply(&self, code: &mut Vec<u8>) -> Result<InputEdit, EditError> {
+        if self.start_byte > self.old_end_byte {
+            return Err(EditError::InvertedRange {
+                start_byte: self.start_byte,
+                old_end_byte: self.old_end_byte,
+            });
+        }
+        if self.old_end_byte > code.len() {
+            return Err(EditError::OutOfRange {
+                old_end_byte: self.old_end_byte,
+                len: code.len(),
+            });
+        }
+
+        let new_end_byte = self.start_byte + self.text.len();
+        let start_position = position(code, self.start_byte);
+        let old_end_position = position(code, self.old_end_byte);
+
+        code.splice(
+            self.start_byte..self.old_end_byte,
+            self.text.iter().copied(),
+        );
+
+        Ok(InputEdit {
+            start_byte: self.start_byte,
+            old_end_byte: self.old_end_byte,
+            new_end_byte,
+            start_position,
+            old_end_position,
+            new_end_position: position(code, new_end_byte),
+        })
+    }
+}
+
+/// The errors of an [`Edit`] which cannot be applied to a code,
+/// such as a stale edit of a language server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditError {
+    /// The start of the range follows its end.
+    InvertedRange {
+        /// The start of the range.
+        start_byte: usize,
+        /// The end of the range.
+        old_end_byte: usize,
+    },
+    /// The end of the range is out of the code.
+    OutOfRange {
+        /// The end of the range.
+        old_end_byte: usize,
+        /// The length of the code.
+        len: usize,
+    },
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvertedRange {
+                start_byte,
+                old_end_byte,
+            } => write!(
+                f,
+                "the edit range {start_byte}..{old_end_byte} starts after its end"
+            ),
+            Self::OutOfRange { old_end_byte, len } => write!(
+                f,
+                "the edit range ends at {old_end_byte}, out of a code of {len} bytes"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EditError {}
+
+// Returns the row and the byte column of an offset
+fn position(code: &[u8], byte: usize) -> Point {
+    let before = &code[..byte];
+    let row = before.iter().filter(|&&c| c == b'\n').count();
+    let column = before
+        .iter()
+        .rposition(|&c| c == b'\n')
+        .map_or(byte, |newline| byte - newline - 1);
+
+    Point { row, column }
+}
+
+/// The metrics of a code which is edited over time, such as the
+/// document of a language server.
+///
+/// Each [`Edit`] reparses the code reusing the nodes of the previous tree
+/// which were not touched. The metrics are not updated incrementally:
+/// they are recomputed over the whole code, but only when requested,
+/// so a burst of edits costs a single computation.
+///
+/// The code is parsed without the results of the preprocessor, so the
+/// C++ macros are not replaced as in the analysis of a whole project,
+/// and the metrics of a C++ code using them may differ.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use rust_code_analysis::{Edit, MetricsOptions, MetricsSession, RustParser};
+///
+/// let source = b"fn f() {}".to_vec();
+/// let path = Path::new("foo.rs");
+/// let mut session =
+///     MetricsSession::<RustParser>::new(source, path, MetricsOptions::default());
+///
+/// // Inserts an argument
+/// session.edit(&Edit::new(5, 5, "a: i32")).unwrap();
+///
+/// assert_eq!(session.code(), b"fn f(a: i32) {}");
+/// assert_eq!(session.metrics().unwrap().metrics.nargs.fn_args_sum(), 1.);
+/// ```
+#[derive(Debug)]
+pub struct MetricsSession<T: ParserTrait> {
+    handle: ParserHandle,
+    parser: T,
+    path: PathBuf,
+    options: MetricsOptions,
+    space: Option<FuncSpace>,
+    outdated: bool,
+}
+
+impl<T: ParserTrait> MetricsSession<T> {
+    /// Creates a session parsing a code.
+    pub fn new(code: Vec<u8>, path: &Path, options: MetricsOptions) -> Self {
+        let parser = T::new(code, path, None);
+        let handle = ParserHandle::new(parser.get_language());
+
+        Self {
+            handle,
+            parser,
+            path: path.to_path_buf(),
+            options,
+            space: None,
+            outdated: true,
+        }
+    }
+
+    /// Applies an edit to the code.
+    ///
+    /// Returns an error, leaving the code untouched, if the range
+    /// of the edit is inverted or out of the code.
+    pub fn edit(&mut self, edit: &Edit) -> Result<(), EditError> {
+        self.parser.edit(edit, &mut self.handle)?;
+        self.outdated = true;
+        Ok(())
+    }
+
+    /// Applies a sequence of edits to the code, in order.
+    ///
+    /// Stops at the first edit which cannot be applied, returning its
+    /// error, while the edits preceding it are kept.
+    pub fn edit_all<'a, I: IntoIterator<Item = &'a Edit>>(
+        &mut self,
+        edits: I,
+    ) -> Result<(), EditError> {
+        for edit in edits {
+            self.edit(edit)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the current code.
+    pub fn code(&self) -> &[u8] {
+        self.parser.get_code()
+    }
+
+    /// Returns the metrics of the current code, recomputing them over
+    /// the whole code if it was edited since the last call.
+    ///
+    /// Returns `None` if the metrics cannot be computed.
+    pub fn metrics(&mut self) -> Option<&FuncSpace> {
+        if self.outdated {
+            self.space = metrics_with_options(&self.parser, &self.path, &self.options);
+            self.outdated = false;
+        }
+        self.space.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LANG, RustParser, get_function_spaces_with_options};
+
+    #[test]
+    fn edit_position() {
+        let mut code = b"ab\ncd\nef".to_vec();
+        let edit = Edit::new(4, 7, "x\ny");
+        let input_edit = edit.apply(&mut code).unwrap();
+
+        assert_eq!(code, b"ab\ncx\nyf");
+        assert_eq!(input_edit.start_position, Point { row: 1, column: 1 });
+        assert_eq!(input_edit.old_end_position, Point { row: 2, column: 1 });
+        assert_eq!(input_edit.new_end_position, Point { row: 2, column: 1 });
+        assert_eq!(input_edit.new_end_byte, 7);
+    }
+
+    #[test]
+    fn sequential_edits() {
+        let path = PathBuf::from("foo.rs");
+        let options = MetricsOptions::default();
+        let mut session = MetricsSession::<RustParser>::new(
+            b"fn f(a: i32) -> i32 {\n    a\n}\n".to_vec(),
+            &path,
+            options.clone(),
+        );
+
+        let edits = [
+            // Wraps the body in a condition
+            Edit::new(26, 27, "if a > 0 { a } else { 0 }"),
+            // Adds a function
+            Edit::new(0, 0, "fn g() {}\n"),
+            // Removes the else branch
+            Edit::new(50, 61, ""),
+        ];
+        let codes: [&[u8]; 3] = [
+            b"fn f(a: i32) -> i32 {\n    if a > 0 { a } else { 0 }\n}\n",
+            b"fn g() {}\nfn f(a: i32) -> i32 {\n    if a > 0 { a } else { 0 }\n}\n",
+            b"fn g() {}\nfn f(a: i32) -> i32 {\n    if a > 0 { a }\n}\n",
+        ];
+
+        for (edit, code) in edits.iter().zip(codes) {
+            session.edit(edit).unwrap();
+            assert_eq!(session.code(), code);
+
+            let full =
+                get_function_spaces_with_options(&LANG::Rust, code.to_vec(), &path, None, &options);
+            assert_eq!(
+                serde_json::to_value(session.metrics()).unwrap(),
+                serde_json::to_value(full).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_edits() {
+        let path = PathBuf::from("foo.rs");
+        let code = b"fn f() {}".to_vec();
+        let mut session =
+            MetricsSession::<RustParser>::new(code.clone(), &path, MetricsOptions::default());
+
+        assert_eq!(
+            session.edit(&Edit::new(5, 4, "")),
+            Err(EditError::InvertedRange {
+                start_byte: 5,
+                old_end_byte: 4
+            })
+        );
+        assert_eq!(
+            session.edit(&Edit::new(9, 12, "x")),
+            Err(EditError::OutOfRange {
+                old_end_byte: 12,
+                len: 9
+            })
+        );
+        assert_eq!(session.code(), code);
+
+        // The edits preceding an invalid one are kept
+        let edits = [Edit::new(9, 9, "\nfn g() {}"), Edit::new(30, 30, "")];
+        assert!(session.edit_all(&edits).is_err());
+        assert_eq!(session.code(), b"fn f() {}\nfn g() {}");
+        assert_eq!(session.metrics().unwrap().metrics.nom.functions_sum(), 2.);
+    }
+}
@@ -1,31 +1,52 @@
-use std::collections::HashMap;
-
-use serde::Serialize;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
 use crate::checker::Checker;
+use crate::comment_rm::rm_comments;
+use crate::gate::{ThresholdGate, Violation};
 use crate::node::Node;
 
 use crate::abc::{self, Abc};
+use crate::bare_ifs::{self, BareIfs};
+use crate::captures::{self, Captures};
+use crate::cfg::{self, Cfg};
+use crate::chain_length::{self, ChainLength};
 use crate::cognitive::{self, Cognitive};
+use crate::conditional_imports::{self, ConditionalImports};
 use crate::cyclomatic::{self, Cyclomatic};
+use crate::decisions::{self, Decisions};
+use crate::early_returns::{self, EarlyReturns};
+use crate::enum_variants::{self, EnumVariants};
+use crate::exception_types::{self, ExceptionTypes};
 use crate::exit::{self, Exit};
-use crate::getter::Getter;
-use crate::halstead::{self, Halstead, HalsteadMaps};
+use crate::expr_nesting::{self, ExprNesting};
+use crate::getter::{ANONYMOUS, Getter};
+use crate::goroutines::{self, Goroutines};
+use crate::halstead::{self, Halstead, HalsteadMaps, HalsteadOverrides};
+use crate::literal_nesting::{self, LiteralNesting};
 use crate::loc::{self, Loc};
 use crate::mi::{self, Mi};
 use crate::nargs::{self, NArgs};
-use crate::nom::{self, Nom};
+use crate::negations::{self, Negations};
+use crate::nlocals::{self, NLocals};
+use crate::nom::{self, Nom, NomExclusions};
 use crate::npa::{self, Npa};
 use crate::npm::{self, Npm};
+use crate::referenced_types::{self, ReferencedTypes};
+use crate::statement_count::{self, StatementCount};
+use crate::template_complexity::{self, TemplateComplexity};
+use crate::type_complexity::{self, TypeComplexity};
+use crate::unsafe_code::{self, UnsafeCode};
 use crate::wmc::{self, Wmc};
 
 use crate::dump_metrics::*;
 use crate::traits::*;
 
 /// The list of supported space kinds.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SpaceKind {
     /// An unknown space
@@ -47,6 +68,9 @@ pub enum SpaceKind {
     Namespace,
     /// An interface
     Interface,
+    /// The default implementation of a `Rust` trait method
+    #[serde(rename = "trait_default")]
+    TraitDefault,
 }
 
 impl fmt::Display for SpaceKind {
@@ -61,6 +85,7 @@ impl fmt::Display for SpaceKind {
             SpaceKind::Unit => "unit",
             SpaceKind::Namespace => "namespace",
             SpaceKind::Interface => "interface",
+            SpaceKind::TraitDefault => "trait_default",
         };
         write!(f, "{s}")
     }
@@ -95,6 +120,62 @@ pub struct CodeMetrics {
     /// `Npa` data
     #[serde(skip_serializing_if = "npa::Stats::is_disabled")]
     pub npa: npa::Stats,
+    /// `ExprNesting` data
+    #[serde(skip_serializing_if = "expr_nesting::Stats::is_disabled")]
+    pub expr_nesting: expr_nesting::Stats,
+    /// `Cfg` data
+    #[serde(skip_serializing_if = "cfg::Stats::is_disabled")]
+    pub cfg: cfg::Stats,
+    /// `EarlyReturns` data
+    #[serde(skip_serializing_if = "early_returns::Stats::is_disabled")]
+    pub early_returns: early_returns::Stats,
+    /// `TypeComplexity` data
+    #[serde(skip_serializing_if = "type_complexity::Stats::is_disabled")]
+    pub type_complexity: type_complexity::Stats,
+    /// `Captures` data
+    #[serde(skip_serializing_if = "captures::Stats::is_disabled")]
+    pub captures: captures::Stats,
+    /// `NLocals` data
+    #[serde(skip_serializing_if = "nlocals::Stats::is_disabled")]
+    pub nlocals: nlocals::Stats,
+    /// `BareIfs` data
+    #[serde(skip_serializing_if = "bare_ifs::Stats::is_disabled")]
+    pub bare_ifs: bare_ifs::Stats,
+    /// `ExceptionTypes` data
+    #[serde(skip_serializing_if = "exception_types::Stats::is_disabled")]
+    pub exception_types: exception_types::Stats,
+    /// `ChainLength` data
+    #[serde(skip_serializing_if = "chain_length::Stats::is_disabled")]
+    pub chain_length: chain_length::Stats,
+    /// `StatementCount` data
+    #[serde(skip_serializing_if = "statement_count::Stats::is_disabled")]
+    pub statement_count: statement_count::Stats,
+    /// `ReferencedTypes` data
+    #[serde(skip_serializing_if = "referenced_types::Stats::is_disabled")]
+    pub referenced_types: referenced_types::Stats,
+    /// `TemplateComplexity` data
+    #[serde(skip_serializing_if = "template_complexity::Stats::is_disabled")]
+    pub template_complexity: template_complexity::Stats,
+    /// `LiteralNesting` data
+    #[serde(skip_serializing_if = "literal_nesting::Stats::is_disabled")]
+    pub literal_nesting: literal_nesting::Stats,
+    /// `Negations` data
+    #[serde(skip_serializing_if = "negations::Stats::is_disabled")]
+    pub negations: negations::Stats,
+    /// `Decisions` data
+    pub decisions: decisions::Stats,
+    /// `EnumVariants` data
+    #[serde(skip_serializing_if = "enum_variants::Stats::is_disabled")]
+    pub enum_variants: enum_variants::Stats,
+    /// `ConditionalImports` data
+    #[serde(skip_serializing_if = "conditional_imports::Stats::is_disabled")]
+    pub conditional_imports: conditional_imports::Stats,
+    /// `UnsafeCode` data
+    #[serde(skip_serializing_if = "unsafe_code::Stats::is_disabled")]
+    pub unsafe_code: unsafe_code::Stats,
+    /// `Goroutines` data
+    #[serde(skip_serializing_if = "goroutines::Stats::is_disabled")]
+    pub goroutines: goroutines::Stats,
 }
 
 impl fmt::Display for CodeMetrics {
@@ -124,6 +205,96 @@ impl CodeMetrics {
         self.wmc.merge(&other.wmc);
         self.npm.merge(&other.npm);
         self.npa.merge(&other.npa);
+        self.expr_nesting.merge(&other.expr_nesting);
+        self.cfg.merge(&other.cfg);
+        self.early_returns.merge(&other.early_returns);
+        self.type_complexity.merge(&other.type_complexity);
+        self.captures.merge(&other.captures);
+        self.nlocals.merge(&other.nlocals);
+        self.bare_ifs.merge(&other.bare_ifs);
+        self.exception_types.merge(&other.exception_types);
+        self.chain_length.merge(&other.chain_length);
+        self.statement_count.merge(&other.statement_count);
+        self.referenced_types.merge(&other.referenced_types);
+        self.template_complexity.merge(&other.template_complexity);
+        self.literal_nesting.merge(&other.literal_nesting);
+        self.negations.merge(&other.negations);
+        self.decisions.merge(&other.decisions);
+        self.enum_variants.merge(&other.enum_variants);
+        self.conditional_imports.merge(&other.conditional_imports);
+        self.unsafe_code.merge(&other.unsafe_code);
+        self.goroutines.merge(&other.goroutines);
+    }
+
+    // Enables the metrics which are only computed on demand
+    fn enable_optional(&mut self, options: &MetricsOptions) {
+        if options.bare_ifs {
+            self.bare_ifs.enable();
+        }
+        if options.captures {
+            self.captures.enable();
+        }
+        if options.cfg_attributes {
+            self.cfg.enable();
+        }
+        if options.chain_length {
+            self.chain_length.enable();
+        }
+        if options.conditional_imports {
+            self.conditional_imports.enable();
+        }
+        if options.cyclomatic_values {
+            self.cyclomatic.collect_values();
+        }
+        if options.early_returns {
+            self.early_returns.enable();
+        }
+        if options.enum_variants {
+            self.enum_variants.enable();
+        }
+        if options.exception_types {
+            self.exception_types.enable();
+        }
+        if options.expr_nesting {
+            self.expr_nesting.enable();
+        }
+        if options.goroutines {
+            self.goroutines.enable();
+        }
+        if options.literal_nesting {
+            self.literal_nesting.enable();
+        }
+        if options.negations {
+            self.negations.enable();
+        }
+        if options.nlocals {
+            self.nlocals.enable();
+        }
+        if options.referenced_types {
+            self.referenced_types.enable();
+        }
+        if options.statement_count {
+            self.statement_count.enable();
+        }
+        if options.template_complexity {
+            self.template_complexity.enable();
+        }
+        if options.type_complexity {
+            self.type_complexity.enable();
+        }
+        if options.unsafe_code {
+            self.unsafe_code.enable();
+        }
+    }
+
+    /// Returns the ratio between the number of arguments of a
+    /// function/closure and the number of its local variables.
+    ///
+    /// Returns `None` if there are no local variables, or if the
+    /// `NLocals` metric has not been computed.
+    pub fn args_locals_ratio(&self) -> Option<f64> {
+        let nlocals = self.nlocals.nlocals();
+        (nlocals > 0.).then(|| (self.nargs.fn_args() + self.nargs.closure_args()) / nlocals)
     }
 }
 
@@ -132,6 +303,9 @@ impl CodeMetrics {
 pub struct FuncSpace {
     /// The name of a function space
     ///
+    /// An anonymous function space, such as a closure, is named
+    /// after its position as `<anonymous@line:column>`.
+    ///
     /// If `None`, an error is occurred in parsing
     /// the name of a function space
     pub name: Option<String>,
@@ -139,6 +313,12 @@ pub struct FuncSpace {
     pub start_line: usize,
     /// The last line of a function space
     pub end_line: usize,
+    /// The byte offset where a function space starts in the source code
+    #[serde(skip)]
+    pub start_byte: usize,
+    /// The byte offset where a function space ends in the source code
+    #[serde(skip)]
+    pub end_byte: usize,
     /// The space kind
     pub kind: SpaceKind,
     /// All subspaces contained in a function space
@@ -160,23 +340,82 @@ impl FuncSpace {
             _ => (node.start_row() + 1, node.end_row() + 1),
         };
 
+        let name = T::get_func_space_name(node, code).map(|name| {
+            if name == ANONYMOUS && kind != SpaceKind::Unit {
+                // Identify an anonymous space by its position,
+                // which does not change across runs on the same code
+                let (row, column) = node.start_position();
+                format!("<anonymous@{}:{}>", row + 1, column + 1)
+            } else {
+                name.split_whitespace().collect::<Vec<_>>().join(" ")
+            }
+        });
+
         Self {
-            name: T::get_func_space_name(node, code)
-                .map(|name| name.split_whitespace().collect::<Vec<_>>().join(" ")),
+            name,
             spaces: Vec::new(),
             metrics: CodeMetrics::default(),
             kind,
             start_line: start_position,
             end_line: end_position,
+            start_byte: node.start_byte(),
+            end_byte: node.end_byte(),
+        }
+    }
+
+    /// Returns the source code of a function space.
+    ///
+    /// `src` must be the same source code used to compute the space.
+    /// If the byte range of the space does not lie on UTF-8 boundaries,
+    /// only its longest valid UTF-8 prefix is returned.
+    pub fn source<'a>(&self, src: &'a [u8]) -> &'a str {
+        let end = self.end_byte.min(src.len());
+        let start = self.start_byte.min(end);
+        let slice = &src[start..end];
+        match std::str::from_utf8(slice) {
+            Ok(text) => text,
+            // Safe to unwrap: the prefix has just been validated
+            Err(e) => std::str::from_utf8(&slice[..e.valid_up_to()]).unwrap(),
         }
     }
+
+    /// Returns the functions contained in a space, nested ones included,
+    /// whose `Cyclomatic` complexity exceeds a threshold, together with
+    /// their names and first lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use rust_code_analysis::{metrics, ParserTrait, RustParser};
+    ///
+    /// let source_code = "fn f(x: bool) { if x {} }";
+    /// let path = PathBuf::from("foo.rs");
+    /// let parser = RustParser::new(source_code.as_bytes().to_vec(), &path, None);
+    /// let space = metrics(&parser, &path).unwrap();
+    ///
+    /// assert_eq!(space.cyclomatic_over(1.)[0].function.as_deref(), Some("f"));
+    /// ```
+    pub fn cyclomatic_over(&self, threshold: f64) -> Vec<Violation> {
+        ThresholdGate {
+            max_cyclomatic: Some(threshold),
+            ..Default::default()
+        }
+        .check(self)
+    }
 }
 
 #[inline(always)]
-fn compute_halstead_mi_and_wmc<T: ParserTrait>(state: &mut State) {
+fn compute_halstead_mi_and_wmc<T: ParserTrait>(state: &mut State, options: &MetricsOptions) {
     state
         .halstead_maps
         .finalize(&mut state.space.metrics.halstead);
+    if options.halstead_frequencies {
+        state
+            .halstead_maps
+            .retain_frequencies(&mut state.space.metrics.halstead);
+    }
     T::Mi::compute(
         &state.space.metrics.loc,
         &state.space.metrics.cyclomatic,
@@ -216,6 +455,21 @@ fn compute_minmax(state: &mut State) {
     state.space.metrics.nom.compute_minmax();
     state.space.metrics.loc.compute_minmax();
     state.space.metrics.abc.compute_minmax();
+    state.space.metrics.expr_nesting.compute_minmax();
+    state.space.metrics.cfg.compute_minmax();
+    state.space.metrics.early_returns.compute_minmax();
+    state.space.metrics.type_complexity.compute_minmax();
+    state.space.metrics.captures.compute_minmax();
+    state.space.metrics.nlocals.compute_minmax();
+    state.space.metrics.bare_ifs.compute_minmax();
+    state.space.metrics.exception_types.compute_minmax();
+    state.space.metrics.chain_length.compute_minmax();
+    state.space.metrics.statement_count.compute_minmax();
+    state.space.metrics.referenced_types.compute_minmax();
+    state.space.metrics.template_complexity.compute_minmax();
+    state.space.metrics.literal_nesting.compute_minmax();
+    state.space.metrics.negations.compute_minmax();
+    state.space.metrics.goroutines.compute_minmax();
 }
 
 #[inline(always)]
@@ -223,9 +477,32 @@ fn compute_sum(state: &mut State) {
     state.space.metrics.wmc.compute_sum();
     state.space.metrics.npm.compute_sum();
     state.space.metrics.npa.compute_sum();
+    state.space.metrics.decisions.compute_sum();
+    state.space.metrics.enum_variants.compute_sum();
+    state.space.metrics.conditional_imports.compute_sum();
+    state.space.metrics.unsafe_code.compute_sum();
 }
 
-fn finalize<T: ParserTrait>(state_stack: &mut Vec<State>, diff_level: usize) {
+#[inline(always)]
+fn is_filtered_out(space: &FuncSpace, options: &MetricsOptions) -> bool {
+    matches!(space.kind, SpaceKind::Function | SpaceKind::TraitDefault)
+        && (options.function_filter.as_ref().is_some_and(|filter| {
+            !space
+                .name
+                .as_ref()
+                .is_some_and(|name| filter.is_match(name))
+        }) || options.line_ranges.as_ref().is_some_and(|ranges| {
+            !ranges
+                .iter()
+                .any(|range| range.start <= space.end_line && space.start_line < range.end)
+        }))
+}
+
+fn finalize<T: ParserTrait>(
+    state_stack: &mut Vec<State>,
+    diff_level: usize,
+    options: &MetricsOptions,
+) {
     if state_stack.is_empty() {
         return;
     }
@@ -234,19 +511,25 @@ fn finalize<T: ParserTrait>(state_stack: &mut Vec<State>, diff_level: usize) {
             let last_state = state_stack.last_mut().unwrap();
             compute_minmax(last_state);
             compute_sum(last_state);
-            compute_halstead_mi_and_wmc::<T>(last_state);
+            compute_halstead_mi_and_wmc::<T>(last_state, options);
             compute_averages(last_state);
             break;
         } else {
             let mut state = state_stack.pop().unwrap();
             compute_minmax(&mut state);
             compute_sum(&mut state);
-            compute_halstead_mi_and_wmc::<T>(&mut state);
+            compute_halstead_mi_and_wmc::<T>(&mut state, options);
             compute_averages(&mut state);
 
+            // Discard the functions which do not satisfy the filter
+            // or are not public, together with all the spaces they contain
+            if is_filtered_out(&state.space, options) || !state.public {
+                continue;
+            }
+
             let last_state = state_stack.last_mut().unwrap();
             last_state.halstead_maps.merge(&state.halstead_maps);
-            compute_halstead_mi_and_wmc::<T>(last_state);
+            compute_halstead_mi_and_wmc::<T>(last_state, options);
 
             // Merge function spaces
             last_state.space.metrics.merge(&state.space.metrics);
@@ -255,10 +538,21 @@ fn finalize<T: ParserTrait>(state_stack: &mut Vec<State>, diff_level: usize) {
     }
 }
 
+// The levels of a node computed from the ones of its parent,
+// which its children receive during the walk
+#[derive(Debug, Clone, Default)]
+struct Levels {
+    cognitive: (usize, usize, usize),
+    loops: cognitive::Loops,
+    expr_nesting: expr_nesting::Depth,
+    early_returns: early_returns::Body,
+}
+
 #[derive(Debug, Clone)]
 struct State<'a> {
     space: FuncSpace,
     halstead_maps: HalsteadMaps<'a>,
+    public: bool,
 }
 
 /// Returns all function spaces data of a code. This function needs a parser to
@@ -284,34 +578,319 @@ struct State<'a> {
 /// metrics(&parser, &path).unwrap();
 /// ```
 pub fn metrics<'a, T: ParserTrait>(parser: &'a T, path: &'a Path) -> Option<FuncSpace> {
+    metrics_with_options(parser, path, &MetricsOptions::default())
+}
+
+/// Returns the function space data of the class named `name`,
+/// together with all its methods, discarding the rest of a code.
+///
+/// When a class contains an inner class with the same name,
+/// the innermost one is returned.
+///
+/// Returns `None` if the code does not contain the class.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use rust_code_analysis::{class_metrics, JavaParser, ParserTrait};
+///
+/// let source_code = "class A { void f() {} } class B { void g() {} }";
+/// let path = PathBuf::from("foo.java");
+/// let parser = JavaParser::new(source_code.as_bytes().to_vec(), &path, None);
+///
+/// let class = class_metrics(&parser, &path, "B").unwrap();
+/// assert_eq!(class.spaces[0].name.as_deref(), Some("g"));
+/// ```
+pub fn class_metrics<T: ParserTrait>(parser: &T, path: &Path, name: &str) -> Option<FuncSpace> {
+    let space = metrics(parser, path)?;
+    find_class(&space, name).cloned()
+}
+
+fn find_class<'a>(space: &'a FuncSpace, name: &str) -> Option<&'a FuncSpace> {
+    // Inner classes are searched first, so the innermost match is returned
+    space
+        .spaces
+        .iter()
+        .find_map(|subspace| find_class(subspace, name))
+        .or_else(|| {
+            (space.kind == SpaceKind::Class && space.name.as_deref() == Some(name)).then_some(space)
+        })
+}
+
+/// How the name of the unit space of a code is derived from its path.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum UnitName {
+    /// The path of the code, as given
+    #[default]
+    Path,
+    /// The final component of the path of the code, such as `foo.rs`
+    /// for `src/foo.rs`
+    FileName,
+    /// A custom name, whatever the path of the code
+    Custom(String),
+}
+
+impl UnitName {
+    fn name(&self, path: &Path) -> Option<String> {
+        match self {
+            UnitName::Path => path.to_str().map(|name| name.to_string()),
+            UnitName::FileName => path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| name.to_string()),
+            UnitName::Custom(name) => Some(name.clone()),
+        }
+    }
+}
+
+/// Options used to customize the computation of the metrics of a code.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsOptions {
+    /// If set, only the functions whose name matches this regular
+    /// expression are kept, both in the output and in the aggregated metrics.
+    ///
+    /// The spaces contained in a discarded function are discarded too.
+    pub function_filter: Option<Regex>,
+    /// Overrides the `Halstead` type assigned to some node kinds.
+    pub halstead_overrides: HalsteadOverrides,
+    /// If `true`, each suspension point of a generator, such as a Python
+    /// `yield`, increases the `Cyclomatic` complexity of its function.
+    pub count_yields: bool,
+    /// If `true`, the closures passed to a chain of method calls,
+    /// such as the lambdas of a `Java` stream pipeline, are considered
+    /// as function spaces, so the complexity of their bodies is attributed
+    /// to them and not to the enclosing function.
+    pub chained_closure_spaces: bool,
+    /// If `true`, the `Python` lambdas and the `C#` lambdas and anonymous
+    /// methods are considered as function spaces, named after their position,
+    /// so their complexity is attributed to them and not to the enclosing
+    /// function.
+    pub lambda_spaces: bool,
+    /// If `true`, a function whose body does not end with a `return`
+    /// has a further implicit exit in the `NExit` metric, so that every
+    /// function has at least one exit.
+    pub implicit_exits: bool,
+    /// The kinds of methods which are not counted by the `Nom` metric.
+    pub nom_exclusions: NomExclusions,
+    /// If `true`, the metrics are computed on the code without its comments,
+    /// as returned by [`rm_comments`], which may confuse the grammar of some
+    /// languages, such as the comments within `C/C++` macros.
+    ///
+    /// The `Loc` metrics are still computed on the original code, so
+    /// the `CLOC` metric reflects its comment lines.
+    ///
+    /// [`rm_comments`]: crate::rm_comments
+    pub strip_comments: bool,
+    /// How the name of the unit space is derived from the path of the code.
+    pub unit_name: UnitName,
+    /// If `true`, only the functions which are part of the public API of
+    /// a code are kept, both in the output and in the aggregated metrics,
+    /// such as the `pub` functions of `Rust`, the `public` methods of
+    /// `Java` and `C++` or the exported functions of `JavaScript`.
+    ///
+    /// The functions nested in a public one are kept with it.
+    pub public_only: bool,
+    /// If `true`, the `Cyclomatic` values of all the spaces contained in
+    /// a space are serialized, together with its aggregated values, so
+    /// that their distribution can be computed.
+    pub cyclomatic_values: bool,
+    /// If set, only the functions overlapping at least one of these ranges
+    /// of lines, such as the hunks of a diff, are kept, both in the output
+    /// and in the aggregated metrics.
+    ///
+    /// The lines start from 1 and the end of each range is excluded,
+    /// so `10..13` contains the lines 10, 11 and 12.
+    pub line_ranges: Option<Vec<Range<usize>>>,
+    /// If `true`, the `Halstead` metric of each space retains the number
+    /// of occurrences of each of its distinct operators and operands,
+    /// which are not serialized but can be inspected through the API.
+    pub halstead_frequencies: bool,
+    /// If `true`, the `Cfg` metric counting the conditional compilation
+    /// attributes, such as `#[cfg(test)]` in `Rust`, is computed.
+    pub cfg_attributes: bool,
+    /// If `true`, the `TypeComplexity` metric counting the members of the
+    /// union types and the cases of the enums is computed.
+    pub type_complexity: bool,
+    /// If `true`, the `TemplateComplexity` metric counting the compile-time
+    /// conditions of the `C++` function templates is computed.
+    pub template_complexity: bool,
+    /// If `true`, the `LiteralNesting` metric computing the nesting depth
+    /// of the literal data structures, such as objects and arrays, is computed.
+    pub literal_nesting: bool,
+    /// If `true`, the `EnumVariants` metric counting the variants of the
+    /// enums, and the ones carrying data, is computed.
+    pub enum_variants: bool,
+    /// If `true`, the `Goroutines` metric counting the goroutines
+    /// launched by the `Go` `go` statements is computed.
+    pub goroutines: bool,
+    /// If `true`, the `ExprNesting` metric computing the nesting depth
+    /// of the expressions is computed.
+    pub expr_nesting: bool,
+    /// If `true`, the `EarlyReturns` metric counting the returns placed
+    /// before the last statement of a function is computed.
+    pub early_returns: bool,
+    /// If `true`, the `Captures` metric counting the variables of the
+    /// enclosing scopes captured by the closures is computed.
+    pub captures: bool,
+    /// If `true`, the `NLocals` metric counting the local variables
+    /// declared by the functions is computed.
+    pub nlocals: bool,
+    /// If `true`, the `BareIfs` metric counting the `if` statements
+    /// without an `else` branch is computed.
+    pub bare_ifs: bool,
+    /// If `true`, the `ExceptionTypes` metric counting the distinct
+    /// exception types handled by the functions is computed.
+    pub exception_types: bool,
+    /// If `true`, the `ChainLength` metric computing the length of
+    /// the chains of method calls is computed.
+    pub chain_length: bool,
+    /// If `true`, the `StatementCount` metric counting the statements
+    /// of the functions is computed.
+    pub statement_count: bool,
+    /// If `true`, the `ReferencedTypes` metric counting the distinct
+    /// types referenced by the functions is computed.
+    pub referenced_types: bool,
+    /// If `true`, the `Negations` metric counting the negation operators
+    /// and the double negations is computed.
+    pub negations: bool,
+    /// If `true`, the `ConditionalImports` metric counting the imports
+    /// depending on the control flow is computed.
+    pub conditional_imports: bool,
+    /// If `true`, the `UnsafeCode` metric counting the `unsafe` blocks
+    /// and functions is computed.
+    pub unsafe_code: bool,
+}
+
+/// Returns all function spaces data of a code, computed according
+/// to the given options.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use regex::Regex;
+/// use rust_code_analysis::{metrics_with_options, MetricsOptions, ParserTrait, RustParser};
+///
+/// let source_code = "fn handle_a() {} fn other() {}";
+///
+/// // The path to a dummy file used to contain the source code
+/// let path = Path::new("foo.rs");
+/// let source_as_vec = source_code.as_bytes().to_vec();
+///
+/// let parser = RustParser::new(source_as_vec, &path, None);
+///
+/// // Only keep the functions whose name starts with `handle_`
+/// let options = MetricsOptions {
+///     function_filter: Some(Regex::new("^handle_").unwrap()),
+///     ..Default::default()
+/// };
+///
+/// metrics_with_options(&parser, &path, &options).unwrap();
+/// ```
+pub fn metrics_with_options<'a, T: ParserTrait>(
+    parser: &'a T,
+    path: &'a Path,
+    options: &MetricsOptions,
+) -> Option<FuncSpace> {
+    let space = compute_spaces(parser, path, options)?;
+    if !options.strip_comments {
+        return Some(space);
+    }
+    let Some(code) = rm_comments(parser) else {
+        return Some(space);
+    };
+
+    // The code has already been preprocessed
+    let stripped = T::new(code, path, None);
+    let mut stripped_space = compute_spaces(&stripped, path, options)?;
+    reconcile_comments::<T>(&mut stripped_space, &space);
+
+    Some(stripped_space)
+}
+
+// Replaces the `Loc` metrics of the spaces computed on a code without
+// comments with the ones of the matching spaces of the original code,
+// since removing the comments turns their lines into blank lines.
+fn reconcile_comments<T: ParserTrait>(stripped: &mut FuncSpace, original: &FuncSpace) {
+    for space in stripped.spaces.iter_mut() {
+        if let Some(original) = original.spaces.iter().find(|original| {
+            original.kind == space.kind
+                && original.start_line == space.start_line
+                && original.end_line == space.end_line
+        }) {
+            reconcile_comments::<T>(space, original);
+        }
+    }
+
+    // The code of a unit starts later when it begins with comments
+    stripped.start_line = original.start_line;
+    stripped.end_line = original.end_line;
+
+    let metrics = &mut stripped.metrics;
+    metrics.loc = original.metrics.loc.clone();
+    // The maintainability index depends on the comment lines
+    T::Mi::compute(
+        &metrics.loc,
+        &metrics.cyclomatic,
+        &metrics.halstead,
+        &mut metrics.mi,
+    );
+}
+
+fn compute_spaces<'a, T: ParserTrait>(
+    parser: &'a T,
+    path: &'a Path,
+    options: &MetricsOptions,
+) -> Option<FuncSpace> {
     let code = parser.get_code();
     let node = parser.get_root();
     let mut cursor = node.cursor();
+    // The stack is reused during the whole walk. Each node carries its
+    // nesting levels for cognitive (conditionals, functions and lambdas),
+    // the loops enclosing it, its expression nesting and the body of its function, so they don't
+    // need to be stored in a map indexed by node nor recomputed from the
+    // ancestors of the node
     let mut stack = Vec::new();
-    let mut children = Vec::new();
     let mut state_stack: Vec<State> = Vec::new();
     let mut last_level = 0;
-    // Initialize nesting_map used for storing nesting information for cognitive
-    // Three type of nesting info: conditionals, functions and lambdas
-    let mut nesting_map = HashMap::<usize, (usize, usize, usize)>::default();
-    nesting_map.insert(node.id(), (0, 0, 0));
-    stack.push((node, 0));
+    let halstead_overrides = options.halstead_overrides.get(parser.get_language());
+    stack.push((node, 0, Levels::default()));
 
-    while let Some((node, level)) = stack.pop() {
+    while let Some((node, level, mut levels)) = stack.pop() {
         if level < last_level {
-            finalize::<T>(&mut state_stack, last_level - level);
+            finalize::<T>(&mut state_stack, last_level - level, options);
             last_level = level;
         }
 
         let kind = T::Getter::get_space_kind(&node);
 
-        let func_space = T::Checker::is_func(&node) || T::Checker::is_func_space(&node);
+        let func_space = T::Checker::is_func(&node)
+            || T::Checker::is_func_space(&node)
+            || (options.chained_closure_spaces && T::Checker::is_chained_closure(&node))
+            || (options.lambda_spaces && T::Checker::is_lambda_space(&node));
         let unit = kind == SpaceKind::Unit;
 
         let new_level = if func_space {
+            // The functions nested in another one are part of it,
+            // so only the outermost ones are checked
+            let public = !options.public_only
+                || !T::Checker::is_func(&node)
+                || state_stack.last().is_some_and(|state| {
+                    matches!(
+                        state.space.kind,
+                        SpaceKind::Function | SpaceKind::TraitDefault
+                    )
+                })
+                || T::Checker::is_public_func(&node, code);
+            let mut space = FuncSpace::new::<T::Getter>(&node, code, kind);
+            space.metrics.enable_optional(options);
             let state = State {
-                space: FuncSpace::new::<T::Getter>(&node, code, kind),
+                space,
                 halstead_maps: HalsteadMaps::new(),
+                public,
             };
             state_stack.push(state);
             last_level = level + 1;
@@ -322,36 +901,121 @@ pub fn metrics<'a, T: ParserTrait>(parser: &'a T, path: &'a Path) -> Option<Func
 
         if let Some(state) = state_stack.last_mut() {
             let last = &mut state.space;
-            T::Cognitive::compute(&node, &mut last.metrics.cognitive, &mut nesting_map);
+            T::Cognitive::compute(
+                &node,
+                code,
+                &mut last.metrics.cognitive,
+                &mut levels.cognitive,
+                &mut levels.loops,
+            );
             T::Cyclomatic::compute(&node, &mut last.metrics.cyclomatic);
-            T::Halstead::compute(&node, code, &mut state.halstead_maps);
+            T::Cyclomatic::compute_named(&node, code, &mut last.metrics.cyclomatic);
+            if options.count_yields {
+                T::Cyclomatic::compute_yield(&node, &mut last.metrics.cyclomatic);
+            }
+            match halstead_overrides.and_then(|overrides| overrides.get(node.kind())) {
+                Some(&typ) => {
+                    halstead::compute_halstead_type(&node, code, typ, &mut state.halstead_maps)
+                }
+                None => T::Halstead::compute(&node, code, &mut state.halstead_maps),
+            }
             T::Loc::compute(&node, &mut last.metrics.loc, func_space, unit);
-            T::Nom::compute(&node, &mut last.metrics.nom);
+            if !T::Nom::is_excluded(&node, code, &options.nom_exclusions) {
+                T::Nom::compute(&node, &mut last.metrics.nom);
+            }
             T::NArgs::compute(&node, &mut last.metrics.nargs);
             T::Exit::compute(&node, &mut last.metrics.nexits);
+            if options.implicit_exits {
+                T::Exit::compute_implicit(&node, &mut last.metrics.nexits);
+            }
             T::Abc::compute(&node, &mut last.metrics.abc);
-            T::Npm::compute(&node, &mut last.metrics.npm);
-            T::Npa::compute(&node, &mut last.metrics.npa);
+            T::Npm::compute(&node, code, &mut last.metrics.npm);
+            T::Npa::compute(&node, code, &mut last.metrics.npa);
+            if options.expr_nesting {
+                T::ExprNesting::compute(
+                    &node,
+                    &mut last.metrics.expr_nesting,
+                    &mut levels.expr_nesting,
+                );
+            }
+            if options.cfg_attributes {
+                T::Cfg::compute(&node, code, &mut last.metrics.cfg);
+            }
+            if options.early_returns {
+                T::EarlyReturns::compute(
+                    &node,
+                    &mut last.metrics.early_returns,
+                    &mut levels.early_returns,
+                );
+            }
+            if options.type_complexity {
+                T::TypeComplexity::compute(&node, &mut last.metrics.type_complexity);
+            }
+            // The captures are counted for the closures which are spaces
+            if options.captures && func_space {
+                T::Captures::compute(&node, code, &mut last.metrics.captures);
+            }
+            if options.nlocals {
+                T::NLocals::compute(&node, &mut last.metrics.nlocals);
+            }
+            if options.bare_ifs {
+                T::BareIfs::compute(&node, &mut last.metrics.bare_ifs);
+            }
+            if options.exception_types {
+                T::ExceptionTypes::compute(&node, code, &mut last.metrics.exception_types);
+            }
+            if options.chain_length {
+                T::ChainLength::compute(&node, &mut last.metrics.chain_length);
+            }
+            if options.statement_count {
+                T::StatementCount::compute(&node, &mut last.metrics.statement_count);
+            }
+            if options.referenced_types {
+                T::ReferencedTypes::compute(&node, code, &mut last.metrics.referenced_types);
+            }
+            if options.template_complexity {
+                T::TemplateComplexity::compute(&node, code, &mut last.metrics.template_complexity);
+            }
+            if options.literal_nesting {
+                T::LiteralNesting::compute(&node, &mut last.metrics.literal_nesting);
+            }
+            if options.negations {
+                T::Negations::compute(&node, &mut last.metrics.negations);
+            }
+            T::Decisions::compute(&node, &mut last.metrics.decisions);
+            if options.enum_variants {
+                T::EnumVariants::compute(&node, &mut last.metrics.enum_variants);
+            }
+            if options.conditional_imports {
+                T::ConditionalImports::compute(&node, code, &mut last.metrics.conditional_imports);
+            }
+            if options.unsafe_code {
+                T::UnsafeCode::compute(&node, &mut last.metrics.unsafe_code);
+            }
+            if options.goroutines {
+                T::Goroutines::compute(&node, &mut last.metrics.goroutines);
+            }
         }
 
         cursor.reset(&node);
         if cursor.goto_first_child() {
+            // Push the children in place and reverse them,
+            // so the first child is the next popped node
+            let first = stack.len();
             loop {
-                children.push((cursor.node(), new_level));
+                stack.push((cursor.node(), new_level, levels.clone()));
                 if !cursor.goto_next_sibling() {
                     break;
                 }
             }
-            for child in children.drain(..).rev() {
-                stack.push(child);
-            }
+            stack[first..].reverse();
         }
     }
 
-    finalize::<T>(&mut state_stack, usize::MAX);
+    finalize::<T>(&mut state_stack, usize::MAX, options);
 
     state_stack.pop().map(|mut state| {
-        state.space.name = path.to_str().map(|name| name.to_string());
+        state.space.name = options.unit_name.name(path);
         state.space
     })
 }
@@ -362,6 +1026,8 @@ pub fn metrics<'a, T: ParserTrait>(parser: &'a T, path: &'a Path) -> Option<Func
 pub struct MetricsCfg {
     /// Path to the file containing the code
     pub path: PathBuf,
+    /// Options used to compute the metrics
+    pub options: MetricsOptions,
 }
 
 pub struct Metrics {
@@ -373,7 +1039,7 @@ impl Callback for Metrics {
     type Cfg = MetricsCfg;
 
     fn call<T: ParserTrait>(cfg: Self::Cfg, parser: &T) -> Self::Res {
-        match metrics(parser, &cfg.path) {
+        match metrics_with_options(parser, &cfg.path, &cfg.options) {
             Some(space) => dump_root(&space),
             _ => Ok(()),
         }
@@ -382,7 +1048,13 @@ impl Callback for Metrics {
 
 #[cfg(test)]
 mod tests {
-    use crate::{CppParser, check_func_space};
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::{
+        CppParser, JavaParser, JavascriptParser, MozjsParser, PythonParser, RustParser,
+        TypescriptParser, check_func_space,
+    };
 
     #[test]
     fn c_scope_resolution_operator() {
@@ -399,4 +1071,573 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn function_filter() {
+        let path = PathBuf::from("foo.rs");
+        let source = "fn handle_a() {
+                if true {}
+            }
+            fn handle_b() {}
+            fn other() {
+                if true {}
+                if false {}
+            }"
+        .as_bytes()
+        .to_vec();
+        let parser = RustParser::new(source, &path, None);
+        let options = MetricsOptions {
+            function_filter: Some(Regex::new("^handle_").unwrap()),
+            ..Default::default()
+        };
+        let func_space = metrics_with_options(&parser, &path, &options).unwrap();
+
+        let names: Vec<_> = func_space
+            .spaces
+            .iter()
+            .map(|space| space.name.as_deref().unwrap())
+            .collect();
+        assert_eq!(names, ["handle_a", "handle_b"]);
+        // 1 (unit) + 2 (handle_a) + 1 (handle_b)
+        assert_eq!(func_space.metrics.cyclomatic.cyclomatic_sum(), 4.);
+        assert_eq!(func_space.metrics.nom.functions_sum(), 2.);
+    }
+
+    #[test]
+    fn cyclomatic_over() {
+        check_func_space::<PythonParser, _>(
+            "def f(a): # 2
+                 if a: pass
+
+             def g(a): # 5
+                 if a: pass
+                 if a: pass
+                 if a: pass
+                 if a: pass
+
+             def h(a): # 8
+                 if a and a and a: pass
+                 if a and a and a: pass
+                 if a: pass",
+            "foo.py",
+            |func_space| {
+                let over = func_space.cyclomatic_over(6.);
+                assert_eq!(over.len(), 1);
+                assert_eq!(over[0].function.as_deref(), Some("h"));
+                assert_eq!(over[0].line, 10);
+                assert_eq!(over[0].value, 8.);
+            },
+        );
+    }
+
+    #[test]
+    fn line_ranges() {
+        let path = PathBuf::from("foo.rs");
+        let source = "fn a() {
+                if true {}
+            }
+            fn b() {
+                if true {}
+            }
+            fn c() {
+                if true {}
+            }"
+        .as_bytes()
+        .to_vec();
+        let parser = RustParser::new(source, &path, None);
+        let options = MetricsOptions {
+            // The second line of `a` and the last line of `c`
+            line_ranges: Some(vec![2..3, 9..10]),
+            ..Default::default()
+        };
+        let func_space = metrics_with_options(&parser, &path, &options).unwrap();
+
+        let names: Vec<_> = func_space
+            .spaces
+            .iter()
+            .map(|space| space.name.as_deref().unwrap())
+            .collect();
+        assert_eq!(names, ["a", "c"]);
+        // 1 (unit) + 2 (a) + 2 (c)
+        assert_eq!(func_space.metrics.cyclomatic.cyclomatic_sum(), 5.);
+    }
+
+    #[test]
+    fn public_only() {
+        let path = PathBuf::from("foo.rs");
+        let source = "pub fn api(x: bool) {
+                fn helper() {}
+                if x {}
+            }
+            fn private() {
+                if true {}
+            }
+            pub(crate) fn internal() {}"
+            .as_bytes()
+            .to_vec();
+        let parser = RustParser::new(source, &path, None);
+        let options = MetricsOptions {
+            public_only: true,
+            ..Default::default()
+        };
+        let func_space = metrics_with_options(&parser, &path, &options).unwrap();
+
+        let names: Vec<_> = func_space
+            .spaces
+            .iter()
+            .map(|space| space.name.as_deref().unwrap())
+            .collect();
+        assert_eq!(names, ["api"]);
+        // The nested function is kept with the public one
+        assert_eq!(
+            func_space.spaces[0].spaces[0].name.as_deref(),
+            Some("helper")
+        );
+        // 1 (unit) + 2 (api) + 1 (helper)
+        assert_eq!(func_space.metrics.cyclomatic.cyclomatic_sum(), 4.);
+        assert_eq!(func_space.metrics.nom.functions_sum(), 2.);
+    }
+
+    #[test]
+    fn public_only_languages() {
+        fn public_names<T: ParserTrait>(source: &str, file: &str) -> Vec<String> {
+            let path = PathBuf::from(file);
+            let parser = T::new(source.as_bytes().to_vec(), &path, None);
+            let options = MetricsOptions {
+                public_only: true,
+                ..Default::default()
+            };
+            let mut names = Vec::new();
+            let mut stack = vec![metrics_with_options(&parser, &path, &options).unwrap()];
+            while let Some(space) = stack.pop() {
+                if space.kind == SpaceKind::Function {
+                    names.push(space.name.unwrap());
+                }
+                stack.extend(space.spaces);
+            }
+            names.sort();
+            names
+        }
+
+        assert_eq!(
+            public_names::<RustParser>(
+                "trait T { fn t(&self) {} }
+                 struct S;
+                 impl T for S { fn t(&self) {} }
+                 impl S { pub fn a(&self) {} fn b(&self) {} }",
+                "foo.rs"
+            ),
+            ["a", "t"]
+        );
+        assert_eq!(
+            public_names::<JavaParser>(
+                "public class A {
+                     public void a() {}
+                     void b() {}
+                     private void c() {}
+                 }
+                 interface I { void i(); default void d() {} }",
+                "foo.java"
+            ),
+            ["a", "d", "i"]
+        );
+        assert_eq!(
+            public_names::<CppParser>(
+                "class A {
+                     void a() {}
+                 public:
+                     void b() {}
+                 };
+                 struct B { void c() {} };
+                 static void d() {}
+                 void e() {}",
+                "foo.cpp"
+            ),
+            ["b", "c", "e"]
+        );
+        assert_eq!(
+            public_names::<JavascriptParser>(
+                "export function a() {}
+                 function b() {}
+                 export const c = function c() {};
+                 export class A { #d() {} e() {} }
+                 class B { f() {} }",
+                "foo.js"
+            ),
+            ["a", "c", "e"]
+        );
+        assert_eq!(
+            public_names::<TypescriptParser>(
+                "export class A { private a() {} public b() {} c() {} }",
+                "foo.ts"
+            ),
+            ["b", "c"]
+        );
+        assert_eq!(
+            public_names::<PythonParser>(
+                "def a(): pass
+                 def _b(): pass
+                 class A:
+                     def __init__(self): pass
+                     def _c(self): pass",
+                "foo.py"
+            ),
+            ["__init__", "a"]
+        );
+    }
+
+    #[test]
+    fn func_space_source() {
+        let path = PathBuf::from("foo.rs");
+        let source = "// Größe
+fn größe() -> u32 {
+    42
+}
+
+fn other() {}
+";
+        let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+        let func_space = metrics(&parser, &path).unwrap();
+
+        assert_eq!(func_space.source(source.as_bytes()), source);
+        assert_eq!(
+            func_space.spaces[0].source(source.as_bytes()),
+            "fn größe() -> u32 {\n    42\n}"
+        );
+        assert_eq!(
+            func_space.spaces[1].source(source.as_bytes()),
+            "fn other() {}"
+        );
+        // A range ending in the middle of a multi-byte character
+        let mut truncated = func_space.spaces[0].clone();
+        truncated.end_byte = truncated.start_byte + "fn grö".len() - 1;
+        assert_eq!(truncated.source(source.as_bytes()), "fn gr");
+    }
+
+    #[test]
+    fn class_metrics_selection() {
+        let path = PathBuf::from("foo.java");
+        let source = "class A {
+                void a1() {}
+                void a2() {}
+            }
+            class B {
+                void b1() {
+                    if (true) {}
+                }
+                void b2() {}
+                class B {
+                    void inner() {}
+                }
+            }";
+        let parser = JavaParser::new(source.as_bytes().to_vec(), &path, None);
+
+        let class = class_metrics(&parser, &path, "A").unwrap();
+        let names: Vec<_> = class
+            .spaces
+            .iter()
+            .map(|space| space.name.as_deref().unwrap())
+            .collect();
+        assert_eq!(names, ["a1", "a2"]);
+        assert_eq!(class.metrics.nom.functions_sum(), 2.);
+
+        // The innermost class is selected
+        let class = class_metrics(&parser, &path, "B").unwrap();
+        assert_eq!(class.start_line, 10);
+        assert_eq!(class.spaces.len(), 1);
+        assert_eq!(class.spaces[0].name.as_deref(), Some("inner"));
+
+        assert!(class_metrics(&parser, &path, "C").is_none());
+    }
+
+    #[test]
+    fn chained_closure_spaces() {
+        let path = PathBuf::from("foo.java");
+        let source = "class A {
+                List<String> f(List<String> names) {
+                    Runnable r = () -> {
+                        if (names.isEmpty()) {}
+                    };
+                    return names.stream()
+                        .filter(name -> {
+                            if (name.isEmpty()) {
+                                return false;
+                            }
+                            return true;
+                        })
+                        .map(name -> name.trim())
+                        .collect(Collectors.toList());
+                }
+            }";
+        let parser = JavaParser::new(source.as_bytes().to_vec(), &path, None);
+
+        // By default, the lambdas are part of the function
+        let func_space = metrics(&parser, &path).unwrap();
+        let f = &func_space.spaces[0].spaces[0];
+        assert!(f.spaces.is_empty());
+        assert_eq!(f.metrics.cyclomatic.cyclomatic(), 3.);
+
+        let options = MetricsOptions {
+            chained_closure_spaces: true,
+            ..Default::default()
+        };
+        let func_space = metrics_with_options(&parser, &path, &options).unwrap();
+        let f = &func_space.spaces[0].spaces[0];
+        assert_eq!(f.name.as_deref(), Some("f"));
+        // The lambda not passed to a chain of calls stays in the function
+        assert_eq!(f.metrics.cyclomatic.cyclomatic(), 2.);
+
+        let lambdas: Vec<_> = f
+            .spaces
+            .iter()
+            .map(|space| {
+                (
+                    space.kind,
+                    space.start_line,
+                    space.metrics.cyclomatic.cyclomatic(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            lambdas,
+            [(SpaceKind::Function, 7, 2.), (SpaceKind::Function, 13, 1.)]
+        );
+        // 1 (unit) + 1 (class) + 2 (f) + 2 (filter) + 1 (map)
+        assert_eq!(func_space.metrics.cyclomatic.cyclomatic_sum(), 7.);
+    }
+
+    #[test]
+    fn python_lambda_spaces() {
+        let path = PathBuf::from("foo.py");
+        let source = "def f(items):
+    key = lambda x: x if x else 0
+    check = lambda x: x > 0 and x < 10
+    return sorted(filter(check, items), key=key)";
+        let parser = PythonParser::new(source.as_bytes().to_vec(), &path, None);
+
+        // By default, the lambdas are part of the function
+        let func_space = metrics(&parser, &path).unwrap();
+        let f = &func_space.spaces[0];
+        assert!(f.spaces.is_empty());
+        assert_eq!(f.metrics.cyclomatic.cyclomatic(), 3.);
+
+        let options = MetricsOptions {
+            lambda_spaces: true,
+            ..Default::default()
+        };
+        let func_space = metrics_with_options(&parser, &path, &options).unwrap();
+        let f = &func_space.spaces[0];
+        assert_eq!(f.name.as_deref(), Some("f"));
+        assert_eq!(f.metrics.cyclomatic.cyclomatic(), 1.);
+
+        let lambdas: Vec<_> = f
+            .spaces
+            .iter()
+            .map(|space| {
+                (
+                    space.name.as_deref(),
+                    space.kind,
+                    space.metrics.cyclomatic.cyclomatic(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            lambdas,
+            [
+                (Some("<anonymous@2:11>"), SpaceKind::Function, 2.),
+                (Some("<anonymous@3:13>"), SpaceKind::Function, 2.)
+            ]
+        );
+    }
+
+    #[test]
+    fn trait_default_methods() {
+        let path = PathBuf::from("foo.rs");
+        let source = "trait T {
+    fn required(&self);
+    fn provided(&self, x: bool) -> i32 {
+        if x { 1 } else { 0 }
+    }
+}
+impl T for S {
+    fn required(&self) {}
+}";
+        let parser = RustParser::new(source.as_bytes().to_vec(), &path, None);
+        let func_space = metrics(&parser, &path).unwrap();
+
+        let kinds = |space: &FuncSpace| -> Vec<_> {
+            space
+                .spaces
+                .iter()
+                .map(|space| (space.kind, space.name.clone().unwrap()))
+                .collect()
+        };
+        assert_eq!(
+            kinds(&func_space),
+            [
+                (SpaceKind::Trait, "T".to_string()),
+                (SpaceKind::Impl, "S".to_string())
+            ]
+        );
+
+        // Only the defaulted method is a space of the trait
+        let provided = &func_space.spaces[0].spaces;
+        assert_eq!(
+            kinds(&func_space.spaces[0]),
+            [(SpaceKind::TraitDefault, "provided".to_string())]
+        );
+        assert_eq!(provided[0].metrics.cyclomatic.cyclomatic(), 2.);
+        assert_eq!(
+            kinds(&func_space.spaces[1]),
+            [(SpaceKind::Function, "required".to_string())]
+        );
+        assert_eq!(
+            serde_json::to_value(provided[0].kind).unwrap(),
+            "trait_default"
+        );
+    }
+
+    #[test]
+    fn anonymous_space_names() {
+        let path = PathBuf::from("foo.js");
+        let source = "const g = () => 1;
+[1, 2].map(x => x + 1);
+setTimeout(() => {
+    console.log(\"done\");
+}, 10);";
+        let parser = MozjsParser::new(source.as_bytes().to_vec(), &path, None);
+
+        let names = || {
+            metrics(&parser, &path)
+                .unwrap()
+                .spaces
+                .into_iter()
+                .map(|space| space.name.unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(), ["g", "<anonymous@2:12>", "<anonymous@3:12>"]);
+        // The names do not change from a run to another
+        assert_eq!(names(), names());
+    }
+
+    #[test]
+    fn strip_comments() {
+        let path = PathBuf::from("foo.c");
+        let source = "/* A header
+ * comment */
+#include <stdio.h>
+
+// The answer
+int f(int a) {
+    /* A comment */
+    return a + 42; // trailing
+}
+";
+        let parser = CppParser::new(source.as_bytes().to_vec(), &path, None);
+        let options = MetricsOptions {
+            strip_comments: true,
+            ..Default::default()
+        };
+
+        let original = metrics(&parser, &path).unwrap();
+        let stripped = metrics_with_options(&parser, &path, &options).unwrap();
+
+        // The comments are lost by parsing the code without them
+        let code = rm_comments(&parser).unwrap();
+        let without_comments = metrics(&CppParser::new(code, &path, None), &path).unwrap();
+        assert_eq!(without_comments.metrics.loc.cloc(), 0.);
+
+        for space in [&original, &stripped] {
+            assert_eq!(space.metrics.loc.cloc(), 5.);
+            assert_eq!(space.metrics.loc.blank(), 1.);
+            assert_eq!(space.spaces[0].metrics.loc.cloc(), 2.);
+        }
+        assert_eq!(
+            serde_json::to_value(&stripped).unwrap(),
+            serde_json::to_value(&original).unwrap()
+        );
+    }
+
+    #[test]
+    fn large_synthetic_file() {
+        let path = PathBuf::from("foo.rs");
+        let function = |name: &str| {
+            format!(
+                "fn {name}(x: i32) -> i32 {{
+    if x > 0 && x < 10 {{
+        for i in 0..x {{
+            if i == 2 || i == 3 {{
+                return i;
+            }}
+        }}
+    }} else if x > 10 {{
+        let g = |y: i32| if y > 0 {{ y }} else {{ 0 }};
+        return g(x);
+    }}
+    0
+}}
+"
+            )
+        };
+        let metrics_of = |source: String| {
+            let parser = RustParser::new(source.into_bytes(), &path, None);
+            metrics(&parser, &path).unwrap()
+        };
+
+        // Many copies of a function have the same metrics of a single one,
+        // whatever their position in the walk
+        let single = metrics_of(function("f"));
+        let copies = 1000;
+        let source = (0..copies).map(|i| function(&format!("f{i}"))).collect();
+        let space = metrics_of(source);
+        let expected = serde_json::to_value(&single.spaces[0].metrics).unwrap();
+        assert_eq!(space.spaces.len(), copies);
+        for function in &space.spaces {
+            assert_eq!(serde_json::to_value(&function.metrics).unwrap(), expected);
+        }
+        assert_eq!(
+            space.metrics.cognitive.cognitive_sum(),
+            copies as f64 * single.metrics.cognitive.cognitive_sum()
+        );
+
+        // Each nested if costs its nesting level plus one
+        let depth = 200;
+        let source = format!(
+            "fn f(x: i32) {{ {} {} }}",
+            "if x > 0 { ".repeat(depth),
+            "}".repeat(depth)
+        );
+        let space = metrics_of(source);
+        assert_eq!(
+            space.spaces[0].metrics.cognitive.cognitive(),
+            (depth * (depth + 1) / 2) as f64
+        );
+        assert_eq!(
+            space.spaces[0].metrics.cyclomatic.cyclomatic(),
+            (depth + 1) as f64
+        );
+    }
+
+    #[test]
+    fn unit_names() {
+        let path = PathBuf::from("src/metrics/foo.rs");
+        let parser = RustParser::new(b"fn f() {}".to_vec(), &path, None);
+        let unit_name = |unit_name| {
+            let options = MetricsOptions {
+                unit_name,
+                ..Default::default()
+            };
+            metrics_with_options(&parser, &path, &options).unwrap().name
+        };
+
+        assert_eq!(
+            unit_name(UnitName::Path).as_deref(),
+            Some("src/metrics/foo.rs")
+        );
+        assert_eq!(unit_name(UnitName::FileName).as_deref(), Some("foo.rs"));
+        assert_eq!(
+            unit_name(UnitName::Custom("module".to_string())).as_deref(),
+            Some("module")
+        );
+    }
 }
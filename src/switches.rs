@@ -0,0 +1,550 @@
+use serde::Serialize;
+
+use crate::traits::*;
+
+use crate::checker::Checker;
+use crate::getter::Getter;
+use crate::langs::LANG;
+use crate::languages::*;
+use crate::node::Node;
+
+/// A switch statement without a default case.
+#[derive(Debug, Serialize)]
+pub struct MissingDefault {
+    /// The name of the function containing the switch
+    ///
+    /// If `None`, the switch is not contained in a function
+    pub function: Option<String>,
+    /// The first line of the switch
+    pub line: usize,
+}
+
+/// Detects the switch statements lacking a default case, which
+/// silently ignore any value not matched by their cases.
+///
+/// C/C++, C#, Go, Java, JavaScript and TypeScript switches and Python
+/// matches are supported. A C# switch expression or a Python match has
+/// a default case when one of its cases matches any value without a guard,
+/// such as `_ => 0` or `case _:`.
+///
+/// Rust matches are left out since the compiler checks their exhaustiveness,
+/// and so are Go select statements, which block on purpose without
+/// a default case.
+///
+/// Returns a vector containing a [`MissingDefault`] for each switch
+/// without a default case.
+///
+/// [`MissingDefault`]: struct.MissingDefault.html
+pub fn missing_defaults<T: ParserTrait>(parser: &T) -> Vec<MissingDefault> {
+    let root = parser.get_root();
+    let code = parser.get_code();
+    let language = parser.get_language();
+    let mut missing = Vec::new();
+
+    root.act_on_node(&mut |n| {
+        let Some(has_default) = has_default(language, n) else {
+            return;
+        };
+        if has_default {
+            return;
+        }
+
+        let mut function = n.parent();
+        while let Some(parent) = function {
+            if T::Checker::is_func(&parent) {
+                break;
+            }
+            function = parent.parent();
+        }
+
+        missing.push(MissingDefault {
+            function: function
+                .and_then(|function| T::Getter::get_func_name(&function, code))
+                .map(|name| name.to_string()),
+            line: n.start_row() + 1,
+        });
+    });
+
+    missing
+}
+
+/// A case of a switch statement falling through to the next one.
+#[derive(Debug, Serialize)]
+pub struct FallThrough {
+    /// The name of the function containing the switch
+    ///
+    /// If `None`, the switch is not contained in a function
+    pub function: Option<String>,
+    /// The first line of the case
+    pub line: usize,
+}
+
+/// Detects the cases of C/C++ switch statements which implicitly fall
+/// through to the next case, since they do not end with a jump such as
+/// a `break` or a `return`.
+///
+/// The empty cases, which share the statements of the next one, are not
+/// reported, and neither are the ones marked as intentional through the
+/// `[[fallthrough]]` attribute or a comment such as `/* fallthrough */`.
+///
+/// Returns a vector containing a [`FallThrough`] for each case
+/// falling through.
+///
+/// [`FallThrough`]: struct.FallThrough.html
+pub fn fall_throughs<T: ParserTrait>(parser: &T) -> Vec<FallThrough> {
+    let root = parser.get_root();
+    let code = parser.get_code();
+    let mut fall_throughs = Vec::new();
+
+    if parser.get_language() != LANG::Cpp {
+        return fall_throughs;
+    }
+
+    root.act_on_node(&mut |n| {
+        if n.kind_id() != Cpp::SwitchStatement {
+            return;
+        }
+        let Some(body) = n.child_by_field_name("body") else {
+            return;
+        };
+
+        let mut function = n.parent();
+        while let Some(parent) = function {
+            if T::Checker::is_func(&parent) {
+                break;
+            }
+            function = parent.parent();
+        }
+
+        let mut children = body.children().peekable();
+        while let Some(case) = children.next() {
+            if case.kind_id() != Cpp::CaseStatement {
+                continue;
+            }
+            // The comments between two cases belong to the switch body
+            let mut annotated = false;
+            let mut is_last = true;
+            while let Some(next) = children.peek() {
+                if next.kind_id() == Cpp::CaseStatement {
+                    is_last = false;
+                    break;
+                }
+                annotated |= is_fall_through_comment(next, code);
+                children.next();
+            }
+
+            if !is_last && !annotated && falls_through(&case, code) {
+                fall_throughs.push(FallThrough {
+                    function: function
+                        .and_then(|function| T::Getter::get_func_name(&function, code))
+                        .map(|name| name.to_string()),
+                    line: case.start_row() + 1,
+                });
+            }
+        }
+    });
+
+    fall_throughs
+}
+
+fn is_fall_through_comment(node: &Node, code: &[u8]) -> bool {
+    node.kind_id() == Cpp::Comment
+        && node.utf8_text(code).is_some_and(|comment| {
+            let comment = comment.to_lowercase().replace([' ', '-', '_'], "");
+            comment.contains("fallthrough") || comment.contains("fallsthrough")
+        })
+}
+
+// Returns whether the statements of a non-empty case do not end with a jump
+// or an annotation of an intentional fall-through.
+fn falls_through(case: &Node, code: &[u8]) -> bool {
+    let mut statements = case
+        .children()
+        .skip_while(|child| child.kind_id() != Cpp::COLON)
+        .skip(1)
+        .peekable();
+    if statements.peek().is_none() {
+        return false;
+    }
+
+    let mut last = None;
+    for statement in statements {
+        if statement.kind_id() == Cpp::Comment {
+            if is_fall_through_comment(&statement, code) {
+                return false;
+            }
+        } else {
+            last = Some(statement);
+        }
+    }
+
+    last.is_some_and(|last| !terminates(&last, code))
+}
+
+fn terminates(statement: &Node, code: &[u8]) -> bool {
+    match statement.kind_id().into() {
+        Cpp::BreakStatement
+        | Cpp::ContinueStatement
+        | Cpp::ReturnStatement
+        | Cpp::CoReturnStatement
+        | Cpp::GotoStatement
+        | Cpp::ThrowStatement => true,
+        Cpp::AttributedStatement => statement
+            .utf8_text(code)
+            .is_some_and(|text| text.contains("fallthrough")),
+        Cpp::CompoundStatement => statement
+            .children()
+            .filter(|child| child.is_named() && child.kind_id() != Cpp::Comment)
+            .last()
+            .is_some_and(|last| terminates(&last, code)),
+        // Both branches of an if statement must end with a jump
+        Cpp::IfStatement => {
+            let consequence = statement.child_by_field_name("consequence");
+            let alternative =
+                statement
+                    .child_by_field_name("alternative")
+                    .and_then(|alternative| {
+                        alternative
+                            .children()
+                            .filter(|child| child.is_named() && child.kind_id() != Cpp::Comment)
+                            .last()
+                    });
+            consequence.is_some_and(|consequence| terminates(&consequence, code))
+                && alternative.is_some_and(|alternative| terminates(&alternative, code))
+        }
+        _ => false,
+    }
+}
+
+// Returns whether a switch has a default case, or `None`
+// if the node is not a switch.
+fn has_default(language: LANG, node: &Node) -> Option<bool> {
+    let body = node.child_by_field_name("body");
+    let cases = || body.iter().flat_map(|body| body.children());
+
+    let has_default = match language {
+        LANG::Cpp if node.kind_id() == Cpp::SwitchStatement => cases().any(|case| {
+            case.kind_id() == Cpp::CaseStatement
+                && case
+                    .child(0)
+                    .is_some_and(|label| label.kind_id() == Cpp::Default)
+        }),
+        LANG::Java if node.kind_id() == Java::SwitchExpression => cases().any(|case| {
+            matches!(
+                case.kind_id().into(),
+                Java::SwitchBlockStatementGroup | Java::SwitchRule
+            ) && case.children().any(|label| {
+                label.kind_id() == Java::SwitchLabel
+                    && label
+                        .child(0)
+                        .is_some_and(|keyword| keyword.kind_id() == Java::Default)
+            })
+        }),
+        LANG::Mozjs if node.kind_id() == Mozjs::SwitchStatement => {
+            cases().any(|case| case.kind_id() == Mozjs::SwitchDefault)
+        }
+        LANG::Javascript if node.kind_id() == Javascript::SwitchStatement => {
+            cases().any(|case| case.kind_id() == Javascript::SwitchDefault)
+        }
+        LANG::Typescript if node.kind_id() == Typescript::SwitchStatement => {
+            cases().any(|case| case.kind_id() == Typescript::SwitchDefault)
+        }
+        LANG::Tsx if node.kind_id() == Tsx::SwitchStatement => {
+            cases().any(|case| case.kind_id() == Tsx::SwitchDefault)
+        }
+        // The cases of a Go switch are not contained in a body
+        LANG::Go
+            if matches!(
+                node.kind_id().into(),
+                Go::ExpressionSwitchStatement | Go::TypeSwitchStatement
+            ) =>
+        {
+            node.children()
+                .any(|case| case.kind_id() == Go::DefaultCase)
+        }
+        LANG::Csharp if node.kind_id() == Csharp::SwitchStatement => cases().any(|section| {
+            section.kind_id() == Csharp::SwitchSection
+                && section
+                    .children()
+                    .any(|label| label.kind_id() == Csharp::Default)
+        }),
+        LANG::Csharp if node.kind_id() == Csharp::SwitchExpression => node.children().any(|arm| {
+            arm.kind_id() == Csharp::SwitchExpressionArm
+                && arm
+                    .child(0)
+                    .is_some_and(|pattern| pattern.kind_id() == Csharp::Discard)
+                && arm
+                    .children()
+                    .all(|child| child.kind_id() != Csharp::WhenClause)
+        }),
+        LANG::Python if node.kind_id() == Python::MatchStatement => {
+            cases().any(|case| case.kind_id() == Python::CaseClause && is_python_wildcard(&case))
+        }
+        _ => return None,
+    };
+
+    Some(has_default)
+}
+
+// Returns whether a case of a Python match matches any value, as the
+// wildcard `case _:` or a capture such as `case x:` without a guard.
+fn is_python_wildcard(case: &Node) -> bool {
+    let mut patterns = case
+        .children()
+        .filter(|child| child.kind_id() == Python::CasePattern);
+    let (Some(pattern), None) = (patterns.next(), patterns.next()) else {
+        return false;
+    };
+    if case
+        .children()
+        .any(|child| child.kind_id() == Python::IfClause)
+    {
+        return false;
+    }
+
+    pattern.child_count() == 1
+        && pattern.child(0).is_some_and(|pattern| {
+            pattern.kind_id() == Python::UNDERSCORE
+                // A dotted name, such as `Color.RED`, is a value
+                || (pattern.kind_id() == Python::DottedName && pattern.child_count() == 1)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{CppParser, CsharpParser, GoParser, JavaParser, JavascriptParser, PythonParser};
+
+    use super::*;
+
+    fn check<T: ParserTrait>(source: &str, filename: &str) -> Vec<MissingDefault> {
+        let path = PathBuf::from(filename);
+        let parser = T::new(source.as_bytes().to_vec(), &path, None);
+        missing_defaults(&parser)
+    }
+
+    #[test]
+    fn c_switch_without_default() {
+        let missing = check::<CppParser>(
+            "int f(int x) {
+                switch (x) {
+                case 0:
+                    return 1;
+                case 1:
+                    return 2;
+                }
+                return 0;
+            }",
+            "foo.c",
+        );
+
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].function.as_deref(), Some("f"));
+        assert_eq!(missing[0].line, 2);
+    }
+
+    #[test]
+    fn c_switch_with_default() {
+        let missing = check::<CppParser>(
+            "int f(int x) {
+                switch (x) {
+                case 0:
+                    return 1;
+                default:
+                    return 0;
+                }
+            }",
+            "foo.c",
+        );
+
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn java_switches() {
+        let missing = check::<JavaParser>(
+            "class A {
+                int f(int x) {
+                    switch (x) {
+                        case 0: return 1;
+                        default: return 0;
+                    }
+                }
+                int g(int x) {
+                    return switch (x) {
+                        case 0 -> 1;
+                        case 1 -> 2;
+                    };
+                }
+            }",
+            "foo.java",
+        );
+
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].function.as_deref(), Some("g"));
+        assert_eq!(missing[0].line, 9);
+    }
+
+    #[test]
+    fn javascript_nested_switches() {
+        let missing = check::<JavascriptParser>(
+            "switch (a) {
+                case 0:
+                    switch (b) {
+                        default:
+                            break;
+                    }
+                    break;
+            }",
+            "foo.js",
+        );
+
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].function, None);
+        assert_eq!(missing[0].line, 1);
+    }
+
+    #[test]
+    fn go_switches() {
+        let missing = check::<GoParser>(
+            "package main
+
+            func f(x int, v interface{}) int {
+                switch x {
+                case 0:
+                    return 1
+                default:
+                    return 0
+                }
+                switch v.(type) {
+                case int:
+                    return 2
+                }
+                select {
+                case <-ch:
+                }
+                return 3
+            }",
+            "foo.go",
+        );
+
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].function.as_deref(), Some("f"));
+        assert_eq!(missing[0].line, 10);
+    }
+
+    #[test]
+    fn csharp_switches() {
+        let missing = check::<CsharpParser>(
+            "class A {
+                int F(int x) {
+                    switch (x) {
+                        case 0:
+                        default:
+                            return 0;
+                    }
+                }
+                int G(int x) {
+                    switch (x) {
+                        case 0: return 1;
+                    }
+                    return x switch { 0 => 1, _ => 2 };
+                }
+                int H(int x) => x switch { 0 => 1, _ when x > 0 => 2 };
+            }",
+            "foo.cs",
+        );
+
+        let functions: Vec<_> = missing
+            .iter()
+            .map(|missing| (missing.function.as_deref(), missing.line))
+            .collect();
+        assert_eq!(functions, [(Some("G"), 10), (Some("H"), 15)]);
+    }
+
+    #[test]
+    fn python_matches() {
+        let missing = check::<PythonParser>(
+            "def f(x):
+                match x:
+                    case 0:
+                        return 1
+                    case _:
+                        return 0
+
+            def g(x):
+                match x:
+                    case 0:
+                        return 1
+                    case Color.RED:
+                        return 2
+                    case y if y > 0:
+                        return 3
+
+            def h(x):
+                match x:
+                    case 0:
+                        return 1
+                    case y:
+                        return y",
+            "foo.py",
+        );
+
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].function.as_deref(), Some("g"));
+        assert_eq!(missing[0].line, 9);
+    }
+
+    #[test]
+    fn c_fall_through() {
+        let path = PathBuf::from("foo.c");
+        let source = "int f(int x) {
+                int y = 0;
+                switch (x) {
+                case 0:
+                    y = 1;
+                case 1:
+                    y = 2;
+                    break;
+                case 2:
+                case 3:
+                    return 3;
+                case 4:
+                    y = 4;
+                    /* fall through */
+                case 5:
+                    y = 5;
+                    [[fallthrough]];
+                case 6:
+                    if (y) {
+                        return 6;
+                    } else {
+                        break;
+                    }
+                case 7:
+                    if (y) {
+                        return 7;
+                    }
+                default:
+                    y = 8;
+                }
+                return y;
+            }";
+        let parser = CppParser::new(source.as_bytes().to_vec(), &path, None);
+        let fall_throughs = fall_throughs(&parser);
+
+        let lines: Vec<_> = fall_throughs.iter().map(|case| case.line).collect();
+        assert_eq!(lines, [4, 24]);
+        assert_eq!(fall_throughs[0].function.as_deref(), Some("f"));
+    }
+
+    #[test]
+    fn no_fall_through_in_other_languages() {
+        let path = PathBuf::from("foo.js");
+        let source = "switch (x) { case 0: y = 1; case 1: y = 2; }";
+        let parser = JavascriptParser::new(source.as_bytes().to_vec(), &path, None);
+
+        assert!(fall_throughs(&parser).is_empty());
+    }
+}
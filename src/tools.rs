@@ -245,6 +245,148 @@ pub fn guess_language<'a, P: AsRef<Path>>(buf: &[u8], path: P) -> (Option<LANG>,
     }
 }
 
+static RE_SHEBANG: OnceLock<Regex> = OnceLock::new();
+static RE_CONTENT: OnceLock<Vec<(LANG, Regex)>> = OnceLock::new();
+static RE_TYPESCRIPT: OnceLock<Regex> = OnceLock::new();
+
+const SHEBANG_EXPRESSION: &str = r"^#!\s*\S*/(?:env\s+(?:-\S+\s+)*)?([\w.-]+)";
+const TYPESCRIPT_EXPRESSION: &str =
+    r"(?m)^\s*(?:export\s+)?(?:interface|type)\s+\w+|:\s*(?:string|number|boolean|any)\b";
+
+// Distinctive constructs of each language, such as the ones which cannot
+// be written in any other supported language
+const CONTENT_EXPRESSIONS: &[(LANG, &str)] = &[
+    (
+        LANG::Python,
+        r"(?m)^\s*(?:def\s+\w+\s*\(.*\)\s*(?:->.*)?:|class\s+\w+\s*(?:\(.*\))?:|(?:elif|except)\b.*:|from\s+[\w.]+\s+import\s+[\w., ]+)\s*$",
+    ),
+    (
+        LANG::Rust,
+        r"(?m)^\s*(?:pub(?:\(\w+\))?\s+)?fn\s+\w+|\blet\s+mut\b|#\[derive\(|^\s*impl\b|^\s*use\s+\w+::",
+    ),
+    (
+        LANG::Java,
+        r"(?m)^\s*package\s+[\w.]+;|^\s*import\s+java|\b(?:public|private|protected)\s+(?:static\s+)?(?:final\s+)?(?:class|interface|enum|void)\b|System\.out\.",
+    ),
+    (
+        LANG::Cpp,
+        r"(?m)^\s*#\s*include\b|\bstd::|^\s*template\s*<|^\s*namespace\s+\w+|\bint\s+main\s*\(",
+    ),
+    (
+        LANG::Javascript,
+        r"(?m)\bfunction\b\s*\w*\s*\(|\bconsole\.\w+\(|\brequire\(|^\s*(?:export\s+default|module\.exports)\b|\bconst\s+\w+\s*=",
+    ),
+    (
+        LANG::Kotlin,
+        r"(?m)^\s*(?:\w+\s+)*fun\s+\w+\s*\(|\bval\s+\w+|^\s*package\s+[\w.]+\s*$",
+    ),
+];
+
+fn get_from_shebang(buf: &[u8]) -> Option<LANG> {
+    let cap = get_regex(&RE_SHEBANG, buf, SHEBANG_EXPRESSION)?;
+    let interpreter = std::str::from_utf8(&cap[1]).ok()?;
+    if interpreter.starts_with("python") {
+        Some(LANG::Python)
+    } else if matches!(interpreter, "node" | "nodejs") {
+        Some(LANG::Javascript)
+    } else if interpreter == "ts-node" {
+        Some(LANG::Typescript)
+    } else {
+        None
+    }
+}
+
+/// Guesses the language of a code from its content only,
+/// such as a code read from the standard input.
+///
+/// The language is guessed using, in this order, the `emacs` and `vim`
+/// mode lines, the interpreter of a shebang line, such as `#!/usr/bin/env python3`,
+/// and the constructs distinctive of each language.
+///
+/// Returns `None` when the language is ambiguous rather than guessing a wrong one.
+///
+/// # Examples
+///
+/// ```
+/// use rust_code_analysis::{guess_language_from_content, LANG};
+///
+/// let source_code = "def f(a):\n    return a\n";
+///
+/// assert_eq!(guess_language_from_content(source_code.as_bytes()), Some(LANG::Python));
+/// ```
+pub fn guess_language_from_content(buf: &[u8]) -> Option<LANG> {
+    if let Some(language) = get_emacs_mode(buf).and_then(|mode| get_from_emacs_mode(&mode)) {
+        return Some(language);
+    }
+    if let Some(language) = get_from_shebang(buf) {
+        return Some(language);
+    }
+
+    let expressions = RE_CONTENT.get_or_init(|| {
+        CONTENT_EXPRESSIONS
+            .iter()
+            .map(|(language, regex)| (*language, Regex::new(regex).unwrap()))
+            .collect()
+    });
+    let mut scores: Vec<_> = expressions
+        .iter()
+        .map(|(language, regex)| (regex.find_iter(buf).count(), *language))
+        .filter(|(score, _)| *score > 0)
+        .collect();
+    scores.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+
+    // The language is ambiguous when many of them score the same
+    let language = match scores.as_slice() {
+        [(_, language)] => *language,
+        [(first, language), (second, _), ..] if first > second => *language,
+        _ => return None,
+    };
+
+    // A TypeScript code is a JavaScript one with type annotations
+    if language == LANG::Javascript
+        && RE_TYPESCRIPT
+            .get_or_init(|| Regex::new(TYPESCRIPT_EXPRESSION).unwrap())
+            .is_match(buf)
+    {
+        return Some(LANG::Typescript);
+    }
+
+    Some(language)
+}
+
+// The number of lines at the top of a code scanned for the markers
+// of the generated code, enough to skip a license header
+const GENERATED_HEADER_LINES: usize = 10;
+
+/// Returns `true` when a code is marked as generated by a tool, such as
+/// the code produced by `protoc` or `bindgen`, which skews the metrics
+/// of a project since it is neither written nor maintained by hand.
+///
+/// The first lines of the code are scanned for the markers of the most
+/// common tools, that is `@generated`, `automatically generated by` and
+/// a `generated` code which must not be edited, such as the
+/// `// Code generated by protoc-gen-go. DO NOT EDIT.` header of `Go`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_code_analysis::is_generated;
+///
+/// let source_code = "// Code generated by protoc-gen-go. DO NOT EDIT.\npackage foo\n";
+///
+/// assert!(is_generated(source_code.as_bytes()));
+/// ```
+pub fn is_generated(buf: &[u8]) -> bool {
+    buf.split(|c| *c == b'\n')
+        .take(GENERATED_HEADER_LINES)
+        .any(|line| {
+            let line = String::from_utf8_lossy(line).to_lowercase();
+            line.contains("@generated")
+                || line.contains("automatically generated by")
+                || (line.contains("generated") && line.contains("do not edit"))
+        })
+}
+
 /// Replaces \n and \r ending characters with a single generic \n
 pub(crate) fn remove_blank_lines(data: &mut Vec<u8>) {
     let count_trailing = data
@@ -383,12 +525,27 @@ pub(crate) fn check_func_space<T: crate::ParserTrait, F: Fn(crate::FuncSpace)>(
     source: &str,
     filename: &str,
     check: F,
+) {
+    check_func_space_with_options::<T, F>(
+        source,
+        filename,
+        &crate::MetricsOptions::default(),
+        check,
+    )
+}
+
+#[cfg(test)]
+pub(crate) fn check_func_space_with_options<T: crate::ParserTrait, F: Fn(crate::FuncSpace)>(
+    source: &str,
+    filename: &str,
+    options: &crate::MetricsOptions,
+    check: F,
 ) {
     let path = std::path::PathBuf::from(filename);
     let mut trimmed_bytes = source.trim_end().trim_matches('\n').as_bytes().to_vec();
     trimmed_bytes.push(b'\n');
     let parser = T::new(trimmed_bytes, &path, None);
-    let func_space = crate::metrics(&parser, &path).unwrap();
+    let func_space = crate::metrics_with_options(&parser, &path, options).unwrap();
 
     check(func_space)
 }
@@ -402,6 +559,18 @@ pub(crate) fn check_metrics<T: crate::ParserTrait>(
     check_func_space::<T, _>(source, filename, |func_space| check(func_space.metrics))
 }
 
+#[cfg(test)]
+pub(crate) fn check_metrics_with_options<T: crate::ParserTrait>(
+    source: &str,
+    filename: &str,
+    options: &crate::MetricsOptions,
+    check: fn(crate::CodeMetrics) -> (),
+) {
+    check_func_space_with_options::<T, _>(source, filename, options, |func_space| {
+        check(func_space.metrics)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -457,4 +626,61 @@ mod tests {
             (Some(LANG::Cpp), "obj-c/c++")
         );
     }
+
+    #[test]
+    fn test_guess_language_from_content() {
+        let buf = b"// -*- c++ -*-\n";
+        assert_eq!(guess_language_from_content(buf), Some(LANG::Cpp));
+
+        let buf = b"#!/usr/bin/env python3\nprint('hello')\n";
+        assert_eq!(guess_language_from_content(buf), Some(LANG::Python));
+
+        let buf = b"#!/usr/bin/node\nlet a = 1;\n";
+        assert_eq!(guess_language_from_content(buf), Some(LANG::Javascript));
+
+        let buf = b"#include <stdio.h>\n\nint main(void) {\n    return 0;\n}\n";
+        assert_eq!(guess_language_from_content(buf), Some(LANG::Cpp));
+
+        let buf = b"use std::io;\n\npub fn f() -> io::Result<()> {\n    let mut a = 1;\n}\n";
+        assert_eq!(guess_language_from_content(buf), Some(LANG::Rust));
+
+        let buf = b"package foo;\n\npublic class A {\n}\n";
+        assert_eq!(guess_language_from_content(buf), Some(LANG::Java));
+
+        let buf = b"function f(a) {\n    console.log(a);\n}\n";
+        assert_eq!(guess_language_from_content(buf), Some(LANG::Javascript));
+
+        let buf = b"function f(a: number) {\n    console.log(a);\n}\n";
+        assert_eq!(guess_language_from_content(buf), Some(LANG::Typescript));
+
+        // Nothing distinctive
+        let buf = b"a = 1\n";
+        assert_eq!(guess_language_from_content(buf), None);
+
+        // As much Rust as C++
+        let buf = b"#include <vector>\nfn f() {}\n";
+        assert_eq!(guess_language_from_content(buf), None);
+    }
+
+    #[test]
+    fn generated_code() {
+        assert!(is_generated(
+            b"// Code generated by protoc-gen-go. DO NOT EDIT.\npackage foo\n"
+        ));
+        assert!(is_generated(
+            b"/* automatically generated by rust-bindgen 0.69.4 */\n"
+        ));
+        assert!(is_generated(
+            b"// Copyright 2024 Foo\n//\n// @generated by the build script\nfn f() {}\n"
+        ));
+        assert!(!is_generated(
+            b"// Do not edit this constant\nconst A: u8 = 1;\n"
+        ));
+        assert!(!is_generated(b"fn generated() {}\n"));
+
+        // The markers below the header are ignored
+        let mut source = "fn f() {}\n".repeat(GENERATED_HEADER_LINES);
+        source.push_str("// @generated\n");
+        assert!(!is_generated(source.as_bytes()));
+    }
 }
@@ -3,22 +3,42 @@ use std::sync::Arc;
 
 use crate::abc::Abc;
 use crate::alterator::Alterator;
+use crate::bare_ifs::BareIfs;
+use crate::captures::Captures;
+use crate::cfg::Cfg;
+use crate::chain_length::ChainLength;
 use crate::checker::Checker;
 use crate::cognitive::Cognitive;
+use crate::conditional_imports::ConditionalImports;
 use crate::cyclomatic::Cyclomatic;
+use crate::decisions::Decisions;
+use crate::early_returns::EarlyReturns;
+use crate::enum_variants::EnumVariants;
+use crate::exception_types::ExceptionTypes;
 use crate::exit::Exit;
+use crate::expr_nesting::ExprNesting;
 use crate::getter::Getter;
+use crate::goroutines::Goroutines;
 use crate::halstead::Halstead;
 use crate::langs::*;
+use crate::literal_nesting::LiteralNesting;
 use crate::loc::Loc;
 use crate::mi::Mi;
 use crate::nargs::NArgs;
+use crate::negations::Negations;
+use crate::nlocals::NLocals;
 use crate::node::Node;
 use crate::nom::Nom;
 use crate::npa::Npa;
 use crate::npm::Npm;
-use crate::parser::Filter;
+use crate::parser::{Filter, ParserHandle};
 use crate::preproc::PreprocResults;
+use crate::referenced_types::ReferencedTypes;
+use crate::session::{Edit, EditError};
+use crate::statement_count::StatementCount;
+use crate::template_complexity::TemplateComplexity;
+use crate::type_complexity::TypeComplexity;
+use crate::unsafe_code::UnsafeCode;
 use crate::wmc::Wmc;
 
 /// A trait for callback functions.
@@ -58,8 +78,44 @@ pub trait ParserTrait {
     type Abc: Abc;
     type Npm: Npm;
     type Npa: Npa;
+    type ExprNesting: ExprNesting;
+    type Cfg: Cfg;
+    type EarlyReturns: EarlyReturns;
+    type TypeComplexity: TypeComplexity;
+    type Captures: Captures;
+    type NLocals: NLocals;
+    type BareIfs: BareIfs;
+    type ExceptionTypes: ExceptionTypes;
+    type ChainLength: ChainLength;
+    type StatementCount: StatementCount;
+    type ReferencedTypes: ReferencedTypes;
+    type TemplateComplexity: TemplateComplexity;
+    type LiteralNesting: LiteralNesting;
+    type Negations: Negations;
+    type Decisions: Decisions;
+    type EnumVariants: EnumVariants;
+    type ConditionalImports: ConditionalImports;
+    type UnsafeCode: UnsafeCode;
+    type Goroutines: Goroutines;
 
     fn new(code: Vec<u8>, path: &Path, pr: Option<Arc<PreprocResults>>) -> Self;
+    fn with_handle(
+        code: Vec<u8>,
+        path: &Path,
+        pr: Option<Arc<PreprocResults>>,
+        handle: &mut ParserHandle,
+    ) -> Self;
+    /// Applies an edit to the code and reparses it, reusing the nodes of
+    /// the previous tree which were not touched by the edit.
+    ///
+    /// Returns an error, leaving the code untouched, if the range
+    /// of the edit is inverted or out of the code.
+    ///
+    /// The edit applies to the parsed code, so for a C++ parser created
+    /// with the results of the preprocessor the range refers to the code
+    /// where the macros were replaced, and they are not replaced again
+    /// in the text of the edit.
+    fn edit(&mut self, edit: &Edit, handle: &mut ParserHandle) -> Result<(), EditError>;
     fn get_language(&self) -> LANG;
     fn get_root(&self) -> Node;
     fn get_code(&self) -> &[u8];
@@ -0,0 +1,308 @@
+use crate::langs::LANG;
+
+/// Extracts the scripts of a Vue single-file component.
+///
+/// The language of the scripts is chosen through the `lang` attribute
+/// of their `<script>` tags: `ts` and `tsx` select TypeScript, while
+/// JavaScript is used when the attribute is missing.
+///
+/// Returns the language of the scripts and a code where everything outside
+/// them is replaced by whitespaces, so that the lines of the code are the same
+/// as the ones of the component.
+///
+/// Returns `None` if the component has no scripts or their language
+/// is not supported.
+///
+/// # Examples
+///
+/// ```
+/// use rust_code_analysis::{extract_vue_script, LANG};
+///
+/// let source_code = "<template><p>Hi</p></template>\n<script>\nlet a = 42;\n</script>";
+///
+/// let (language, code) = extract_vue_script(source_code.as_bytes()).unwrap();
+/// assert_eq!(language, LANG::Javascript);
+/// ```
+pub fn extract_vue_script(source: &[u8]) -> Option<(LANG, Vec<u8>)> {
+    let mut code: Vec<u8> = source
+        .iter()
+        .map(|&c| if c == b'\n' || c == b'\r' { c } else { b' ' })
+        .collect();
+    let mut language = None;
+
+    let mut pos = 0;
+    while let Some(start) = find_tag(source, b"<script", pos) {
+        let tag = parse_tag(source, start)?;
+
+        // A `<script setup>` block and a plain `<script>` one
+        // must share the same language
+        let tag_language = get_language(&tag.attributes)?;
+        if *language.get_or_insert(tag_language) != tag_language {
+            return None;
+        }
+
+        pos = tag.end;
+        if tag.self_closing {
+            continue;
+        }
+        let body_end = find_tag(source, b"</script", tag.end).unwrap_or(source.len());
+        code[tag.end..body_end].copy_from_slice(&source[tag.end..body_end]);
+        pos = body_end;
+    }
+
+    language.map(|language| (language, code))
+}
+
+// The attributes of a tag, as pairs of names and optional values
+type Attributes<'a> = Vec<(&'a [u8], Option<&'a [u8]>)>;
+
+struct Tag<'a> {
+    attributes: Attributes<'a>,
+    // The position following the `>` closing the tag
+    end: usize,
+    self_closing: bool,
+}
+
+fn find(source: &[u8], pattern: &[u8], from: usize) -> Option<usize> {
+    source
+        .get(from..)?
+        .windows(pattern.len())
+        .position(|window| window.eq_ignore_ascii_case(pattern))
+        .map(|position| from + position)
+}
+
+// Finds the start of a tag, such as `<script`, skipping the tags
+// whose name only starts with the same characters, such as `<scripts>`
+fn find_tag(source: &[u8], tag: &[u8], from: usize) -> Option<usize> {
+    let mut pos = from;
+    loop {
+        let start = find(source, tag, pos)?;
+        pos = start + tag.len();
+        if source
+            .get(pos)
+            .is_none_or(|&c| c.is_ascii_whitespace() || c == b'>' || c == b'/')
+        {
+            return Some(start);
+        }
+    }
+}
+
+// Parses the attributes of the tag starting at `start`, whose values
+// can be quoted and then contain whitespaces or `>`, such as
+// `generic="T extends Foo<Bar>"`.
+//
+// Returns `None` if the tag is not closed.
+fn parse_tag(source: &[u8], start: usize) -> Option<Tag<'_>> {
+    let is_name_end = |c: u8| c.is_ascii_whitespace() || matches!(c, b'=' | b'>' | b'/');
+    let skip_whitespaces = |mut pos: usize| {
+        while source.get(pos).is_some_and(u8::is_ascii_whitespace) {
+            pos += 1;
+        }
+        pos
+    };
+
+    let mut attributes = Vec::new();
+    // Skip the name of the tag
+    let mut pos = start + 1;
+    while !is_name_end(*source.get(pos)?) {
+        pos += 1;
+    }
+    loop {
+        pos = skip_whitespaces(pos);
+        match *source.get(pos)? {
+            b'>' => {
+                return Some(Tag {
+                    attributes,
+                    end: pos + 1,
+                    self_closing: source[..pos].ends_with(b"/"),
+                });
+            }
+            b'/' => {
+                pos += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        let name_start = pos;
+        while !is_name_end(*source.get(pos)?) {
+            pos += 1;
+        }
+        let name = &source[name_start..pos];
+
+        pos = skip_whitespaces(pos);
+        if source.get(pos) != Some(&b'=') {
+            attributes.push((name, None));
+            continue;
+        }
+        pos = skip_whitespaces(pos + 1);
+
+        let value = match *source.get(pos)? {
+            quote @ (b'"' | b'\'') => {
+                let value_start = pos + 1;
+                let value_end =
+                    value_start + source[value_start..].iter().position(|&c| c == quote)?;
+                pos = value_end + 1;
+                &source[value_start..value_end]
+            }
+            _ => {
+                let value_start = pos;
+                while !source
+                    .get(pos)
+                    .is_none_or(|&c| c.is_ascii_whitespace() || c == b'>')
+                {
+                    pos += 1;
+                }
+                &source[value_start..pos]
+            }
+        };
+        // A stray `=` without a name is skipped with its value
+        if !name.is_empty() {
+            attributes.push((name, Some(value)));
+        }
+    }
+}
+
+fn get_language(attributes: &Attributes) -> Option<LANG> {
+    let lang = attributes
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(b"lang"))
+        .and_then(|(_, value)| *value);
+
+    match lang {
+        None | Some(b"js") | Some(b"jsx") => Some(LANG::Javascript),
+        Some(b"ts") => Some(LANG::Typescript),
+        Some(b"tsx") => Some(LANG::Tsx),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{MetricsOptions, get_function_spaces_with_options};
+
+    use super::*;
+
+    #[test]
+    fn typescript_script() {
+        let source = "<template>
+  <p>{{ msg }}</p>
+</template>
+
+<script lang=\"ts\">
+type Kind = 'a' | 'b' | 'c';
+
+function f(x: number): number {
+  if (x > 0) {
+    return 1;
+  }
+  return 0;
+}
+</script>
+
+<style scoped>
+p { color: red; }
+</style>
+";
+        let (language, code) = extract_vue_script(source.as_bytes()).unwrap();
+        assert_eq!(language, LANG::Typescript);
+        assert_eq!(code.len(), source.len());
+
+        let path = PathBuf::from("foo.vue");
+        let options = MetricsOptions {
+            type_complexity: true,
+            ..Default::default()
+        };
+        let space =
+            get_function_spaces_with_options(&language, code, &path, None, &options).unwrap();
+
+        // The lines are the ones of the component
+        assert_eq!(space.spaces[0].name.as_deref(), Some("f"));
+        assert_eq!(space.spaces[0].start_line, 8);
+        assert_eq!(space.spaces[0].end_line, 13);
+        assert_eq!(space.spaces[0].metrics.cyclomatic.cyclomatic(), 2.);
+        // Type-level constructs are only parsed in TypeScript
+        assert_eq!(space.metrics.type_complexity.type_complexity_sum(), 3.);
+    }
+
+    #[test]
+    fn script_languages() {
+        let source = "<script>let a = 1;</script><script setup>let b = 2;</script>";
+        let (language, code) = extract_vue_script(source.as_bytes()).unwrap();
+        assert_eq!(language, LANG::Javascript);
+        assert_eq!(code.len(), source.len());
+        assert_eq!(
+            String::from_utf8(code)
+                .unwrap()
+                .split_whitespace()
+                .collect::<Vec<_>>(),
+            ["let", "a", "=", "1;", "let", "b", "=", "2;"]
+        );
+
+        let source = "<script lang='tsx'></script>";
+        assert_eq!(extract_vue_script(source.as_bytes()).unwrap().0, LANG::Tsx);
+
+        assert!(extract_vue_script(b"<script lang=\"ts\"></script><script></script>").is_none());
+        assert!(extract_vue_script(b"<script lang=\"coffee\"></script>").is_none());
+        assert!(extract_vue_script(b"<template></template>").is_none());
+    }
+
+    #[test]
+    fn quoted_attributes() {
+        // A `>` in a quoted value does not close the tag
+        let source = "<script setup lang=\"ts\" generic=\"T extends Foo<Bar>\">let a = 1;</script>";
+        let (language, code) = extract_vue_script(source.as_bytes()).unwrap();
+        assert_eq!(language, LANG::Typescript);
+        assert_eq!(String::from_utf8(code).unwrap().trim(), "let a = 1;");
+
+        let source = "<script generic='T extends Map<K, V>' lang='tsx'></script>";
+        assert_eq!(extract_vue_script(source.as_bytes()).unwrap().0, LANG::Tsx);
+
+        // An unquoted value
+        let source = "<script lang=ts></script>";
+        assert_eq!(
+            extract_vue_script(source.as_bytes()).unwrap().0,
+            LANG::Typescript
+        );
+
+        // A quoted value which is not closed
+        assert!(extract_vue_script(b"<script lang=\"ts></script>").is_none());
+    }
+
+    #[test]
+    fn spaces_around_equal() {
+        let source = "<script lang = \"ts\">let a = 1;</script>";
+        assert_eq!(
+            extract_vue_script(source.as_bytes()).unwrap().0,
+            LANG::Typescript
+        );
+
+        let source = "<script\n  setup\n  lang=\n  'tsx'\n>let a = 1;</script>";
+        assert_eq!(extract_vue_script(source.as_bytes()).unwrap().0, LANG::Tsx);
+
+        // A `lang` attribute without a value
+        assert!(extract_vue_script(b"<script lang></script>").is_some());
+    }
+
+    #[test]
+    fn tag_name_boundary() {
+        // Tags whose name starts with `script` are not scripts
+        assert!(extract_vue_script(b"<scripts>let a = 1;</scripts>").is_none());
+        assert!(extract_vue_script(b"<script-foo lang=\"ts\">let a = 1;</script-foo>").is_none());
+
+        let source = "<script-foo>x</script-foo><script>let a = 1;</scripts></script>";
+        let (language, code) = extract_vue_script(source.as_bytes()).unwrap();
+        assert_eq!(language, LANG::Javascript);
+        assert_eq!(
+            String::from_utf8(code).unwrap().trim(),
+            "let a = 1;</scripts>"
+        );
+
+        // A self-closing script has no body
+        let source = "<script src=\"./a.js\" /><template>x</template>";
+        let (_, code) = extract_vue_script(source.as_bytes()).unwrap();
+        assert!(code.iter().all(u8::is_ascii_whitespace));
+    }
+}